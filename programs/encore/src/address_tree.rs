@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use light_sdk::address::AddressSeed;
+
+/// Address tree version this build creates addresses in.
+///
+/// Builds use V2 address trees. The `v1-trees` feature switches to V1
+/// trees and the matching `derive_address`, for environments (some devnet
+/// setups, older local validators) that only expose V1 trees. Addresses
+/// derived under one version don't match the other, so a deployment
+/// must stick to one.
+#[cfg(not(feature = "v1-trees"))]
+const ADDRESS_TREE: [u8; 32] = light_sdk_types::ADDRESS_TREE_V2;
+#[cfg(feature = "v1-trees")]
+const ADDRESS_TREE: [u8; 32] = light_sdk_types::ADDRESS_TREE_V1;
+
+/// Reject address trees of the wrong version (skipped in test mode).
+#[allow(unused_variables)]
+pub fn check_address_tree(address_tree_pubkey: &Pubkey) -> Result<()> {
+    #[cfg(not(feature = "test-mode"))]
+    if address_tree_pubkey.to_bytes() != ADDRESS_TREE {
+        msg!("Invalid address tree: must use the build's address tree version");
+        return Err(ProgramError::InvalidAccountData.into());
+    }
+    Ok(())
+}
+
+/// Derive a compressed address (and its seed) for the build's tree version.
+pub fn derive_address(
+    seeds: &[&[u8]],
+    address_tree_pubkey: &Pubkey,
+    program_id: &Pubkey,
+) -> ([u8; 32], AddressSeed) {
+    #[cfg(not(feature = "v1-trees"))]
+    return light_sdk::address::v2::derive_address(seeds, address_tree_pubkey, program_id);
+    #[cfg(feature = "v1-trees")]
+    return light_sdk::address::v1::derive_address(seeds, address_tree_pubkey, program_id);
+}
@@ -0,0 +1,78 @@
+//! Basis-point arithmetic shared by fees, royalties, resale caps and
+//! refunds.
+//!
+//! # Rounding
+//! Every share rounds down. Fees, royalties and refunds therefore never
+//! take more than their exact share, and resale caps never allow more than
+//! the exact multiple. When an amount is split, the remainder goes to
+//! whoever receives "the rest" (the seller, the vault): see [`split`].
+//!
+//! # Overflow
+//! Products are taken in `u128`, so `amount * bps` can't overflow. Only a
+//! result above `u64::MAX` is possible, and only for `bps` above
+//! [`BPS_DENOMINATOR`] (resale caps); [`of`] reports it and [`share`]
+//! saturates.
+
+/// 100% in basis points.
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// `amount * bps / 10000`, rounded down, or None if the result exceeds `u64`.
+pub fn of(amount: u64, bps: u64) -> Option<u64> {
+    mul_div(amount, bps, BPS_DENOMINATOR)
+}
+
+/// `amount * bps / 10000`, rounded down and saturating at `u64::MAX`.
+pub fn share(amount: u64, bps: u64) -> u64 {
+    of(amount, bps).unwrap_or(u64::MAX)
+}
+
+/// Split `amount` into `(share, rest)` with `share` rounded down, so the
+/// two always add up to `amount`. `bps` above 100% takes everything.
+pub fn split(amount: u64, bps: u64) -> (u64, u64) {
+    let share = share(amount, bps).min(amount);
+    (share, amount - share)
+}
+
+/// `amount * numerator / denominator`, rounded down, or None if the
+/// denominator is zero or the result exceeds `u64`.
+pub fn mul_div(amount: u64, numerator: u64, denominator: u64) -> Option<u64> {
+    (amount as u128)
+        .checked_mul(numerator as u128)?
+        .checked_div(denominator as u128)
+        .and_then(|v| u64::try_from(v).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_down() {
+        assert_eq!(of(999, 250), Some(24)); // 24.975
+        assert_eq!(of(1, 9_999), Some(0));
+        assert_eq!(split(1_001, 5_000), (500, 501));
+    }
+
+    #[test]
+    fn split_adds_up() {
+        for amount in [0, 1, 7, 10_001, u64::MAX] {
+            for bps in [0, 1, 333, 5_000, 9_999, 10_000, 20_000] {
+                let (share, rest) = split(amount, bps);
+                assert_eq!(share + rest, amount);
+            }
+        }
+    }
+
+    #[test]
+    fn no_intermediate_overflow() {
+        assert_eq!(of(u64::MAX, 10_000), Some(u64::MAX));
+        assert_eq!(of(u64::MAX, 5_000), Some(u64::MAX / 2));
+    }
+
+    #[test]
+    fn result_overflow() {
+        assert_eq!(of(u64::MAX, 20_000), None);
+        assert_eq!(share(u64::MAX, 20_000), u64::MAX);
+        assert_eq!(mul_div(1, 1, 0), None);
+    }
+}
@@ -1,16 +1,105 @@
+use anchor_lang::prelude::*;
+
 pub const EVENT_SEED: &[u8] = b"event";
 pub const TICKET_SEED: &[u8] = b"ticket";
 pub const IDENTITY_COUNTER_SEED: &[u8] = b"identity_counter";
 pub const LISTING_SEED: &[u8] = b"listing";
 pub const ESCROW_SEED: &[u8] = b"escrow";
+pub const PROTOCOL_SEED: &[u8] = b"protocol";
+pub const BLOCKED_BUYER_SEED: &[u8] = b"blocked_buyer";
+pub const ATTESTATION_SEED: &[u8] = b"attestation";
+pub const INSURANCE_SEED: &[u8] = b"insurance";
+pub const INSURANCE_POOL_SEED: &[u8] = b"insurance_pool";
+pub const CREDIT_SEED: &[u8] = b"credit";
+pub const LEDGER_SEED: &[u8] = b"ledger";
+pub const PRICE_FEED_SEED: &[u8] = b"price_feed";
+pub const RECEIPT_SEED: &[u8] = b"receipt";
+pub const REDEMPTION_RECEIPT_SEED: &[u8] = b"redemption_receipt";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const FEE_EXEMPTION_SEED: &[u8] = b"fee_exemption";
+pub const CLIENT_NONCE_SEED: &[u8] = b"client_nonce";
+pub const QUEUED_CLAIM_SEED: &[u8] = b"queued_claim";
+pub const SELLER_COUNTER_SEED: &[u8] = b"seller_counter";
+pub const TRANSFER_REQUEST_SEED: &[u8] = b"transfer_request";
+pub const WAITLIST_SEED: &[u8] = b"waitlist";
+pub const WAITLIST_ENTRY_SEED: &[u8] = b"waitlist_entry";
+pub const PROCEEDS_HOLD_SEED: &[u8] = b"proceeds_hold";
+pub const ROYALTY_VAULT_SEED: &[u8] = b"royalty_vault";
+pub const PAYOUT_ACCRUAL_SEED: &[u8] = b"payout_accrual";
+pub const SCANNER_SEED: &[u8] = b"scanner";
+pub const REPUTATION_SEED: &[u8] = b"reputation";
+pub const PARTIAL_REFUND_SEED: &[u8] = b"partial_refund";
+pub const MINT_DELEGATE_SEED: &[u8] = b"mint_delegate";
+pub const ALLOCATION_SEED: &[u8] = b"allocation";
+pub const GIFT_SEED: &[u8] = b"gift";
+pub const ANNOUNCEMENTS_SEED: &[u8] = b"announcements";
+pub const ENTRANCE_STATS_SEED: &[u8] = b"entrance_stats";
+pub const SEAT_REASSIGNMENT_SEED: &[u8] = b"seat_reassignment";
+pub const FIAT_PROVIDER_SEED: &[u8] = b"fiat_provider";
+pub const FIAT_HOLD_SEED: &[u8] = b"fiat_hold";
+pub const PENDING_TRANSFER_SEED: &[u8] = b"pending_transfer";
+pub const FAN_ATTESTATION_SEED: &[u8] = b"fan_attestation";
+pub const SPONSOR_POOL_SEED: &[u8] = b"sponsor_pool";
+pub const GUARDIAN_SET_SEED: &[u8] = b"guardian_set";
+pub const TICKET_RECOVERY_SEED: &[u8] = b"ticket_recovery";
+pub const TICKET_EXCHANGE_SEED: &[u8] = b"ticket_exchange";
+pub const GATE_USE_SEED: &[u8] = b"gate_use";
+pub const METRICS_SEED: &[u8] = b"metrics";
+pub const DISCLOSURE_SEED: &[u8] = b"disclosure";
+pub const DOMAIN_SEED: &[u8] = b"domain";
 
 pub const MIN_RESALE_CAP_BPS: u32 = 10000;
 pub const MAX_RESALE_CAP_BPS: u32 = 100000;
 
 pub const MAX_TICKET_SUPPLY: u32 = 1_000_000;
 pub const CLAIM_TIMEOUT_SECONDS: i64 = 86400; // 24 hours
+pub const SELLER_BOND_BUYER_SHARE_BPS: u64 = 8000; // Rest goes to the protocol
+pub const LISTING_GC_GRACE_SECONDS: i64 = 7 * 86_400; // Time for the seller to close it themselves
+pub const LISTING_GC_BOUNTY_BPS: u64 = 1000; // Of the rent; the rest and any bond go to the seller
 
 pub const MAX_EVENT_LOCATION_LEN: usize = 64;
 pub const MAX_EVENT_DESCRIPTION_LEN: usize = 200;
 
 pub const MAX_EVENT_NAME_LEN: usize = 64;
+pub const MAX_POW_DIFFICULTY: u8 = 32;
+pub const POW_MAX_SLOT_AGE: u64 = 150; // ~1 minute
+pub const MAX_INSURANCE_BPS: u16 = 10000;
+pub const MAX_PRICE_AGE_SECONDS: i64 = 300; // 5 minutes
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1000; // 10%
+pub const MAX_ROYALTY_BPS: u16 = 2500; // 25%
+pub const MAX_ROYALTY_SPLITS: usize = 8;
+pub const MAX_CUSTOM_FIELDS: usize = 4;
+pub const MAX_ADDONS: usize = 8; // Entitlements fit a u8 bitmask
+pub const MAX_RELEASE_TRANCHES: usize = 8;
+pub const COMPLETION_AUTH_DOMAIN: &[u8] = b"encore:complete_sale";
+pub const SIGNED_ORDER_DOMAIN: &[u8] = b"encore:signed_order";
+pub const SNAPSHOT_LEAF_DOMAIN: &[u8] = b"encore:snapshot_leaf";
+pub const HOLDER_LEAF_DOMAIN: &[u8] = b"encore:holder_leaf";
+pub const UNBOUND_COMMITMENT_DOMAIN: &[u8] = b"encore:unbound_commitment";
+pub const KEY_NULLIFIER_DOMAIN: &[u8] = b"encore:key_nullifier";
+pub const GATE_USE_DOMAIN: &[u8] = b"encore:gate_use";
+pub const VIEW_KEY_DOMAIN: &[u8] = b"encore:view_key";
+pub const JUPITER_PROGRAM_ID: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+pub const MAX_MEMO_LEN: usize = 256;
+pub const MAX_CLAIM_QUEUE_LEN: u32 = 4;
+pub const MAX_PAYMENT_OPTIONS: usize = 3;
+pub const MAX_PRUNE_BATCH: usize = 8;
+pub const MAX_REDEEM_BATCH: usize = 4;
+pub const MAX_CHECKOUT_EVENTS: usize = 2; // One mint each, to fit a transaction
+pub const MAX_SNAPSHOT_PROOF_LEN: usize = 20; // 2^20 wallets
+pub const MAX_ANNOUNCEMENT_URI_LEN: usize = 200;
+pub const MAX_ENTRANCES: usize = 32; // Scanner zones are a u32 bitmask
+pub const DOOR_RELEASE_WINDOW_SECONDS: i64 = 86_400; // Show day: from 24h before start
+pub const ORACLE_CREDIT_VALIDITY_SECONDS: u32 = 365 * 86_400;
+pub const MAX_POLICY_REGIONS: usize = 16;
+pub const MAX_POLICY_VENUES: usize = 8;
+pub const MAX_GUARDIANS: usize = 8;
+pub const MAX_GUARDED_TICKETS: usize = 8;
+pub const MAX_DOMAIN_LEN: usize = 128;
+pub const MIN_RECOVERY_DELAY_SECONDS: i64 = 3 * 86_400; // Time for the holder to notice and veto
+
+/// Feature flags reported by `get_version`, one bit per Cargo feature
+pub const FEATURE_V1_TREES: u64 = 1 << 0;
+pub const FEATURE_TEST_MODE: u64 = 1 << 1;
+pub const FEATURE_STRICT_INVARIANTS: u64 = 1 << 2;
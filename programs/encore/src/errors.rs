@@ -82,4 +82,439 @@ pub enum EncoreError {
 
     #[msg("Listing not cancelled or completed")]
     ListingNotCancelled,
+
+    #[msg("Protocol admin co-signature required")]
+    AdminCosignRequired,
+
+    #[msg("Wallet is blocked for this event")]
+    BuyerBlocked,
+
+    #[msg("Identity counter does not match buyer")]
+    InvalidIdentityCounter,
+
+    #[msg("Mint rate limit reached, try again later")]
+    MintRateLimited,
+
+    #[msg("Too many tickets minted for this event in one slot")]
+    TooManyMintsInSlot,
+
+    #[msg("Proof-of-work difficulty exceeds maximum allowed")]
+    PowDifficultyTooHigh,
+
+    #[msg("Proof-of-work slot is too old or in the future")]
+    ProofOfWorkExpired,
+
+    #[msg("Proof-of-work does not meet event difficulty")]
+    ProofOfWorkInvalid,
+
+    #[msg("A valid credential attestation is required for this event")]
+    CredentialRequired,
+
+    #[msg("Event has been cancelled")]
+    EventCancelled,
+
+    #[msg("Insurance policy parameters are invalid")]
+    InvalidInsurancePolicy,
+
+    #[msg("Insurance is not offered for this event")]
+    InsuranceNotOffered,
+
+    #[msg("Insurance claim is outside the policy terms")]
+    InsuranceClaimNotAllowed,
+
+    #[msg("Insurance pool cannot cover this payout")]
+    InsufficientInsurancePool,
+
+    #[msg("Event has not been cancelled")]
+    EventNotCancelled,
+
+    #[msg("Refund credits are not enabled for this event")]
+    CreditsNotEnabled,
+
+    #[msg("Refund credit does not belong to this buyer or organizer")]
+    InvalidCredit,
+
+    #[msg("Refund credit has expired")]
+    CreditExpired,
+
+    #[msg("Price feed is stale")]
+    PriceFeedStale,
+
+    #[msg("Invalid price feed update")]
+    InvalidPriceFeed,
+
+    #[msg("Protocol fee exceeds maximum")]
+    InvalidProtocolFee,
+
+    #[msg("Insufficient treasury balance")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Invalid fee exemption")]
+    InvalidFeeExemption,
+
+    #[msg("Sale completion not signed or authorized by seller")]
+    CompletionNotAuthorized,
+
+    #[msg("Signed order has expired")]
+    OrderExpired,
+
+    #[msg("Signed order is missing a valid seller signature")]
+    InvalidOrderSignature,
+
+    #[msg("Ticket does not match the listing")]
+    ListingTicketMismatch,
+
+    #[msg("Claim queue is full")]
+    ClaimQueueFull,
+
+    #[msg("Listing has queued claims; promote the head of the queue")]
+    ClaimQueueNotEmpty,
+
+    #[msg("Queued claim is not at the head of the queue")]
+    NotQueueHead,
+
+    #[msg("Queued claim can only be refunded once the listing is finished")]
+    QueuedClaimStillActive,
+
+    #[msg("Queued claim was already withdrawn")]
+    ClaimAlreadyWithdrawn,
+
+    #[msg("Listing terms changed since the claim")]
+    ListingTermsChanged,
+
+    #[msg("Event is closed")]
+    EventClosed,
+
+    #[msg("Event has not taken place yet")]
+    EventNotOver,
+
+    #[msg("Event is not closed")]
+    EventNotClosed,
+
+    #[msg("Too many accounts to prune in one transaction")]
+    TooManyAccountsToPrune,
+
+    #[msg("Event requires an approved transfer request")]
+    TransferApprovalRequired,
+
+    #[msg("Transfer request has not been approved")]
+    TransferNotApproved,
+
+    #[msg("Transfer does not match the approved request")]
+    TransferRequestMismatch,
+
+    #[msg("Ticket is already personalized")]
+    TicketAlreadyPersonalized,
+
+    #[msg("Named ticket requires a valid ID-verification attestation")]
+    IdentityNotVerified,
+
+    #[msg("Linked tickets must be transferred and redeemed together")]
+    LinkedTicketRequired,
+
+    #[msg("No freed seats are available for this waitlist")]
+    NoOpenSeats,
+
+    #[msg("Waitlist entry is not at the head of the waitlist")]
+    NotWaitlistHead,
+
+    #[msg("Waitlist entry was already withdrawn")]
+    WaitlistEntryWithdrawn,
+
+    #[msg("Event policy hook program is missing or does not match")]
+    PolicyHookRequired,
+
+    #[msg("Compliance policy lists too many regions or venues")]
+    InvalidCompliancePolicy,
+
+    #[msg("Sales for this event must be paid through the program")]
+    PaidTransferRequired,
+
+    #[msg("Event holds resale proceeds: pass the proceeds hold account")]
+    ProceedsHoldRequired,
+
+    #[msg("Proceeds hold does not belong to this ticket")]
+    ProceedsHoldMismatch,
+
+    #[msg("Proceeds are held until the ticket is redeemed or the event has passed")]
+    ProceedsStillHeld,
+
+    #[msg("Proceeds can only be reclaimed after the ticket was voided")]
+    ProceedsNotReclaimable,
+
+    #[msg("Report hash does not match the reported figures")]
+    ReportHashMismatch,
+
+    #[msg("Royalty must be within the cap and its splits must sum to 100%")]
+    InvalidRoyaltyConfig,
+
+    #[msg("Event charges a royalty: pass its royalty vault")]
+    RoyaltyVaultRequired,
+
+    #[msg("Royalty recipients must be passed in split order")]
+    RoyaltyRecipientMismatch,
+
+    #[msg("Payout accrual does not belong to this event and recipient")]
+    PayoutAccrualMismatch,
+
+    #[msg("No payout owed")]
+    NothingToClaim,
+
+    #[msg("Nullifier does not belong to this event")]
+    InvalidNullifier,
+
+    #[msg("Custom field values do not match the event's schema")]
+    InvalidCustomFields,
+
+    #[msg("Custom field schema can't change once tickets are minted")]
+    CustomFieldsLocked,
+
+    #[msg("Add-on does not exist or the event has no room for more")]
+    InvalidAddOn,
+
+    #[msg("Add-on is sold out")]
+    AddOnSoldOut,
+
+    #[msg("Release tranches must be in time order and fit within max supply")]
+    InvalidReleaseSchedule,
+
+    #[msg("Reserved supply must cover box-office sales and fit within max supply")]
+    InvalidReservedSupply,
+
+    #[msg("Official exchange listings need the protocol config and event accounts")]
+    ExchangeAccountsRequired,
+
+    #[msg("Official exchange price must equal face value plus fees")]
+    ExchangePriceMismatch,
+
+    #[msg("Listing doesn't accept this payment mint for this sale")]
+    UnsupportedPaymentMint,
+
+    #[msg("Token payments need the mint's token accounts and token program")]
+    TokenAccountsRequired,
+
+    #[msg("Payment options must be unique mints with nonzero prices")]
+    InvalidPaymentOptions,
+
+    #[msg("Token account doesn't match the claim's payment mint")]
+    PaymentMintMismatch,
+
+    #[msg("Swap must be followed by mint_ticket or claim_listing")]
+    PurchaseRequired,
+
+    #[msg("Swap returned less than the minimum output")]
+    SwapOutputTooLow,
+
+    #[msg("Memo must be non-empty UTF-8 within the length limit")]
+    InvalidMemo,
+
+    #[msg("This event requires a memo justifying the void")]
+    MemoRequired,
+
+    #[msg("Ticket address mode can't change once tickets are minted")]
+    TicketAddressModeLocked,
+
+    #[msg("Secret isn't derived with the event's secret derivation standard")]
+    NonStandardSecret,
+
+    #[msg("Batch must hold between one and the maximum number of tickets")]
+    InvalidRedeemBatch,
+
+    #[msg("Scanner needs at least one zone and a future expiry")]
+    InvalidScanner,
+
+    #[msg("Scanner isn't authorized for this zone")]
+    ScannerNotAuthorized,
+
+    #[msg("Bonded listings time out through sweep_expired_claim")]
+    SellerBondPosted,
+
+    #[msg("Partial refund needs 1-10000 bps, a future deadline and a nonzero amount")]
+    InvalidPartialRefund,
+
+    #[msg("Partial refund claim deadline has passed")]
+    PartialRefundClosed,
+
+    #[msg("Partial refund can't be closed before its claim deadline")]
+    PartialRefundOpen,
+
+    #[msg("Partial refund payout is zero or below the holder's minimum")]
+    PartialRefundUnderfunded,
+
+    #[msg("Checkout must be followed only by the buyer's mints, one per listed event")]
+    InvalidCheckout,
+
+    #[msg("Cart total exceeds the buyer's maximum")]
+    CheckoutTotalExceeded,
+
+    #[msg("Delegate quota must be positive and cover tickets already minted")]
+    InvalidMintDelegate,
+
+    #[msg("Mint delegate has used its quota")]
+    MintDelegateQuotaExceeded,
+
+    #[msg("Allocation needs a positive size and a future deadline")]
+    InvalidAllocation,
+
+    #[msg("Allocation can't be returned before its deadline")]
+    AllocationActive,
+
+    #[msg("Allocation is fully minted or past its deadline")]
+    AllocationExhausted,
+
+    #[msg("Comp mints need exactly one of a mint delegate or an allocation")]
+    CompSourceRequired,
+
+    #[msg("Resale is disabled during the blackout after the on-sale")]
+    ResaleBlackout,
+
+    #[msg("This event's resale cap is USD-adjusted; pass the price feed")]
+    PriceFeedRequired,
+
+    #[msg("State invariant violated")]
+    InvariantViolated,
+
+    #[msg("Presale needs a source event with a published snapshot and a future end")]
+    InvalidPresale,
+
+    #[msg("Buyer is not in the presale snapshot")]
+    NotOnPresaleAllowlist,
+
+    #[msg("Gift expiry must be in the future")]
+    InvalidGiftExpiry,
+
+    #[msg("Gift link has expired")]
+    GiftExpired,
+
+    #[msg("Gift link has not expired yet")]
+    GiftNotExpired,
+
+    #[msg("Announcement URI must be 1 to 200 bytes")]
+    InvalidAnnouncement,
+
+    #[msg("Scanner-attested redemptions must pass the entrance stats account")]
+    EntranceStatsRequired,
+
+    #[msg("Door release must be positive, within the step and the fire-code capacity")]
+    InvalidDoorRelease,
+
+    #[msg("Door releases open 24 hours before the event")]
+    DoorReleaseNotOpen,
+
+    #[msg("Seat reassignment must target a minted ticket")]
+    InvalidSeatReassignment,
+
+    #[msg("Fiat provider needs a positive settlement window and price cap")]
+    InvalidFiatProvider,
+
+    #[msg("Fiat hold is still inside its settlement window")]
+    FiatHoldPending,
+
+    #[msg("Fiat hold is past its settlement window")]
+    FiatHoldFinal,
+
+    #[msg("Holder count exceeds live tickets")]
+    InvalidHolderCount,
+
+    #[msg("Fan lane share must be at most 100% with a positive window")]
+    InvalidFanLane,
+
+    #[msg("Sponsor pool needs a positive per-mint amount within the per-user limit")]
+    InvalidSponsorPool,
+
+    #[msg("Buyer has used up their fee sponsorship")]
+    SponsorshipLimitReached,
+
+    #[msg("Sponsor pool balance is too low")]
+    SponsorPoolEmpty,
+
+    #[msg("Identity counter epochs cannot change once tickets are minted")]
+    IdentityEpochLocked,
+
+    #[msg("Validity proof is stale; fetch a new one and retry")]
+    StaleProof,
+
+    #[msg("Merkle tree is full; retry with another tree")]
+    TreeCapacityExceeded,
+
+    #[msg("Validity proof is past its slot window; fetch a new one and retry")]
+    ProofExpired,
+
+    #[msg("Bound ticket must use a fresh secret")]
+    SecretRotationRequired,
+
+    #[msg("Keyed ticket commitment must be its ownership key")]
+    InvalidOwnershipKey,
+
+    #[msg("Unbound tickets must be bound to a wallet first")]
+    UnboundTicket,
+
+    #[msg("Guardian threshold must be between 1 and the number of distinct guardians")]
+    InvalidGuardianSet,
+
+    #[msg("Recovery delay is below the minimum")]
+    RecoveryDelayTooShort,
+
+    #[msg("Signer is not a guardian for this holder")]
+    NotGuardian,
+
+    #[msg("Guardian set already guards the maximum number of tickets")]
+    TooManyGuardedTickets,
+
+    #[msg("Ticket is not guarded by this guardian set")]
+    TicketNotGuarded,
+
+    #[msg("Guardian already approved this recovery")]
+    RecoveryAlreadyApproved,
+
+    #[msg("Recovery is to a different commitment")]
+    RecoveryMismatch,
+
+    #[msg("Recovery needs more approvals or is still in its veto window")]
+    RecoveryLocked,
+
+    #[msg("Ticket exchange needs another event, a size and a future deadline")]
+    InvalidTicketExchange,
+
+    #[msg("Ticket exchange is past its deadline or has no tickets left")]
+    TicketExchangeClosed,
+
+    #[msg("Ticket exchange is still open")]
+    TicketExchangeOpen,
+
+    #[msg("Ticket doesn't meet the gate's requirements")]
+    TicketGateNotMet,
+
+    #[msg("Single-use verification must be signed by the verifier")]
+    VerifierRequired,
+
+    #[msg("Domain must be a lowercase host name matching its hash")]
+    InvalidDomain,
+
+    #[msg("Payment currency does not match the event's settlement mint")]
+    SettlementMintMismatch,
+
+    #[msg("Listing can't be collected until its grace period ends")]
+    ListingGracePeriod,
+
+    #[msg("An event with different parameters already exists for this authority")]
+    EventConfigMismatch,
+
+    #[msg("Signer is not the program's upgrade authority")]
+    UpgradeAuthorityRequired,
+
+    #[msg("Queued claim does not belong to this listing")]
+    QueuedClaimMismatch,
+
+    #[msg("Events can only settle in SOL")]
+    UnsupportedSettlementMint,
+
+    #[msg("Swap must fund a purchase made by the same buyer")]
+    PurchaseBuyerMismatch,
+
+    #[msg("Fiat hold price exceeds the provider's maximum")]
+    FiatHoldPriceTooHigh,
+
+    #[msg("Only tickets held under the seller's key can be listed")]
+    ListingRequiresKeyedTicket,
 }
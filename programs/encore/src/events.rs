@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{
+    AddOn, CompliancePolicy, CredentialRequirement, FanLane, FeeBreakdown, FeeSource, FieldDef,
+    FieldValue, HoldStatus, InsurancePolicy, LedgerEntryKind, Presale, ReleaseTranche,
+    RoyaltyConfig, SettlementReport, TransferKind, VoidReason,
+};
+
 #[event]
 pub struct EventCreated {
     pub event_config: Pubkey,
@@ -12,6 +18,7 @@ pub struct EventCreated {
     pub event_description: String,
     pub max_tickets_per_person: u8,
     pub event_timestamp: i64,
+    pub settlement_mint: Option<Pubkey>,
 }
 
 #[event]
@@ -24,12 +31,30 @@ pub struct EventUpdated {
 #[event]
 pub struct TicketMinted {
     pub event_config: Pubkey,
-    pub purchase_price: u64,
+    pub tickets_minted: u32,
+    /// Set under `EmissionPolicy::Public`
+    pub ticket_id: Option<u32>,
+    pub owner_commitment: Option<[u8; 32]>,
+    /// Omitted under `EmissionPolicy::Aggregate`
+    pub purchase_price: Option<u64>,
+    pub purchase_price_usd_micros: Option<u64>,
+    pub fees: Option<FeeBreakdown>,
+    /// Solana Pay reference, if the purchase carried one
+    pub reference: Option<Pubkey>,
 }
 
 #[event]
 pub struct TicketTransferred {
     pub event_config: Pubkey,
+    pub gift_transfers: u32,
+    pub sale_transfers: u32,
+    /// Set under `EmissionPolicy::Public`
+    pub ticket_id: Option<u32>,
+    pub new_owner_commitment: Option<[u8; 32]>,
+    /// Omitted under `EmissionPolicy::Aggregate`
+    pub kind: Option<TransferKind>,
+    pub protocol_fee: Option<u64>,
+    pub paid_in_protocol: Option<bool>,
 }
 
 #[event]
@@ -40,4 +65,673 @@ pub struct SaleCompleted {
     pub event_config: Pubkey,
     pub ticket_id: u32,
     pub price_lamports: u64,
+    pub official_exchange: bool,
+    pub payment_mint: Option<Pubkey>,
+    pub fees: FeeBreakdown,
+}
+
+#[event]
+pub struct TicketVoided {
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub reason: VoidReason,
+    pub voided_at: i64,
+    /// Hash of the SPL memo justifying the void, if one was attached
+    pub memo_hash: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct BlocklistUpdated {
+    pub event_config: Pubkey,
+    pub wallet: Pubkey,
+    pub blocked: bool,
+}
+
+#[event]
+pub struct CredentialRequirementUpdated {
+    pub event_config: Pubkey,
+    pub required_credential: Option<CredentialRequirement>,
+}
+
+#[event]
+pub struct EventCancelled {
+    pub event_config: Pubkey,
+    pub authority: Pubkey,
+    pub cancelled_at: i64,
+}
+
+#[event]
+pub struct InsurancePolicyUpdated {
+    pub event_config: Pubkey,
+    pub insurance_policy: Option<InsurancePolicy>,
+}
+
+#[event]
+pub struct InsuranceClaimed {
+    pub event_config: Pubkey,
+    pub coverage: Pubkey,
+    pub holder: Pubkey,
+    pub payout_lamports: u64,
+    pub event_cancelled: bool,
+    pub memo_hash: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct RefundCreditIssued {
+    pub event_config: Pubkey,
+    pub organizer: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub memo_hash: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct RefundCreditRedeemed {
+    pub event_config: Pubkey,
+    pub organizer: Pubkey,
+    pub owner: Pubkey,
+    pub amount_applied: u64,
+    pub amount_remaining: u64,
+}
+
+#[event]
+pub struct LedgerEntryRecorded {
+    pub event_config: Pubkey,
+    pub sequence: u64,
+    pub kind: LedgerEntryKind,
+    pub amount: u64,
+    pub recorded_at: i64,
+}
+
+#[event]
+pub struct PriceFeedUpdated {
+    pub usd_micros_per_sol: u64,
+    pub updated_at: i64,
+}
+
+#[event]
+pub struct ProtocolFeeCollected {
+    pub event_config: Pubkey,
+    pub source: FeeSource,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub admin: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeeExemptionUpdated {
+    pub subject: Pubkey,
+    pub discount_bps: u16,
+}
+
+#[event]
+pub struct SignedOrderFilled {
+    pub event_config: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub ticket_id: u32,
+    pub price_lamports: u64,
+    pub nonce: u64,
+    pub reference: Option<Pubkey>,
+}
+
+#[event]
+pub struct ClaimQueued {
+    pub listing: Pubkey,
+    pub buyer: Pubkey,
+    pub position: u32,
+}
+
+#[event]
+pub struct QueuedClaimPromoted {
+    pub listing: Pubkey,
+    pub buyer: Pubkey,
+    pub position: u32,
+}
+
+#[event]
+pub struct EventClosed {
+    pub event_config: Pubkey,
+    pub closed_at: i64,
+}
+
+#[event]
+pub struct EventAccountsPruned {
+    pub event_config: Pubkey,
+    pub identity_counters: u8,
+    pub nullifiers: u8,
+}
+
+#[event]
+pub struct TransferRequested {
+    pub event_config: Pubkey,
+    pub transfer_request: Pubkey,
+    pub holder: Pubkey,
+    pub kind: TransferKind,
+}
+
+#[event]
+pub struct TransferRequestReviewed {
+    pub event_config: Pubkey,
+    pub transfer_request: Pubkey,
+    pub reviewer: Pubkey,
+    pub approved: bool,
+}
+
+#[event]
+pub struct IdVerificationUpdated {
+    pub event_config: Pubkey,
+    pub id_verification: Option<CredentialRequirement>,
+}
+
+#[event]
+pub struct TicketPersonalized {
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+}
+
+#[event]
+pub struct TicketRedeemed {
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub named: bool,
+    /// Add-on entitlements to hand out at the door
+    pub addons: u8,
+    /// Entrance, when a scanner attested the redemption
+    pub zone: Option<u8>,
+}
+
+#[event]
+pub struct WaitlistJoined {
+    pub waitlist: Pubkey,
+    pub buyer: Pubkey,
+    pub position: u32,
+}
+
+#[event]
+pub struct WaitlistSeatReleased {
+    pub waitlist: Pubkey,
+    pub tier: u8,
+    pub open_seats: u32,
+}
+
+#[event]
+pub struct WaitlistPromoted {
+    pub event_config: Pubkey,
+    pub waitlist: Pubkey,
+    pub tier: u8,
+    pub buyer: Pubkey,
+    pub ticket_id: u32,
+}
+
+#[event]
+pub struct CompliancePolicyUpdated {
+    pub event_config: Pubkey,
+    pub compliance_policy: Option<CompliancePolicy>,
+}
+
+#[event]
+pub struct ProceedsReleased {
+    pub proceeds_hold: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub status: HoldStatus,
+}
+
+#[event]
+pub struct ReportPublished {
+    pub event_config: Pubkey,
+    pub report_hash: [u8; 32],
+    pub report: SettlementReport,
+    pub matches_counters: bool,
+    pub published_at: i64,
+}
+
+#[event]
+pub struct ReportDiscrepancy {
+    pub event_config: Pubkey,
+    pub report_hash: [u8; 32],
+    pub reported: SettlementReport,
+    pub on_chain: SettlementReport,
+}
+
+#[event]
+pub struct RoyaltyConfigUpdated {
+    pub event_config: Pubkey,
+    pub royalty: Option<RoyaltyConfig>,
+}
+
+#[event]
+pub struct RoyaltiesDistributed {
+    pub event_config: Pubkey,
+    pub amount: u64,
+    pub recipients: u8,
+}
+
+#[event]
+pub struct PayoutClaimed {
+    pub event_config: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct NullifierAudit {
+    pub event_config: Pubkey,
+    pub nullifiers_created: u64,
+    pub transfers: u64,
+    pub marketplace_sales: u32,
+    pub reissues: u32,
+    pub redemptions: u32,
+    pub refunds: u32,
+    pub balanced: bool,
+}
+
+#[event]
+pub struct CustomFieldsUpdated {
+    pub event_config: Pubkey,
+    pub custom_fields: Vec<FieldDef>,
+}
+
+#[event]
+pub struct AddOnAdded {
+    pub event_config: Pubkey,
+    pub index: u8,
+    pub addon: AddOn,
+}
+
+#[event]
+pub struct ReleaseScheduleUpdated {
+    pub event_config: Pubkey,
+    pub release_schedule: Vec<ReleaseTranche>,
+}
+
+#[event]
+pub struct ExchangeListingCreated {
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub face_value_lamports: u64,
+    pub price_lamports: u64,
+}
+
+#[event]
+pub struct ListingClaimed {
+    pub listing: Pubkey,
+    pub buyer: Pubkey,
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub payment_mint: Option<Pubkey>,
+    pub reference: Option<Pubkey>,
+}
+
+#[event]
+pub struct TicketIndexed {
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub address: [u8; 32],
+}
+
+#[event]
+pub struct ScannerUpdated {
+    pub event_config: Pubkey,
+    pub device_key: Pubkey,
+    pub zones: u32,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct SellerBondForfeited {
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub to_buyer: u64,
+    pub to_protocol: u64,
+}
+
+#[event]
+pub struct PartialRefundOpened {
+    pub event_config: Pubkey,
+    pub refund_bps: u16,
+    pub claim_deadline: i64,
+}
+
+#[event]
+pub struct PartialRefundClaimed {
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub holder: Pubkey,
+    pub owed: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PartialRefundToppedUp {
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct CheckoutCompleted {
+    pub buyer: Pubkey,
+    pub event_configs: Vec<Pubkey>,
+    pub tickets: u32,
+    pub total_lamports: u64,
+}
+
+#[event]
+pub struct MintDelegateUpdated {
+    pub event_config: Pubkey,
+    pub delegate: Pubkey,
+    pub quota: u32,
+    pub minted: u32,
+}
+
+#[event]
+pub struct AllocationUpdated {
+    pub event_config: Pubkey,
+    pub partner: Pubkey,
+    pub size: u32,
+    pub minted: u32,
+    pub returned: u32,
+}
+
+#[event]
+pub struct ResaleCapBaseUpdated {
+    pub event_config: Pubkey,
+    pub usd_micros_per_sol: Option<u64>,
+}
+
+#[event]
+pub struct ListingPriced {
+    pub listing: Pubkey,
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub price_lamports: u64,
+    pub original_price: u64,
+    pub max_price_lamports: Option<u64>,
+}
+
+#[event]
+pub struct SnapshotPublished {
+    pub event_config: Pubkey,
+    pub snapshot_root: [u8; 32],
+}
+
+#[event]
+pub struct PresaleUpdated {
+    pub event_config: Pubkey,
+    pub presale: Option<Presale>,
+}
+
+#[event]
+pub struct GiftCreated {
+    pub gift: Pubkey,
+    pub event_config: Pubkey,
+    pub sender: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct GiftClaimed {
+    pub gift: Pubkey,
+    pub event_config: Pubkey,
+    pub new_owner_commitment: [u8; 32],
+}
+
+#[event]
+pub struct GiftCancelled {
+    pub gift: Pubkey,
+    pub event_config: Pubkey,
+}
+
+#[event]
+pub struct GiftExpired {
+    pub gift: Pubkey,
+    pub event_config: Pubkey,
+}
+
+#[event]
+pub struct AnnouncementPosted {
+    pub event_config: Pubkey,
+    pub sequence: u32,
+    pub content_hash: [u8; 32],
+    pub uri: String,
+    pub head: [u8; 32],
+    pub posted_at: i64,
+}
+
+#[event]
+pub struct DoorCapacityReleased {
+    pub event_config: Pubkey,
+    pub device: Pubkey,
+    pub amount: u32,
+    pub max_supply: u32,
+}
+
+#[event]
+pub struct SeatReassignmentProposed {
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub custom_fields: Vec<FieldValue>,
+    pub compensation: u64,
+}
+
+#[event]
+pub struct SeatReassignmentResolved {
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub accepted: bool,
+}
+
+#[event]
+pub struct ForceMajeureCancelled {
+    pub event_config: Pubkey,
+    pub oracle: Pubkey,
+    pub attestation_hash: [u8; 32],
+}
+
+#[event]
+pub struct FiatProviderUpdated {
+    pub event_config: Pubkey,
+    pub psp: Pubkey,
+    pub settlement_seconds: u32,
+    pub max_price: u64,
+}
+
+#[event]
+pub struct FiatHoldCreated {
+    pub event_config: Pubkey,
+    pub psp: Pubkey,
+    pub ticket_id: u32,
+    pub finalizes_at: i64,
+}
+
+#[event]
+pub struct FiatHoldResolved {
+    pub event_config: Pubkey,
+    pub psp: Pubkey,
+    pub ticket_id: u32,
+    pub finalized: bool,
+}
+
+#[event]
+pub struct HolderCountPublished {
+    pub event_config: Pubkey,
+    pub distinct_holders: u32,
+    pub live_tickets: u32,
+    pub holders_root: [u8; 32],
+    pub as_of_nullifier: u64,
+    pub published_at: i64,
+}
+
+#[event]
+pub struct MintPreviewed {
+    pub event_config: Pubkey,
+    pub buyer: Pubkey,
+    pub seats: u32,
+    pub from_reserve: bool,
+    pub fees: FeeBreakdown,
+    pub credit_applied: u64,
+    pub insurance_premium: u64,
+    pub total_due: u64,
+    pub purchase_price_usd_micros: Option<u64>,
+}
+
+#[event]
+pub struct SalePreviewed {
+    pub listing: Pubkey,
+    pub event_config: Pubkey,
+    pub buyer: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub proceeds_held: bool,
+    pub fees: FeeBreakdown,
+}
+
+#[event]
+pub struct TransferPrepared {
+    pub event_config: Pubkey,
+    pub pending_transfer: Pubkey,
+    pub holder: Pubkey,
+}
+
+#[event]
+pub struct PendingTransferCancelled {
+    pub event_config: Pubkey,
+    pub pending_transfer: Pubkey,
+}
+
+#[event]
+pub struct FanLaneUpdated {
+    pub event_config: Pubkey,
+    pub fan_lane: Option<FanLane>,
+}
+
+#[event]
+pub struct FanAttestationUpdated {
+    pub event_config: Pubkey,
+    pub fan: Pubkey,
+    pub active: bool,
+}
+
+#[event]
+pub struct SponsorPoolUpdated {
+    pub event_config: Pubkey,
+    pub relayer: Pubkey,
+    pub lamports_per_mint: u64,
+    pub max_per_user: u64,
+}
+
+#[event]
+pub struct GasSponsored {
+    pub event_config: Pubkey,
+    pub relayer: Pubkey,
+    pub buyer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ProofWindowExpired {
+    pub proof_slot: u64,
+    pub expires_at_slot: u64,
+    pub current_slot: u64,
+}
+
+#[event]
+pub struct TicketBound {
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+}
+
+#[event]
+pub struct GuardianSetConfigured {
+    pub holder: Pubkey,
+    pub guardians: u8,
+    pub threshold: u8,
+    pub recovery_delay_seconds: i64,
+}
+
+#[event]
+pub struct RecoveryApproved {
+    pub guardian_set: Pubkey,
+    pub ticket_commitment: [u8; 32],
+    pub guardian: Pubkey,
+    pub approvals: u8,
+    pub unlocks_at: Option<i64>,
+}
+
+#[event]
+pub struct RecoveryVetoed {
+    pub guardian_set: Pubkey,
+    pub ticket_commitment: [u8; 32],
+}
+
+#[event]
+pub struct TicketRecovered {
+    pub event_config: Pubkey,
+    pub guardian_set: Pubkey,
+    pub ticket_id: u32,
+}
+
+#[event]
+pub struct CancelledEventClaimRefunded {
+    pub event_config: Pubkey,
+    pub listing: Pubkey,
+    pub buyer: Pubkey,
+    pub payment_mint: Option<Pubkey>,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TicketExchangeUpdated {
+    pub from_event: Pubkey,
+    pub to_event: Pubkey,
+    pub price_lamports: u64,
+    pub size: u32,
+    pub exchanged: u32,
+    pub returned: u32,
+}
+
+#[event]
+pub struct TicketExchanged {
+    pub from_event: Pubkey,
+    pub to_event: Pubkey,
+    pub amount_paid: u64,
+    pub amount_credited: u64,
+}
+
+#[event]
+pub struct DomainRegistryUpdated {
+    pub domain: String,
+    pub domain_hash: [u8; 32],
+    pub release_hash: Option<[u8; 32]>,
+    pub registered: bool,
+}
+
+#[event]
+pub struct PartialRefundPreviewed {
+    pub event_config: Pubkey,
+    pub owed: u64,
+    pub payout: u64,
+    pub available: u64,
+    pub outstanding: u64,
+    pub shortfall: u64,
+}
+
+#[event]
+pub struct ListingCollected {
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub cranker: Pubkey,
+    pub bounty: u64,
 }
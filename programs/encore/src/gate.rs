@@ -0,0 +1,103 @@
+//! Interface for programs that gate on Encore tickets: merch drops,
+//! Discord verifiers, partner perks.
+//!
+//! A gated program CPIs `verify_ticket` with its `TicketGate`, with the
+//! holder signing the outer transaction, and reads the `TicketPass` from
+//! the return data. The call fails unless the holder has a live ticket
+//! that passes the gate. Redeemed and spent tickets no longer exist, so
+//! every pass is for an unredeemed ticket.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::GATE_USE_DOMAIN;
+use crate::state::{FieldValue, PrivateTicket};
+
+/// A custom-field value the ticket must hold, e.g. a "tier" field.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GateField {
+    /// Position in the event's custom-field schema
+    pub index: u8,
+    pub value: FieldValue,
+}
+
+/// What a gated program requires of a ticket.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TicketGate {
+    /// Event the ticket must belong to
+    pub event_config: Pubkey,
+
+    /// Custom field the ticket must match, if any
+    pub field: Option<GateField>,
+
+    /// Add-ons the ticket must include, as a bitmask
+    pub addons: u8,
+}
+
+impl TicketGate {
+    pub fn admits(&self, ticket: &PrivateTicket) -> bool {
+        ticket.event_config == self.event_config
+            && ticket.addons & self.addons == self.addons
+            && self.field.is_none_or(|field| {
+                ticket.custom_fields.get(field.index as usize) == Some(&field.value)
+            })
+    }
+}
+
+/// Returned by `verify_ticket`: the holder passed `gate`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TicketPass {
+    pub gate: TicketGate,
+
+    /// Wallet that proved it holds the ticket
+    pub holder: Pubkey,
+
+    /// Seat the pass was issued for; stable across transfers
+    pub ticket_id: u32,
+
+    /// Verifier and scope the pass was used up under, for single-use passes
+    pub single_use: Option<(Pubkey, [u8; 32])>,
+}
+
+/// Address seed of the `GateUse` recording that `verifier` has accepted
+/// a ticket under `scope`.
+///
+/// Keyed by the seat rather than the secret, so handing the ticket to
+/// another wallet doesn't earn a second use.
+pub fn gate_use_seed(
+    verifier: &Pubkey,
+    scope: &[u8; 32],
+    event_config: &Pubkey,
+    ticket_id: u32,
+) -> [u8; 32] {
+    hashv(&[
+        GATE_USE_DOMAIN,
+        verifier.as_ref(),
+        scope,
+        event_config.as_ref(),
+        &ticket_id.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Implemented by a gated program's config to describe its gate, so its
+/// instructions build `verify_ticket` calls and check passes the same way.
+pub trait TicketGated {
+    fn ticket_gate(&self) -> TicketGate;
+
+    /// Scope a pass is used up under, e.g. one per drop; `None` lets a
+    /// holder verify any number of times.
+    fn use_scope(&self) -> Option<[u8; 32]> {
+        None
+    }
+
+    /// True if `pass` came from a `verify_ticket` call for this gate,
+    /// signed by `verifier`.
+    fn accepts(&self, pass: &TicketPass, verifier: &Pubkey) -> bool {
+        pass.gate == self.ticket_gate()
+            && match self.use_scope() {
+                Some(scope) => pass.single_use == Some((*verifier, scope)),
+                None => true,
+            }
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ALLOCATION_SEED, EVENT_SEED};
+use crate::errors::EncoreError;
+use crate::events::AllocationUpdated;
+use crate::invariants;
+use crate::state::{Allocation, EventConfig};
+
+#[derive(Accounts)]
+#[instruction(partner: Pubkey)]
+pub struct CreateAllocation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Allocation::INIT_SPACE,
+        seeds = [ALLOCATION_SEED, event_config.key().as_ref(), partner.as_ref()],
+        bump
+    )]
+    pub allocation: Account<'info, Allocation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Carve `size` tickets out of public supply for `partner`, who mints them
+/// with `mint_comp_ticket` until `deadline` (event authority only).
+pub fn create_allocation(
+    ctx: Context<CreateAllocation>,
+    partner: Pubkey,
+    size: u32,
+    deadline: i64,
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    require!(
+        size > 0 && deadline > Clock::get()?.unix_timestamp,
+        EncoreError::InvalidAllocation
+    );
+    require!(
+        event_config.can_allocate(size),
+        EncoreError::MaxSupplyReached
+    );
+
+    event_config.allocated_supply += size;
+
+    let allocation = &mut ctx.accounts.allocation;
+    allocation.event_config = event_config.key();
+    allocation.partner = partner;
+    allocation.size = size;
+    allocation.minted = 0;
+    allocation.deadline = deadline;
+    allocation.bump = ctx.bumps.allocation;
+
+    emit!(AllocationUpdated {
+        event_config: allocation.event_config,
+        partner,
+        size,
+        minted: 0,
+        returned: 0,
+    });
+
+    msg!("✅ Allocation of {} tickets created for {}", size, partner);
+
+    invariants::check_event(&ctx.accounts.event_config)?;
+
+    Ok(())
+}
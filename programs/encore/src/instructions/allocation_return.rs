@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ALLOCATION_SEED, EVENT_SEED};
+use crate::errors::EncoreError;
+use crate::events::AllocationUpdated;
+use crate::invariants;
+use crate::state::{Allocation, EventConfig};
+
+#[derive(Accounts)]
+pub struct ReturnAllocation<'info> {
+    /// Anyone may crank an expired allocation back to supply
+    pub payer: Signer<'info>,
+
+    /// CHECK: Event authority, receives the allocation's rent
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Closed; rent returns to the authority
+    #[account(
+        mut,
+        seeds = [ALLOCATION_SEED, event_config.key().as_ref(), allocation.partner.as_ref()],
+        bump = allocation.bump,
+        close = authority,
+    )]
+    pub allocation: Account<'info, Allocation>,
+}
+
+/// Return a partner allocation's unminted tickets to public supply once
+/// its deadline has passed. Permissionless, so unused partner holds can't
+/// strand supply if the organizer doesn't act.
+pub fn return_allocation(ctx: Context<ReturnAllocation>) -> Result<()> {
+    let allocation = &ctx.accounts.allocation;
+    require!(
+        Clock::get()?.unix_timestamp > allocation.deadline,
+        EncoreError::AllocationActive
+    );
+
+    let returned = allocation.remaining();
+    let event_config = &mut ctx.accounts.event_config;
+    event_config.allocated_supply = event_config.allocated_supply.saturating_sub(returned);
+
+    emit!(AllocationUpdated {
+        event_config: allocation.event_config,
+        partner: allocation.partner,
+        size: allocation.size,
+        minted: allocation.minted,
+        returned,
+    });
+
+    msg!(
+        "✅ {} allocated tickets returned to public supply",
+        returned
+    );
+
+    invariants::check_event(&ctx.accounts.event_config)?;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ANNOUNCEMENTS_SEED, EVENT_SEED, MAX_ANNOUNCEMENT_URI_LEN};
+use crate::errors::EncoreError;
+use crate::events::AnnouncementPosted;
+use crate::state::{Announcements, EventConfig};
+
+#[derive(Accounts)]
+pub struct PostAnnouncement<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Created with the first notice
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Announcements::INIT_SPACE,
+        seeds = [ANNOUNCEMENTS_SEED, event_config.key().as_ref()],
+        bump
+    )]
+    pub announcements: Account<'info, Announcements>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Post an official notice to the event's ticket holders (event authority
+/// only).
+///
+/// `content_hash` is the SHA-256 of the notice served at `uri`. Wallets
+/// showing tickets for the event follow `AnnouncementPosted` and reject
+/// content that doesn't hash to it.
+pub fn post_announcement(
+    ctx: Context<PostAnnouncement>,
+    content_hash: [u8; 32],
+    uri: String,
+) -> Result<()> {
+    require!(
+        !uri.is_empty() && uri.len() <= MAX_ANNOUNCEMENT_URI_LEN,
+        EncoreError::InvalidAnnouncement
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let announcements = &mut ctx.accounts.announcements;
+    announcements.event_config = ctx.accounts.event_config.key();
+    announcements.count = announcements
+        .count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    announcements.head = Announcements::next_head(&announcements.head, &content_hash, &uri);
+    announcements.latest_hash = content_hash;
+    announcements.latest_uri = uri.clone();
+    announcements.posted_at = now;
+    announcements.bump = ctx.bumps.announcements;
+
+    emit!(AnnouncementPosted {
+        event_config: announcements.event_config,
+        sequence: announcements.count,
+        content_hash,
+        uri,
+        head: announcements.head,
+        posted_at: now,
+    });
+
+    msg!("✅ Announcement {} posted", announcements.count);
+
+    Ok(())
+}
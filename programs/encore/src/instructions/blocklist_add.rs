@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BLOCKED_BUYER_SEED, EVENT_SEED};
+use crate::errors::EncoreError;
+use crate::events::BlocklistUpdated;
+use crate::state::{BlockedBuyer, EventConfig};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddBlockedBuyer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BlockedBuyer::INIT_SPACE,
+        seeds = [BLOCKED_BUYER_SEED, event_config.key().as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub blocked_buyer: Account<'info, BlockedBuyer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Block a wallet from minting or claiming listings for this event.
+pub fn add_blocked_buyer(ctx: Context<AddBlockedBuyer>, wallet: Pubkey) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    let blocked_buyer = &mut ctx.accounts.blocked_buyer;
+
+    blocked_buyer.event_config = event_config.key();
+    blocked_buyer.wallet = wallet;
+    blocked_buyer.blocked_at = Clock::get()?.unix_timestamp;
+    blocked_buyer.bump = ctx.bumps.blocked_buyer;
+
+    emit!(BlocklistUpdated {
+        event_config: event_config.key(),
+        wallet,
+        blocked: true,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BLOCKED_BUYER_SEED, EVENT_SEED};
+use crate::errors::EncoreError;
+use crate::events::BlocklistUpdated;
+use crate::state::{BlockedBuyer, EventConfig};
+
+#[derive(Accounts)]
+pub struct RemoveBlockedBuyer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Blocklist entry - closed and rent returned to authority
+    #[account(
+        mut,
+        seeds = [BLOCKED_BUYER_SEED, event_config.key().as_ref(), blocked_buyer.wallet.as_ref()],
+        bump = blocked_buyer.bump,
+        close = authority,
+    )]
+    pub blocked_buyer: Account<'info, BlockedBuyer>,
+}
+
+/// Unblock a wallet by closing its blocklist entry.
+pub fn remove_blocked_buyer(ctx: Context<RemoveBlockedBuyer>) -> Result<()> {
+    emit!(BlocklistUpdated {
+        event_config: ctx.accounts.event_config.key(),
+        wallet: ctx.accounts.blocked_buyer.wallet,
+        blocked: false,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{CREDIT_SEED, EVENT_SEED, LEDGER_SEED};
+use crate::errors::EncoreError;
+use crate::events::RefundCreditIssued;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::invariants;
+use crate::memo::memo_hash;
+use crate::state::{
+    EventConfig, EventLedger, FieldValue, LedgerEntryKind, Nullifier, OwnershipMode, PrivateTicket,
+    RefundCredit,
+};
+
+#[derive(Accounts)]
+pub struct IssueRefundCredit<'info> {
+    /// Ticket holder exchanging the ticket for a credit (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    /// CHECK: Instructions sysvar; pass to attach the transaction's SPL memo
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+/// Exchange a ticket for a cancelled event into a refund credit.
+///
+/// The credit is worth the ticket's original price and can be spent on
+/// any future mint from the same organizer until it expires.
+///
+/// # Operations
+/// 1. Validate event is cancelled and credits are enabled
+/// 2. Verify holder owns the ticket via commitment
+/// 3. CREATE nullifier + CREATE credit + CLOSE ticket
+pub fn issue_refund_credit<'info>(
+    ctx: Context<'_, '_, '_, 'info, IssueRefundCredit<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    holder_secret: [u8; 32],
+    credit_address_seed: [u8; 32],
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let holder = &ctx.accounts.holder;
+
+    require!(event_config.cancelled, EncoreError::EventNotCancelled);
+    require!(
+        event_config.credit_validity_seconds > 0,
+        EncoreError::CreditsNotEnabled
+    );
+
+    // commitment = SHA256(owner_pubkey || secret)
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(holder.key.as_ref());
+    commitment_input.extend_from_slice(&holder_secret);
+    let owner_commitment = hash(&commitment_input).to_bytes();
+
+    // --- Light Protocol CPI Setup ---
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Create nullifier ---
+    let nullifier_seed = hash(&holder_secret);
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_nullifier_sequence()?;
+
+    // --- Step 2: Create credit ---
+    let (credit_address, credit_seed) = derive_address(
+        &[CREDIT_SEED, credit_address_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let expires_at = Clock::get()?
+        .unix_timestamp
+        .saturating_add(event_config.credit_validity_seconds as i64);
+
+    let mut credit_account = LightAccount::<RefundCredit>::new_init(
+        &crate::ID,
+        Some(credit_address),
+        output_state_tree_index,
+    );
+    credit_account.organizer = event_config.authority;
+    credit_account.owner = holder.key();
+    credit_account.amount = original_price;
+    credit_account.expires_at = expires_at;
+
+    // --- Step 3: Close ticket (proves the original price) ---
+    let ticket_account = LightAccount::<PrivateTicket>::new_close(
+        &crate::ID,
+        &ticket_account_meta,
+        PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id,
+            owner_commitment,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            ownership: OwnershipMode::Bound,
+        },
+    )?;
+
+    // --- Execute CPI ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    // Two new addresses: nullifier (index 0) and credit (index 1)
+    let nullifier_params =
+        address_tree_info.into_new_address_params_assigned_packed(nullifier_address_seed, Some(0));
+    let credit_params =
+        address_tree_info.into_new_address_params_assigned_packed(credit_seed, Some(1));
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(credit_account)?
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[nullifier_params, credit_params])
+        .invoke(light_cpi_accounts)?;
+
+    ctx.accounts
+        .ledger
+        .record(LedgerEntryKind::CreditIssued, original_price)?;
+
+    event_config.tickets_refunded = event_config
+        .tickets_refunded
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(RefundCreditIssued {
+        event_config: event_config.key(),
+        organizer: event_config.authority,
+        owner: holder.key(),
+        amount: original_price,
+        expires_at,
+        memo_hash: memo_hash(ctx.accounts.instructions_sysvar.as_deref())?,
+    });
+
+    msg!("✅ Refund credit issued: {} lamports", original_price);
+
+    invariants::check_event(&ctx.accounts.event_config)?;
+
+    Ok(())
+}
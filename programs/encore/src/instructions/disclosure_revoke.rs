@@ -0,0 +1,61 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, ValidityProof},
+};
+
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::state::TicketDisclosure;
+
+#[derive(Accounts)]
+pub struct RevokeDisclosure<'info> {
+    /// Holder who published the disclosure (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+}
+
+/// Close a disclosure before its ticket moves, cutting off the view key.
+///
+/// The disclosure's data names the signer as its holder, so only the
+/// wallet that published it can close it.
+///
+/// # Operations
+/// 1. CLOSE the disclosure
+pub fn revoke_disclosure<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevokeDisclosure<'info>>,
+    proof: ValidityProof,
+    disclosure_account_meta: CompressedAccountMeta,
+    event_config: Pubkey,
+    ticket_address: [u8; 32],
+    ticket_id: u32,
+) -> Result<()> {
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let disclosure_account = LightAccount::<TicketDisclosure>::new_close(
+        &crate::ID,
+        &disclosure_account_meta,
+        TicketDisclosure {
+            holder: ctx.accounts.holder.key(),
+            event_config,
+            ticket_address,
+            ticket_id,
+        },
+    )?;
+
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(disclosure_account)?
+        .invoke(light_cpi_accounts)?;
+
+    msg!("✅ Disclosure of ticket {} revoked", ticket_id);
+
+    Ok(())
+}
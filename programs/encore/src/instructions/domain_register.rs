@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DOMAIN_SEED, PROTOCOL_SEED};
+use crate::errors::EncoreError;
+use crate::events::DomainRegistryUpdated;
+use crate::state::{ProtocolConfig, RegisteredDomain};
+
+#[derive(Accounts)]
+#[instruction(domain_hash: [u8; 32])]
+pub struct RegisterDomain<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ EncoreError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Created on first registration; later calls re-pin the release hash
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + RegisteredDomain::INIT_SPACE,
+        seeds = [DOMAIN_SEED, domain_hash.as_ref()],
+        bump
+    )]
+    pub registered_domain: Account<'info, RegisteredDomain>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add a canonical frontend domain to the registry, or update the release
+/// hash pinned to it (protocol admin only).
+pub fn register_domain(
+    ctx: Context<RegisterDomain>,
+    domain_hash: [u8; 32],
+    domain: String,
+    release_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        RegisteredDomain::is_canonical(&domain)
+            && RegisteredDomain::hash_domain(&domain) == domain_hash,
+        EncoreError::InvalidDomain
+    );
+
+    let registered_domain = &mut ctx.accounts.registered_domain;
+    if registered_domain.registered_at == 0 {
+        registered_domain.registered_at = Clock::get()?.unix_timestamp;
+    }
+    registered_domain.domain = domain;
+    registered_domain.domain_hash = domain_hash;
+    registered_domain.release_hash = release_hash;
+    registered_domain.bump = ctx.bumps.registered_domain;
+
+    emit!(DomainRegistryUpdated {
+        domain: registered_domain.domain.clone(),
+        domain_hash,
+        release_hash,
+        registered: true,
+    });
+
+    msg!("✅ Domain {} registered", registered_domain.domain);
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DOMAIN_SEED, PROTOCOL_SEED};
+use crate::errors::EncoreError;
+use crate::events::DomainRegistryUpdated;
+use crate::state::{ProtocolConfig, RegisteredDomain};
+
+#[derive(Accounts)]
+pub struct RemoveDomain<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ EncoreError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Registry entry - closed and rent returned to admin
+    #[account(
+        mut,
+        seeds = [DOMAIN_SEED, registered_domain.domain_hash.as_ref()],
+        bump = registered_domain.bump,
+        close = admin,
+    )]
+    pub registered_domain: Account<'info, RegisteredDomain>,
+}
+
+/// Drop a domain from the registry, e.g. a retired or compromised
+/// frontend, so wallets start warning on it.
+pub fn remove_domain(ctx: Context<RemoveDomain>) -> Result<()> {
+    let registered_domain = &ctx.accounts.registered_domain;
+
+    emit!(DomainRegistryUpdated {
+        domain: registered_domain.domain.clone(),
+        domain_hash: registered_domain.domain_hash,
+        release_hash: None,
+        registered: false,
+    });
+
+    Ok(())
+}
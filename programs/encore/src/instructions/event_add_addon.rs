@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, MAX_ADDONS};
+use crate::errors::EncoreError;
+use crate::events::AddOnAdded;
+use crate::state::{AddOn, EventConfig};
+
+#[derive(Accounts)]
+pub struct AddAddOn<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Offer a new add-on product with tickets of this event.
+pub fn add_addon(
+    ctx: Context<AddAddOn>,
+    name: [u8; 16],
+    price_lamports: u64,
+    supply: u32,
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    require!(
+        event_config.addons.len() < MAX_ADDONS && supply > 0,
+        EncoreError::InvalidAddOn
+    );
+
+    let addon = AddOn {
+        name,
+        price_lamports,
+        supply,
+        sold: 0,
+    };
+    event_config.addons.push(addon);
+    event_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(AddOnAdded {
+        event_config: event_config.key(),
+        index: (event_config.addons.len() - 1) as u8,
+        addon,
+    });
+
+    Ok(())
+}
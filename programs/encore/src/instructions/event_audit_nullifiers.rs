@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::events::NullifierAudit;
+use crate::state::EventConfig;
+
+#[derive(Accounts)]
+pub struct AuditNullifiers<'info> {
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Check that the event's nullifier count matches its stats.
+///
+/// Permissionless. Every transfer, marketplace sale, reissue, redemption and
+/// refund claim spends one ticket secret, so `nullifiers_created` must equal their
+/// sum. The result is emitted either way. An indexer can go further: each
+/// `Nullifier` carries its event and sequence number, so the compressed
+/// accounts it sees for the event must be exactly `0..nullifiers_created`
+/// (less any pruned).
+pub fn audit_nullifiers(ctx: Context<AuditNullifiers>) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    let balanced = event_config.nullifiers_created == event_config.nullified_tickets();
+
+    emit!(NullifierAudit {
+        event_config: event_config.key(),
+        nullifiers_created: event_config.nullifiers_created,
+        transfers: event_config.gift_transfers as u64 + event_config.sale_transfers as u64,
+        marketplace_sales: event_config.marketplace_sales,
+        reissues: event_config.tickets_reissued,
+        redemptions: event_config.tickets_redeemed,
+        refunds: event_config.tickets_refunded,
+        balanced,
+    });
+
+    msg!(
+        "Nullifier audit: {} created, balanced: {}",
+        event_config.nullifiers_created,
+        balanced
+    );
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::EVENT_SEED;
+use crate::errors::EncoreError;
+use crate::events::EventCancelled;
+use crate::state::EventConfig;
+
+#[derive(Accounts)]
+pub struct CancelEvent<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Cancel an event. Minting stops and cancellation-dependent
+/// flows (e.g. insurance claims) become available. Escrowed marketplace
+/// claims can be refunded at once with `refund_cancelled_claim`.
+/// Irreversible.
+pub fn cancel_event(ctx: Context<CancelEvent>) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+
+    let clock = Clock::get()?;
+    event_config.cancelled = true;
+    event_config.updated_at = clock.unix_timestamp;
+
+    emit!(EventCancelled {
+        event_config: event_config.key(),
+        authority: event_config.authority,
+        cancelled_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
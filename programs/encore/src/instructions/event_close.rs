@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::EVENT_SEED;
+use crate::errors::EncoreError;
+use crate::events::EventClosed;
+use crate::state::EventConfig;
+
+#[derive(Accounts)]
+pub struct CloseEvent<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Close an event once it has taken place (or was cancelled). Minting stops
+/// and per-event compressed state can be pruned. Irreversible.
+pub fn close_event(ctx: Context<CloseEvent>) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    require!(!event_config.closed, EncoreError::EventClosed);
+
+    let clock = Clock::get()?;
+    require!(
+        event_config.cancelled || clock.unix_timestamp >= event_config.event_timestamp,
+        EncoreError::EventNotOver
+    );
+
+    event_config.closed = true;
+    event_config.updated_at = clock.unix_timestamp;
+
+    emit!(EventClosed {
+        event_config: event_config.key(),
+        closed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
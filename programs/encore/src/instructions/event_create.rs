@@ -3,7 +3,9 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::EncoreError;
 use crate::events::EventCreated;
-use crate::state::EventConfig;
+use crate::state::{
+    EmissionPolicy, EventConfig, EventLedger, ProtocolMetrics, TicketAddressMode, TransferPolicy,
+};
 
 #[derive(Accounts)]
 pub struct CreateEvent<'info> {
@@ -19,6 +21,19 @@ pub struct CreateEvent<'info> {
     )]
     pub event_config: Account<'info, EventConfig>,
 
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + EventLedger::INIT_SPACE,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -32,32 +47,145 @@ pub fn create_event(
     event_description: String,
     max_tickets_per_person: u8,
     event_timestamp: i64,
+    settlement_mint: Option<Pubkey>,
+) -> Result<()> {
+    init_event(
+        ctx.accounts.authority.key(),
+        &mut ctx.accounts.event_config,
+        ctx.bumps.event_config,
+        &mut ctx.accounts.ledger,
+        ctx.bumps.ledger,
+        &mut ctx.accounts.metrics,
+        max_supply,
+        resale_cap_bps,
+        event_name,
+        event_location,
+        event_description,
+        max_tickets_per_person,
+        event_timestamp,
+        settlement_mint,
+    )
+}
+
+/// Validate a new event's parameters and initialize it with its ledger.
+/// Shared with `create_event_idempotent`.
+pub(crate) fn init_event(
+    authority: Pubkey,
+    event_config: &mut Account<EventConfig>,
+    event_config_bump: u8,
+    ledger: &mut Account<EventLedger>,
+    ledger_bump: u8,
+    metrics: &mut Account<ProtocolMetrics>,
+    max_supply: u32,
+    resale_cap_bps: u32,
+    event_name: String,
+    event_location: String,
+    event_description: String,
+    max_tickets_per_person: u8,
+    event_timestamp: i64,
+    settlement_mint: Option<Pubkey>,
 ) -> Result<()> {
     require!(max_supply > 0, EncoreError::InvalidTicketSupply);
-    require!(max_supply <= MAX_TICKET_SUPPLY, EncoreError::TicketSupplyTooLarge);
-    require!(resale_cap_bps >= MIN_RESALE_CAP_BPS, EncoreError::ResaleCapTooLow);
-    require!(resale_cap_bps <= MAX_RESALE_CAP_BPS, EncoreError::ResaleCapTooHigh);
+    require!(
+        max_supply <= MAX_TICKET_SUPPLY,
+        EncoreError::TicketSupplyTooLarge
+    );
+    require!(
+        resale_cap_bps >= MIN_RESALE_CAP_BPS,
+        EncoreError::ResaleCapTooLow
+    );
+    require!(
+        resale_cap_bps <= MAX_RESALE_CAP_BPS,
+        EncoreError::ResaleCapTooHigh
+    );
     require!(!event_name.is_empty(), EncoreError::EventNameEmpty);
-    require!(event_name.len() <= MAX_EVENT_NAME_LEN, EncoreError::EventNameTooLong);
-    require!(event_location.len() <= MAX_EVENT_LOCATION_LEN, EncoreError::EventLocationTooLong);
-    require!(event_description.len() <= MAX_EVENT_DESCRIPTION_LEN, EncoreError::EventDescriptionTooLong);
+    require!(
+        event_name.len() <= MAX_EVENT_NAME_LEN,
+        EncoreError::EventNameTooLong
+    );
+    require!(
+        event_location.len() <= MAX_EVENT_LOCATION_LEN,
+        EncoreError::EventLocationTooLong
+    );
+    require!(
+        event_description.len() <= MAX_EVENT_DESCRIPTION_LEN,
+        EncoreError::EventDescriptionTooLong
+    );
 
     let clock = Clock::get()?;
-    require!(event_timestamp > clock.unix_timestamp, EncoreError::EventTimestampInPast);
+    require!(
+        event_timestamp > clock.unix_timestamp,
+        EncoreError::EventTimestampInPast
+    );
+    // Primary sales, refunds and royalties only settle in SOL so far; an
+    // SPL-settled event would take no payments at all
+    require!(
+        settlement_mint.is_none(),
+        EncoreError::UnsupportedSettlementMint
+    );
 
-    let event_config = &mut ctx.accounts.event_config;
-    event_config.authority = ctx.accounts.authority.key();
+    event_config.authority = authority;
+    event_config.cancelled = false;
+    event_config.closed = false;
     event_config.max_supply = max_supply;
     event_config.tickets_minted = 0;
+    event_config.tickets_voided = 0;
+    event_config.reserved_supply = 0;
+    event_config.reserved_minted = 0;
+    event_config.allocated_supply = 0;
+    event_config.resale_blackout_seconds = 0;
+    event_config.fire_code_capacity = 0;
+    event_config.door_release_step = 0;
+    event_config.door_released = 0;
+    event_config.tickets_redeemed = 0;
+    event_config.tickets_refunded = 0;
+    event_config.marketplace_sales = 0;
+    event_config.nullifiers_created = 0;
+    event_config.gift_transfers = 0;
+    event_config.sale_transfers = 0;
+    event_config.tickets_reissued = 0;
     event_config.resale_cap_bps = resale_cap_bps;
+    event_config.resale_cap_usd_rate = None;
     event_config.event_name = event_name.clone();
     event_config.event_location = event_location.clone();
     event_config.event_description = event_description.clone();
     event_config.max_tickets_per_person = max_tickets_per_person;
+    event_config.mint_rate_limit = 0;
+    event_config.mint_rate_window_seconds = 0;
+    event_config.identity_epoch_seconds = 0;
+    event_config.max_mints_per_slot = 0;
+    event_config.pow_difficulty = 0;
+    event_config.required_credential = None;
+    event_config.id_verification = None;
+    event_config.insurance_policy = None;
+    event_config.transfer_policy = TransferPolicy::Open;
+    event_config.require_paid_sales = false;
+    event_config.escrow_resale_proceeds = false;
+    event_config.require_void_memo = false;
+    event_config.require_scanner = false;
+    event_config.emission_policy = EmissionPolicy::Standard;
+    event_config.ticket_address_mode = TicketAddressMode::Random;
+    event_config.secret_derivation = None;
+    event_config.royalty = None;
+    event_config.custom_fields = Vec::new();
+    event_config.addons = Vec::new();
+    event_config.release_schedule = Vec::new();
+    event_config.compliance_policy = None;
+    event_config.snapshot_root = None;
+    event_config.presale = None;
+    event_config.fan_lane = None;
+    event_config.settlement_mint = settlement_mint;
+    event_config.credit_validity_seconds = 0;
     event_config.event_timestamp = event_timestamp;
     event_config.created_at = clock.unix_timestamp;
     event_config.updated_at = 0;
-    event_config.bump = ctx.bumps.event_config;
+    event_config.bump = event_config_bump;
+
+    ledger.event_config = event_config.key();
+    ledger.entry_count = 0;
+    ledger.bump = ledger_bump;
+
+    metrics.record_event()?;
 
     emit!(EventCreated {
         event_config: event_config.key(),
@@ -69,6 +197,7 @@ pub fn create_event(
         event_description,
         max_tickets_per_person,
         event_timestamp,
+        settlement_mint,
     });
 
     Ok(())
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, LEDGER_SEED, METRICS_SEED};
+use crate::errors::EncoreError;
+use crate::instructions::event_create::init_event;
+use crate::state::{EventConfig, EventLedger, ProtocolMetrics};
+
+#[derive(Accounts)]
+pub struct CreateEventIdempotent<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + EventConfig::INIT_SPACE,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + EventLedger::INIT_SPACE,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// `create_event` that succeeds without changes when the authority's event
+/// already exists with the same parameters, so API clients can retry after
+/// an RPC timeout without checking whether the first attempt landed.
+///
+/// An existing event with different parameters fails with
+/// `EventConfigMismatch` rather than being overwritten; use `update_event`
+/// for changes.
+pub fn create_event_idempotent(
+    ctx: Context<CreateEventIdempotent>,
+    max_supply: u32,
+    resale_cap_bps: u32,
+    event_name: String,
+    event_location: String,
+    event_description: String,
+    max_tickets_per_person: u8,
+    event_timestamp: i64,
+    settlement_mint: Option<Pubkey>,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let event_config = &ctx.accounts.event_config;
+
+    // A fresh account is zeroed; an existing one names its authority
+    if event_config.authority == authority {
+        require!(
+            event_config.max_supply == max_supply
+                && event_config.resale_cap_bps == resale_cap_bps
+                && event_config.event_name == event_name
+                && event_config.event_location == event_location
+                && event_config.event_description == event_description
+                && event_config.max_tickets_per_person == max_tickets_per_person
+                && event_config.event_timestamp == event_timestamp
+                && event_config.settlement_mint == settlement_mint,
+            EncoreError::EventConfigMismatch
+        );
+
+        msg!("✅ Event already exists: {:?}", event_config.key());
+        return Ok(());
+    }
+
+    init_event(
+        authority,
+        &mut ctx.accounts.event_config,
+        ctx.bumps.event_config,
+        &mut ctx.accounts.ledger,
+        ctx.bumps.ledger,
+        &mut ctx.accounts.metrics,
+        max_supply,
+        resale_cap_bps,
+        event_name,
+        event_location,
+        event_description,
+        max_tickets_per_person,
+        event_timestamp,
+        settlement_mint,
+    )
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, ORACLE_CREDIT_VALIDITY_SECONDS, PROTOCOL_SEED};
+use crate::errors::EncoreError;
+use crate::events::{EventCancelled, ForceMajeureCancelled};
+use crate::state::{EventConfig, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct OracleCancelEvent<'info> {
+    /// The protocol's force-majeure oracle
+    pub oracle: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol_config.bump,
+        constraint = protocol_config.cancellation_oracle == Some(oracle.key()) @ EncoreError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Event authority, used to derive the event PDA
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Cancel an event on the force-majeure oracle's attestation.
+///
+/// Has the same effect as `cancel_event`, so insurance claims open, and
+/// holders can exchange tickets for refund credits without waiting on an
+/// organizer who has gone dark: events that never enabled credits get
+/// `ORACLE_CREDIT_VALIDITY_SECONDS`. `attestation_hash` commits to the
+/// oracle's evidence (weather report, closure order) for later audit.
+pub fn oracle_cancel_event(
+    ctx: Context<OracleCancelEvent>,
+    attestation_hash: [u8; 32],
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+
+    let clock = Clock::get()?;
+    event_config.cancelled = true;
+    if event_config.credit_validity_seconds == 0 {
+        event_config.credit_validity_seconds = ORACLE_CREDIT_VALIDITY_SECONDS;
+    }
+    event_config.updated_at = clock.unix_timestamp;
+
+    emit!(EventCancelled {
+        event_config: event_config.key(),
+        authority: event_config.authority,
+        cancelled_at: clock.unix_timestamp,
+    });
+
+    emit!(ForceMajeureCancelled {
+        event_config: event_config.key(),
+        oracle: ctx.accounts.oracle.key(),
+        attestation_hash,
+    });
+
+    msg!("✅ Event cancelled by oracle attestation");
+
+    Ok(())
+}
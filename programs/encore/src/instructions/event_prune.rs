@@ -0,0 +1,120 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMetaBurn, ValidityProof},
+};
+
+use crate::constants::{EVENT_SEED, MAX_PRUNE_BATCH};
+use crate::errors::EncoreError;
+use crate::events::EventAccountsPruned;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::state::{EventConfig, IdentityCounter, Nullifier};
+
+/// An identity counter to prune, with its current data for the input hash.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PrunableIdentityCounter {
+    pub account_meta: CompressedAccountMetaBurn,
+    pub current: IdentityCounter,
+}
+
+/// A nullifier to prune, with its current data for the input hash.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PrunableNullifier {
+    pub account_meta: CompressedAccountMetaBurn,
+    pub current: Nullifier,
+}
+
+#[derive(Accounts)]
+pub struct PruneEventAccounts<'info> {
+    /// Event authority pruning the accounts (also pays fees)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Burn identity counters and nullifiers after an event is closed.
+///
+/// Burned accounts leave the state tree entirely. Their addresses stay in
+/// the address tree, so a burned nullifier still can't be re-created and
+/// double-spend protection is unaffected. Burned nullifiers drop out of the
+/// per-event sequence an indexer sees; `EventAccountsPruned` records how
+/// many, so audits can account for them.
+///
+/// # Operations
+/// 1. Validate the event is closed and the batch is small enough
+/// 2. Check every identity counter and nullifier belongs to this event
+/// 3. BURN all accounts in one CPI
+pub fn prune_event_accounts<'info>(
+    ctx: Context<'_, '_, '_, 'info, PruneEventAccounts<'info>>,
+    proof: ValidityProof,
+    identity_counters: Vec<PrunableIdentityCounter>,
+    nullifiers: Vec<PrunableNullifier>,
+) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    require!(event_config.closed, EncoreError::EventNotClosed);
+    require!(
+        identity_counters.len() + nullifiers.len() <= MAX_PRUNE_BATCH,
+        EncoreError::TooManyAccountsToPrune
+    );
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.authority.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    let identity_count = identity_counters.len() as u8;
+    let nullifier_count = nullifiers.len() as u8;
+
+    let mut cpi = LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof);
+    for counter in identity_counters {
+        require_keys_eq!(
+            counter.current.event,
+            event_config.key(),
+            EncoreError::InvalidIdentityCounter
+        );
+        cpi = cpi.with_light_account(LightAccount::<IdentityCounter>::new_burn(
+            &crate::ID,
+            &counter.account_meta,
+            counter.current,
+        )?)?;
+    }
+    for nullifier in nullifiers {
+        require_keys_eq!(
+            nullifier.current.event_config,
+            event_config.key(),
+            EncoreError::InvalidNullifier
+        );
+        cpi = cpi.with_light_account(LightAccount::<Nullifier>::new_burn(
+            &crate::ID,
+            &nullifier.account_meta,
+            nullifier.current,
+        )?)?;
+    }
+    cpi.invoke(light_cpi_accounts)?;
+
+    emit!(EventAccountsPruned {
+        event_config: event_config.key(),
+        identity_counters: identity_count,
+        nullifiers: nullifier_count,
+    });
+
+    msg!(
+        "✅ Pruned {} identity counters and {} nullifiers",
+        identity_count,
+        nullifier_count
+    );
+
+    Ok(())
+}
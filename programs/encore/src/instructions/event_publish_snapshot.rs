@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::EVENT_SEED;
+use crate::errors::EncoreError;
+use crate::events::SnapshotPublished;
+use crate::state::EventConfig;
+
+#[derive(Accounts)]
+pub struct PublishSnapshot<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Publish the Merkle root of the event's attendance or holder snapshot.
+///
+/// The organizer builds the snapshot off-chain (redemption receipts for
+/// attendance, ticket owners for holders) over `snapshot_leaf(wallet)`.
+/// Later events reference it with `set_presale` to give these wallets
+/// first access. Republishing replaces the root for presales set afterwards.
+pub fn publish_snapshot(ctx: Context<PublishSnapshot>, snapshot_root: [u8; 32]) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    event_config.snapshot_root = Some(snapshot_root);
+    event_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(SnapshotPublished {
+        event_config: event_config.key(),
+        snapshot_root,
+    });
+
+    msg!("✅ Snapshot published");
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::DOOR_RELEASE_WINDOW_SECONDS;
+use crate::errors::EncoreError;
+use crate::events::DoorCapacityReleased;
+use crate::invariants;
+use crate::state::{EventConfig, Scanner};
+
+#[derive(Accounts)]
+pub struct ReleaseDoorCapacity<'info> {
+    /// Gate device key registered as a scanner for the event
+    pub device: Signer<'info>,
+
+    #[account(
+        constraint = scanner.device_key == device.key() @ EncoreError::ScannerNotAuthorized,
+    )]
+    pub scanner: Account<'info, Scanner>,
+
+    #[account(mut, address = scanner.event_config)]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Release standing-room supply at the door on show day.
+///
+/// Any unexpired scanner may add up to `door_release_step` tickets per
+/// call, as long as `max_supply` stays within `fire_code_capacity`, so gate
+/// staff can open up unused capacity with their device keys while the
+/// organizer's key stays offline. Released tickets go on sale immediately,
+/// on top of any release schedule.
+pub fn release_door_capacity(ctx: Context<ReleaseDoorCapacity>, amount: u32) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let event_config = &mut ctx.accounts.event_config;
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(!event_config.closed, EncoreError::EventClosed);
+    require!(
+        now <= ctx.accounts.scanner.expires_at,
+        EncoreError::ScannerNotAuthorized
+    );
+    require!(
+        now >= event_config.event_timestamp - DOOR_RELEASE_WINDOW_SECONDS,
+        EncoreError::DoorReleaseNotOpen
+    );
+
+    let max_supply = event_config
+        .max_supply
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    require!(
+        amount > 0
+            && amount <= event_config.door_release_step
+            && max_supply <= event_config.fire_code_capacity,
+        EncoreError::InvalidDoorRelease
+    );
+
+    event_config.max_supply = max_supply;
+    event_config.door_released = event_config
+        .door_released
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    event_config.updated_at = now;
+
+    emit!(DoorCapacityReleased {
+        event_config: event_config.key(),
+        device: ctx.accounts.device.key(),
+        amount,
+        max_supply,
+    });
+
+    msg!("✅ Released {} tickets at the door", amount);
+
+    invariants::check_event(&ctx.accounts.event_config)?;
+
+    Ok(())
+}
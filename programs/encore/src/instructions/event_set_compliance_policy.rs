@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, MAX_POLICY_REGIONS, MAX_POLICY_VENUES};
+use crate::errors::EncoreError;
+use crate::events::CompliancePolicyUpdated;
+use crate::state::{CompliancePolicy, EventConfig};
+
+#[derive(Accounts)]
+pub struct SetCompliancePolicy<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Publish (or clear) the event's region/venue restrictions and policy hook.
+pub fn set_compliance_policy(
+    ctx: Context<SetCompliancePolicy>,
+    compliance_policy: Option<CompliancePolicy>,
+) -> Result<()> {
+    if let Some(policy) = compliance_policy.as_ref() {
+        require!(
+            policy.regions.len() <= MAX_POLICY_REGIONS && policy.venues.len() <= MAX_POLICY_VENUES,
+            EncoreError::InvalidCompliancePolicy
+        );
+    }
+
+    let event_config = &mut ctx.accounts.event_config;
+    event_config.compliance_policy = compliance_policy.clone();
+    event_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(CompliancePolicyUpdated {
+        event_config: event_config.key(),
+        compliance_policy,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::EVENT_SEED;
+use crate::errors::EncoreError;
+use crate::events::CredentialRequirementUpdated;
+use crate::state::{CredentialRequirement, EventConfig};
+
+#[derive(Accounts)]
+pub struct SetRequiredCredential<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Require (or stop requiring) a credential attestation to mint.
+/// Pass `None` to remove the requirement.
+pub fn set_required_credential(
+    ctx: Context<SetRequiredCredential>,
+    required_credential: Option<CredentialRequirement>,
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    event_config.required_credential = required_credential;
+    event_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(CredentialRequirementUpdated {
+        event_config: event_config.key(),
+        required_credential,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, MAX_CUSTOM_FIELDS};
+use crate::errors::EncoreError;
+use crate::events::CustomFieldsUpdated;
+use crate::state::{EventConfig, FieldDef};
+
+#[derive(Accounts)]
+pub struct SetCustomFields<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Declare the event's custom ticket fields (door time, parking pass, ...).
+/// Locked once the first ticket is minted, so every ticket follows one schema.
+pub fn set_custom_fields(
+    ctx: Context<SetCustomFields>,
+    custom_fields: Vec<FieldDef>,
+) -> Result<()> {
+    require!(
+        custom_fields.len() <= MAX_CUSTOM_FIELDS,
+        EncoreError::InvalidCustomFields
+    );
+
+    let event_config = &mut ctx.accounts.event_config;
+    require!(
+        event_config.tickets_minted == 0,
+        EncoreError::CustomFieldsLocked
+    );
+
+    event_config.custom_fields = custom_fields.clone();
+    event_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(CustomFieldsUpdated {
+        event_config: event_config.key(),
+        custom_fields,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::bps::BPS_DENOMINATOR;
+use crate::constants::EVENT_SEED;
+use crate::errors::EncoreError;
+use crate::events::FanLaneUpdated;
+use crate::state::{EventConfig, FanLane};
+
+#[derive(Accounts)]
+pub struct SetFanLane<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Hold `share_bps` of each supply tranche for verified fans during the
+/// first `window_seconds` after its release (event authority only). A zero
+/// share turns the lane off.
+///
+/// Fans are admitted with `issue_fan_attestation`, ideally ahead of the
+/// on-sale. Changing the lane resets the count of fan mints in the current
+/// window.
+pub fn set_fan_lane(ctx: Context<SetFanLane>, share_bps: u16, window_seconds: u32) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+
+    let fan_lane = if share_bps == 0 {
+        None
+    } else {
+        require!(
+            share_bps as u64 <= BPS_DENOMINATOR && window_seconds > 0,
+            EncoreError::InvalidFanLane
+        );
+        Some(FanLane {
+            share_bps,
+            window_seconds,
+            window_start: 0,
+            fan_minted: 0,
+        })
+    };
+
+    event_config.fan_lane = fan_lane;
+    event_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(FanLaneUpdated {
+        event_config: event_config.key(),
+        fan_lane,
+    });
+
+    Ok(())
+}
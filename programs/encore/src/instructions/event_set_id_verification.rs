@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::EVENT_SEED;
+use crate::errors::EncoreError;
+use crate::events::IdVerificationUpdated;
+use crate::state::{CredentialRequirement, EventConfig};
+
+#[derive(Accounts)]
+pub struct SetIdVerification<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Set the ID-verification issuer that named tickets are checked against at
+/// redemption. Attestations are keyed by the ticket's `name_commitment`
+/// rather than a wallet. Pass `None` to remove it.
+pub fn set_id_verification(
+    ctx: Context<SetIdVerification>,
+    id_verification: Option<CredentialRequirement>,
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    event_config.id_verification = id_verification;
+    event_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(IdVerificationUpdated {
+        event_config: event_config.key(),
+        id_verification,
+    });
+
+    Ok(())
+}
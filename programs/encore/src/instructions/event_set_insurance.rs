@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, MAX_INSURANCE_BPS};
+use crate::errors::EncoreError;
+use crate::events::InsurancePolicyUpdated;
+use crate::state::{EventConfig, InsurancePolicy};
+
+#[derive(Accounts)]
+pub struct SetInsurancePolicy<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Offer (or withdraw) ticket insurance on future mints.
+/// Existing cover keeps the terms it was bought under.
+pub fn set_insurance_policy(
+    ctx: Context<SetInsurancePolicy>,
+    insurance_policy: Option<InsurancePolicy>,
+) -> Result<()> {
+    if let Some(policy) = insurance_policy {
+        require!(
+            policy.premium_bps > 0
+                && policy.premium_bps <= MAX_INSURANCE_BPS
+                && policy.payout_bps <= MAX_INSURANCE_BPS
+                && policy.cancel_cutoff_seconds >= 0,
+            EncoreError::InvalidInsurancePolicy
+        );
+    }
+
+    let event_config = &mut ctx.accounts.event_config;
+    event_config.insurance_policy = insurance_policy;
+    event_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(InsurancePolicyUpdated {
+        event_config: event_config.key(),
+        insurance_policy,
+    });
+
+    Ok(())
+}
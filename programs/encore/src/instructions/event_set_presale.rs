@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::EVENT_SEED;
+use crate::errors::EncoreError;
+use crate::events::PresaleUpdated;
+use crate::state::{EventConfig, Presale};
+
+#[derive(Accounts)]
+pub struct SetPresale<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Past event whose snapshot is the allowlist; omit to clear the presale
+    pub source_event: Option<Account<'info, EventConfig>>,
+}
+
+/// Restrict mints until `ends_at` to wallets in another event's snapshot
+/// ("previous attendees get first access").
+///
+/// The source event's published root is copied onto this event; buyers
+/// prove membership with a Merkle proof in `mint_ticket`. Box-office mints
+/// from the hold-back aren't restricted.
+pub fn set_presale(ctx: Context<SetPresale>, ends_at: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let event_config = &mut ctx.accounts.event_config;
+
+    let presale = match ctx.accounts.source_event.as_ref() {
+        Some(source_event) => {
+            require!(
+                source_event.key() != event_config.key() && ends_at > now,
+                EncoreError::InvalidPresale
+            );
+            Some(Presale {
+                source_event: source_event.key(),
+                snapshot_root: source_event
+                    .snapshot_root
+                    .ok_or(EncoreError::InvalidPresale)?,
+                ends_at,
+            })
+        }
+        None => None,
+    };
+
+    event_config.presale = presale;
+    event_config.updated_at = now;
+
+    emit!(PresaleUpdated {
+        event_config: event_config.key(),
+        presale,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, MAX_RELEASE_TRANCHES};
+use crate::errors::EncoreError;
+use crate::events::ReleaseScheduleUpdated;
+use crate::state::{EventConfig, ReleaseTranche};
+
+#[derive(Accounts)]
+pub struct SetReleaseSchedule<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Release supply in tranches (e.g. 1,000 now, 2,000 more a week later).
+///
+/// Each tranche adds its `supply` once `release_at` passes; mints beyond
+/// the released total fail. An empty schedule puts all of `max_supply` on
+/// sale. Tickets already minted count against the earliest tranches.
+pub fn set_release_schedule(
+    ctx: Context<SetReleaseSchedule>,
+    release_schedule: Vec<ReleaseTranche>,
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+
+    let total = release_schedule
+        .iter()
+        .try_fold(0u32, |total, tranche| total.checked_add(tranche.supply));
+    require!(
+        release_schedule.len() <= MAX_RELEASE_TRANCHES
+            && release_schedule
+                .windows(2)
+                .all(|pair| pair[0].release_at < pair[1].release_at)
+            && total.is_some_and(|total| total <= event_config.max_supply),
+        EncoreError::InvalidReleaseSchedule
+    );
+
+    event_config.release_schedule = release_schedule.clone();
+    event_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(ReleaseScheduleUpdated {
+        event_config: event_config.key(),
+        release_schedule,
+    });
+
+    Ok(())
+}
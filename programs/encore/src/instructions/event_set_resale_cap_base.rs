@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, MAX_PRICE_AGE_SECONDS, PRICE_FEED_SEED};
+use crate::errors::EncoreError;
+use crate::events::ResaleCapBaseUpdated;
+use crate::state::{EventConfig, PriceFeed};
+
+#[derive(Accounts)]
+pub struct SetResaleCapBase<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Required when switching to a USD-adjusted base
+    #[account(seeds = [PRICE_FEED_SEED], bump = price_feed.bump)]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+}
+
+/// Choose what the resale cap is measured against (event authority only).
+///
+/// With `usd_adjusted`, later resales cap against face value re-priced at
+/// the rate of the day, so a cap set during a SOL crash doesn't become
+/// punitive, or meaningless after a rally. A ticket's face value is valued
+/// at the rate it was bought at (`PrivateTicket::usd_micros_per_sol`);
+/// the current feed rate is pinned for tickets minted without a feed.
+/// Otherwise the cap applies to the lamports originally paid.
+pub fn set_resale_cap_base(ctx: Context<SetResaleCapBase>, usd_adjusted: bool) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let rate = if usd_adjusted {
+        let price_feed = ctx
+            .accounts
+            .price_feed
+            .as_ref()
+            .ok_or(EncoreError::PriceFeedRequired)?;
+        require!(
+            price_feed.is_fresh(now, MAX_PRICE_AGE_SECONDS),
+            EncoreError::PriceFeedStale
+        );
+        Some(price_feed.usd_micros_per_sol)
+    } else {
+        None
+    };
+
+    let event_config = &mut ctx.accounts.event_config;
+    event_config.resale_cap_usd_rate = rate;
+    event_config.updated_at = now;
+
+    emit!(ResaleCapBaseUpdated {
+        event_config: event_config.key(),
+        usd_micros_per_sol: rate,
+    });
+
+    Ok(())
+}
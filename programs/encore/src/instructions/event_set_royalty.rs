@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, ROYALTY_VAULT_SEED};
+use crate::errors::EncoreError;
+use crate::events::RoyaltyConfigUpdated;
+use crate::state::{EventConfig, RoyaltyConfig, RoyaltyVault};
+
+#[derive(Accounts)]
+pub struct SetRoyalty<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Created on first use; collects royalties until distributed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RoyaltyVault::INIT_SPACE,
+        seeds = [ROYALTY_VAULT_SEED, event_config.key().as_ref()],
+        bump
+    )]
+    pub royalty_vault: Account<'info, RoyaltyVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set (or remove) the resale royalty and how it is split.
+///
+/// Royalties already in the vault are paid out under the splits in force
+/// when `distribute_royalties` runs.
+pub fn set_royalty(ctx: Context<SetRoyalty>, royalty: Option<RoyaltyConfig>) -> Result<()> {
+    if let Some(config) = royalty.as_ref() {
+        require!(config.is_valid(), EncoreError::InvalidRoyaltyConfig);
+        // The royalty vault accrues lamports
+        require!(
+            ctx.accounts.event_config.settles_in(None),
+            EncoreError::SettlementMintMismatch
+        );
+    }
+
+    let vault = &mut ctx.accounts.royalty_vault;
+    vault.event_config = ctx.accounts.event_config.key();
+    vault.bump = ctx.bumps.royalty_vault;
+
+    let event_config = &mut ctx.accounts.event_config;
+    event_config.royalty = royalty.clone();
+    event_config.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(RoyaltyConfigUpdated {
+        event_config: event_config.key(),
+        royalty,
+    });
+
+    Ok(())
+}
@@ -3,7 +3,8 @@ use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::errors::EncoreError;
 use crate::events::EventUpdated;
-use crate::state::EventConfig;
+use crate::secret::SecretDerivation;
+use crate::state::{EmissionPolicy, EventConfig, TicketAddressMode, TransferPolicy};
 
 #[derive(Accounts)]
 pub struct UpdateEvent<'info> {
@@ -21,7 +22,24 @@ pub struct UpdateEvent<'info> {
 pub fn update_event(
     ctx: Context<UpdateEvent>,
     resale_cap_bps: Option<u32>,
-
+    mint_rate_limit: Option<u8>,
+    mint_rate_window_seconds: Option<u32>,
+    max_mints_per_slot: Option<u8>,
+    pow_difficulty: Option<u8>,
+    credit_validity_seconds: Option<u32>,
+    transfer_policy: Option<TransferPolicy>,
+    require_paid_sales: Option<bool>,
+    escrow_resale_proceeds: Option<bool>,
+    emission_policy: Option<EmissionPolicy>,
+    reserved_supply: Option<u32>,
+    require_void_memo: Option<bool>,
+    ticket_address_mode: Option<TicketAddressMode>,
+    secret_derivation: Option<Option<SecretDerivation>>,
+    require_scanner: Option<bool>,
+    resale_blackout_seconds: Option<u32>,
+    fire_code_capacity: Option<u32>,
+    door_release_step: Option<u32>,
+    identity_epoch_seconds: Option<u32>,
 ) -> Result<()> {
     let event_config = &mut ctx.accounts.event_config;
     let clock = Clock::get()?;
@@ -32,7 +50,112 @@ pub fn update_event(
         event_config.resale_cap_bps = cap;
     }
 
+    // Rate limit is disabled while either value is zero
+    if let Some(limit) = mint_rate_limit {
+        event_config.mint_rate_limit = limit;
+    }
+
+    if let Some(window) = mint_rate_window_seconds {
+        event_config.mint_rate_window_seconds = window;
+    }
+
+    // Zero disables the per-slot cap
+    if let Some(max) = max_mints_per_slot {
+        event_config.max_mints_per_slot = max;
+    }
+
+    if let Some(difficulty) = pow_difficulty {
+        require!(
+            difficulty <= MAX_POW_DIFFICULTY,
+            EncoreError::PowDifficultyTooHigh
+        );
+        event_config.pow_difficulty = difficulty;
+    }
+
+    // Non-zero switches cancellation refunds to credits valid this long
+    if let Some(validity) = credit_validity_seconds {
+        event_config.credit_validity_seconds = validity;
+    }
+
+    if let Some(policy) = transfer_policy {
+        event_config.transfer_policy = policy;
+    }
+
+    // When set, declared sales in `transfer_ticket` must pay through the program
+    if let Some(required) = require_paid_sales {
+        event_config.require_paid_sales = required;
+    }
+
+    // Buyer protection: hold `complete_sale` proceeds until check-in
+    if let Some(escrow) = escrow_resale_proceeds {
+        event_config.escrow_resale_proceeds = escrow;
+    }
+
+    if let Some(policy) = emission_policy {
+        event_config.emission_policy = policy;
+    }
+
+    // Box-office hold-back; can't drop below what the box office already sold
+    if let Some(reserved) = reserved_supply {
+        require!(
+            reserved >= event_config.reserved_minted && reserved <= event_config.max_supply,
+            EncoreError::InvalidReservedSupply
+        );
+        event_config.reserved_supply = reserved;
+    }
+
+    // Voids must then carry an SPL memo justifying them
+    if let Some(required) = require_void_memo {
+        event_config.require_void_memo = required;
+    }
+
+    // Fixed once tickets exist, so every issued ticket follows one rule
+    if let Some(mode) = ticket_address_mode {
+        require!(
+            event_config.tickets_minted == 0,
+            EncoreError::TicketAddressModeLocked
+        );
+        event_config.ticket_address_mode = mode;
+    }
+
+    // Flows that bind identity to a ticket then require standard secrets
+    if let Some(derivation) = secret_derivation {
+        event_config.secret_derivation = derivation;
+    }
+
+    // Redemptions must then be co-signed by a registered scanner
+    if let Some(required) = require_scanner {
+        event_config.require_scanner = required;
+    }
 
+    // Zero disables the blackout on listings and priced transfers
+    if let Some(blackout) = resale_blackout_seconds {
+        event_config.resale_blackout_seconds = blackout;
+    }
+
+    // Ceiling on door releases; zero disables them
+    if let Some(capacity) = fire_code_capacity {
+        require!(
+            capacity == 0 || capacity >= event_config.max_supply,
+            EncoreError::InvalidDoorRelease
+        );
+        event_config.fire_code_capacity = capacity;
+    }
+
+    // Most a scanner may add per `release_door_capacity` call
+    if let Some(step) = door_release_step {
+        event_config.door_release_step = step;
+    }
+
+    // Fixed once tickets exist: a new length would move every buyer to a
+    // fresh counter mid-sale
+    if let Some(seconds) = identity_epoch_seconds {
+        require!(
+            event_config.tickets_minted == 0,
+            EncoreError::IdentityEpochLocked
+        );
+        event_config.identity_epoch_seconds = seconds;
+    }
 
     event_config.updated_at = clock.unix_timestamp;
 
@@ -40,7 +163,6 @@ pub fn update_event(
         event_config: event_config.key(),
         authority: event_config.authority,
         resale_cap_bps: event_config.resale_cap_bps,
-
     });
 
     Ok(())
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, TICKET_EXCHANGE_SEED};
+use crate::errors::EncoreError;
+use crate::events::TicketExchangeUpdated;
+use crate::invariants;
+use crate::state::{EventConfig, TicketExchange};
+
+#[derive(Accounts)]
+pub struct OpenTicketExchange<'info> {
+    /// Authority of the postponed or cancelled event
+    pub from_authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, from_authority.key().as_ref()],
+        bump = from_event.bump,
+    )]
+    pub from_event: Account<'info, EventConfig>,
+
+    /// Authority of the replacement event, giving up the supply
+    #[account(mut)]
+    pub to_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, to_authority.key().as_ref()],
+        bump = to_event.bump,
+    )]
+    pub to_event: Account<'info, EventConfig>,
+
+    #[account(
+        init,
+        payer = to_authority,
+        space = 8 + TicketExchange::INIT_SPACE,
+        seeds = [TICKET_EXCHANGE_SEED, from_event.key().as_ref()],
+        bump
+    )]
+    pub ticket_exchange: Account<'info, TicketExchange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Let `from_event`'s holders exchange their tickets into `to_event` until
+/// `deadline` (both event authorities).
+///
+/// `size` tickets are carved out of the replacement event's supply, as for
+/// a partner allocation. Each is issued at `price_lamports`.
+pub fn open_ticket_exchange(
+    ctx: Context<OpenTicketExchange>,
+    price_lamports: u64,
+    size: u32,
+    deadline: i64,
+) -> Result<()> {
+    let from_event = &ctx.accounts.from_event;
+    let to_event = &mut ctx.accounts.to_event;
+    require!(
+        from_event.key() != to_event.key() && size > 0 && deadline > Clock::get()?.unix_timestamp,
+        EncoreError::InvalidTicketExchange
+    );
+    require!(!to_event.cancelled, EncoreError::EventCancelled);
+    require!(!to_event.closed, EncoreError::EventClosed);
+    require!(to_event.can_allocate(size), EncoreError::MaxSupplyReached);
+
+    to_event.allocated_supply += size;
+
+    let ticket_exchange = &mut ctx.accounts.ticket_exchange;
+    ticket_exchange.from_event = from_event.key();
+    ticket_exchange.to_event = to_event.key();
+    ticket_exchange.price_lamports = price_lamports;
+    ticket_exchange.size = size;
+    ticket_exchange.exchanged = 0;
+    ticket_exchange.deadline = deadline;
+    ticket_exchange.bump = ctx.bumps.ticket_exchange;
+
+    emit!(TicketExchangeUpdated {
+        from_event: ticket_exchange.from_event,
+        to_event: ticket_exchange.to_event,
+        price_lamports,
+        size,
+        exchanged: 0,
+        returned: 0,
+    });
+
+    msg!(
+        "✅ Ticket exchange opened for {} tickets into {}",
+        size,
+        ticket_exchange.to_event
+    );
+
+    invariants::check_event(&ctx.accounts.to_event)?;
+
+    Ok(())
+}
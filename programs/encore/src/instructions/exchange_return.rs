@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, TICKET_EXCHANGE_SEED};
+use crate::errors::EncoreError;
+use crate::events::TicketExchangeUpdated;
+use crate::invariants;
+use crate::state::{EventConfig, TicketExchange};
+
+#[derive(Accounts)]
+pub struct ReturnTicketExchange<'info> {
+    /// Anyone may crank an expired exchange back to supply
+    pub payer: Signer<'info>,
+
+    /// CHECK: Replacement event's authority, receives the exchange's rent
+    #[account(mut)]
+    pub to_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, to_authority.key().as_ref()],
+        bump = to_event.bump,
+    )]
+    pub to_event: Account<'info, EventConfig>,
+
+    /// Closed; rent returns to the replacement event's authority
+    #[account(
+        mut,
+        seeds = [TICKET_EXCHANGE_SEED, ticket_exchange.from_event.as_ref()],
+        bump = ticket_exchange.bump,
+        has_one = to_event @ EncoreError::InvalidTicketExchange,
+        close = to_authority,
+    )]
+    pub ticket_exchange: Account<'info, TicketExchange>,
+}
+
+/// Return a ticket exchange's unused tickets to the replacement event's
+/// public supply once its deadline has passed. Permissionless, like
+/// `return_allocation`.
+pub fn return_ticket_exchange(ctx: Context<ReturnTicketExchange>) -> Result<()> {
+    let ticket_exchange = &ctx.accounts.ticket_exchange;
+    require!(
+        Clock::get()?.unix_timestamp > ticket_exchange.deadline,
+        EncoreError::TicketExchangeOpen
+    );
+
+    let returned = ticket_exchange.remaining();
+    let to_event = &mut ctx.accounts.to_event;
+    to_event.allocated_supply = to_event.allocated_supply.saturating_sub(returned);
+
+    emit!(TicketExchangeUpdated {
+        from_event: ticket_exchange.from_event,
+        to_event: ticket_exchange.to_event,
+        price_lamports: ticket_exchange.price_lamports,
+        size: ticket_exchange.size,
+        exchanged: ticket_exchange.exchanged,
+        returned,
+    });
+
+    msg!("✅ {} exchange tickets returned to public supply", returned);
+
+    invariants::check_event(&ctx.accounts.to_event)?;
+
+    Ok(())
+}
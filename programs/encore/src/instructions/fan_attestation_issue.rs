@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, FAN_ATTESTATION_SEED};
+use crate::errors::EncoreError;
+use crate::events::FanAttestationUpdated;
+use crate::state::{EventConfig, FanAttestation};
+
+#[derive(Accounts)]
+#[instruction(fan: Pubkey)]
+pub struct IssueFanAttestation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FanAttestation::INIT_SPACE,
+        seeds = [FAN_ATTESTATION_SEED, event_config.key().as_ref(), fan.as_ref()],
+        bump
+    )]
+    pub fan_attestation: Account<'info, FanAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Attest `fan` as a verified fan, admitting them to the event's fan lane
+/// (event authority only).
+pub fn issue_fan_attestation(ctx: Context<IssueFanAttestation>, fan: Pubkey) -> Result<()> {
+    let fan_attestation = &mut ctx.accounts.fan_attestation;
+    fan_attestation.event_config = ctx.accounts.event_config.key();
+    fan_attestation.fan = fan;
+    fan_attestation.issued_at = Clock::get()?.unix_timestamp;
+    fan_attestation.bump = ctx.bumps.fan_attestation;
+
+    emit!(FanAttestationUpdated {
+        event_config: fan_attestation.event_config,
+        fan,
+        active: true,
+    });
+
+    msg!("✅ Fan attested: {}", fan);
+
+    Ok(())
+}
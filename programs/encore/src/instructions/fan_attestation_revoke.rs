@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, FAN_ATTESTATION_SEED};
+use crate::errors::EncoreError;
+use crate::events::FanAttestationUpdated;
+use crate::state::{EventConfig, FanAttestation};
+
+#[derive(Accounts)]
+pub struct RevokeFanAttestation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            FAN_ATTESTATION_SEED,
+            event_config.key().as_ref(),
+            fan_attestation.fan.as_ref(),
+        ],
+        bump = fan_attestation.bump,
+        close = authority,
+    )]
+    pub fan_attestation: Account<'info, FanAttestation>,
+}
+
+/// Revoke a fan attestation, returning its rent to the organizer (event
+/// authority only). Tickets already minted through the lane are kept.
+pub fn revoke_fan_attestation(ctx: Context<RevokeFanAttestation>) -> Result<()> {
+    emit!(FanAttestationUpdated {
+        event_config: ctx.accounts.event_config.key(),
+        fan: ctx.accounts.fan_attestation.fan,
+        active: false,
+    });
+
+    msg!("✅ Fan attestation revoked");
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::bps::BPS_DENOMINATOR;
+use crate::constants::{FEE_EXEMPTION_SEED, PROTOCOL_SEED};
+use crate::errors::EncoreError;
+use crate::events::FeeExemptionUpdated;
+use crate::state::{FeeExemption, ProtocolConfig};
+
+#[derive(Accounts)]
+#[instruction(subject: Pubkey)]
+pub struct AddFeeExemption<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ EncoreError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + FeeExemption::INIT_SPACE,
+        seeds = [FEE_EXEMPTION_SEED, subject.as_ref()],
+        bump
+    )]
+    pub fee_exemption: Account<'info, FeeExemption>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grant a protocol fee discount to an organizer or event (protocol admin only).
+pub fn add_fee_exemption(
+    ctx: Context<AddFeeExemption>,
+    subject: Pubkey,
+    discount_bps: u16,
+) -> Result<()> {
+    require!(
+        discount_bps > 0 && discount_bps as u64 <= BPS_DENOMINATOR,
+        EncoreError::InvalidFeeExemption
+    );
+
+    let fee_exemption = &mut ctx.accounts.fee_exemption;
+    fee_exemption.subject = subject;
+    fee_exemption.discount_bps = discount_bps;
+    fee_exemption.granted_at = Clock::get()?.unix_timestamp;
+    fee_exemption.bump = ctx.bumps.fee_exemption;
+
+    emit!(FeeExemptionUpdated {
+        subject,
+        discount_bps,
+    });
+
+    Ok(())
+}
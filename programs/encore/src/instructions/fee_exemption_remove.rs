@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FEE_EXEMPTION_SEED, PROTOCOL_SEED};
+use crate::errors::EncoreError;
+use crate::events::FeeExemptionUpdated;
+use crate::state::{FeeExemption, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct RemoveFeeExemption<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ EncoreError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Exemption entry - closed and rent returned to admin
+    #[account(
+        mut,
+        seeds = [FEE_EXEMPTION_SEED, fee_exemption.subject.as_ref()],
+        bump = fee_exemption.bump,
+        close = admin,
+    )]
+    pub fee_exemption: Account<'info, FeeExemption>,
+}
+
+/// Revoke a fee exemption by closing its registry entry.
+pub fn remove_fee_exemption(ctx: Context<RemoveFeeExemption>) -> Result<()> {
+    emit!(FeeExemptionUpdated {
+        subject: ctx.accounts.fee_exemption.subject,
+        discount_bps: 0,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FIAT_HOLD_SEED, FIAT_PROVIDER_SEED};
+use crate::errors::EncoreError;
+use crate::events::FiatHoldResolved;
+use crate::invariants;
+use crate::state::{EventConfig, FiatHold, FiatProvider};
+
+#[derive(Accounts)]
+pub struct ClawbackFiatHold<'info> {
+    /// PSP that created the hold, receives its rent
+    #[account(mut, address = fiat_hold.psp)]
+    pub psp: Signer<'info>,
+
+    #[account(mut, address = fiat_hold.event_config)]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [FIAT_PROVIDER_SEED, event_config.key().as_ref(), psp.key().as_ref()],
+        bump = fiat_provider.bump,
+    )]
+    pub fiat_provider: Account<'info, FiatProvider>,
+
+    #[account(
+        mut,
+        seeds = [
+            FIAT_HOLD_SEED,
+            event_config.key().as_ref(),
+            &fiat_hold.ticket_id.to_le_bytes(),
+        ],
+        bump = fiat_hold.bump,
+        close = psp,
+    )]
+    pub fiat_hold: Account<'info, FiatHold>,
+}
+
+/// Claw back a held ticket after a chargeback (PSP only, before the
+/// settlement window ends), or at any time once the event is cancelled or
+/// closed and the hold can't be finalized. The ticket is never issued and
+/// its id counts as voided.
+pub fn clawback_fiat_hold(ctx: Context<ClawbackFiatHold>) -> Result<()> {
+    let fiat_hold = &ctx.accounts.fiat_hold;
+    let event_config = &ctx.accounts.event_config;
+    require!(
+        Clock::get()?.unix_timestamp < fiat_hold.finalizes_at
+            || event_config.cancelled
+            || event_config.closed,
+        EncoreError::FiatHoldFinal
+    );
+
+    let event_config = &mut ctx.accounts.event_config;
+    event_config.tickets_voided = event_config
+        .tickets_voided
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let fiat_provider = &mut ctx.accounts.fiat_provider;
+    fiat_provider.clawed_back = fiat_provider
+        .clawed_back
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(FiatHoldResolved {
+        event_config: event_config.key(),
+        psp: fiat_hold.psp,
+        ticket_id: fiat_hold.ticket_id,
+        finalized: false,
+    });
+
+    msg!(
+        "✅ Fiat hold for ticket {} clawed back",
+        fiat_hold.ticket_id
+    );
+
+    invariants::check_event(&ctx.accounts.event_config)?;
+
+    Ok(())
+}
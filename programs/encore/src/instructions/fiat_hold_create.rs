@@ -0,0 +1,228 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::{
+    cpi::{
+        v2::{CpiAccounts, LightSystemProgramCpi},
+        InvokeLightSystemProgram, LightCpiInstruction,
+    },
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::check_address_tree;
+use crate::constants::{
+    BLOCKED_BUYER_SEED, EVENT_SEED, FAN_ATTESTATION_SEED, FIAT_HOLD_SEED, FIAT_PROVIDER_SEED,
+    METRICS_SEED,
+};
+use crate::errors::EncoreError;
+use crate::events::FiatHoldCreated;
+use crate::instructions::ticket_mint::{
+    check_primary_sale, record_identity_mint, LIGHT_CPI_SIGNER,
+};
+use crate::invariants;
+use crate::state::{
+    EventConfig, FanAttestation, FiatHold, FiatProvider, IdentityCounter, ProtocolMetrics,
+};
+
+#[derive(Accounts)]
+pub struct CreateFiatHold<'info> {
+    /// Registered PSP key, pays the hold's rent
+    #[account(mut)]
+    pub psp: Signer<'info>,
+
+    /// CHECK: Wallet the PSP sold to (not required to sign); its blocklist
+    /// entry, identity counter and attestations are checked as for a mint
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [FIAT_PROVIDER_SEED, event_config.key().as_ref(), psp.key().as_ref()],
+        bump = fiat_provider.bump,
+    )]
+    pub fiat_provider: Account<'info, FiatProvider>,
+
+    /// CHECK: Blocklist entry for the buyer; must not exist
+    #[account(
+        seeds = [BLOCKED_BUYER_SEED, event_config.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        constraint = blocked_buyer.data_is_empty() @ EncoreError::BuyerBlocked,
+    )]
+    pub blocked_buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Buyer's credential attestation, verified against
+    /// `event_config.required_credential` when the event requires one
+    pub attestation: Option<UncheckedAccount<'info>>,
+
+    /// Buyer's verified-fan attestation, admitting them to the fan lane
+    #[account(
+        seeds = [FAN_ATTESTATION_SEED, event_config.key().as_ref(), buyer.key().as_ref()],
+        bump = fan_attestation.bump,
+    )]
+    pub fan_attestation: Option<Account<'info, FanAttestation>>,
+
+    /// CHECK: Must match `compliance_policy.hook_program` when one is set
+    pub policy_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Passed through to the policy hook, which validates it
+    pub policy_attestation: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init,
+        payer = psp,
+        space = 8 + FiatHold::INIT_SPACE,
+        seeds = [
+            FIAT_HOLD_SEED,
+            event_config.key().as_ref(),
+            &(event_config.tickets_minted + 1).to_le_bytes(),
+        ],
+        bump
+    )]
+    pub fiat_hold: Account<'info, FiatHold>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sell a ticket for fiat into a custodial hold (registered PSP only).
+///
+/// The ticket id is taken from public supply now, but the ticket is only
+/// issued to `owner_commitment` by `finalize_fiat_hold` once the PSP's
+/// settlement window passes. Until then `clawback_fiat_hold` voids it if
+/// the card payment is reversed. `original_price` is the lamport value the
+/// resale cap applies to, at most the provider's `max_price`.
+///
+/// # Primary sale checks
+/// The hold is a primary sale to `buyer`, so it passes the same checks as
+/// `mint_ticket`: blocklist, presale allowlist, credential, compliance hook
+/// and the fan lane through `check_primary_sale`, and the buyer's identity
+/// counter is updated for the per-person limit, rate limit and per-slot cap.
+pub fn create_fiat_hold<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateFiatHold<'info>>,
+    owner_commitment: [u8; 32],
+    original_price: u64,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    identity_account_meta: Option<CompressedAccountMeta>,
+    current_identity: Option<IdentityCounter>,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    require!(
+        original_price <= ctx.accounts.fiat_provider.max_price,
+        EncoreError::FiatHoldPriceTooHigh
+    );
+    // Held tickets are issued without custom fields
+    check_primary_sale(
+        &ctx.accounts.event_config,
+        ctx.accounts.buyer.as_ref(),
+        now,
+        1,
+        original_price,
+        &[],
+        false,
+        ctx.accounts.fan_attestation.is_some(),
+        allowlist_proof.as_deref(),
+        ctx.accounts
+            .attestation
+            .as_ref()
+            .map(|attestation| attestation.as_ref()),
+        ctx.accounts
+            .policy_hook_program
+            .as_ref()
+            .map(|hook| hook.as_ref()),
+        ctx.accounts
+            .policy_attestation
+            .as_ref()
+            .map(|attestation| attestation.as_ref()),
+    )?;
+
+    // --- Identity counter, as for a direct mint ---
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.psp.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    let (identity_account, new_identity_seed) = record_identity_mint(
+        &ctx.accounts.event_config,
+        ctx.accounts.buyer.key,
+        &clock,
+        1,
+        &address_tree_pubkey,
+        output_state_tree_index,
+        identity_account_meta,
+        current_identity,
+    )?;
+
+    let mut new_addresses = Vec::new();
+    if let Some(identity_seed) = new_identity_seed {
+        new_addresses.push(
+            address_tree_info.into_new_address_params_assigned_packed(identity_seed, Some(0)),
+        );
+    }
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(identity_account)?
+        .with_new_addresses(&new_addresses)
+        .invoke(light_cpi_accounts)?;
+
+    let event_config = &mut ctx.accounts.event_config;
+    if ctx.accounts.fan_attestation.is_some() {
+        event_config.record_fan_mint(1, now);
+    }
+
+    let ticket_id = event_config.tickets_minted + 1;
+    event_config.tickets_minted = ticket_id;
+    ctx.accounts.metrics.record_mints(1)?;
+
+    let fiat_provider = &mut ctx.accounts.fiat_provider;
+    fiat_provider.held = fiat_provider
+        .held
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let finalizes_at = now.saturating_add(fiat_provider.settlement_seconds as i64);
+
+    let fiat_hold = &mut ctx.accounts.fiat_hold;
+    fiat_hold.event_config = event_config.key();
+    fiat_hold.psp = fiat_provider.psp;
+    fiat_hold.ticket_id = ticket_id;
+    fiat_hold.owner_commitment = owner_commitment;
+    fiat_hold.original_price = original_price;
+    fiat_hold.finalizes_at = finalizes_at;
+    fiat_hold.created_at = now;
+    fiat_hold.bump = ctx.bumps.fiat_hold;
+
+    emit!(FiatHoldCreated {
+        event_config: event_config.key(),
+        psp: fiat_hold.psp,
+        ticket_id,
+        finalizes_at,
+    });
+
+    msg!("✅ Ticket {} held until fiat settlement", ticket_id);
+
+    invariants::check_event(&ctx.accounts.event_config)?;
+
+    Ok(())
+}
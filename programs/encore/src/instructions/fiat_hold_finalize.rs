@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{
+        v2::{CpiAccounts, LightSystemProgramCpi},
+        InvokeLightSystemProgram, LightCpiInstruction,
+    },
+    instruction::{PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{FIAT_HOLD_SEED, FIAT_PROVIDER_SEED, TICKET_SEED};
+use crate::errors::EncoreError;
+use crate::events::{FiatHoldResolved, TicketIndexed, TicketMinted};
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::state::{EventConfig, FiatHold, FiatProvider, PrivateTicket};
+
+#[derive(Accounts)]
+pub struct FinalizeFiatHold<'info> {
+    /// Anyone can finalize a settled hold; pays for the ticket
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: PSP that created the hold, receives its rent
+    #[account(mut, address = fiat_hold.psp)]
+    pub psp: UncheckedAccount<'info>,
+
+    #[account(address = fiat_hold.event_config)]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [FIAT_PROVIDER_SEED, event_config.key().as_ref(), psp.key().as_ref()],
+        bump = fiat_provider.bump,
+    )]
+    pub fiat_provider: Account<'info, FiatProvider>,
+
+    #[account(
+        mut,
+        seeds = [
+            FIAT_HOLD_SEED,
+            event_config.key().as_ref(),
+            &fiat_hold.ticket_id.to_le_bytes(),
+        ],
+        bump = fiat_hold.bump,
+        close = psp,
+    )]
+    pub fiat_hold: Account<'info, FiatHold>,
+}
+
+/// Issue a held fiat ticket to its buyer once the settlement window has
+/// passed. Permissionless, so the buyer, the PSP or a crank can run it.
+///
+/// Nothing is issued once the event is cancelled or closed; the PSP claws
+/// the hold back instead and refunds the buyer off-chain.
+///
+/// # Operations
+/// 1. Check the event is live and the settlement window has ended
+/// 2. CREATE ticket owned by the hold's buyer commitment
+/// 3. Close the hold, returning its rent to the PSP
+pub fn finalize_fiat_hold<'info>(
+    ctx: Context<'_, '_, '_, 'info, FinalizeFiatHold<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    let fiat_hold = &ctx.accounts.fiat_hold;
+    let ticket_id = fiat_hold.ticket_id;
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(!event_config.closed, EncoreError::EventClosed);
+    require!(
+        Clock::get()?.unix_timestamp >= fiat_hold.finalizes_at,
+        EncoreError::FiatHoldPending
+    );
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.payer.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    let (ticket_address, ticket_seed) = derive_address(
+        &[
+            TICKET_SEED,
+            event_config
+                .ticket_address_seed(&event_config.key(), ticket_id, ticket_address_seed)
+                .as_ref(),
+        ],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(ticket_address),
+        output_state_tree_index,
+    );
+    ticket_account.event_config = event_config.key();
+    ticket_account.ticket_id = ticket_id;
+    ticket_account.owner_commitment = fiat_hold.owner_commitment;
+    ticket_account.original_price = fiat_hold.original_price;
+    ticket_account.name_commitment = None;
+    ticket_account.link_id = None;
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[
+            address_tree_info.into_new_address_params_assigned_packed(ticket_seed, Some(0))
+        ])
+        .invoke(light_cpi_accounts)?;
+
+    let fiat_provider = &mut ctx.accounts.fiat_provider;
+    fiat_provider.finalized = fiat_provider
+        .finalized
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(TicketIndexed {
+        event_config: event_config.key(),
+        ticket_id,
+        address: ticket_address,
+    });
+
+    let policy = event_config.emission_policy;
+    let public = policy.includes_commitments();
+    emit!(TicketMinted {
+        event_config: event_config.key(),
+        tickets_minted: event_config.tickets_minted,
+        ticket_id: public.then_some(ticket_id),
+        owner_commitment: public.then_some(fiat_hold.owner_commitment),
+        purchase_price: policy.includes_prices().then_some(fiat_hold.original_price),
+        purchase_price_usd_micros: None,
+        fees: None,
+        reference: None,
+    });
+
+    emit!(FiatHoldResolved {
+        event_config: event_config.key(),
+        psp: fiat_hold.psp,
+        ticket_id,
+        finalized: true,
+    });
+
+    msg!("✅ Fiat ticket {} finalized", ticket_id);
+
+    Ok(())
+}
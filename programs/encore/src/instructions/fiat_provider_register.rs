@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, FIAT_PROVIDER_SEED};
+use crate::errors::EncoreError;
+use crate::events::FiatProviderUpdated;
+use crate::state::{EventConfig, FiatProvider};
+
+#[derive(Accounts)]
+#[instruction(psp: Pubkey)]
+pub struct RegisterFiatProvider<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Created on first registration; later calls change the window
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FiatProvider::INIT_SPACE,
+        seeds = [FIAT_PROVIDER_SEED, event_config.key().as_ref(), psp.as_ref()],
+        bump
+    )]
+    pub fiat_provider: Account<'info, FiatProvider>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Let a fiat PSP sell tickets through custodial holds that finalize after
+/// `settlement_seconds` (event authority only), at face values up to
+/// `max_price`. New terms apply to holds created afterwards.
+pub fn register_fiat_provider(
+    ctx: Context<RegisterFiatProvider>,
+    psp: Pubkey,
+    settlement_seconds: u32,
+    max_price: u64,
+) -> Result<()> {
+    require!(
+        settlement_seconds > 0 && max_price > 0,
+        EncoreError::InvalidFiatProvider
+    );
+
+    let fiat_provider = &mut ctx.accounts.fiat_provider;
+    fiat_provider.event_config = ctx.accounts.event_config.key();
+    fiat_provider.psp = psp;
+    fiat_provider.settlement_seconds = settlement_seconds;
+    fiat_provider.max_price = max_price;
+    fiat_provider.bump = ctx.bumps.fiat_provider;
+
+    emit!(FiatProviderUpdated {
+        event_config: fiat_provider.event_config,
+        psp,
+        settlement_seconds,
+        max_price,
+    });
+
+    Ok(())
+}
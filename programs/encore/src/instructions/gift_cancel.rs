@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use light_sdk::instruction::{PackedAddressTreeInfo, ValidityProof};
+
+use crate::constants::GIFT_SEED;
+use crate::events::GiftCancelled;
+use crate::instructions::gift_claim::issue_gift_ticket;
+use crate::state::Gift;
+
+#[derive(Accounts)]
+pub struct CancelGift<'info> {
+    /// Gift sender taking the ticket back
+    #[account(mut, address = gift.sender)]
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GIFT_SEED, gift.claim_key.as_ref()],
+        bump = gift.bump,
+        close = sender,
+    )]
+    pub gift: Account<'info, Gift>,
+}
+
+/// Take back an unclaimed gift, minting the ticket to a fresh commitment
+/// of the sender's. The link stops working once the gift account closes.
+pub fn cancel_gift<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelGift<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    new_owner_commitment: [u8; 32],
+    new_ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let gift = &ctx.accounts.gift;
+
+    issue_gift_ticket(
+        gift,
+        ctx.accounts.sender.as_ref(),
+        ctx.remaining_accounts,
+        proof,
+        address_tree_info,
+        output_state_tree_index,
+        new_owner_commitment,
+        new_ticket_address_seed,
+    )?;
+
+    emit!(GiftCancelled {
+        gift: gift.key(),
+        event_config: gift.event_config,
+    });
+
+    msg!(
+        "✅ Gift cancelled: ticket {} returned to sender",
+        gift.ticket_id
+    );
+
+    Ok(())
+}
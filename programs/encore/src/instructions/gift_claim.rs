@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{
+        v2::{CpiAccounts, LightSystemProgramCpi},
+        InvokeLightSystemProgram, LightCpiInstruction,
+    },
+    instruction::{PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{GIFT_SEED, TICKET_SEED};
+use crate::errors::EncoreError;
+use crate::events::GiftClaimed;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::state::{Gift, PrivateTicket};
+
+#[derive(Accounts)]
+pub struct ClaimGift<'info> {
+    /// Recipient, pays for the new ticket
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// The link's claim keypair
+    pub claim_key: Signer<'info>,
+
+    /// CHECK: Gift sender, receives the gift account's rent
+    #[account(mut, address = gift.sender)]
+    pub sender: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [GIFT_SEED, claim_key.key().as_ref()],
+        bump = gift.bump,
+        close = sender,
+    )]
+    pub gift: Account<'info, Gift>,
+}
+
+/// Redeem a gift link, minting the held ticket to the recipient's
+/// commitment. The gift account is closed and its rent returned to the
+/// sender.
+pub fn claim_gift<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimGift<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    new_owner_commitment: [u8; 32],
+    new_ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let gift = &ctx.accounts.gift;
+    require!(
+        !gift.is_expired(Clock::get()?.unix_timestamp),
+        EncoreError::GiftExpired
+    );
+
+    issue_gift_ticket(
+        gift,
+        ctx.accounts.recipient.as_ref(),
+        ctx.remaining_accounts,
+        proof,
+        address_tree_info,
+        output_state_tree_index,
+        new_owner_commitment,
+        new_ticket_address_seed,
+    )?;
+
+    emit!(GiftClaimed {
+        gift: gift.key(),
+        event_config: gift.event_config,
+        new_owner_commitment,
+    });
+
+    msg!("✅ Gift claimed: ticket {} issued", gift.ticket_id);
+
+    Ok(())
+}
+
+/// CREATE the ticket a gift holds, owned by `new_owner_commitment`.
+pub(crate) fn issue_gift_ticket<'info>(
+    gift: &Gift,
+    payer: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    new_owner_commitment: [u8; 32],
+    new_ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let light_cpi_accounts = CpiAccounts::new(payer, remaining_accounts, LIGHT_CPI_SIGNER);
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    let (ticket_address, ticket_seed) = derive_address(
+        &[TICKET_SEED, new_ticket_address_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut ticket = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(ticket_address),
+        output_state_tree_index,
+    );
+    ticket.event_config = gift.event_config;
+    ticket.ticket_id = gift.ticket_id;
+    ticket.owner_commitment = new_owner_commitment;
+    ticket.original_price = gift.original_price;
+    ticket.usd_micros_per_sol = gift.usd_micros_per_sol;
+    ticket.name_commitment = None;
+    ticket.link_id = None;
+    ticket.provenance = gift.provenance;
+    ticket.custom_fields = gift.custom_fields.clone();
+    ticket.addons = gift.addons;
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(ticket)?
+        .with_new_addresses(&[
+            address_tree_info.into_new_address_params_assigned_packed(ticket_seed, Some(0))
+        ])
+        .invoke(light_cpi_accounts)?;
+
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{
+        v2::{CpiAccounts, LightSystemProgramCpi},
+        InvokeLightSystemProgram, LightCpiInstruction,
+    },
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{EVENT_SEED, GIFT_SEED};
+use crate::errors::EncoreError;
+use crate::events::GiftCreated;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::state::{
+    fields_match_schema, EventConfig, FieldValue, Gift, Nullifier, OwnershipMode, PrivateTicket,
+    TransferPolicy,
+};
+
+#[derive(Accounts)]
+#[instruction(
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    claim_key: Pubkey,
+)]
+pub struct CreateGift<'info> {
+    /// Holder gifting the ticket, pays the gift account's rent
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// CHECK: Event authority, used to derive the event PDA
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        init,
+        payer = sender,
+        space = 8 + Gift::INIT_SPACE,
+        seeds = [GIFT_SEED, claim_key.as_ref()],
+        bump
+    )]
+    pub gift: Account<'info, Gift>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Gift a ticket through a claimable link instead of a live transfer.
+///
+/// The sender nullifies the ticket and parks its data in a `Gift` keyed by
+/// the link's claim key. Whoever holds the link calls `claim_gift` to mint
+/// it to their own commitment; until then the sender can `cancel_gift` to
+/// take it back. Linked pairs move with `transfer_ticket` only: the
+/// sender's ticket is closed as unlinked, so half of a pair fails the CPI.
+///
+/// The sender's ticket is closed with the data parked on the gift, so the
+/// recipient's ticket carries its proven face value, fields and add-ons.
+///
+/// # Expiry
+/// Links lapse at `expires_at`. After that anyone can `expire_gift`, which
+/// mints the ticket back to `return_commitment`, a fresh commitment of the
+/// sender's, so an unclaimed gift never strands the ticket.
+///
+/// # Operations
+/// 1. Check the event allows unapproved transfers
+/// 2. CREATE nullifier for the sender's secret
+/// 3. Store the ticket data on the gift account, CLOSE the sender's ticket
+pub fn create_gift<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateGift<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    claim_key: Pubkey,
+    // Sender's ticket, closed with this data (the CPI fails on a mismatch)
+    ticket_account_meta: CompressedAccountMeta,
+    current_ticket_id: u32,
+    current_original_price: u64,
+    current_usd_micros_per_sol: Option<u64>,
+    current_name_commitment: Option<[u8; 32]>,
+    current_provenance: [u8; 32],
+    current_custom_fields: Vec<FieldValue>,
+    current_addons: u8,
+    sender_secret: [u8; 32],
+    return_commitment: [u8; 32],
+    expires_at: i64,
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(expires_at > now, EncoreError::InvalidGiftExpiry);
+    require!(
+        event_config.transfer_policy == TransferPolicy::Open,
+        EncoreError::TransferApprovalRequired
+    );
+    require!(
+        fields_match_schema(&event_config.custom_fields, &current_custom_fields),
+        EncoreError::InvalidCustomFields
+    );
+
+    // The sender's ticket as claimed; closing it checks the claim, and so
+    // the sender's commitment, against the state tree
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(ctx.accounts.sender.key().as_ref());
+    commitment_input.extend_from_slice(&sender_secret);
+    let ticket = PrivateTicket {
+        event_config: event_config.key(),
+        ticket_id: current_ticket_id,
+        owner_commitment: hash(&commitment_input).to_bytes(),
+        original_price: current_original_price,
+        usd_micros_per_sol: current_usd_micros_per_sol,
+        name_commitment: current_name_commitment,
+        link_id: None,
+        provenance: current_provenance,
+        custom_fields: current_custom_fields,
+        addons: current_addons,
+        ownership: OwnershipMode::Bound,
+    };
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.sender.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 2: Create nullifier ---
+    let nullifier_seed = hash(&sender_secret);
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_nullifier_sequence()?;
+
+    // --- Step 3: Park the ticket on the gift, close the sender's ---
+    let gift = &mut ctx.accounts.gift;
+    gift.sender = ctx.accounts.sender.key();
+    gift.claim_key = claim_key;
+    gift.event_config = event_config.key();
+    gift.ticket_id = ticket.ticket_id;
+    gift.original_price = ticket.original_price;
+    gift.usd_micros_per_sol = ticket.usd_micros_per_sol;
+    gift.custom_fields = ticket.custom_fields.clone();
+    gift.addons = ticket.addons;
+    gift.provenance = PrivateTicket::next_provenance(&ticket.provenance, &nullifier_address);
+    gift.return_commitment = return_commitment;
+    gift.expires_at = expires_at;
+    gift.created_at = now;
+    gift.bump = ctx.bumps.gift;
+
+    let ticket_account =
+        LightAccount::<PrivateTicket>::new_close(&crate::ID, &ticket_account_meta, ticket)?;
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(ticket_account)? // CLOSE sender's ticket
+        .with_new_addresses(&[address_tree_info
+            .into_new_address_params_assigned_packed(nullifier_address_seed, Some(0))])
+        .invoke(light_cpi_accounts)?;
+
+    event_config.gift_transfers = event_config
+        .gift_transfers
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let gift = &ctx.accounts.gift;
+    emit!(GiftCreated {
+        gift: gift.key(),
+        event_config: gift.event_config,
+        sender: gift.sender,
+        expires_at,
+    });
+
+    msg!("✅ Gift created: ticket held for the link holder");
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use light_sdk::instruction::{PackedAddressTreeInfo, ValidityProof};
+
+use crate::constants::GIFT_SEED;
+use crate::errors::EncoreError;
+use crate::events::GiftExpired;
+use crate::instructions::gift_claim::issue_gift_ticket;
+use crate::state::Gift;
+
+#[derive(Accounts)]
+pub struct ExpireGift<'info> {
+    /// Anyone may crank an expired gift, paying for the returned ticket
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Gift sender, receives the gift account's rent
+    #[account(mut, address = gift.sender)]
+    pub sender: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [GIFT_SEED, gift.claim_key.as_ref()],
+        bump = gift.bump,
+        close = sender,
+    )]
+    pub gift: Account<'info, Gift>,
+}
+
+/// Return an unclaimed gift to its sender once the link has expired.
+///
+/// The ticket is minted to the `return_commitment` the sender set when
+/// gifting, so no secret is needed and any keeper can run it.
+pub fn expire_gift<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExpireGift<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    new_ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let gift = &ctx.accounts.gift;
+    require!(
+        gift.is_expired(Clock::get()?.unix_timestamp),
+        EncoreError::GiftNotExpired
+    );
+
+    issue_gift_ticket(
+        gift,
+        ctx.accounts.payer.as_ref(),
+        ctx.remaining_accounts,
+        proof,
+        address_tree_info,
+        output_state_tree_index,
+        gift.return_commitment,
+        new_ticket_address_seed,
+    )?;
+
+    emit!(GiftExpired {
+        gift: gift.key(),
+        event_config: gift.event_config,
+    });
+
+    msg!(
+        "✅ Gift expired: ticket {} returned to sender",
+        gift.ticket_id
+    );
+
+    Ok(())
+}
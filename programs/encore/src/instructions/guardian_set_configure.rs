@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GUARDIAN_SET_SEED, MAX_GUARDIANS, MIN_RECOVERY_DELAY_SECONDS};
+use crate::errors::EncoreError;
+use crate::events::GuardianSetConfigured;
+use crate::state::GuardianSet;
+
+#[derive(Accounts)]
+pub struct ConfigureGuardianSet<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Created on first configuration; later calls replace the guardians
+    /// and keep the guarded tickets
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = 8 + GuardianSet::INIT_SPACE,
+        seeds = [GUARDIAN_SET_SEED, holder.key().as_ref()],
+        bump
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Choose the guardians who can recover the holder's tickets.
+///
+/// Approvals already given to a pending recovery only count while their
+/// guardian stays in the set, so replacing a compromised guardian also
+/// withdraws their approvals.
+pub fn configure_guardian_set(
+    ctx: Context<ConfigureGuardianSet>,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+    recovery_delay_seconds: i64,
+) -> Result<()> {
+    let holder = ctx.accounts.holder.key();
+
+    let distinct = guardians
+        .iter()
+        .enumerate()
+        .all(|(i, g)| *g != holder && !guardians[..i].contains(g));
+    require!(
+        distinct
+            && guardians.len() <= MAX_GUARDIANS
+            && threshold > 0
+            && threshold as usize <= guardians.len(),
+        EncoreError::InvalidGuardianSet
+    );
+    require!(
+        recovery_delay_seconds >= MIN_RECOVERY_DELAY_SECONDS,
+        EncoreError::RecoveryDelayTooShort
+    );
+
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    guardian_set.holder = holder;
+    guardian_set.guardians = guardians;
+    guardian_set.threshold = threshold;
+    guardian_set.recovery_delay_seconds = recovery_delay_seconds;
+    guardian_set.bump = ctx.bumps.guardian_set;
+
+    emit!(GuardianSetConfigured {
+        holder,
+        guardians: guardian_set.guardians.len() as u8,
+        threshold,
+        recovery_delay_seconds,
+    });
+
+    Ok(())
+}
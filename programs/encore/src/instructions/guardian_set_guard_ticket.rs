@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::constants::{GUARDIAN_SET_SEED, MAX_GUARDED_TICKETS};
+use crate::errors::EncoreError;
+use crate::state::{GuardedTicket, GuardianSet};
+
+#[derive(Accounts)]
+pub struct GuardTicket<'info> {
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GUARDIAN_SET_SEED, holder.key().as_ref()],
+        bump = guardian_set.bump,
+        has_one = holder @ EncoreError::Unauthorized
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+}
+
+/// Put one of the holder's tickets under guard.
+///
+/// Revealing the secret proves the commitment is the holder's, which is
+/// what stops guardians recovering anyone else's ticket. It links the
+/// holder to the commitment, as spending the ticket would, but the ticket
+/// still can't move without the holder's signature.
+pub fn guard_ticket(ctx: Context<GuardTicket>, secret: [u8; 32]) -> Result<()> {
+    let guardian_set = &mut ctx.accounts.guardian_set;
+
+    // commitment = SHA256(owner_pubkey || secret)
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(ctx.accounts.holder.key.as_ref());
+    commitment_input.extend_from_slice(&secret);
+    let ticket_commitment = hash(&commitment_input).to_bytes();
+
+    if guardian_set.guarded(&ticket_commitment).is_some() {
+        return Ok(());
+    }
+    require!(
+        guardian_set.tickets.len() < MAX_GUARDED_TICKETS,
+        EncoreError::TooManyGuardedTickets
+    );
+
+    guardian_set.tickets.push(GuardedTicket {
+        ticket_commitment,
+        secret_hash: hash(&secret).to_bytes(),
+    });
+
+    Ok(())
+}
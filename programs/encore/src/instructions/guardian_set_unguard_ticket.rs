@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::GUARDIAN_SET_SEED;
+use crate::errors::EncoreError;
+use crate::state::GuardianSet;
+
+#[derive(Accounts)]
+pub struct UnguardTicket<'info> {
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GUARDIAN_SET_SEED, holder.key().as_ref()],
+        bump = guardian_set.bump,
+        has_one = holder @ EncoreError::Unauthorized
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+}
+
+/// Take a ticket out of guard, e.g. once it's been transferred or redeemed.
+pub fn unguard_ticket(ctx: Context<UnguardTicket>, ticket_commitment: [u8; 32]) -> Result<()> {
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    require!(
+        guardian_set.guarded(&ticket_commitment).is_some(),
+        EncoreError::TicketNotGuarded
+    );
+    guardian_set
+        .tickets
+        .retain(|t| t.ticket_commitment != ticket_commitment);
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::EVENT_SEED;
+use crate::errors::EncoreError;
+use crate::events::HolderCountPublished;
+use crate::state::EventConfig;
+
+#[derive(Accounts)]
+pub struct PublishHolderCount<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Publish how many distinct commitments hold the event's live tickets,
+/// for sponsorship reporting without naming any holder.
+///
+/// # Verification
+/// The statement is pinned to the event's nullifier count at publish time.
+/// Anyone can replay the compressed ticket accounts up to that nullifier,
+/// collect the distinct owner commitments and rebuild `holders_root` over
+/// `holder_leaf`; a matching root with `distinct_holders` leaves proves the
+/// figure. Holders can also check their own commitment is counted with a
+/// Merkle proof. Only commitments, already public in ticket state, go
+/// into the tree.
+///
+/// On-chain the count is bounded by the live ticket supply, so it can never
+/// claim more holders than tickets.
+pub fn publish_holder_count(
+    ctx: Context<PublishHolderCount>,
+    distinct_holders: u32,
+    holders_root: [u8; 32],
+) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+
+    let live_tickets = event_config
+        .tickets_minted
+        .saturating_sub(event_config.tickets_voided)
+        .saturating_sub(event_config.tickets_refunded);
+    require!(
+        distinct_holders <= live_tickets && (distinct_holders > 0 || live_tickets == 0),
+        EncoreError::InvalidHolderCount
+    );
+
+    emit!(HolderCountPublished {
+        event_config: event_config.key(),
+        distinct_holders,
+        live_tickets,
+        holders_root,
+        as_of_nullifier: event_config.nullifiers_created,
+        published_at: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "✅ Holder count published: {} holders of {} tickets",
+        distinct_holders,
+        live_tickets
+    );
+
+    Ok(())
+}
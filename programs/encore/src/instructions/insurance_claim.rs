@@ -0,0 +1,258 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{EVENT_SEED, INSURANCE_POOL_SEED, INSURANCE_SEED, LEDGER_SEED};
+use crate::errors::EncoreError;
+use crate::events::{InsuranceClaimed, WaitlistSeatReleased};
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::invariants;
+use crate::memo::memo_hash;
+use crate::state::{
+    EventConfig, EventLedger, FieldValue, InsuranceCoverage, LedgerEntryKind, Nullifier,
+    OwnershipMode, PrivateTicket, Waitlist,
+};
+
+#[derive(Accounts)]
+pub struct ClaimInsurance<'info> {
+    /// Holder who bought the cover (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Cover being claimed - closed and rent returned to holder
+    #[account(
+        mut,
+        seeds = [INSURANCE_SEED, event_config.key().as_ref(), &coverage.owner_commitment],
+        bump = coverage.bump,
+        has_one = holder @ EncoreError::Unauthorized,
+        has_one = event_config @ EncoreError::InvalidTicket,
+        close = holder,
+    )]
+    pub coverage: Account<'info, InsuranceCoverage>,
+
+    /// Insurance pool PDA paying the claim
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_SEED, event_config.key().as_ref()],
+        bump,
+    )]
+    pub insurance_pool: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    /// Waitlist of the surrendered ticket's tier; pass to release the seat
+    /// to it (ignored once the event is cancelled)
+    #[account(
+        mut,
+        constraint = waitlist.event_config == event_config.key() @ EncoreError::Unauthorized,
+    )]
+    pub waitlist: Option<Account<'info, Waitlist>>,
+
+    /// CHECK: Instructions sysvar; pass to attach the transaction's SPL memo
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim the insurance payout for a ticket.
+///
+/// Allowed when the event was cancelled, or when the holder cancels before
+/// the policy's cutoff. Either way the holder surrenders the ticket: the
+/// nullifier for its secret is created, so it can no longer be sold or
+/// transferred (and a ticket already sold can't be claimed on).
+///
+/// The ticket is closed as well, so a ticket the organizer voided (closed
+/// without a nullifier) can't be surrendered for a payout.
+///
+/// # Operations
+/// 1. Validate claim is within policy terms
+/// 2. Verify holder owns the insured ticket via commitment
+/// 3. CREATE nullifier and CLOSE the ticket (surrenders it)
+/// 4. Pay out from the insurance pool and close the cover
+/// 5. Release the seat to the tier's waitlist, if one was passed
+pub fn claim_insurance<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimInsurance<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    holder_secret: [u8; 32],
+    // Insured ticket, closed with this data (the CPI fails on a mismatch)
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let coverage = &ctx.accounts.coverage;
+    let holder = &ctx.accounts.holder;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        event_config.cancelled || now <= coverage.cancel_deadline,
+        EncoreError::InsuranceClaimNotAllowed
+    );
+
+    // commitment = SHA256(owner_pubkey || secret)
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(holder.key.as_ref());
+    commitment_input.extend_from_slice(&holder_secret);
+    require!(
+        hash(&commitment_input).to_bytes() == coverage.owner_commitment,
+        EncoreError::NotTicketOwner
+    );
+
+    let payout = coverage.payout_lamports;
+    require!(
+        ctx.accounts.insurance_pool.lamports() >= payout,
+        EncoreError::InsufficientInsurancePool
+    );
+
+    // --- Light Protocol CPI Setup ---
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Create nullifier (surrender ticket) ---
+    let nullifier_seed = hash(&holder_secret);
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_nullifier_sequence()?;
+
+    let ticket_account = LightAccount::<PrivateTicket>::new_close(
+        &crate::ID,
+        &ticket_account_meta,
+        PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id,
+            owner_commitment: coverage.owner_commitment,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            ownership: OwnershipMode::Bound,
+        },
+    )?;
+
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    let nullifier_params =
+        address_tree_info.into_new_address_params_assigned_packed(nullifier_address_seed, Some(0));
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(ticket_account)? // CLOSE surrendered ticket
+        .with_new_addresses(&[nullifier_params])
+        .invoke(light_cpi_accounts)?;
+
+    // --- Pay out from pool using PDA signing ---
+    if payout > 0 {
+        let event_key = event_config.key();
+        let pool_seeds: &[&[u8]] = &[
+            INSURANCE_POOL_SEED,
+            event_key.as_ref(),
+            &[ctx.bumps.insurance_pool],
+        ];
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.insurance_pool.to_account_info(),
+                    to: ctx.accounts.holder.to_account_info(),
+                },
+                &[pool_seeds],
+            ),
+            payout,
+        )?;
+    }
+
+    ctx.accounts
+        .ledger
+        .record(LedgerEntryKind::InsurancePayout, payout)?;
+
+    event_config.tickets_refunded = event_config
+        .tickets_refunded
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(InsuranceClaimed {
+        event_config: event_config.key(),
+        coverage: coverage.key(),
+        holder: holder.key(),
+        payout_lamports: payout,
+        event_cancelled: event_config.cancelled,
+        memo_hash: memo_hash(ctx.accounts.instructions_sysvar.as_deref())?,
+    });
+
+    if !event_config.cancelled {
+        if let Some(waitlist) = ctx.accounts.waitlist.as_mut() {
+            waitlist.open_seats = waitlist
+                .open_seats
+                .checked_add(1)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+
+            emit!(WaitlistSeatReleased {
+                waitlist: waitlist.key(),
+                tier: waitlist.tier,
+                open_seats: waitlist.open_seats,
+            });
+        }
+    }
+
+    msg!("✅ Insurance claimed: {} lamports", payout);
+
+    invariants::check_event(&ctx.accounts.event_config)?;
+
+    Ok(())
+}
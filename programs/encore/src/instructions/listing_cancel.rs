@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::constants::LISTING_SEED;
 use crate::errors::EncoreError;
-use crate::state::{Listing, ListingStatus};
+use crate::state::Listing;
 
 #[derive(Accounts)]
 pub struct CancelListing<'info> {
@@ -13,7 +13,12 @@ pub struct CancelListing<'info> {
     /// Listing being cancelled - will be closed and rent returned to seller
     #[account(
         mut,
-        seeds = [LISTING_SEED, listing.seller.as_ref(), &listing.ticket_commitment],
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
         bump = listing.bump,
         close = seller,  // Close account and return rent to seller
     )]
@@ -31,10 +36,7 @@ pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
     let listing = &ctx.accounts.listing;
 
     // Validate listing status - can only cancel Active listings
-    require!(
-        listing.status == ListingStatus::Active,
-        EncoreError::ListingNotActive
-    );
+    require!(listing.is_active(), EncoreError::ListingNotActive);
 
     // Validate seller is the listing seller
     require!(listing.seller == seller.key(), EncoreError::NotSeller);
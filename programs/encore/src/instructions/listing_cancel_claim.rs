@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
 
-use crate::constants::{ESCROW_SEED, LISTING_SEED};
+use crate::constants::{ESCROW_SEED, LEDGER_SEED, LISTING_SEED, REPUTATION_SEED};
 use crate::errors::EncoreError;
-use crate::state::{Listing, ListingStatus};
+use crate::invariants;
+use crate::state::{
+    EventLedger, LedgerEntryKind, Listing, ListingStatus, Reputation, ReputationEntry,
+};
 
 #[derive(Accounts)]
 pub struct CancelClaim<'info> {
@@ -13,7 +17,12 @@ pub struct CancelClaim<'info> {
     /// Listing being unclaimed
     #[account(
         mut,
-        seeds = [LISTING_SEED, listing.seller.as_ref(), &listing.ticket_commitment],
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
         bump = listing.bump,
     )]
     pub listing: Account<'info, Listing>,
@@ -27,6 +36,33 @@ pub struct CancelClaim<'info> {
     )]
     pub escrow: SystemAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, listing.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    /// Buyer's reputation, charged with an abandoned claim
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + Reputation::INIT_SPACE,
+        seeds = [REPUTATION_SEED, buyer.key().as_ref()],
+        bump
+    )]
+    pub buyer_reputation: Account<'info, Reputation>,
+
+    /// Escrow's token account, for claims paid in an SPL mint
+    #[account(mut, token::authority = escrow)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Buyer's token account receiving a token refund
+    #[account(mut, token::authority = buyer)]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -38,9 +74,10 @@ pub struct CancelClaim<'info> {
 ///
 /// # Privacy Model
 /// - No conflict: This only affects the Listing state
-/// - Identity: The Buyer signs, proving they are listing.buyer
+/// - Identity: The Buyer signs, proving they are the claim's buyer
 ///
 /// # Escrow
+/// - Claims paid in an SPL mint are refunded from the escrow's token account
 /// - Refunds all SOL from escrow back to buyer
 ///
 /// # Operations
@@ -48,47 +85,89 @@ pub struct CancelClaim<'info> {
 /// 2. Validate buyer is the listing buyer
 /// 3. Refund escrow SOL to buyer
 /// 4. Reset listing to Active state
+/// 5. Count an abandoned claim on the buyer's reputation
 pub fn cancel_claim(ctx: Context<CancelClaim>) -> Result<()> {
     let buyer = &ctx.accounts.buyer;
     let listing_key = ctx.accounts.listing.key();
     let escrow_bump = ctx.bumps.escrow;
     let listing = &mut ctx.accounts.listing;
+    let status_before = listing.status;
 
     // Validate listing status is Claimed
-    require!(
-        listing.status == ListingStatus::Claimed,
-        EncoreError::ListingNotClaimed
-    );
+    let claim = listing.claim().ok_or(EncoreError::ListingNotClaimed)?;
 
     // Validate the signer is the buyer who claimed
-    require!(listing.buyer == Some(*buyer.key), EncoreError::NotBuyer);
+    require!(claim.buyer == *buyer.key, EncoreError::NotBuyer);
+    let payment_mint = claim.payment_mint;
 
-    // Refund escrow SOL to buyer using PDA signing
-    let escrow_balance = ctx.accounts.escrow.lamports();
-    if escrow_balance > 0 {
+    if let Some(mint) = payment_mint {
+        // Refund the token payment from the escrow's token account
+        let (Some(escrow_token), Some(buyer_token), Some(token_program)) = (
+            ctx.accounts.escrow_token_account.as_ref(),
+            ctx.accounts.buyer_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(EncoreError::TokenAccountsRequired);
+        };
+        require!(
+            escrow_token.mint == mint && buyer_token.mint == mint,
+            EncoreError::PaymentMintMismatch
+        );
         let escrow_seeds: &[&[u8]] = &[ESCROW_SEED, listing_key.as_ref(), &[escrow_bump]];
-
-        anchor_lang::system_program::transfer(
+        token::transfer(
             CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.escrow.to_account_info(),
-                    to: ctx.accounts.buyer.to_account_info(),
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: escrow_token.to_account_info(),
+                    to: buyer_token.to_account_info(),
+                    authority: ctx.accounts.escrow.to_account_info(),
                 },
                 &[escrow_seeds],
             ),
-            escrow_balance,
+            escrow_token.amount,
         )?;
-        msg!("💰 Refunded {} lamports to buyer", escrow_balance);
+        msg!(
+            "💰 Refunded {} of mint {} to buyer",
+            escrow_token.amount,
+            mint
+        );
+    } else {
+        // Refund escrow SOL to buyer using PDA signing
+        let escrow_balance = ctx.accounts.escrow.lamports();
+        if escrow_balance > 0 {
+            let escrow_seeds: &[&[u8]] = &[ESCROW_SEED, listing_key.as_ref(), &[escrow_bump]];
+
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to: ctx.accounts.buyer.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                escrow_balance,
+            )?;
+            msg!("💰 Refunded {} lamports to buyer", escrow_balance);
+        }
+
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::EscrowRefund, escrow_balance)?;
     }
 
     // Reset listing to Active state
     listing.status = ListingStatus::Active;
-    listing.buyer = None;
-    listing.buyer_commitment = None;
-    listing.claimed_at = None;
+
+    ctx.accounts.buyer_reputation.record(
+        buyer.key(),
+        ctx.bumps.buyer_reputation,
+        ReputationEntry::AbandonedClaim,
+    )?;
 
     msg!("✅ Claim cancelled by buyer: {:?}", buyer.key());
 
+    invariants::check_listing_transition(&status_before, &ctx.accounts.listing.status)?;
+
     Ok(())
 }
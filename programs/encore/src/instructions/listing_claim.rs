@@ -1,12 +1,40 @@
+#![allow(unexpected_cfgs)]
+
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount},
+};
+use light_sdk::{
+    address::PackedReadOnlyAddress,
+    cpi::{
+        v2::{CpiAccounts, LightSystemProgramCpi},
+        InvokeLightSystemProgram, LightCpiInstruction,
+    },
+    instruction::{PackedAddressTreeInfo, ValidityProof},
+};
 
-use crate::constants::{ESCROW_SEED, LISTING_SEED};
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{
+    BLOCKED_BUYER_SEED, CLIENT_NONCE_SEED, ESCROW_SEED, LEDGER_SEED, LISTING_SEED,
+};
 use crate::errors::EncoreError;
-use crate::state::{Listing, ListingStatus};
+use crate::events::ListingClaimed;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::invariants;
+use crate::proof_window::{check_proof_window, ProofWindow};
+use crate::state::{
+    ClientNonce, EventLedger, LedgerEntryKind, Listing, ListingClaim, ListingStatus,
+};
+
+/// Position of the buyer among `ClaimListing`'s accounts, for instructions
+/// that introspect a claim
+pub(crate) const CLAIM_BUYER_INDEX: usize = 0;
 
 #[derive(Accounts)]
-#[instruction()]
+#[instruction(buyer_commitment: [u8; 32], client_nonce: u64)]
 pub struct ClaimListing<'info> {
     /// Buyer who is claiming the listing
     #[account(mut)]
@@ -15,7 +43,12 @@ pub struct ClaimListing<'info> {
     /// Listing being claimed
     #[account(
         mut,
-        seeds = [LISTING_SEED, listing.seller.as_ref(), &listing.ticket_commitment],
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
         bump = listing.bump,
     )]
     pub listing: Account<'info, Listing>,
@@ -29,6 +62,53 @@ pub struct ClaimListing<'info> {
     )]
     pub escrow: SystemAccount<'info>,
 
+    /// CHECK: Blocklist entry for the buyer on the listed event; must not exist
+    #[account(
+        seeds = [BLOCKED_BUYER_SEED, listing.event_config.as_ref(), buyer.key().as_ref()],
+        bump,
+        constraint = blocked_buyer.data_is_empty() @ EncoreError::BuyerBlocked,
+    )]
+    pub blocked_buyer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, listing.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    /// Pass to make retries with the same `client_nonce` fail instead of double-claiming
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + ClientNonce::INIT_SPACE,
+        seeds = [CLIENT_NONCE_SEED, buyer.key().as_ref(), &client_nonce.to_le_bytes()],
+        bump
+    )]
+    pub client_nonce_record: Option<Account<'info, ClientNonce>>,
+
+    /// SPL mint to pay in instead of SOL; must be one of the listing's
+    /// payment options
+    pub payment_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut, token::mint = payment_mint, token::authority = buyer)]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Escrow's token account for `payment_mint`
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = escrow,
+    )]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Solana Pay reference; only its key is used, echoed in the event
+    pub reference: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -43,47 +123,181 @@ pub struct ClaimListing<'info> {
 /// - Buyer deposits listing.price_lamports to escrow PDA
 /// - SOL is held until sale completes or claim is cancelled
 ///
+/// # Payment mints
+/// When `payment_mint` is passed, the buyer pays that mint's price from
+/// `listing.payment_options` into the escrow's token account instead. The
+/// claim records the mint so settlement and refunds use the same route.
+/// Token deposits aren't recorded in the lamport ledger.
+///
+/// # Freshness
+/// The ticket may have been transferred out-of-band since it was listed.
+/// The proof must show the nullifier derived from `listing.nullifier_seed`
+/// does not exist yet, so a stale listing can't be
+/// claimed (and the buyer's funds locked until the claim times out).
+///
+/// # Solana Pay
+/// A `reference` account is echoed in `ListingClaimed` so point-of-sale
+/// systems can match the claim to an in-person sale.
+///
+/// # Retries
+/// When `client_nonce_record` is passed, `client_nonce` is recorded and a
+/// resubmission with the same nonce fails. Otherwise `client_nonce` is ignored.
+///
 /// # Operations
 /// 1. Validate listing is Active
-/// 2. Transfer SOL from buyer to escrow
-/// 3. Set buyer, buyer_commitment, claimed_at
-/// 4. Set status to Claimed
-pub fn claim_listing(
-    ctx: Context<ClaimListing>,
+/// 2. Prove the ticket's nullifier does not exist (read-only address)
+/// 3. Transfer SOL (or the chosen payment mint) from buyer to escrow
+/// 4. Set buyer, buyer_commitment, claimed_at
+/// 5. Set status to Claimed
+pub fn claim_listing<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimListing<'info>>,
     buyer_commitment: [u8; 32], // Buyer's new commitment for ticket transfer
+    client_nonce: u64,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    proof_window: Option<ProofWindow>,
 ) -> Result<()> {
+    check_proof_window(proof_window)?;
+
     let buyer = &ctx.accounts.buyer;
     let listing = &mut ctx.accounts.listing;
+    let status_before = listing.status;
     let escrow = &ctx.accounts.escrow;
 
     // Validate listing status
+    require!(listing.is_active(), EncoreError::ListingNotActive);
     require!(
-        listing.status == ListingStatus::Active,
-        EncoreError::ListingNotActive
+        listing.queue_head == listing.queue_tail,
+        EncoreError::ClaimQueueNotEmpty
     );
 
-    // Transfer SOL from buyer to escrow
-    let price = listing.price_lamports;
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: buyer.to_account_info(),
-                to: escrow.to_account_info(),
-            },
-        ),
-        price,
+    // --- Freshness: ticket's nullifier must not exist ---
+    prove_ticket_unspent(
+        ctx.accounts.buyer.as_ref(),
+        ctx.remaining_accounts,
+        proof,
+        address_tree_info,
+        &listing.nullifier_seed,
     )?;
 
-    msg!("💰 Deposited {} lamports to escrow", price);
+    let payment_mint = ctx.accounts.payment_mint.as_ref().map(|mint| mint.key());
+    if let Some(mint) = payment_mint {
+        // Route the token payment into the escrow's token account
+        let price = listing
+            .payment_price(&mint)
+            .ok_or(EncoreError::UnsupportedPaymentMint)?;
+        let (Some(buyer_token), Some(escrow_token), Some(token_program)) = (
+            ctx.accounts.buyer_token_account.as_ref(),
+            ctx.accounts.escrow_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(EncoreError::TokenAccountsRequired);
+        };
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: buyer_token.to_account_info(),
+                    to: escrow_token.to_account_info(),
+                    authority: buyer.to_account_info(),
+                },
+            ),
+            price,
+        )?;
+
+        msg!("💰 Deposited {} of mint {} to escrow", price, mint);
+    } else {
+        // Transfer SOL from buyer to escrow
+        let price = listing.price_lamports;
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: buyer.to_account_info(),
+                    to: escrow.to_account_info(),
+                },
+            ),
+            price,
+        )?;
+
+        msg!("💰 Deposited {} lamports to escrow", price);
+
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::EscrowDeposit, price)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(record) = ctx.accounts.client_nonce_record.as_mut() {
+        record.consume(
+            buyer.key(),
+            client_nonce,
+            now,
+            ctx.bumps.client_nonce_record.unwrap_or_default(),
+        );
+    }
 
     // Set claim data
-    listing.buyer = Some(*buyer.key);
-    listing.buyer_commitment = Some(buyer_commitment);
-    listing.claimed_at = Some(Clock::get()?.unix_timestamp);
-    listing.status = ListingStatus::Claimed;
+    listing.status = ListingStatus::Claimed(ListingClaim {
+        buyer: *buyer.key,
+        buyer_commitment,
+        claimed_at: now,
+        terms_hash: listing.terms_hash(),
+        payment_mint,
+    });
+
+    emit!(ListingClaimed {
+        listing: listing.key(),
+        buyer: *buyer.key,
+        event_config: listing.event_config,
+        ticket_id: listing.ticket_id,
+        payment_mint,
+        reference: ctx
+            .accounts
+            .reference
+            .as_ref()
+            .map(|reference| reference.key()),
+    });
 
     msg!("✅ Listing claimed by buyer: {:?}", buyer.key());
 
+    invariants::check_listing_transition(&status_before, &ctx.accounts.listing.status)?;
+    invariants::check_escrow(&ctx.accounts.listing, ctx.accounts.escrow.lamports())?;
+
+    Ok(())
+}
+
+/// Prove the ticket behind `nullifier_seed` is unspent: its nullifier must
+/// not exist yet (a read-only address in the Light CPI).
+pub(crate) fn prove_ticket_unspent<'info>(
+    payer: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    nullifier_seed: &[u8; 32],
+) -> Result<()> {
+    let light_cpi_accounts = CpiAccounts::new(payer, remaining_accounts, LIGHT_CPI_SIGNER);
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    let (nullifier_address, _) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let unspent_nullifier = PackedReadOnlyAddress {
+        address: nullifier_address,
+        address_merkle_tree_root_index: address_tree_info.root_index,
+        address_merkle_tree_account_index: address_tree_info.address_merkle_tree_pubkey_index,
+    };
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_read_only_addresses(&[unspent_nullifier])
+        .invoke(light_cpi_accounts)?;
+
     Ok(())
 }
@@ -13,7 +13,12 @@ pub struct CloseListing<'info> {
     /// Listing being closed - rent returned to seller
     #[account(
         mut,
-        seeds = [LISTING_SEED, listing.seller.as_ref(), &listing.ticket_commitment],
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
         bump = listing.bump,
         close = seller,
     )]
@@ -22,6 +27,7 @@ pub struct CloseListing<'info> {
 
 /// Close a cancelled or completed listing to reclaim rent.
 /// This is used to clean up "zombie" listings that are no longer needed.
+/// Left long enough, anyone can close it instead with `collect_listing`.
 ///
 /// # Operations
 /// 1. Validate listing is Cancelled or Completed
@@ -35,7 +41,10 @@ pub fn close_listing(ctx: Context<CloseListing>) -> Result<()> {
 
     // Can only close Cancelled or Completed listings
     require!(
-        listing.status == ListingStatus::Cancelled || listing.status == ListingStatus::Completed,
+        matches!(
+            listing.status,
+            ListingStatus::Cancelled | ListingStatus::Completed
+        ),
         EncoreError::ListingNotCancelled
     );
 
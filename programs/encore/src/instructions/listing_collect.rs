@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::bps;
+use crate::constants::{LISTING_GC_BOUNTY_BPS, LISTING_GC_GRACE_SECONDS, LISTING_SEED};
+use crate::errors::EncoreError;
+use crate::events::ListingCollected;
+use crate::state::{Listing, ListingStatus};
+
+#[derive(Accounts)]
+pub struct CollectListing<'info> {
+    /// Anyone; paid the bounty
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// CHECK: Listing's seller, receives the rest of the rent and any bond
+    #[account(mut, address = listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// Listing being collected - closed to the seller
+    #[account(
+        mut,
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
+        bump = listing.bump,
+        close = seller,
+    )]
+    pub listing: Account<'info, Listing>,
+}
+
+/// Close a finished listing the seller left open. Permissionless, so
+/// keepers can stop dead listings piling up.
+///
+/// Once a Completed or Cancelled listing has sat for
+/// `LISTING_GC_GRACE_SECONDS`, the cranker takes `LISTING_GC_BOUNTY_BPS`
+/// of its rent and the seller gets the rest, bond included, as
+/// `close_listing` would have given them.
+///
+/// # Operations
+/// 1. Validate listing is Cancelled or Completed and past its grace period
+/// 2. Pay the bounty out of the rent
+/// 3. Close account to the seller (handled by Anchor's `close` constraint)
+pub fn collect_listing(ctx: Context<CollectListing>) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+
+    require!(
+        matches!(
+            listing.status,
+            ListingStatus::Cancelled | ListingStatus::Completed
+        ),
+        EncoreError::ListingNotCancelled
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= listing.settled_at + LISTING_GC_GRACE_SECONDS,
+        EncoreError::ListingGracePeriod
+    );
+
+    let rent = listing
+        .to_account_info()
+        .lamports()
+        .saturating_sub(listing.seller_bond);
+    let bounty = bps::share(rent, LISTING_GC_BOUNTY_BPS);
+    ctx.accounts.listing.sub_lamports(bounty)?;
+    ctx.accounts.cranker.add_lamports(bounty)?;
+
+    emit!(ListingCollected {
+        listing: ctx.accounts.listing.key(),
+        seller: ctx.accounts.seller.key(),
+        cranker: ctx.accounts.cranker.key(),
+        bounty,
+    });
+
+    Ok(())
+}
@@ -1,32 +1,58 @@
 #![allow(unexpected_cfgs)]
 
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use anchor_spl::token::{self, Token, TokenAccount};
 use light_sdk::{
     account::LightAccount,
-    address::v2::derive_address,
     cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
-    instruction::{PackedAddressTreeInfo, ValidityProof},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
 };
 
-use crate::constants::{ESCROW_SEED, LISTING_SEED, TICKET_SEED};
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{
+    COMPLETION_AUTH_DOMAIN, ESCROW_SEED, LEDGER_SEED, LISTING_SEED, METRICS_SEED,
+    PROCEEDS_HOLD_SEED, PROTOCOL_SEED, REPUTATION_SEED, ROYALTY_VAULT_SEED, TICKET_SEED,
+    TREASURY_SEED,
+};
 use crate::errors::EncoreError;
-use crate::events::SaleCompleted;
+use crate::events::{ProtocolFeeCollected, SaleCompleted};
+use crate::instructions::listing_claim::prove_ticket_unspent;
+use crate::instructions::listing_refund_queued_claim::refund_queued_claims;
 use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
 use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
-use crate::state::{Listing, ListingStatus, Nullifier, PrivateTicket};
+use crate::invariants;
+use crate::proof_window::{check_proof_window, ProofWindow};
+use crate::signature::has_ed25519_signature;
+use crate::state::{
+    EventConfig, EventLedger, FeeBreakdown, FeeExemption, FeeSource, FieldValue, HoldStatus,
+    LedgerEntryKind, Listing, ListingStatus, Nullifier, OwnershipMode, PrivateTicket, ProceedsHold,
+    ProtocolConfig, ProtocolMetrics, ProtocolTreasury, Reputation, ReputationEntry, RoyaltyVault,
+    TransferPolicy,
+};
 
 #[derive(Accounts)]
 #[instruction()]
 pub struct CompleteSale<'info> {
-    /// Seller who is completing the sale
+    /// Seller receiving the proceeds. Either signs this transaction or has
+    /// pre-signed a completion authorization (see `completion_message`).
+    /// CHECK: Must be the listing's seller
+    #[account(mut, address = listing.seller)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// Seller or keeper submitting the transaction (pays fees)
     #[account(mut)]
-    pub seller: Signer<'info>,
+    pub payer: Signer<'info>,
 
     /// Listing being completed
     #[account(
         mut,
-        seeds = [LISTING_SEED, listing.seller.as_ref(), &listing.ticket_commitment],
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
         bump = listing.bump,
     )]
     pub listing: Account<'info, Listing>,
@@ -40,65 +66,340 @@ pub struct CompleteSale<'info> {
     )]
     pub escrow: SystemAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, listing.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol treasury receiving the sale fee
+    #[account(mut, seeds = [TREASURY_SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
+    /// Seller's reputation, credited with the completed sale
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Reputation::INIT_SPACE,
+        seeds = [REPUTATION_SEED, seller.key().as_ref()],
+        bump
+    )]
+    pub seller_reputation: Account<'info, Reputation>,
+
+    /// Listed event, used to match a fee exemption to its organizer and
+    /// count the sale
+    #[account(mut, address = listing.event_config)]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Fee exemption for this event or its organizer, if one was granted
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    /// CHECK: Instructions sysvar, used to find a delegated completion signature
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Receives the seller's proceeds instead of the seller, required when
+    /// the event sets `escrow_resale_proceeds`
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProceedsHold::INIT_SPACE,
+        seeds = [PROCEEDS_HOLD_SEED, listing.key().as_ref()],
+        bump
+    )]
+    pub proceeds_hold: Option<Account<'info, ProceedsHold>>,
+
+    /// Collects the resale royalty, required when the event sets one
+    #[account(
+        mut,
+        seeds = [ROYALTY_VAULT_SEED, listing.event_config.as_ref()],
+        bump = royalty_vault.bump,
+    )]
+    pub royalty_vault: Option<Account<'info, RoyaltyVault>>,
+
+    /// Escrow's token account, for claims paid in an SPL mint
+    #[account(mut, token::authority = escrow)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::authority = seller)]
+    pub seller_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Treasury's token account receiving the sale fee in the payment mint
+    #[account(mut, token::authority = treasury)]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
+/// Message a seller signs to let a keeper complete the sale:
+/// domain || listing || buyer_commitment.
+pub fn completion_message(listing: &Pubkey, buyer_commitment: &[u8; 32]) -> Vec<u8> {
+    let mut message = Vec::with_capacity(COMPLETION_AUTH_DOMAIN.len() + 64);
+    message.extend_from_slice(COMPLETION_AUTH_DOMAIN);
+    message.extend_from_slice(listing.as_ref());
+    message.extend_from_slice(buyer_commitment);
+    message
+}
+
+/// Split a claim's escrowed payment into royalty, protocol fee and seller
+/// proceeds, as `complete_sale` pays it out.
+///
+/// SOL claims pay the event royalty, taken on face value for official
+/// exchange listings. SPL claims pay no royalty (events with one only
+/// settle in SOL). Official exchange listings pay the seller exactly face
+/// value and the protocol the rest; otherwise any fee exemption applies.
+pub(crate) fn sale_fees(
+    listing: &Listing,
+    escrow_amount: u64,
+    event_key: &Pubkey,
+    event_config: &EventConfig,
+    protocol_config: &ProtocolConfig,
+    fee_exemption: Option<&FeeExemption>,
+) -> Result<FeeBreakdown> {
+    let paid_in_sol = listing
+        .claim()
+        .is_some_and(|claim| claim.payment_mint.is_none());
+    let face_value = if paid_in_sol && listing.official_exchange {
+        listing.face_value_lamports
+    } else {
+        escrow_amount
+    };
+    let royalty = match event_config.royalty.as_ref() {
+        Some(royalty) if paid_in_sol => royalty.royalty(face_value),
+        _ => 0,
+    };
+
+    let (protocol_fee, net_to_seller) = if paid_in_sol && listing.official_exchange {
+        // Seller gets exactly face value; the protocol keeps the rest of
+        // the fees the buyer paid on top
+        let protocol_fee = escrow_amount
+            .checked_sub(listing.face_value_lamports)
+            .and_then(|v| v.checked_sub(royalty))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        (protocol_fee, listing.face_value_lamports)
+    } else {
+        let mut protocol_fee = protocol_config.sale_fee(escrow_amount);
+        if let Some(exemption) = fee_exemption {
+            require!(
+                exemption.applies_to(event_key, &event_config.authority),
+                EncoreError::InvalidFeeExemption
+            );
+            protocol_fee = exemption.apply(protocol_fee);
+        }
+        let net_to_seller = escrow_amount
+            .checked_sub(protocol_fee)
+            .and_then(|v| v.checked_sub(royalty))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        (protocol_fee, net_to_seller)
+    };
+
+    Ok(FeeBreakdown {
+        gross: escrow_amount,
+        face_value,
+        royalty,
+        protocol_fee,
+        organizer_fee: 0,
+        net_to_seller,
+    })
+}
+
 /// Complete a marketplace sale by transferring the ticket to the buyer.
 ///
-/// # Privacy Model (Issue #009 pattern)
-/// - Seller proves ownership via secret + commitment
-/// - Creates nullifier to prevent double-spend
+/// # Delegated completion
+/// If the seller doesn't sign, the transaction must include an ed25519
+/// program instruction with the seller's signature over
+/// `completion_message(listing, buyer_commitment)`, so a keeper can
+/// complete the sale as soon as a claim lands. The keeper holds nothing of
+/// the seller's beyond that signature: the listed ticket's data comes from
+/// the indexer, and the keyed ticket is closed on the authorization alone.
+///
+/// # Already-transferred tickets
+/// Before spending the ticket, `unspent_proof` must show its nullifier
+/// doesn't exist yet (a read-only address, as in `claim_listing`), so a
+/// ticket that moved after the claim is caught before the escrow or the
+/// listing is touched. A failed Light CPI aborts the transaction before
+/// the program could return its own error; clients map the failure to
+/// `TicketAlreadyTransferred` with `light_error::from_instruction_error`,
+/// and the buyer cancels the claim to be refunded.
+///
+/// # Queued claims
+/// Backup claims still queued are refunded with the sale: the first
+/// `2 * queued_refunds` remaining accounts are (queued claim, buyer)
+/// pairs, ahead of the Light accounts. Any left out are refunded later
+/// with `refund_queued_claim`.
+///
+/// # Royalties
+/// If the event sets a royalty, that slice of the sale goes to the event's
+/// `RoyaltyVault`; `distribute_royalties` splits it among the recipients
+/// later, keeping this instruction's cost independent of their number.
+///
+/// # Buyer protection
+/// When the event sets `escrow_resale_proceeds`, the seller's proceeds go
+/// to a `ProceedsHold` instead. The seller withdraws them once the buyer's
+/// ticket is redeemed or the event has passed; if the ticket is voided
+/// first, the buyer reclaims them.
+///
+/// Claims paid in an SPL mint settle from the escrow's token account: the
+/// protocol fee goes to the treasury's token account and the rest to the
+/// seller's. Events with royalties or held proceeds only settle in SOL.
+///
+/// Official exchange listings pay the seller exactly face value; fee
+/// exemptions don't apply since the fees were priced into the listing.
+///
+/// # Keyed tickets
+/// - The listed ticket is held under the seller's key, so the seller's
+///   signature (or authorization) proves ownership; no secret goes on-chain
+/// - Creates the ticket's key nullifier to prevent double-spend
 /// - Creates new ticket with buyer's commitment
+/// - Closes the listed ticket, so the buyer's ticket carries its proven
+///   face value, fields and add-ons
+///
+/// An official exchange listing's face value must be the ticket's.
+///
+/// # Secret handoff
+/// Nothing is handed off. The buyer's ticket is issued to the
+/// `buyer_commitment` registered at claim time, built from a secret only
+/// the buyer holds, so it's spendable as soon as the sale lands whether or
+/// not the seller's frontend is still around. Sealing the seller's secret
+/// to the buyer would need that secret on-chain to prove the listed ticket,
+/// which keyed listings avoid.
+///
+/// # Linked pairs
+/// The listed ticket is closed as unlinked (`link_id: None`), so half of a
+/// linked pair can't be sold on its own: the CPI fails and the buyer
+/// cancels the claim. Pairs move together with `transfer_ticket`.
 ///
 /// # Operations
-/// 1. Validate listing is Claimed
-/// 2. Verify seller owns the ticket via commitment
-/// 3. CREATE nullifier (prevents reuse of this secret)
-/// 4. CREATE new ticket with buyer's commitment
-/// 5. Set listing status to Completed
+/// 1. Validate listing is Claimed on unchanged terms and seller authorized completion
+/// 2. Verify the closed ticket is the listed one via its nullifier seed
+/// 3. Prove the ticket's nullifier does not exist (read-only address)
+/// 4. CREATE nullifier (prevents reuse of the ticket)
+/// 5. CREATE new ticket with buyer's commitment, CLOSE the listed ticket
+/// 6. Pay out escrow to seller (or the proceeds hold), minus the protocol sale
+///    fee and any royalty
+/// 7. Set listing status to Completed
+/// 8. Refund the queued claims passed along
+/// 9. Credit the sale to the seller's reputation
 pub fn complete_sale<'info>(
     ctx: Context<'_, '_, '_, 'info, CompleteSale<'info>>,
     proof: ValidityProof,
+    unspent_proof: ValidityProof, // Non-inclusion of the listed ticket's nullifier
     address_tree_info: PackedAddressTreeInfo,
     output_state_tree_index: u8,
     new_ticket_address_seed: [u8; 32],
     _ticket_bump: u8,
-    seller_secret: [u8; 32],
+    // Listed ticket, closed with this data and the listing's
+    ticket_account_meta: CompressedAccountMeta,
+    current_original_price: u64,
+    current_usd_micros_per_sol: Option<u64>,
+    current_name_commitment: Option<[u8; 32]>,
+    current_provenance: [u8; 32],
+    current_custom_fields: Vec<FieldValue>,
+    current_addons: u8,
+    proof_window: Option<ProofWindow>,
+    queued_refunds: u8,
 ) -> Result<()> {
+    check_proof_window(proof_window)?;
+
+    // Queued claims to refund come first in the remaining accounts
+    let queued_len = 2 * queued_refunds as usize;
+    require!(
+        ctx.remaining_accounts.len() >= queued_len,
+        EncoreError::QueuedClaimMismatch
+    );
+    let (queued_accounts, light_accounts) = ctx.remaining_accounts.split_at(queued_len);
+
     let seller = &ctx.accounts.seller;
 
     // Get listing key and escrow bump before mutable borrow
     let listing_key = ctx.accounts.listing.key();
     let escrow_bump = ctx.bumps.escrow;
 
+    // Approval-gated events only transfer through approved requests
+    require!(
+        ctx.accounts.event_config.transfer_policy == TransferPolicy::Open,
+        EncoreError::TransferApprovalRequired
+    );
+    require!(
+        ctx.accounts.proceeds_hold.is_some() == ctx.accounts.event_config.escrow_resale_proceeds,
+        EncoreError::ProceedsHoldRequired
+    );
+
     let listing = &mut ctx.accounts.listing;
+    let status_before = listing.status;
 
     // Validate listing status
+    let claim = *listing.claim().ok_or(EncoreError::ListingNotClaimed)?;
+
+    // Settle against the terms the buyer locked funds for
     require!(
-        listing.status == ListingStatus::Claimed,
-        EncoreError::ListingNotClaimed
+        claim.terms_hash == listing.terms_hash(),
+        EncoreError::ListingTermsChanged
     );
 
-    // Verify seller owns the ticket via commitment
-    // commitment = SHA256(owner_pubkey || secret)
-    let mut commitment_input = Vec::with_capacity(64);
-    commitment_input.extend_from_slice(seller.key.as_ref());
-    commitment_input.extend_from_slice(&seller_secret);
-    let computed_commitment = hash(&commitment_input);
+    let buyer_commitment = claim.buyer_commitment;
+
+    if !seller.is_signer {
+        require!(
+            has_ed25519_signature(
+                &ctx.accounts.instructions_sysvar.to_account_info(),
+                seller.key,
+                &completion_message(&listing_key, &buyer_commitment),
+            ),
+            EncoreError::CompletionNotAuthorized
+        );
+    }
+
+    // The listing names the ticket by its nullifier seed
+    let nullifier_seed = PrivateTicket::key_nullifier_seed(&ticket_account_meta.address);
     require!(
-        computed_commitment.to_bytes() == listing.ticket_commitment,
-        EncoreError::NotTicketOwner
+        nullifier_seed == listing.nullifier_seed,
+        EncoreError::ListingTicketMismatch
     );
 
-    // Get buyer commitment from listing (must be set during claim)
-    let buyer_commitment = listing
-        .buyer_commitment
-        .ok_or(EncoreError::ListingNotClaimed)?;
+    // --- Freshness: ticket's nullifier must not exist ---
+    prove_ticket_unspent(
+        ctx.accounts.payer.as_ref(),
+        light_accounts,
+        unspent_proof,
+        address_tree_info,
+        &nullifier_seed,
+    )?;
+
+    // The listed ticket as claimed; closing it below checks the claim
+    // against the state tree
+    let ticket = PrivateTicket {
+        event_config: listing.event_config,
+        ticket_id: listing.ticket_id,
+        owner_commitment: listing.ticket_commitment,
+        original_price: current_original_price,
+        usd_micros_per_sol: current_usd_micros_per_sol,
+        name_commitment: current_name_commitment,
+        link_id: None,
+        provenance: current_provenance,
+        custom_fields: current_custom_fields,
+        addons: current_addons,
+        ownership: OwnershipMode::Key(seller.key()),
+    };
+    require!(
+        !listing.official_exchange || listing.face_value_lamports == ticket.original_price,
+        EncoreError::ExchangePriceMismatch
+    );
 
     // --- Light Protocol CPI Setup ---
     let light_cpi_accounts = CpiAccounts::new(
-        ctx.accounts.seller.as_ref(),
-        ctx.remaining_accounts,
+        ctx.accounts.payer.as_ref(),
+        light_accounts,
         LIGHT_CPI_SIGNER,
     );
 
@@ -107,18 +408,10 @@ pub fn complete_sale<'info>(
         .get_tree_pubkey(&light_cpi_accounts)
         .map_err(|_| EncoreError::InvalidAddressTree)?;
 
-    // Validate V2 address tree (skip in test mode)
-    #[cfg(not(feature = "test-mode"))]
-    if address_tree_pubkey.to_bytes() != light_sdk_types::ADDRESS_TREE_V2 {
-        msg!("Invalid address tree: must use V2");
-        return Err(ProgramError::InvalidAccountData.into());
-    }
+    check_address_tree(&address_tree_pubkey)?;
 
     // --- Step 1: Create nullifier ---
-    // Nullifier address = derive(["nullifier", hash(secret)])
-    // Using hash of secret for the nullifier seed
-    let nullifier_seed = hash(&seller_secret);
-
+    // Nullifier address = derive(["nullifier", key_nullifier_seed(ticket)])
     let (nullifier_address, nullifier_address_seed) = derive_address(
         &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
         &address_tree_pubkey,
@@ -126,11 +419,13 @@ pub fn complete_sale<'info>(
     );
     msg!("Nullifier address: {:?}", nullifier_address);
 
-    let nullifier_account = LightAccount::<Nullifier>::new_init(
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
         &crate::ID,
         Some(nullifier_address),
         output_state_tree_index,
     );
+    nullifier_account.event_config = listing.event_config;
+    nullifier_account.sequence = ctx.accounts.event_config.next_nullifier_sequence()?;
 
     // --- Step 2: Create new ticket with buyer's commitment ---
     let (new_ticket_address, new_ticket_seed) = derive_address(
@@ -145,12 +440,14 @@ pub fn complete_sale<'info>(
         Some(new_ticket_address),
         output_state_tree_index,
     );
-    new_ticket_account.event_config = listing.event_config;
-    new_ticket_account.ticket_id = listing.ticket_id; // Preserve ticket ID
-    new_ticket_account.owner_commitment = buyer_commitment; // Buyer's commitment
-    new_ticket_account.original_price = listing.price_lamports; // Preserve for resale cap
+    // Bought tickets are held under the buyer's commitment
+    *new_ticket_account =
+        ticket.reissue(&nullifier_address, buyer_commitment, OwnershipMode::Bound);
+
+    let ticket_account =
+        LightAccount::<PrivateTicket>::new_close(&crate::ID, &ticket_account_meta, ticket)?;
 
-    // --- Execute CPI: CREATE nullifier + CREATE new ticket ---
+    // --- Execute CPI: CREATE nullifier + CREATE new ticket, CLOSE listed ticket ---
     use light_sdk::cpi::v2::LightSystemProgramCpi;
 
     // Two new addresses: nullifier (index 0) and new ticket (index 1)
@@ -162,44 +459,220 @@ pub fn complete_sale<'info>(
     LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
         .with_light_account(nullifier_account)? // CREATE nullifier
         .with_light_account(new_ticket_account)? // CREATE new ticket
+        .with_light_account(ticket_account)? // CLOSE listed ticket
         .with_new_addresses(&[nullifier_params, new_ticket_params])
         .invoke(light_cpi_accounts)?;
 
-    // --- Step 3: Transfer escrow SOL to seller using PDA signing ---
-    let escrow_balance = ctx.accounts.escrow.lamports();
-    if escrow_balance > 0 {
-        let escrow_seeds: &[&[u8]] = &[ESCROW_SEED, listing_key.as_ref(), &[escrow_bump]];
+    let official_exchange = listing.official_exchange;
+    let payment_mint = claim.payment_mint;
+    let fees = if let Some(mint) = payment_mint {
+        // --- Step 3: Pay out the escrow's token account ---
+        // Royalties, held proceeds and the ledger are denominated in lamports
+        require!(
+            ctx.accounts.event_config.royalty.is_none() && ctx.accounts.proceeds_hold.is_none(),
+            EncoreError::UnsupportedPaymentMint
+        );
+        let (Some(escrow_token), Some(seller_token), Some(treasury_token), Some(token_program)) = (
+            ctx.accounts.escrow_token_account.as_ref(),
+            ctx.accounts.seller_token_account.as_ref(),
+            ctx.accounts.treasury_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(EncoreError::TokenAccountsRequired);
+        };
+        require!(
+            escrow_token.mint == mint && seller_token.mint == mint && treasury_token.mint == mint,
+            EncoreError::PaymentMintMismatch
+        );
 
-        anchor_lang::system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.escrow.to_account_info(),
-                    to: ctx.accounts.seller.to_account_info(),
-                },
-                &[escrow_seeds],
-            ),
-            escrow_balance,
+        let escrow_seeds: &[&[u8]] = &[ESCROW_SEED, listing_key.as_ref(), &[escrow_bump]];
+        let fees = sale_fees(
+            listing,
+            escrow_token.amount,
+            &ctx.accounts.event_config.key(),
+            &ctx.accounts.event_config,
+            &ctx.accounts.protocol_config,
+            ctx.accounts.fee_exemption.as_deref(),
         )?;
+
+        for (to, amount) in [
+            (treasury_token.to_account_info(), fees.protocol_fee),
+            (seller_token.to_account_info(), fees.net_to_seller),
+        ] {
+            if amount > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        token::Transfer {
+                            from: escrow_token.to_account_info(),
+                            to,
+                            authority: ctx.accounts.escrow.to_account_info(),
+                        },
+                        &[escrow_seeds],
+                    ),
+                    amount,
+                )?;
+            }
+        }
         msg!(
-            "💰 Transferred {} lamports from escrow to seller",
-            escrow_balance
+            "💰 Transferred {} of mint {} from escrow to seller",
+            fees.net_to_seller,
+            mint
         );
-    }
+
+        fees
+    } else {
+        // --- Step 3: Transfer escrow SOL to seller and treasury using PDA signing ---
+        let escrow_seeds: &[&[u8]] = &[ESCROW_SEED, listing_key.as_ref(), &[escrow_bump]];
+        let fees = sale_fees(
+            listing,
+            ctx.accounts.escrow.lamports(),
+            &ctx.accounts.event_config.key(),
+            &ctx.accounts.event_config,
+            &ctx.accounts.protocol_config,
+            ctx.accounts.fee_exemption.as_deref(),
+        )?;
+        let FeeBreakdown {
+            royalty,
+            protocol_fee,
+            net_to_seller: seller_proceeds,
+            ..
+        } = fees;
+
+        if royalty > 0 {
+            let vault = ctx
+                .accounts
+                .royalty_vault
+                .as_mut()
+                .ok_or(EncoreError::RoyaltyVaultRequired)?;
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to: vault.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                royalty,
+            )?;
+            vault.pending = vault
+                .pending
+                .checked_add(royalty)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+
+        if protocol_fee > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to: ctx.accounts.treasury.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                protocol_fee,
+            )?;
+            ctx.accounts
+                .treasury
+                .record_fee(FeeSource::Sale, protocol_fee)?;
+            ctx.accounts.metrics.record_fee(protocol_fee)?;
+
+            emit!(ProtocolFeeCollected {
+                event_config: listing.event_config,
+                source: FeeSource::Sale,
+                amount: protocol_fee,
+            });
+        }
+
+        // Held proceeds wait in the hold account until check-in
+        let proceeds_recipient = match ctx.accounts.proceeds_hold.as_mut() {
+            Some(hold) => {
+                hold.listing = listing_key;
+                hold.event_config = listing.event_config;
+                hold.seller = seller.key();
+                hold.buyer = claim.buyer;
+                hold.ticket_address = new_ticket_address;
+                hold.amount = seller_proceeds;
+                hold.status = HoldStatus::Held;
+                hold.bump = ctx.bumps.proceeds_hold.unwrap_or_default();
+                hold.to_account_info()
+            }
+            None => ctx.accounts.seller.to_account_info(),
+        };
+
+        if seller_proceeds > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to: proceeds_recipient,
+                    },
+                    &[escrow_seeds],
+                ),
+                seller_proceeds,
+            )?;
+            msg!(
+                "💰 Transferred {} lamports from escrow to seller",
+                seller_proceeds
+            );
+        }
+
+        let ledger = &mut ctx.accounts.ledger;
+        ledger.record(LedgerEntryKind::ResaleSettlement, seller_proceeds)?;
+        ledger.record(LedgerEntryKind::ProtocolFee, protocol_fee)?;
+        ledger.record(LedgerEntryKind::Royalty, royalty)?;
+
+        fees
+    };
+
+    ctx.accounts.event_config.marketplace_sales = ctx
+        .accounts
+        .event_config
+        .marketplace_sales
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    // Token-paid volume isn't in lamports, so only the sale is counted
+    ctx.accounts
+        .metrics
+        .record_resale(payment_mint.map_or(fees.gross, |_| 0))?;
 
     // Update listing status
     listing.status = ListingStatus::Completed;
+    listing.settled_at = Clock::get()?.unix_timestamp;
+
+    // The listing is finished, so its queue can be refunded
+    let refunded = refund_queued_claims(&listing_key, listing.created_at, queued_accounts)?;
+    if refunded > 0 {
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::EscrowRefund, refunded)?;
+        msg!("💰 Refunded {} lamports to queued buyers", refunded);
+    }
+
+    ctx.accounts.seller_reputation.record(
+        seller.key(),
+        ctx.bumps.seller_reputation,
+        ReputationEntry::CompletedSale,
+    )?;
 
     emit!(SaleCompleted {
         listing: listing.key(),
         seller: seller.key(),
-        buyer: listing.buyer.unwrap(),
+        buyer: claim.buyer,
         event_config: listing.event_config,
         ticket_id: listing.ticket_id,
         price_lamports: listing.price_lamports,
+        official_exchange,
+        payment_mint,
+        fees,
     });
 
     msg!("✅ Sale completed: nullifier created, new ticket issued to buyer");
 
+    invariants::check_listing_transition(&status_before, &ctx.accounts.listing.status)?;
+
     Ok(())
 }
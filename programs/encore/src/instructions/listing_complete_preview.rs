@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::{ESCROW_SEED, LISTING_SEED, PROTOCOL_SEED};
+use crate::errors::EncoreError;
+use crate::events::SalePreviewed;
+use crate::instructions::listing_complete::sale_fees;
+use crate::state::{EventConfig, FeeExemption, Listing, ProtocolConfig, TransferPolicy};
+
+#[derive(Accounts)]
+pub struct PreviewCompleteSale<'info> {
+    #[account(
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+
+    /// CHECK: Escrow PDA holding the buyer's payment
+    #[account(seeds = [ESCROW_SEED, listing.key().as_ref()], bump)]
+    pub escrow: SystemAccount<'info>,
+
+    #[account(address = listing.event_config)]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Fee exemption for this event or its organizer, if one was granted
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    /// Escrow's token account, for claims paid in an SPL mint
+    #[account(token::authority = escrow)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+/// Dry run of `complete_sale`'s settlement: checks the claim would settle
+/// and emits `SalePreviewed` with the exact royalty, protocol fee and
+/// seller proceeds, changing nothing.
+///
+/// Ownership (the seller's secret) and the completion authorization are
+/// checked only by `complete_sale` itself.
+pub fn preview_complete_sale(ctx: Context<PreviewCompleteSale>) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+    let event_config = &ctx.accounts.event_config;
+
+    require!(
+        event_config.transfer_policy == TransferPolicy::Open,
+        EncoreError::TransferApprovalRequired
+    );
+
+    let claim = listing.claim().ok_or(EncoreError::ListingNotClaimed)?;
+    require!(
+        claim.terms_hash == listing.terms_hash(),
+        EncoreError::ListingTermsChanged
+    );
+
+    let escrow_amount = match claim.payment_mint {
+        Some(mint) => {
+            require!(
+                event_config.royalty.is_none() && !event_config.escrow_resale_proceeds,
+                EncoreError::UnsupportedPaymentMint
+            );
+            let escrow_token = ctx
+                .accounts
+                .escrow_token_account
+                .as_ref()
+                .ok_or(EncoreError::TokenAccountsRequired)?;
+            require!(escrow_token.mint == mint, EncoreError::PaymentMintMismatch);
+            escrow_token.amount
+        }
+        None => ctx.accounts.escrow.lamports(),
+    };
+
+    let fees = sale_fees(
+        listing,
+        escrow_amount,
+        &event_config.key(),
+        event_config,
+        &ctx.accounts.protocol_config,
+        ctx.accounts.fee_exemption.as_deref(),
+    )?;
+
+    emit!(SalePreviewed {
+        listing: listing.key(),
+        event_config: listing.event_config,
+        buyer: claim.buyer,
+        payment_mint: claim.payment_mint,
+        proceeds_held: event_config.escrow_resale_proceeds,
+        fees,
+    });
+
+    Ok(())
+}
@@ -1,75 +1,225 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::System;
+use anchor_lang::system_program::{self, System};
 
-use crate::constants::LISTING_SEED;
+use crate::constants::{LISTING_SEED, PROTOCOL_SEED, SELLER_COUNTER_SEED};
 use crate::errors::EncoreError;
-use crate::state::{Listing, ListingStatus};
+use crate::events::{ExchangeListingCreated, ListingPriced};
+use crate::state::{EventConfig, Listing, ListingStatus, ProtocolConfig, SellerCounter};
 
 #[derive(Accounts)]
-#[instruction(ticket_commitment: [u8; 32])]
+#[instruction(ticket_commitment: [u8; 32], nullifier_seed: [u8; 32], price_lamports: u64, event_config: Pubkey)]
 pub struct CreateListing<'info> {
     /// Seller who is listing the ticket
     #[account(mut)]
     pub seller: Signer<'info>,
 
+    /// Seller's listing counter, supplying the listing nonce
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + SellerCounter::INIT_SPACE,
+        seeds = [SELLER_COUNTER_SEED, seller.key().as_ref()],
+        bump
+    )]
+    pub seller_counter: Account<'info, SellerCounter>,
+
     /// Listing account to be created
     #[account(
         init,
         payer = seller,
-        space = 8 + std::mem::size_of::<Listing>(),
-        seeds = [LISTING_SEED, seller.key().as_ref(), &ticket_commitment],
+        space = 8 + Listing::INIT_SPACE,
+        seeds = [
+            LISTING_SEED,
+            seller.key().as_ref(),
+            &ticket_commitment,
+            &seller_counter.listing_count.to_le_bytes(),
+        ],
         bump
     )]
     pub listing: Account<'info, Listing>,
 
+    /// Required for official exchange listings, to price the fees
+    #[account(seeds = [PROTOCOL_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Option<Account<'info, ProtocolConfig>>,
+
+    /// Event the ticket belongs to: checked for a resale blackout and used
+    /// to price an official exchange listing's royalty
+    #[account(address = event_config)]
+    pub listed_event: Account<'info, EventConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
 /// Create a new marketplace listing for a private ticket.
 ///
-/// # Privacy Model
-/// - Seller proves ownership via commitment (from ticket)
-/// - Encrypted secret allows ownership proof without revealing secret
-/// - Listing is public but ticket ownership remains private
+/// # Keyed tickets
+/// The listed ticket must be held under the seller's key
+/// (`OwnershipMode::Key`): `ticket_commitment` is the seller's key bytes
+/// and `nullifier_seed` is `PrivateTicket::key_nullifier_seed` of the
+/// ticket's address, which lets buyers check the ticket is unspent.
+/// `complete_sale` then closes it on the seller's signature, without a
+/// secret. Anything else fails with `ListingRequiresKeyedTicket`: a
+/// `Bound` ticket can only be spent by revealing its secret, so holders
+/// first move it to their key with `transfer_ticket`.
+///
+/// # Price context
+/// `ListingPriced` carries the asked price next to the event's maximum
+/// resale price for the seller-declared `original_price`, so wallets can
+/// show "face value × cap" without re-deriving the policy. The listing
+/// itself doesn't enforce the cap.
+///
+/// # Resale blackout
+/// Listings can't be created during the event's `resale_blackout_seconds`
+/// after the public on-sale, to damp instant flipping.
+///
+/// # Official exchange
+/// With `official_exchange` set to the ticket's face value, the listing
+/// must be priced at exactly face value plus the protocol sale fee and
+/// royalty (see `Listing::exchange_price`). The seller receives face value
+/// and nothing more, and `ExchangeListingCreated` flags the listing for
+/// fan-to-fan exchange frontends.
+///
+/// # Seller bond
+/// A nonzero `seller_bond` is moved onto the listing account. It comes back
+/// with the rent when the listing closes, unless a claim times out without
+/// completion: `sweep_expired_claim` then pays most of it to the buyer and
+/// the rest to the protocol.
+///
+/// # Auto-relist
+/// With `auto_relist`, a claim that falls through puts the listing back on
+/// sale on the same terms without the seller: anyone may release it after
+/// the timeout, and a swept listing returns to Active instead of Cancelled.
 ///
 /// # Operations
-/// 1. Validate price > 0
-/// 2. Create listing account
+/// 1. Validate the ticket is keyed to the seller and price > 0, or the
+///    exchange price for official listings
+/// 2. Create listing account at the seller's next nonce
 /// 3. Set status to Active
 pub fn create_listing(
     ctx: Context<CreateListing>,
-    ticket_commitment: [u8; 32], // The ticket's current commitment
-    encrypted_secret: [u8; 32],  // secret XOR hash(listing_pda)
+    ticket_commitment: [u8; 32], // The ticket's commitment: the seller's key
+    nullifier_seed: [u8; 32],    // key_nullifier_seed(ticket address)
     price_lamports: u64,
     event_config: Pubkey,
     ticket_id: u32,
     _ticket_address_seed: [u8; 32], // Not used, for client reference
     _ticket_bump: u8,               // Not used, for client reference
+    official_exchange: Option<u64>, // Face value, for official exchange listings
+    seller_bond: u64,
+    auto_relist: bool,
+    original_price: u64, // Ticket's face value, for the cap context in `ListingPriced`
 ) -> Result<()> {
     let seller = &ctx.accounts.seller;
-    let listing = &mut ctx.accounts.listing;
+
+    // Only tickets held under the seller's key can be listed
+    require!(
+        ticket_commitment == seller.key().to_bytes(),
+        EncoreError::ListingRequiresKeyedTicket
+    );
 
     // Validate price
     require!(price_lamports > 0, EncoreError::InvalidPrice);
+    require!(
+        !ctx.accounts
+            .listed_event
+            .in_resale_blackout(Clock::get()?.unix_timestamp),
+        EncoreError::ResaleBlackout
+    );
+    if let Some(face_value) = official_exchange {
+        // Exchange prices are in lamports
+        require!(
+            ctx.accounts.listed_event.settles_in(None),
+            EncoreError::SettlementMintMismatch
+        );
+        let protocol_config = ctx
+            .accounts
+            .protocol_config
+            .as_ref()
+            .ok_or(EncoreError::ExchangeAccountsRequired)?;
+        let exchange_price =
+            Listing::exchange_price(face_value, protocol_config, &ctx.accounts.listed_event)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        require!(
+            face_value > 0 && price_lamports == exchange_price,
+            EncoreError::ExchangePriceMismatch
+        );
+    }
+
+    let seller_counter = &mut ctx.accounts.seller_counter;
+    seller_counter.seller = *seller.key;
+    seller_counter.bump = ctx.bumps.seller_counter;
+    let nonce = seller_counter.next_nonce()?;
+
+    let listing = &mut ctx.accounts.listing;
 
     // Initialize listing
     listing.seller = *seller.key;
     listing.ticket_commitment = ticket_commitment;
-    listing.encrypted_secret = encrypted_secret;
+    listing.nullifier_seed = nullifier_seed;
     listing.price_lamports = price_lamports;
     listing.event_config = event_config;
     listing.ticket_id = ticket_id;
-    listing.buyer = None;
-    listing.buyer_commitment = None;
-    listing.claimed_at = None;
+    listing.nonce = nonce;
     listing.status = ListingStatus::Active;
+    listing.payment_options = Vec::new();
+    listing.settled_at = 0;
+    listing.queue_head = 0;
+    listing.queue_tail = 0;
+    listing.official_exchange = official_exchange.is_some();
+    listing.face_value_lamports = official_exchange.unwrap_or(0);
+    listing.seller_bond = seller_bond;
+    listing.auto_relist = auto_relist;
     listing.created_at = Clock::get()?.unix_timestamp;
     listing.bump = ctx.bumps.listing;
 
+    // The bond is held on the listing account until it closes
+    if seller_bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.seller.to_account_info(),
+                    to: ctx.accounts.listing.to_account_info(),
+                },
+            ),
+            seller_bond,
+        )?;
+        msg!("💰 Seller bond of {} lamports posted", seller_bond);
+    }
+
+    // Cap context for wallets; unavailable when the cap needs a price feed
+    let listed_event = &ctx.accounts.listed_event;
+    let max_price_lamports = listed_event
+        .resale_cap_base(original_price, None, None, Clock::get()?.unix_timestamp)
+        .ok()
+        .map(|base| listed_event.calculate_max_resale_price(base));
+
+    let listing = &ctx.accounts.listing;
+    emit!(ListingPriced {
+        listing: listing.key(),
+        event_config,
+        ticket_id,
+        price_lamports,
+        original_price,
+        max_price_lamports,
+    });
+
+    if let Some(face_value_lamports) = official_exchange {
+        emit!(ExchangeListingCreated {
+            listing: listing.key(),
+            seller: listing.seller,
+            event_config,
+            ticket_id,
+            face_value_lamports,
+            price_lamports,
+        });
+    }
+
     msg!(
-        "✅ Listing created: {} lamports for ticket {}",
+        "✅ Listing created: {} lamports for ticket {} (nonce {})",
         price_lamports,
-        ticket_id
+        ticket_id,
+        nonce
     );
 
     Ok(())
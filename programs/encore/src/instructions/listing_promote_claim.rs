@@ -0,0 +1,129 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::instruction::{PackedAddressTreeInfo, ValidityProof};
+
+use crate::constants::{ESCROW_SEED, LISTING_SEED, QUEUED_CLAIM_SEED};
+use crate::errors::EncoreError;
+use crate::events::QueuedClaimPromoted;
+use crate::instructions::listing_claim::prove_ticket_unspent;
+use crate::invariants;
+use crate::state::{Listing, ListingClaim, ListingStatus, QueuedClaim};
+
+#[derive(Accounts)]
+pub struct PromoteQueuedClaim<'info> {
+    /// Keeper advancing the queue (pays the freshness proof's fees)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Listing whose active claim was cancelled or released
+    #[account(
+        mut,
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+
+    /// Escrow PDA receiving the promoted buyer's payment
+    /// CHECK: This is a PDA that holds SOL, validated by seeds
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, listing.key().as_ref()],
+        bump,
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    /// Claim at the head of the queue - closed and rent returned to buyer
+    #[account(
+        mut,
+        seeds = [QUEUED_CLAIM_SEED, listing.key().as_ref(), queued_claim.buyer.as_ref()],
+        bump = queued_claim.bump,
+        close = buyer,
+    )]
+    pub queued_claim: Account<'info, QueuedClaim>,
+
+    /// CHECK: Queued buyer, receives the closed account's rent
+    #[account(mut, address = queued_claim.buyer)]
+    pub buyer: UncheckedAccount<'info>,
+}
+
+/// Promote the head of the claim queue to the listing's active claim.
+/// Permissionless, so keepers can advance the queue.
+///
+/// A withdrawn claim at the head is skipped (closed) without promotion;
+/// call again for the next position.
+///
+/// # Freshness
+/// As in `claim_listing`, the proof must show the listed ticket's
+/// nullifier does not exist yet, so a buyer's payment isn't locked on a
+/// ticket that was spent while they waited. If it was, the listing can't
+/// sell and queued buyers withdraw their payments.
+///
+/// # Operations
+/// 1. Validate listing is Active and the claim is at the queue head
+/// 2. Prove the ticket's nullifier does not exist (read-only address)
+/// 3. Move the queued payment into the listing escrow
+/// 4. Set buyer, buyer_commitment, claimed_at
+/// 5. Set status to Claimed
+pub fn promote_queued_claim<'info>(
+    ctx: Context<'_, '_, '_, 'info, PromoteQueuedClaim<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+    let status_before = listing.status;
+    let queued_claim = &ctx.accounts.queued_claim;
+
+    require!(listing.is_active(), EncoreError::ListingNotActive);
+    require!(
+        queued_claim.position == listing.queue_head,
+        EncoreError::NotQueueHead
+    );
+
+    listing.queue_head += 1;
+
+    if queued_claim.withdrawn {
+        msg!(
+            "Skipped withdrawn claim at position {}",
+            queued_claim.position
+        );
+        return Ok(());
+    }
+
+    prove_ticket_unspent(
+        ctx.accounts.payer.as_ref(),
+        ctx.remaining_accounts,
+        proof,
+        address_tree_info,
+        &listing.nullifier_seed,
+    )?;
+
+    // Queued claim is program-owned, so lamports are moved directly
+    queued_claim.sub_lamports(queued_claim.amount)?;
+    ctx.accounts.escrow.add_lamports(queued_claim.amount)?;
+
+    listing.status = ListingStatus::Claimed(ListingClaim {
+        buyer: queued_claim.buyer,
+        buyer_commitment: queued_claim.buyer_commitment,
+        claimed_at: Clock::get()?.unix_timestamp,
+        terms_hash: listing.terms_hash(),
+        payment_mint: None,
+    });
+
+    emit!(QueuedClaimPromoted {
+        listing: listing.key(),
+        buyer: queued_claim.buyer,
+        position: queued_claim.position,
+    });
+
+    msg!("✅ Queued claim promoted: {:?}", queued_claim.buyer);
+
+    invariants::check_listing_transition(&status_before, &ctx.accounts.listing.status)?;
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::{
+    BLOCKED_BUYER_SEED, LEDGER_SEED, LISTING_SEED, MAX_CLAIM_QUEUE_LEN, QUEUED_CLAIM_SEED,
+};
+use crate::errors::EncoreError;
+use crate::events::ClaimQueued;
+use crate::state::{EventLedger, LedgerEntryKind, Listing, QueuedClaim};
+
+#[derive(Accounts)]
+pub struct QueueClaim<'info> {
+    /// Buyer joining the claim queue
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Listing being claimed
+    #[account(
+        mut,
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+
+    /// Queued claim, also holds the buyer's payment
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + QueuedClaim::INIT_SPACE,
+        seeds = [QUEUED_CLAIM_SEED, listing.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub queued_claim: Account<'info, QueuedClaim>,
+
+    /// CHECK: Blocklist entry for the buyer on the listed event; must not exist
+    #[account(
+        seeds = [BLOCKED_BUYER_SEED, listing.event_config.as_ref(), buyer.key().as_ref()],
+        bump,
+        constraint = blocked_buyer.data_is_empty() @ EncoreError::BuyerBlocked,
+    )]
+    pub blocked_buyer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, listing.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a backup claim behind a listing's active claim.
+///
+/// The buyer deposits the price into their own `QueuedClaim` account. If
+/// the active claim is cancelled or released, `promote_queued_claim` moves
+/// the head of the queue into the listing; once the listing is finished,
+/// remaining claims are refunded with `refund_queued_claim`.
+///
+/// # Operations
+/// 1. Validate listing is claimed (or has a queue) and the queue has room
+/// 2. Transfer SOL from buyer to the queued claim
+/// 3. Record the claim at the tail of the queue
+pub fn queue_claim(ctx: Context<QueueClaim>, buyer_commitment: [u8; 32]) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+
+    require!(
+        listing.claim().is_some()
+            || (listing.is_active() && listing.queue_head != listing.queue_tail),
+        EncoreError::ListingNotClaimed
+    );
+    require!(
+        listing.queue_tail - listing.queue_head < MAX_CLAIM_QUEUE_LEN,
+        EncoreError::ClaimQueueFull
+    );
+
+    let price = listing.price_lamports;
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.queued_claim.to_account_info(),
+            },
+        ),
+        price,
+    )?;
+
+    ctx.accounts
+        .ledger
+        .record(LedgerEntryKind::EscrowDeposit, price)?;
+
+    let position = listing.queue_tail;
+    listing.queue_tail += 1;
+
+    let queued_claim = &mut ctx.accounts.queued_claim;
+    queued_claim.listing = listing.key();
+    queued_claim.listing_created_at = listing.created_at;
+    queued_claim.event_config = listing.event_config;
+    queued_claim.buyer = ctx.accounts.buyer.key();
+    queued_claim.buyer_commitment = buyer_commitment;
+    queued_claim.position = position;
+    queued_claim.amount = price;
+    queued_claim.withdrawn = false;
+    queued_claim.queued_at = Clock::get()?.unix_timestamp;
+    queued_claim.bump = ctx.bumps.queued_claim;
+
+    emit!(ClaimQueued {
+        listing: listing.key(),
+        buyer: queued_claim.buyer,
+        position,
+    });
+
+    msg!("✅ Claim queued at position {}", position);
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::constants::{ESCROW_SEED, LEDGER_SEED, LISTING_SEED};
+use crate::errors::EncoreError;
+use crate::events::CancelledEventClaimRefunded;
+use crate::invariants;
+use crate::state::{EventConfig, EventLedger, LedgerEntryKind, Listing, ListingStatus};
+
+#[derive(Accounts)]
+pub struct RefundCancelledClaim<'info> {
+    /// Anyone may crank refunds once the event is cancelled (pays fees)
+    pub payer: Signer<'info>,
+
+    #[account(address = listing.event_config)]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+
+    /// Escrow PDA holding the buyer's payment (refunded to the buyer)
+    /// CHECK: This is a PDA that holds SOL, validated by seeds
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, listing.key().as_ref()],
+        bump,
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    /// Buyer who will receive the refund
+    #[account(
+        mut,
+        constraint = listing.claim().map(|claim| claim.buyer) == Some(buyer.key()) @ EncoreError::NotBuyer,
+    )]
+    pub buyer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, listing.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    /// Escrow's token account, for claims paid in an SPL mint
+    #[account(mut, token::authority = escrow)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Buyer's token account receiving a token refund
+    #[account(mut, token::authority = buyer)]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Refund a claim on a cancelled event's listing without waiting for the
+/// claim timeout.
+///
+/// Permissionless, so keepers can release every buyer's escrow as soon as
+/// the event is cancelled. The listing is Cancelled, which also frees its
+/// queued claims for `refund_queued_claim`. The seller isn't at fault: any
+/// bond stays on the listing and comes back with `close_listing`, and no
+/// timeout is counted against them.
+///
+/// # Operations
+/// 1. Validate the event is cancelled and the listing Claimed
+/// 2. Refund the escrow (SOL or the claim's payment mint) to the buyer
+/// 3. Cancel the listing
+pub fn refund_cancelled_claim(ctx: Context<RefundCancelledClaim>) -> Result<()> {
+    require!(
+        ctx.accounts.event_config.cancelled,
+        EncoreError::EventNotCancelled
+    );
+
+    let listing_key = ctx.accounts.listing.key();
+    let escrow_bump = ctx.bumps.escrow;
+    let escrow_seeds: &[&[u8]] = &[ESCROW_SEED, listing_key.as_ref(), &[escrow_bump]];
+
+    let claim = *ctx
+        .accounts
+        .listing
+        .claim()
+        .ok_or(EncoreError::ListingNotClaimed)?;
+
+    let refunded = if let Some(mint) = claim.payment_mint {
+        let (Some(escrow_token), Some(buyer_token), Some(token_program)) = (
+            ctx.accounts.escrow_token_account.as_ref(),
+            ctx.accounts.buyer_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(EncoreError::TokenAccountsRequired);
+        };
+        require!(
+            escrow_token.mint == mint && buyer_token.mint == mint,
+            EncoreError::PaymentMintMismatch
+        );
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: escrow_token.to_account_info(),
+                    to: buyer_token.to_account_info(),
+                    authority: ctx.accounts.escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            escrow_token.amount,
+        )?;
+        escrow_token.amount
+    } else {
+        let escrow_balance = ctx.accounts.escrow.lamports();
+        if escrow_balance > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to: ctx.accounts.buyer.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                escrow_balance,
+            )?;
+            msg!("💰 Refunded {} lamports to buyer", escrow_balance);
+        }
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::EscrowRefund, escrow_balance)?;
+        escrow_balance
+    };
+
+    let listing = &mut ctx.accounts.listing;
+    let status_before = listing.status;
+    listing.status = ListingStatus::Cancelled;
+    listing.settled_at = Clock::get()?.unix_timestamp;
+
+    emit!(CancelledEventClaimRefunded {
+        event_config: listing.event_config,
+        listing: listing_key,
+        buyer: claim.buyer,
+        payment_mint: claim.payment_mint,
+        amount: refunded,
+    });
+
+    msg!("✅ Claim refunded after event cancellation");
+
+    invariants::check_listing_transition(&status_before, &ctx.accounts.listing.status)?;
+
+    Ok(())
+}
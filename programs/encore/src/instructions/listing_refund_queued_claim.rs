@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::{LEDGER_SEED, QUEUED_CLAIM_SEED};
+use crate::errors::EncoreError;
+use crate::state::{EventLedger, LedgerEntryKind, Listing, ListingStatus, QueuedClaim};
+
+#[derive(Accounts)]
+pub struct RefundQueuedClaim<'info> {
+    /// Queued claim - closed and all lamports returned to buyer
+    #[account(
+        mut,
+        seeds = [QUEUED_CLAIM_SEED, queued_claim.listing.as_ref(), queued_claim.buyer.as_ref()],
+        bump = queued_claim.bump,
+        close = buyer,
+    )]
+    pub queued_claim: Account<'info, QueuedClaim>,
+
+    /// CHECK: Queued buyer, receives the refund
+    #[account(mut, address = queued_claim.buyer)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: The listing; may already be closed
+    #[account(address = queued_claim.listing)]
+    pub listing: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, queued_claim.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+}
+
+/// Refund a queued claim once its listing is finished. Permissionless, so
+/// keepers can sweep the queue after a sale.
+///
+/// The listing is finished if it is Completed or Cancelled, was closed, or
+/// was closed and re-created (different `created_at`).
+pub fn refund_queued_claim(ctx: Context<RefundQueuedClaim>) -> Result<()> {
+    let queued_claim = &ctx.accounts.queued_claim;
+
+    let listing_info = ctx.accounts.listing.to_account_info();
+    let finished = if listing_info.data_is_empty() {
+        true
+    } else {
+        let listing = Listing::try_deserialize(&mut &listing_info.data.borrow()[..])?;
+        listing.created_at != queued_claim.listing_created_at
+            || matches!(
+                listing.status,
+                ListingStatus::Completed | ListingStatus::Cancelled
+            )
+    };
+    require!(finished, EncoreError::QueuedClaimStillActive);
+
+    let amount = queued_claim.amount;
+    ctx.accounts
+        .ledger
+        .record(LedgerEntryKind::EscrowRefund, amount)?;
+
+    // Account is closed by Anchor's `close = buyer` constraint, returning
+    // the escrowed amount together with the rent
+    msg!("💰 Refunded {} lamports to queued buyer", amount);
+
+    Ok(())
+}
+
+/// Close queued claims on a finished listing, passed as (queued claim,
+/// buyer) account pairs, returning each buyer's payment with the rent.
+/// Returns the payments refunded, for the ledger.
+pub(crate) fn refund_queued_claims<'info>(
+    listing: &Pubkey,
+    listing_created_at: i64,
+    accounts: &[AccountInfo<'info>],
+) -> Result<u64> {
+    let mut refunded = 0u64;
+    for pair in accounts.chunks_exact(2) {
+        let (info, buyer) = (&pair[0], &pair[1]);
+        require_keys_eq!(*info.owner, crate::ID, EncoreError::QueuedClaimMismatch);
+        let queued_claim = QueuedClaim::try_deserialize(&mut &info.data.borrow()[..])?;
+        require!(
+            queued_claim.listing == *listing
+                && queued_claim.listing_created_at == listing_created_at
+                && queued_claim.buyer == buyer.key(),
+            EncoreError::QueuedClaimMismatch
+        );
+
+        // Program-owned, so the payment and rent are moved directly
+        let lamports = info.lamports();
+        info.sub_lamports(lamports)?;
+        buyer.add_lamports(lamports)?;
+        info.assign(&system_program::ID);
+        info.resize(0)?;
+
+        refunded = refunded
+            .checked_add(queued_claim.amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+    Ok(refunded)
+}
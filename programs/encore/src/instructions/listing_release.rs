@@ -1,59 +1,97 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::LISTING_SEED;
+use crate::constants::{LISTING_SEED, REPUTATION_SEED};
 use crate::errors::EncoreError;
-use crate::state::{Listing, ListingStatus};
+use crate::invariants;
+use crate::state::{Listing, ListingStatus, Reputation, ReputationEntry};
 
 #[derive(Accounts)]
 pub struct ReleaseClaim<'info> {
-    /// Seller who is releasing the claim
+    /// Seller releasing the claim, or anyone for an `auto_relist` listing
     #[account(mut)]
-    pub seller: Signer<'info>,
+    pub payer: Signer<'info>,
 
     /// Listing being released
     #[account(
         mut,
-        seeds = [LISTING_SEED, listing.seller.as_ref(), &listing.ticket_commitment],
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
         bump = listing.bump,
     )]
     pub listing: Account<'info, Listing>,
+
+    /// Buyer whose claim lapsed
+    /// CHECK: Must be the claim's buyer
+    #[account(
+        constraint = listing.claim().map(|claim| claim.buyer) == Some(buyer.key()) @ EncoreError::NotBuyer,
+    )]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// Buyer's reputation, charged with an abandoned claim
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Reputation::INIT_SPACE,
+        seeds = [REPUTATION_SEED, buyer.key().as_ref()],
+        bump
+    )]
+    pub buyer_reputation: Account<'info, Reputation>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Release a claimed listing if the buyer didn't pay within the timeout.
 ///
+/// Listings created with `auto_relist` can be released by anyone, so they
+/// go back on sale without waiting for the seller.
+///
 /// # Operations
 /// 1. Validate listing is Claimed
 /// 2. Validate timeout has been reached (24 hours)
 /// 3. Set status back to Active
 /// 4. Clear buyer data
+/// 5. Count an abandoned claim on the buyer's reputation
 pub fn release_claim(ctx: Context<ReleaseClaim>) -> Result<()> {
-    let seller = &ctx.accounts.seller;
+    let payer = &ctx.accounts.payer;
     let listing = &mut ctx.accounts.listing;
+    let status_before = listing.status;
 
     // Validate listing status
+    let claim = listing.claim().ok_or(EncoreError::ListingNotClaimed)?;
+
+    // Validate seller is the listing seller, unless the listing relists itself
     require!(
-        listing.status == ListingStatus::Claimed,
-        EncoreError::ListingNotClaimed
+        listing.auto_relist || listing.seller == *payer.key,
+        EncoreError::NotSeller
     );
 
-    // Validate seller is the listing seller
-    require!(listing.seller == *seller.key, EncoreError::NotSeller);
-
     // Validate timeout has been reached
     let current_time = Clock::get()?.unix_timestamp;
-    let claimed_at = listing.claimed_at.ok_or(EncoreError::ListingNotClaimed)?;
     require!(
-        current_time > claimed_at + crate::constants::CLAIM_TIMEOUT_SECONDS,
+        current_time > claim.claimed_at + crate::constants::CLAIM_TIMEOUT_SECONDS,
         EncoreError::ClaimTimeoutNotReached
     );
 
+    // The bond is forfeited through `sweep_expired_claim`, which also
+    // refunds the buyer
+    require!(listing.seller_bond == 0, EncoreError::SellerBondPosted);
+
     // Reset listing to Active
     listing.status = ListingStatus::Active;
-    listing.buyer = None;
-    listing.buyer_commitment = None;
-    listing.claimed_at = None;
 
-    msg!("✅ Claim released by seller: {:?}", seller.key());
+    ctx.accounts.buyer_reputation.record(
+        ctx.accounts.buyer.key(),
+        ctx.bumps.buyer_reputation,
+        ReputationEntry::AbandonedClaim,
+    )?;
+
+    msg!("✅ Claim released by: {:?}", payer.key());
+
+    invariants::check_listing_transition(&status_before, &ctx.accounts.listing.status)?;
 
     Ok(())
 }
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
 
-use crate::constants::{ESCROW_SEED, LISTING_SEED};
+use crate::constants::{ESCROW_SEED, LEDGER_SEED, LISTING_SEED};
 use crate::errors::EncoreError;
-use crate::state::{Listing, ListingStatus};
+use crate::invariants;
+use crate::state::{EventLedger, LedgerEntryKind, Listing, ListingStatus};
 
 #[derive(Accounts)]
 pub struct SellerCancelClaim<'info> {
@@ -13,7 +15,12 @@ pub struct SellerCancelClaim<'info> {
     /// Listing being unclaimed
     #[account(
         mut,
-        seeds = [LISTING_SEED, listing.seller.as_ref(), &listing.ticket_commitment],
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
         bump = listing.bump,
         constraint = listing.seller == *seller.key @ EncoreError::NotSeller,
     )]
@@ -29,13 +36,30 @@ pub struct SellerCancelClaim<'info> {
     pub escrow: SystemAccount<'info>,
 
     /// Buyer who will receive the refund
-    /// CHECK: Must match listing.buyer, receives refund
+    /// CHECK: Must match the listing's claim buyer, receives refund
     #[account(
         mut,
-        constraint = Some(buyer.key()) == listing.buyer @ EncoreError::NotBuyer,
+        constraint = listing.claim().map(|claim| claim.buyer) == Some(buyer.key()) @ EncoreError::NotBuyer,
     )]
     pub buyer: SystemAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, listing.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    /// Escrow's token account, for claims paid in an SPL mint
+    #[account(mut, token::authority = escrow)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Buyer's token account receiving a token refund
+    #[account(mut, token::authority = buyer)]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -52,6 +76,7 @@ pub struct SellerCancelClaim<'info> {
 /// - Identity: The Seller signs, proving they are listing.seller
 ///
 /// # Escrow
+/// - Claims paid in an SPL mint are refunded from the escrow's token account
 /// - Refunds all SOL from escrow back to BUYER (not seller!)
 ///
 /// # Operations
@@ -64,46 +89,83 @@ pub fn seller_cancel_claim(ctx: Context<SellerCancelClaim>) -> Result<()> {
     let listing_key = ctx.accounts.listing.key();
     let escrow_bump = ctx.bumps.escrow;
     let listing = &mut ctx.accounts.listing;
+    let status_before = listing.status;
 
     // Validate listing status is Claimed
-    require!(
-        listing.status == ListingStatus::Claimed,
-        EncoreError::ListingNotClaimed
-    );
+    let payment_mint = listing
+        .claim()
+        .ok_or(EncoreError::ListingNotClaimed)?
+        .payment_mint;
 
-    // Refund escrow SOL to buyer (NOT seller!) using PDA signing
-    let escrow_balance = ctx.accounts.escrow.lamports();
-    if escrow_balance > 0 {
+    if let Some(mint) = payment_mint {
+        // Refund the token payment from the escrow's token account
+        let (Some(escrow_token), Some(buyer_token), Some(token_program)) = (
+            ctx.accounts.escrow_token_account.as_ref(),
+            ctx.accounts.buyer_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(EncoreError::TokenAccountsRequired);
+        };
+        require!(
+            escrow_token.mint == mint && buyer_token.mint == mint,
+            EncoreError::PaymentMintMismatch
+        );
         let escrow_seeds: &[&[u8]] = &[ESCROW_SEED, listing_key.as_ref(), &[escrow_bump]];
-
-        anchor_lang::system_program::transfer(
+        token::transfer(
             CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: ctx.accounts.escrow.to_account_info(),
-                    to: ctx.accounts.buyer.to_account_info(),
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: escrow_token.to_account_info(),
+                    to: buyer_token.to_account_info(),
+                    authority: ctx.accounts.escrow.to_account_info(),
                 },
                 &[escrow_seeds],
             ),
-            escrow_balance,
+            escrow_token.amount,
         )?;
         msg!(
-            "💰 Refunded {} lamports to buyer: {:?}",
-            escrow_balance,
-            ctx.accounts.buyer.key()
+            "💰 Refunded {} of mint {} to buyer",
+            escrow_token.amount,
+            mint
         );
+    } else {
+        // Refund escrow SOL to buyer (NOT seller!) using PDA signing
+        let escrow_balance = ctx.accounts.escrow.lamports();
+        if escrow_balance > 0 {
+            let escrow_seeds: &[&[u8]] = &[ESCROW_SEED, listing_key.as_ref(), &[escrow_bump]];
+
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to: ctx.accounts.buyer.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                escrow_balance,
+            )?;
+            msg!(
+                "💰 Refunded {} lamports to buyer: {:?}",
+                escrow_balance,
+                ctx.accounts.buyer.key()
+            );
+        }
+
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::EscrowRefund, escrow_balance)?;
     }
 
     // Reset listing to Active state
     listing.status = ListingStatus::Active;
-    listing.buyer = None;
-    listing.buyer_commitment = None;
-    listing.claimed_at = None;
 
     msg!(
         "✅ Claim cancelled by seller: {:?}, listing back to Active",
         seller.key()
     );
 
+    invariants::check_listing_transition(&status_before, &ctx.accounts.listing.status)?;
+
     Ok(())
 }
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LISTING_SEED, MAX_PAYMENT_OPTIONS};
+use crate::errors::EncoreError;
+use crate::state::{Listing, PaymentOption};
+
+#[derive(Accounts)]
+pub struct SetPaymentOptions<'info> {
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
+        bump = listing.bump,
+        has_one = seller @ EncoreError::NotSeller,
+    )]
+    pub listing: Account<'info, Listing>,
+}
+
+/// Set the SPL mints a listing accepts besides SOL, each at its own price.
+///
+/// Buyers pick a mint when claiming; `claim_listing` routes the payment to
+/// the escrow's token account for that mint. Official exchange listings
+/// are priced in lamports and can't take other mints.
+///
+/// # Operations
+/// 1. Validate listing is Active and not an official exchange listing
+/// 2. Validate options are unique mints with nonzero prices
+/// 3. Replace the listing's payment options
+pub fn set_payment_options(
+    ctx: Context<SetPaymentOptions>,
+    options: Vec<PaymentOption>,
+) -> Result<()> {
+    let listing = &mut ctx.accounts.listing;
+
+    require!(listing.is_active(), EncoreError::ListingNotActive);
+    require!(
+        !listing.official_exchange,
+        EncoreError::UnsupportedPaymentMint
+    );
+
+    require!(
+        options.len() <= MAX_PAYMENT_OPTIONS
+            && options.iter().all(|option| option.price > 0)
+            && options
+                .iter()
+                .enumerate()
+                .all(|(i, option)| { options[..i].iter().all(|other| other.mint != option.mint) }),
+        EncoreError::InvalidPaymentOptions
+    );
+
+    listing.payment_options = options;
+
+    msg!(
+        "✅ Listing accepts {} payment mints",
+        listing.payment_options.len()
+    );
+
+    Ok(())
+}
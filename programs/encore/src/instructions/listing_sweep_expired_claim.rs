@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::bps;
+use crate::constants::{
+    CLAIM_TIMEOUT_SECONDS, ESCROW_SEED, LEDGER_SEED, LISTING_SEED, METRICS_SEED, REPUTATION_SEED,
+    SELLER_BOND_BUYER_SHARE_BPS, TREASURY_SEED,
+};
+use crate::errors::EncoreError;
+use crate::events::SellerBondForfeited;
+use crate::invariants;
+use crate::state::{
+    EventLedger, FeeSource, LedgerEntryKind, Listing, ListingStatus, ProtocolMetrics,
+    ProtocolTreasury, Reputation, ReputationEntry,
+};
+
+#[derive(Accounts)]
+pub struct SweepExpiredClaim<'info> {
+    /// Anyone may sweep an expired claim (pays fees)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            &listing.ticket_commitment,
+            &listing.nonce.to_le_bytes(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Account<'info, Listing>,
+
+    /// Escrow PDA holding the buyer's payment (refunded to the buyer)
+    /// CHECK: This is a PDA that holds SOL, validated by seeds
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, listing.key().as_ref()],
+        bump,
+    )]
+    pub escrow: SystemAccount<'info>,
+
+    /// Buyer who will receive the refund and their share of the bond
+    #[account(
+        mut,
+        constraint = listing.claim().map(|claim| claim.buyer) == Some(buyer.key()) @ EncoreError::NotBuyer,
+    )]
+    pub buyer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, listing.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    /// Protocol treasury receiving the rest of a forfeited bond
+    #[account(mut, seeds = [TREASURY_SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
+    /// Seller's reputation, charged with a timeout
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Reputation::INIT_SPACE,
+        seeds = [REPUTATION_SEED, listing.seller.as_ref()],
+        bump
+    )]
+    pub seller_reputation: Account<'info, Reputation>,
+
+    /// Escrow's token account, for claims paid in an SPL mint
+    #[account(mut, token::authority = escrow)]
+    pub escrow_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Buyer's token account receiving a token refund
+    #[account(mut, token::authority = buyer)]
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sweep a claim the seller never completed.
+///
+/// Once the claim timeout has passed, anyone can refund the buyer. The
+/// listing returns to Active if it was created with `auto_relist`;
+/// otherwise the seller is presumed gone and it is Cancelled. If the seller posted a bond, it is
+/// forfeited: `SELLER_BOND_BUYER_SHARE_BPS` of it to the buyer for the
+/// time lost, the rest to the protocol treasury.
+///
+/// # Operations
+/// 1. Validate listing is Claimed and the claim timed out
+/// 2. Refund the escrow (SOL or the claim's payment mint) to the buyer
+/// 3. Forfeit the seller bond, if any
+/// 4. Reset listing to Active (`auto_relist`) or Cancelled
+/// 5. Count a timeout on the seller's reputation
+pub fn sweep_expired_claim(ctx: Context<SweepExpiredClaim>) -> Result<()> {
+    let listing_key = ctx.accounts.listing.key();
+    let escrow_bump = ctx.bumps.escrow;
+    let escrow_seeds: &[&[u8]] = &[ESCROW_SEED, listing_key.as_ref(), &[escrow_bump]];
+
+    let claim = *ctx
+        .accounts
+        .listing
+        .claim()
+        .ok_or(EncoreError::ListingNotClaimed)?;
+    require!(
+        Clock::get()?.unix_timestamp > claim.claimed_at + CLAIM_TIMEOUT_SECONDS,
+        EncoreError::ClaimTimeoutNotReached
+    );
+
+    if let Some(mint) = claim.payment_mint {
+        let (Some(escrow_token), Some(buyer_token), Some(token_program)) = (
+            ctx.accounts.escrow_token_account.as_ref(),
+            ctx.accounts.buyer_token_account.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+        ) else {
+            return err!(EncoreError::TokenAccountsRequired);
+        };
+        require!(
+            escrow_token.mint == mint && buyer_token.mint == mint,
+            EncoreError::PaymentMintMismatch
+        );
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: escrow_token.to_account_info(),
+                    to: buyer_token.to_account_info(),
+                    authority: ctx.accounts.escrow.to_account_info(),
+                },
+                &[escrow_seeds],
+            ),
+            escrow_token.amount,
+        )?;
+    } else {
+        let escrow_balance = ctx.accounts.escrow.lamports();
+        if escrow_balance > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to: ctx.accounts.buyer.to_account_info(),
+                    },
+                    &[escrow_seeds],
+                ),
+                escrow_balance,
+            )?;
+            msg!("💰 Refunded {} lamports to buyer", escrow_balance);
+        }
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::EscrowRefund, escrow_balance)?;
+    }
+
+    // Forfeit the bond; it sits on the program-owned listing account
+    let bond = ctx.accounts.listing.seller_bond;
+    if bond > 0 {
+        let (to_buyer, to_protocol) = bps::split(bond, SELLER_BOND_BUYER_SHARE_BPS);
+
+        ctx.accounts.listing.sub_lamports(bond)?;
+        ctx.accounts.buyer.add_lamports(to_buyer)?;
+        ctx.accounts.treasury.add_lamports(to_protocol)?;
+        ctx.accounts
+            .treasury
+            .record_fee(FeeSource::Penalty, to_protocol)?;
+        ctx.accounts.metrics.record_fee(to_protocol)?;
+        ctx.accounts.listing.seller_bond = 0;
+
+        emit!(SellerBondForfeited {
+            listing: listing_key,
+            seller: ctx.accounts.listing.seller,
+            buyer: claim.buyer,
+            to_buyer,
+            to_protocol,
+        });
+    }
+
+    let listing = &mut ctx.accounts.listing;
+    let status_before = listing.status;
+    listing.status = if listing.auto_relist {
+        ListingStatus::Active
+    } else {
+        listing.settled_at = Clock::get()?.unix_timestamp;
+        ListingStatus::Cancelled
+    };
+
+    ctx.accounts.seller_reputation.record(
+        listing.seller,
+        ctx.bumps.seller_reputation,
+        ReputationEntry::SellerTimeout,
+    )?;
+
+    msg!("✅ Expired claim swept, listing now {:?}", listing.status);
+
+    invariants::check_listing_transition(&status_before, &ctx.accounts.listing.status)?;
+
+    Ok(())
+}
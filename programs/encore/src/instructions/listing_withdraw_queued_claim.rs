@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LEDGER_SEED, QUEUED_CLAIM_SEED};
+use crate::errors::EncoreError;
+use crate::state::{EventLedger, LedgerEntryKind, QueuedClaim};
+
+#[derive(Accounts)]
+pub struct WithdrawQueuedClaim<'info> {
+    /// Buyer leaving the queue
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [QUEUED_CLAIM_SEED, queued_claim.listing.as_ref(), buyer.key().as_ref()],
+        bump = queued_claim.bump,
+    )]
+    pub queued_claim: Account<'info, QueuedClaim>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, queued_claim.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+}
+
+/// Leave the claim queue and get the payment back.
+///
+/// The account stays (holding only rent) so the queue order is preserved;
+/// it is closed when promotion skips it or the listing finishes.
+pub fn withdraw_queued_claim(ctx: Context<WithdrawQueuedClaim>) -> Result<()> {
+    let queued_claim = &mut ctx.accounts.queued_claim;
+    require!(!queued_claim.withdrawn, EncoreError::ClaimAlreadyWithdrawn);
+
+    let amount = queued_claim.amount;
+    queued_claim.sub_lamports(amount)?;
+    ctx.accounts.buyer.add_lamports(amount)?;
+
+    queued_claim.amount = 0;
+    queued_claim.withdrawn = true;
+
+    ctx.accounts
+        .ledger
+        .record(LedgerEntryKind::EscrowRefund, amount)?;
+
+    msg!("💰 Refunded {} lamports to queued buyer", amount);
+
+    Ok(())
+}
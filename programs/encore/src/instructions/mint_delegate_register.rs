@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, MINT_DELEGATE_SEED};
+use crate::errors::EncoreError;
+use crate::events::MintDelegateUpdated;
+use crate::state::{EventConfig, MintDelegate};
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct RegisterMintDelegate<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Created on first registration; later calls change the quota
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MintDelegate::INIT_SPACE,
+        seeds = [MINT_DELEGATE_SEED, event_config.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub mint_delegate: Account<'info, MintDelegate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Authorize a service key to mint comp tickets up to `quota` (event
+/// authority only). Re-registering sets a new quota and keeps the count
+/// already minted.
+pub fn register_mint_delegate(
+    ctx: Context<RegisterMintDelegate>,
+    delegate: Pubkey,
+    quota: u32,
+) -> Result<()> {
+    let mint_delegate = &mut ctx.accounts.mint_delegate;
+    require!(
+        quota > 0 && quota >= mint_delegate.minted,
+        EncoreError::InvalidMintDelegate
+    );
+
+    mint_delegate.event_config = ctx.accounts.event_config.key();
+    mint_delegate.delegate = delegate;
+    mint_delegate.quota = quota;
+    mint_delegate.bump = ctx.bumps.mint_delegate;
+
+    emit!(MintDelegateUpdated {
+        event_config: mint_delegate.event_config,
+        delegate,
+        quota,
+        minted: mint_delegate.minted,
+    });
+
+    msg!("✅ Mint delegate {} registered, quota {}", delegate, quota);
+
+    Ok(())
+}
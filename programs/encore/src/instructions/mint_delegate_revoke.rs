@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, MINT_DELEGATE_SEED};
+use crate::errors::EncoreError;
+use crate::events::MintDelegateUpdated;
+use crate::state::{EventConfig, MintDelegate};
+
+#[derive(Accounts)]
+pub struct RevokeMintDelegate<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Closed; rent returns to the authority
+    #[account(
+        mut,
+        seeds = [
+            MINT_DELEGATE_SEED,
+            event_config.key().as_ref(),
+            mint_delegate.delegate.as_ref(),
+        ],
+        bump = mint_delegate.bump,
+        close = authority,
+    )]
+    pub mint_delegate: Account<'info, MintDelegate>,
+}
+
+/// Revoke a mint delegate (event authority only).
+pub fn revoke_mint_delegate(ctx: Context<RevokeMintDelegate>) -> Result<()> {
+    let mint_delegate = &ctx.accounts.mint_delegate;
+
+    emit!(MintDelegateUpdated {
+        event_config: mint_delegate.event_config,
+        delegate: mint_delegate.delegate,
+        quota: 0,
+        minted: mint_delegate.minted,
+    });
+
+    msg!("✅ Mint delegate {} revoked", mint_delegate.delegate);
+
+    Ok(())
+}
@@ -1,25 +1,247 @@
+pub mod allocation_create;
+pub mod allocation_return;
+pub mod announcement_post;
+pub mod blocklist_add;
+pub mod blocklist_remove;
+pub mod credit_issue;
+pub mod disclosure_revoke;
+pub mod domain_register;
+pub mod domain_remove;
+pub mod event_add_addon;
+pub mod event_audit_nullifiers;
+pub mod event_cancel;
+pub mod event_close;
 pub mod event_create;
+pub mod event_create_idempotent;
+pub mod event_oracle_cancel;
+pub mod event_prune;
+pub mod event_publish_snapshot;
+pub mod event_release_door_capacity;
+pub mod event_set_compliance_policy;
+pub mod event_set_credential;
+pub mod event_set_custom_fields;
+pub mod event_set_fan_lane;
+pub mod event_set_id_verification;
+pub mod event_set_insurance;
+pub mod event_set_presale;
+pub mod event_set_release_schedule;
+pub mod event_set_resale_cap_base;
+pub mod event_set_royalty;
 pub mod event_update;
+pub mod exchange_open;
+pub mod exchange_return;
+pub mod fan_attestation_issue;
+pub mod fan_attestation_revoke;
+pub mod fee_exemption_add;
+pub mod fee_exemption_remove;
+pub mod fiat_hold_clawback;
+pub mod fiat_hold_create;
+pub mod fiat_hold_finalize;
+pub mod fiat_provider_register;
+pub mod gift_cancel;
+pub mod gift_claim;
+pub mod gift_create;
+pub mod gift_expire;
+pub mod guardian_set_configure;
+pub mod guardian_set_guard_ticket;
+pub mod guardian_set_unguard_ticket;
+pub mod holder_count_publish;
+pub mod insurance_claim;
 pub mod listing_cancel;
 pub mod listing_cancel_claim;
 pub mod listing_claim;
 pub mod listing_close;
+pub mod listing_collect;
 pub mod listing_complete;
+pub mod listing_complete_preview;
 pub mod listing_create;
+pub mod listing_promote_claim;
+pub mod listing_queue_claim;
+pub mod listing_refund_cancelled_claim;
+pub mod listing_refund_queued_claim;
 pub mod listing_release;
 pub mod listing_seller_cancel_claim;
+pub mod listing_set_payment_options;
+pub mod listing_sweep_expired_claim;
+pub mod listing_withdraw_queued_claim;
+pub mod mint_delegate_register;
+pub mod mint_delegate_revoke;
+pub mod order_fill;
+pub mod partial_refund_claim;
+pub mod partial_refund_close;
+pub mod partial_refund_fund;
+pub mod partial_refund_open;
+pub mod partial_refund_preview;
+pub mod partial_refund_top_up;
+pub mod payout_claim;
+pub mod payout_open;
+pub mod price_feed_initialize;
+pub mod price_feed_update;
+pub mod proceeds_hold_reclaim;
+pub mod proceeds_hold_withdraw;
+pub mod program_get_version;
+pub mod protocol_initialize;
+pub mod protocol_update;
+pub mod purchase_checkout;
+pub mod purchase_swap;
+pub mod recovery_approve;
+pub mod recovery_veto;
+pub mod report_publish;
+pub mod royalty_distribute;
+pub mod scanner_register;
+pub mod scanner_revoke;
+pub mod seat_reassignment_accept;
+pub mod seat_reassignment_propose;
+pub mod seat_reassignment_withdraw;
+pub mod sponsor_pool_configure;
+pub mod sponsor_pool_fund;
+pub mod sponsor_pool_withdraw;
+pub mod ticket_bind;
+pub mod ticket_disclose;
+pub mod ticket_exchange;
 pub mod ticket_mint;
+pub mod ticket_mint_comp;
+pub mod ticket_mint_preview;
+pub mod ticket_personalize;
+pub mod ticket_recover;
+pub mod ticket_redeem;
+pub mod ticket_redeem_batch;
 pub mod ticket_transfer;
+pub mod ticket_transfer_cancel;
+pub mod ticket_transfer_finalize;
+pub mod ticket_transfer_keyed;
+pub mod ticket_transfer_prepare;
+pub mod ticket_verify;
+pub mod ticket_void;
+pub mod transfer_request_approve;
+pub mod transfer_request_cancel;
+pub mod transfer_request_create;
+pub mod treasury_withdraw;
+pub mod waitlist_create;
+pub mod waitlist_join;
+pub mod waitlist_leave;
+pub mod waitlist_promote;
 
+pub use allocation_create::*;
+pub use allocation_return::*;
+pub use announcement_post::*;
+pub use blocklist_add::*;
+pub use blocklist_remove::*;
+pub use credit_issue::*;
+pub use disclosure_revoke::*;
+pub use domain_register::*;
+pub use domain_remove::*;
+pub use event_add_addon::*;
+pub use event_audit_nullifiers::*;
+pub use event_cancel::*;
+pub use event_close::*;
 pub use event_create::*;
+pub use event_create_idempotent::*;
+pub use event_oracle_cancel::*;
+pub use event_prune::*;
+pub use event_publish_snapshot::*;
+pub use event_release_door_capacity::*;
+pub use event_set_compliance_policy::*;
+pub use event_set_credential::*;
+pub use event_set_custom_fields::*;
+pub use event_set_fan_lane::*;
+pub use event_set_id_verification::*;
+pub use event_set_insurance::*;
+pub use event_set_presale::*;
+pub use event_set_release_schedule::*;
+pub use event_set_resale_cap_base::*;
+pub use event_set_royalty::*;
 pub use event_update::*;
+pub use exchange_open::*;
+pub use exchange_return::*;
+pub use fan_attestation_issue::*;
+pub use fan_attestation_revoke::*;
+pub use fee_exemption_add::*;
+pub use fee_exemption_remove::*;
+pub use fiat_hold_clawback::*;
+pub use fiat_hold_create::*;
+pub use fiat_hold_finalize::*;
+pub use fiat_provider_register::*;
+pub use gift_cancel::*;
+pub use gift_claim::*;
+pub use gift_create::*;
+pub use gift_expire::*;
+pub use guardian_set_configure::*;
+pub use guardian_set_guard_ticket::*;
+pub use guardian_set_unguard_ticket::*;
+pub use holder_count_publish::*;
+pub use insurance_claim::*;
 pub use listing_cancel::*;
 pub use listing_cancel_claim::*;
 pub use listing_claim::*;
 pub use listing_close::*;
+pub use listing_collect::*;
 pub use listing_complete::*;
+pub use listing_complete_preview::*;
 pub use listing_create::*;
+pub use listing_promote_claim::*;
+pub use listing_queue_claim::*;
+pub use listing_refund_cancelled_claim::*;
+pub use listing_refund_queued_claim::*;
 pub use listing_release::*;
 pub use listing_seller_cancel_claim::*;
+pub use listing_set_payment_options::*;
+pub use listing_sweep_expired_claim::*;
+pub use listing_withdraw_queued_claim::*;
+pub use mint_delegate_register::*;
+pub use mint_delegate_revoke::*;
+pub use order_fill::*;
+pub use partial_refund_claim::*;
+pub use partial_refund_close::*;
+pub use partial_refund_fund::*;
+pub use partial_refund_open::*;
+pub use partial_refund_preview::*;
+pub use partial_refund_top_up::*;
+pub use payout_claim::*;
+pub use payout_open::*;
+pub use price_feed_initialize::*;
+pub use price_feed_update::*;
+pub use proceeds_hold_reclaim::*;
+pub use proceeds_hold_withdraw::*;
+pub use program_get_version::*;
+pub use protocol_initialize::*;
+pub use protocol_update::*;
+pub use purchase_checkout::*;
+pub use purchase_swap::*;
+pub use recovery_approve::*;
+pub use recovery_veto::*;
+pub use report_publish::*;
+pub use royalty_distribute::*;
+pub use scanner_register::*;
+pub use scanner_revoke::*;
+pub use seat_reassignment_accept::*;
+pub use seat_reassignment_propose::*;
+pub use seat_reassignment_withdraw::*;
+pub use sponsor_pool_configure::*;
+pub use sponsor_pool_fund::*;
+pub use sponsor_pool_withdraw::*;
+pub use ticket_bind::*;
+pub use ticket_disclose::*;
+pub use ticket_exchange::*;
 pub use ticket_mint::*;
+pub use ticket_mint_comp::*;
+pub use ticket_mint_preview::*;
+pub use ticket_personalize::*;
+pub use ticket_recover::*;
+pub use ticket_redeem::*;
+pub use ticket_redeem_batch::*;
 pub use ticket_transfer::*;
+pub use ticket_transfer_cancel::*;
+pub use ticket_transfer_finalize::*;
+pub use ticket_transfer_keyed::*;
+pub use ticket_transfer_prepare::*;
+pub use ticket_verify::*;
+pub use ticket_void::*;
+pub use transfer_request_approve::*;
+pub use transfer_request_cancel::*;
+pub use transfer_request_create::*;
+pub use treasury_withdraw::*;
+pub use waitlist_create::*;
+pub use waitlist_join::*;
+pub use waitlist_leave::*;
+pub use waitlist_promote::*;
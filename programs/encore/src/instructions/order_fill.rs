@@ -0,0 +1,357 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use anchor_lang::system_program;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::*;
+use crate::errors::EncoreError;
+use crate::events::{ProtocolFeeCollected, SignedOrderFilled};
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::signature::has_ed25519_signature;
+use crate::state::{
+    ClientNonce, EventConfig, EventLedger, FeeExemption, FeeSource, FieldValue, LedgerEntryKind,
+    Nullifier, OwnershipMode, PriceFeed, PrivateTicket, ProtocolConfig, ProtocolMetrics,
+    ProtocolTreasury, SignedOrder, TransferPolicy,
+};
+
+#[derive(Accounts)]
+#[instruction(
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    order: SignedOrder,
+    seller_secret: [u8; 32],
+    buyer_commitment: [u8; 32],
+    new_ticket_address_seed: [u8; 32],
+    client_nonce: u64,
+)]
+pub struct FillSignedOrder<'info> {
+    /// Buyer filling the order (pays price and fees)
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Seller who signed the order, receives the payment. Verified
+    /// via the ed25519 signature and the ticket commitment.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Event owner, used to derive the event config
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// CHECK: Blocklist entry for the buyer; must not exist
+    #[account(
+        seeds = [BLOCKED_BUYER_SEED, event_config.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        constraint = blocked_buyer.data_is_empty() @ EncoreError::BuyerBlocked,
+    )]
+    pub blocked_buyer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol treasury receiving the sale fee
+    #[account(mut, seeds = [TREASURY_SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
+    /// Fee exemption for this event or its organizer, if one was granted
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    /// CHECK: Instructions sysvar, used to find the seller's order signature
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Pass to make retries with the same `client_nonce` fail instead of paying twice
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + ClientNonce::INIT_SPACE,
+        seeds = [CLIENT_NONCE_SEED, buyer.key().as_ref(), &client_nonce.to_le_bytes()],
+        bump
+    )]
+    pub client_nonce_record: Option<Account<'info, ClientNonce>>,
+
+    /// CHECK: Solana Pay reference; only its key is used, echoed in the event
+    pub reference: Option<UncheckedAccount<'info>>,
+
+    /// SOL/USD feed, required when the event's resale cap is USD-adjusted
+    #[account(seeds = [PRICE_FEED_SEED], bump = price_feed.bump)]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Settle an off-chain signed sell order in one transaction.
+///
+/// The seller never sends a listing transaction: they sign a `SignedOrder`
+/// and hand it (with their secret, encrypted to the buyer) off-chain. The
+/// buyer submits it here with an ed25519 instruction carrying the signature.
+/// An optional `client_nonce_record` makes resubmissions fail (see `claim_listing`).
+///
+/// The seller's ticket is closed with the order's ticket id, face value and
+/// purchase rate, so an order quoting a lower `original_price` (or another
+/// rate) to loosen the resale cap can't be filled, and the buyer's ticket carries the spent ticket's
+/// proven fields and add-ons.
+///
+/// # Linked pairs
+/// The seller's ticket is closed as unlinked (`link_id: None`), so half of a
+/// linked pair fails the CPI instead of leaving its companion behind. Pairs
+/// move together with `transfer_ticket`.
+///
+/// # Operations
+/// 1. Verify the order signature, expiry and resale cap
+/// 2. Verify seller owns the ticket via commitment
+/// 3. CREATE nullifier (prevents reuse of this secret, and replays)
+/// 4. CREATE new ticket with buyer's commitment
+/// 5. CLOSE the seller's ticket
+/// 6. Pay seller, minus the protocol sale fee
+pub fn fill_signed_order<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillSignedOrder<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    order: SignedOrder,
+    seller_secret: [u8; 32],
+    buyer_commitment: [u8; 32],
+    new_ticket_address_seed: [u8; 32],
+    client_nonce: u64,
+    // Seller's ticket, closed with this data and the order's
+    ticket_account_meta: CompressedAccountMeta,
+    current_name_commitment: Option<[u8; 32]>,
+    current_provenance: [u8; 32],
+    current_custom_fields: Vec<FieldValue>,
+    current_addons: u8,
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let seller_key = ctx.accounts.seller.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(
+        event_config.transfer_policy == TransferPolicy::Open,
+        EncoreError::TransferApprovalRequired
+    );
+    require!(order.price_lamports > 0, EncoreError::InvalidPrice);
+    require!(
+        event_config.settles_in(None),
+        EncoreError::SettlementMintMismatch
+    );
+    require!(now <= order.expires_at, EncoreError::OrderExpired);
+    let cap_base = event_config.resale_cap_base(
+        order.original_price,
+        order.usd_micros_per_sol,
+        ctx.accounts.price_feed.as_deref(),
+        now,
+    )?;
+    require!(
+        event_config.is_valid_resale_price(cap_base, order.price_lamports),
+        EncoreError::ExceedsResaleCap
+    );
+
+    require!(
+        has_ed25519_signature(
+            &ctx.accounts.instructions_sysvar.to_account_info(),
+            &seller_key,
+            &order.message(&event_config.key())?,
+        ),
+        EncoreError::InvalidOrderSignature
+    );
+
+    // Verify seller owns the ticket via commitment
+    // commitment = SHA256(owner_pubkey || secret)
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(seller_key.as_ref());
+    commitment_input.extend_from_slice(&seller_secret);
+    require!(
+        hash(&commitment_input).to_bytes() == order.ticket_commitment,
+        EncoreError::NotTicketOwner
+    );
+
+    // The ticket being sold, as the order and seller claim it; closing it
+    // below checks the claim against the state tree
+    let ticket = PrivateTicket {
+        event_config: event_config.key(),
+        ticket_id: order.ticket_id,
+        owner_commitment: order.ticket_commitment,
+        original_price: order.original_price,
+        usd_micros_per_sol: order.usd_micros_per_sol,
+        name_commitment: current_name_commitment,
+        link_id: None,
+        provenance: current_provenance,
+        custom_fields: current_custom_fields,
+        addons: current_addons,
+        ownership: OwnershipMode::Bound,
+    };
+
+    // --- Light Protocol CPI Setup ---
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.buyer.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Create nullifier ---
+    let nullifier_seed = hash(&seller_secret);
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_nullifier_sequence()?;
+
+    // --- Step 2: Create new ticket with buyer's commitment ---
+    let (new_ticket_address, new_ticket_seed) = derive_address(
+        &[TICKET_SEED, new_ticket_address_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut new_ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(new_ticket_address),
+        output_state_tree_index,
+    );
+    // Bought tickets are held under the buyer's commitment
+    *new_ticket_account =
+        ticket.reissue(&nullifier_address, buyer_commitment, OwnershipMode::Bound);
+
+    // --- Step 3: Close the seller's ticket ---
+    let ticket_account =
+        LightAccount::<PrivateTicket>::new_close(&crate::ID, &ticket_account_meta, ticket)?;
+
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    // Two new addresses: nullifier (index 0) and new ticket (index 1)
+    let nullifier_params =
+        address_tree_info.into_new_address_params_assigned_packed(nullifier_address_seed, Some(0));
+    let new_ticket_params =
+        address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(1));
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(new_ticket_account)?
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[nullifier_params, new_ticket_params])
+        .invoke(light_cpi_accounts)?;
+
+    // --- Step 4: Payment ---
+    let mut protocol_fee = ctx.accounts.protocol_config.sale_fee(order.price_lamports);
+    if let Some(exemption) = ctx.accounts.fee_exemption.as_ref() {
+        require!(
+            exemption.applies_to(&event_config.key(), &event_config.authority),
+            EncoreError::InvalidFeeExemption
+        );
+        protocol_fee = exemption.apply(protocol_fee);
+    }
+    let seller_proceeds = order.price_lamports - protocol_fee;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        seller_proceeds,
+    )?;
+
+    if protocol_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            protocol_fee,
+        )?;
+        ctx.accounts
+            .treasury
+            .record_fee(FeeSource::Sale, protocol_fee)?;
+        ctx.accounts.metrics.record_fee(protocol_fee)?;
+
+        emit!(ProtocolFeeCollected {
+            event_config: event_config.key(),
+            source: FeeSource::Sale,
+            amount: protocol_fee,
+        });
+    }
+
+    event_config.marketplace_sales = event_config
+        .marketplace_sales
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    ctx.accounts.metrics.record_resale(order.price_lamports)?;
+
+    let ledger = &mut ctx.accounts.ledger;
+    ledger.record(LedgerEntryKind::ResaleSettlement, seller_proceeds)?;
+    ledger.record(LedgerEntryKind::ProtocolFee, protocol_fee)?;
+
+    if let Some(record) = ctx.accounts.client_nonce_record.as_mut() {
+        record.consume(
+            ctx.accounts.buyer.key(),
+            client_nonce,
+            now,
+            ctx.bumps.client_nonce_record.unwrap_or_default(),
+        );
+    }
+
+    emit!(SignedOrderFilled {
+        event_config: event_config.key(),
+        seller: seller_key,
+        buyer: ctx.accounts.buyer.key(),
+        ticket_id: order.ticket_id,
+        price_lamports: order.price_lamports,
+        nonce: order.nonce,
+        reference: ctx
+            .accounts
+            .reference
+            .as_ref()
+            .map(|reference| reference.key()),
+    });
+
+    msg!("✅ Signed order filled: nullifier created, new ticket issued to buyer");
+
+    Ok(())
+}
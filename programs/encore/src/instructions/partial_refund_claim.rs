@@ -0,0 +1,219 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{EVENT_SEED, LEDGER_SEED, PARTIAL_REFUND_SEED};
+use crate::errors::EncoreError;
+use crate::events::PartialRefundClaimed;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::invariants;
+use crate::state::{
+    EventConfig, EventLedger, FieldValue, LedgerEntryKind, OwnershipMode, PartialRefund,
+    PartialRefundClaim, PrivateTicket,
+};
+
+#[derive(Accounts)]
+pub struct ClaimPartialRefund<'info> {
+    /// Ticket holder claiming the refund (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [PARTIAL_REFUND_SEED, event_config.key().as_ref()],
+        bump = partial_refund.bump,
+    )]
+    pub partial_refund: Account<'info, PartialRefund>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+}
+
+/// Claim a partial refund on a ticket, keeping the ticket.
+///
+/// The ticket is read through an unchanged update, which proves the signer
+/// holds it; a compressed `PartialRefundClaim` keyed by ticket id stops it
+/// being refunded twice.
+///
+/// # Underfunding
+/// The claim pays `PartialRefund::payout`: the full refund unless the
+/// program is short, in which case it pays the refund pro rata and records
+/// the remainder on the claim marker for `top_up_partial_refund`.
+/// `min_payout` is the least the holder will accept;
+/// `preview_partial_refund` shows what a claim would pay now.
+///
+/// # Operations
+/// 1. Validate the program is open and the event not cancelled
+/// 2. Verify holder owns the ticket via commitment
+/// 3. CREATE refund claim marker + UPDATE ticket (unchanged)
+/// 4. Pay `refund_bps` of the original price, pro rata when underfunded
+pub fn claim_partial_refund<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimPartialRefund<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    holder_secret: [u8; 32],
+    min_payout: u64,
+) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    let holder = &ctx.accounts.holder;
+    let partial_refund_key = ctx.accounts.partial_refund.key();
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(
+        Clock::get()?.unix_timestamp <= ctx.accounts.partial_refund.claim_deadline,
+        EncoreError::PartialRefundClosed
+    );
+
+    let owed = ctx.accounts.partial_refund.refund(original_price);
+    let rent = Rent::get()?.minimum_balance(8 + PartialRefund::INIT_SPACE);
+    let available = ctx
+        .accounts
+        .partial_refund
+        .to_account_info()
+        .lamports()
+        .saturating_sub(rent);
+    let amount = ctx.accounts.partial_refund.payout(owed, available);
+    require!(
+        amount > 0 && amount >= min_payout,
+        EncoreError::PartialRefundUnderfunded
+    );
+
+    // commitment = SHA256(owner_pubkey || secret)
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(holder.key.as_ref());
+    commitment_input.extend_from_slice(&holder_secret);
+    let owner_commitment = hash(&commitment_input).to_bytes();
+
+    // --- Light Protocol CPI Setup ---
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Create refund claim marker ---
+    let (claim_address, claim_address_seed) = derive_address(
+        &[
+            PARTIAL_REFUND_SEED,
+            partial_refund_key.as_ref(),
+            &ticket_id.to_le_bytes(),
+        ],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut claim_account = LightAccount::<PartialRefundClaim>::new_init(
+        &crate::ID,
+        Some(claim_address),
+        output_state_tree_index,
+    );
+    claim_account.partial_refund = partial_refund_key;
+    claim_account.ticket_id = ticket_id;
+    claim_account.holder = holder.key();
+    claim_account.owed = owed;
+    claim_account.paid = amount;
+
+    // --- Step 2: Read ticket (the input hash proves ownership) ---
+    let ticket_account = LightAccount::<PrivateTicket>::new_mut(
+        &crate::ID,
+        &ticket_account_meta,
+        PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id,
+            owner_commitment,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            ownership: OwnershipMode::Bound,
+        },
+    )?;
+
+    // --- Execute CPI ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    let claim_params =
+        address_tree_info.into_new_address_params_assigned_packed(claim_address_seed, Some(0));
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(claim_account)?
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[claim_params])
+        .invoke(light_cpi_accounts)?;
+
+    // --- Step 3: Pay the refund ---
+    ctx.accounts.partial_refund.sub_lamports(amount)?;
+    ctx.accounts.holder.add_lamports(amount)?;
+
+    let partial_refund = &mut ctx.accounts.partial_refund;
+    partial_refund.claimed = partial_refund
+        .claimed
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    partial_refund.claims = partial_refund
+        .claims
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    partial_refund.outstanding = partial_refund.outstanding.saturating_sub(amount);
+    partial_refund.shortfall = partial_refund
+        .shortfall
+        .checked_add(owed - amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    ctx.accounts
+        .ledger
+        .record(LedgerEntryKind::PartialRefund, amount)?;
+
+    emit!(PartialRefundClaimed {
+        event_config: event_config.key(),
+        ticket_id,
+        holder: holder.key(),
+        owed,
+        amount,
+    });
+
+    msg!("💰 Partial refund of {} lamports paid", amount);
+
+    invariants::check_partial_refund(&ctx.accounts.partial_refund)?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, PARTIAL_REFUND_SEED};
+use crate::errors::EncoreError;
+use crate::state::{EventConfig, PartialRefund};
+
+#[derive(Accounts)]
+pub struct ClosePartialRefund<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Closed to the organizer along with any unclaimed funds
+    #[account(
+        mut,
+        seeds = [PARTIAL_REFUND_SEED, event_config.key().as_ref()],
+        bump = partial_refund.bump,
+        close = authority,
+    )]
+    pub partial_refund: Account<'info, PartialRefund>,
+}
+
+/// Close a partial refund program after its claim deadline, returning
+/// unclaimed funds to the organizer.
+pub fn close_partial_refund(ctx: Context<ClosePartialRefund>) -> Result<()> {
+    let partial_refund = &ctx.accounts.partial_refund;
+    require!(
+        Clock::get()?.unix_timestamp > partial_refund.claim_deadline,
+        EncoreError::PartialRefundOpen
+    );
+
+    msg!(
+        "✅ Partial refund closed: {} of {} lamports claimed",
+        partial_refund.claimed,
+        partial_refund.funded
+    );
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, System};
+
+use crate::constants::PARTIAL_REFUND_SEED;
+use crate::errors::EncoreError;
+use crate::invariants;
+use crate::state::PartialRefund;
+
+#[derive(Accounts)]
+pub struct FundPartialRefund<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PARTIAL_REFUND_SEED, partial_refund.event_config.as_ref()],
+        bump = partial_refund.bump,
+    )]
+    pub partial_refund: Account<'info, PartialRefund>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add lamports to a partial refund program. Anyone may fund it, usually
+/// the organizer or a promoter covering the change.
+pub fn fund_partial_refund(ctx: Context<FundPartialRefund>, amount: u64) -> Result<()> {
+    require!(amount > 0, EncoreError::InvalidPartialRefund);
+    require!(
+        Clock::get()?.unix_timestamp <= ctx.accounts.partial_refund.claim_deadline,
+        EncoreError::PartialRefundClosed
+    );
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.partial_refund.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let partial_refund = &mut ctx.accounts.partial_refund;
+    partial_refund.funded = partial_refund
+        .funded
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    msg!("💰 Partial refund funded with {} lamports", amount);
+
+    invariants::check_partial_refund(&ctx.accounts.partial_refund)?;
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::bps::BPS_DENOMINATOR;
+use crate::constants::{EVENT_SEED, PARTIAL_REFUND_SEED};
+use crate::errors::EncoreError;
+use crate::events::PartialRefundOpened;
+use crate::state::{EventConfig, PartialRefund};
+
+#[derive(Accounts)]
+pub struct OpenPartialRefund<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PartialRefund::INIT_SPACE,
+        seeds = [PARTIAL_REFUND_SEED, event_config.key().as_ref()],
+        bump
+    )]
+    pub partial_refund: Account<'info, PartialRefund>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a partial refund on an event that changed materially.
+///
+/// Holders claim `refund_bps` of their ticket's original price with
+/// `claim_partial_refund` and keep a valid ticket. Fund the program with
+/// `fund_partial_refund`. While it holds too little, claims are paid pro
+/// rata against `total_owed` and topped up later (see `PartialRefund`).
+///
+/// `total_owed` is the refund across the event's live tickets, summed off
+/// chain from their original prices. Understating it only shifts funds
+/// towards early claims, and claims never count for less than their own
+/// refund.
+pub fn open_partial_refund(
+    ctx: Context<OpenPartialRefund>,
+    refund_bps: u16,
+    claim_deadline: i64,
+    total_owed: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        refund_bps > 0
+            && refund_bps as u64 <= BPS_DENOMINATOR
+            && claim_deadline > now
+            && total_owed > 0,
+        EncoreError::InvalidPartialRefund
+    );
+    require!(
+        !ctx.accounts.event_config.cancelled,
+        EncoreError::EventCancelled
+    );
+    require!(
+        ctx.accounts.event_config.settles_in(None),
+        EncoreError::SettlementMintMismatch
+    );
+
+    let partial_refund = &mut ctx.accounts.partial_refund;
+    partial_refund.event_config = ctx.accounts.event_config.key();
+    partial_refund.refund_bps = refund_bps;
+    partial_refund.claim_deadline = claim_deadline;
+    partial_refund.funded = 0;
+    partial_refund.claimed = 0;
+    partial_refund.claims = 0;
+    partial_refund.outstanding = total_owed;
+    partial_refund.shortfall = 0;
+    partial_refund.created_at = now;
+    partial_refund.bump = ctx.bumps.partial_refund;
+
+    emit!(PartialRefundOpened {
+        event_config: partial_refund.event_config,
+        refund_bps,
+        claim_deadline,
+    });
+
+    msg!("✅ Partial refund of {} bps opened", refund_bps);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PARTIAL_REFUND_SEED;
+use crate::events::PartialRefundPreviewed;
+use crate::state::PartialRefund;
+
+#[derive(Accounts)]
+pub struct PreviewPartialRefund<'info> {
+    #[account(
+        seeds = [PARTIAL_REFUND_SEED, partial_refund.event_config.as_ref()],
+        bump = partial_refund.bump,
+    )]
+    pub partial_refund: Account<'info, PartialRefund>,
+}
+
+/// Dry run of `claim_partial_refund`'s payout for a ticket bought at
+/// `original_price`: emits `PartialRefundPreviewed` with what the claim
+/// would pay now and the program's remaining funds, changing nothing.
+///
+/// Clients show it as the expected recovery and pass it as `min_payout`.
+pub fn preview_partial_refund(
+    ctx: Context<PreviewPartialRefund>,
+    original_price: u64,
+) -> Result<()> {
+    let partial_refund = &ctx.accounts.partial_refund;
+
+    let rent = Rent::get()?.minimum_balance(8 + PartialRefund::INIT_SPACE);
+    let available = partial_refund
+        .to_account_info()
+        .lamports()
+        .saturating_sub(rent);
+
+    let owed = partial_refund.refund(original_price);
+    emit!(PartialRefundPreviewed {
+        event_config: partial_refund.event_config,
+        owed,
+        payout: partial_refund.payout(owed, available),
+        available,
+        outstanding: partial_refund.outstanding,
+        shortfall: partial_refund.shortfall,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{
+        v2::{CpiAccounts, LightSystemProgramCpi},
+        InvokeLightSystemProgram, LightCpiInstruction,
+    },
+    instruction::{account_meta::CompressedAccountMeta, ValidityProof},
+};
+
+use crate::constants::{EVENT_SEED, LEDGER_SEED, PARTIAL_REFUND_SEED};
+use crate::errors::EncoreError;
+use crate::events::PartialRefundToppedUp;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::invariants;
+use crate::state::{EventConfig, EventLedger, LedgerEntryKind, PartialRefund, PartialRefundClaim};
+
+#[derive(Accounts)]
+pub struct TopUpPartialRefund<'info> {
+    /// Holder recorded on the claim (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [PARTIAL_REFUND_SEED, event_config.key().as_ref()],
+        bump = partial_refund.bump,
+    )]
+    pub partial_refund: Account<'info, PartialRefund>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+}
+
+/// Pay out more of a claim that `claim_partial_refund` paid pro rata.
+///
+/// The claim marker is updated with the holder as signer, which proves the
+/// claim is theirs, and records the new total paid. The top-up pays the
+/// claim's remainder on the same pro rata terms as a new claim, so it's
+/// paid in full once the program covers everything outstanding.
+///
+/// # Operations
+/// 1. Validate the program is open and the event not cancelled
+/// 2. Pay `PartialRefund::payout` of the claim's remainder
+/// 3. UPDATE the claim marker with the new total paid
+pub fn top_up_partial_refund<'info>(
+    ctx: Context<'_, '_, '_, 'info, TopUpPartialRefund<'info>>,
+    proof: ValidityProof,
+    claim_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    owed: u64,
+    paid: u64,
+    min_payout: u64,
+) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    let holder = &ctx.accounts.holder;
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(
+        Clock::get()?.unix_timestamp <= ctx.accounts.partial_refund.claim_deadline,
+        EncoreError::PartialRefundClosed
+    );
+
+    // The input hash proves the marker as given; a wrong holder fails it
+    let mut claim_account = LightAccount::<PartialRefundClaim>::new_mut(
+        &crate::ID,
+        &claim_account_meta,
+        PartialRefundClaim {
+            partial_refund: ctx.accounts.partial_refund.key(),
+            ticket_id,
+            holder: holder.key(),
+            owed,
+            paid,
+        },
+    )?;
+
+    let rent = Rent::get()?.minimum_balance(8 + PartialRefund::INIT_SPACE);
+    let available = ctx
+        .accounts
+        .partial_refund
+        .to_account_info()
+        .lamports()
+        .saturating_sub(rent);
+    let amount = ctx
+        .accounts
+        .partial_refund
+        .payout(claim_account.remaining(), available);
+    require!(
+        amount > 0 && amount >= min_payout,
+        EncoreError::PartialRefundUnderfunded
+    );
+    claim_account.paid = paid
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let remaining = claim_account.remaining();
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(claim_account)?
+        .invoke(light_cpi_accounts)?;
+
+    ctx.accounts.partial_refund.sub_lamports(amount)?;
+    ctx.accounts.holder.add_lamports(amount)?;
+
+    let partial_refund = &mut ctx.accounts.partial_refund;
+    partial_refund.claimed = partial_refund
+        .claimed
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    partial_refund.outstanding = partial_refund.outstanding.saturating_sub(amount);
+    partial_refund.shortfall = partial_refund.shortfall.saturating_sub(amount);
+
+    ctx.accounts
+        .ledger
+        .record(LedgerEntryKind::PartialRefund, amount)?;
+
+    emit!(PartialRefundToppedUp {
+        event_config: event_config.key(),
+        ticket_id,
+        holder: holder.key(),
+        amount,
+        remaining,
+    });
+
+    msg!(
+        "💰 Partial refund topped up by {} lamports, {} still owed",
+        amount,
+        remaining
+    );
+
+    invariants::check_partial_refund(&ctx.accounts.partial_refund)?;
+
+    Ok(())
+}
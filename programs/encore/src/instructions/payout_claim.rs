@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PAYOUT_ACCRUAL_SEED;
+use crate::errors::EncoreError;
+use crate::events::PayoutClaimed;
+use crate::state::PayoutAccrual;
+
+#[derive(Accounts)]
+pub struct ClaimPayout<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PAYOUT_ACCRUAL_SEED,
+            payout_accrual.event_config.as_ref(),
+            recipient.key().as_ref(),
+        ],
+        bump = payout_accrual.bump,
+        has_one = recipient @ EncoreError::Unauthorized,
+    )]
+    pub payout_accrual: Account<'info, PayoutAccrual>,
+}
+
+/// Withdraw everything owed on an accrual. The account stays open for
+/// future credits.
+pub fn claim_payout(ctx: Context<ClaimPayout>) -> Result<()> {
+    let accrual = &mut ctx.accounts.payout_accrual;
+    let amount = accrual.owed;
+    require!(amount > 0, EncoreError::NothingToClaim);
+
+    accrual.sub_lamports(amount)?;
+    ctx.accounts.recipient.add_lamports(amount)?;
+
+    accrual.owed = 0;
+    accrual.claimed = accrual
+        .claimed
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(PayoutClaimed {
+        event_config: accrual.event_config,
+        recipient: accrual.recipient,
+        amount,
+    });
+
+    msg!("💰 Claimed {} lamports of payouts", amount);
+
+    Ok(())
+}
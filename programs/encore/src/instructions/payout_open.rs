@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PAYOUT_ACCRUAL_SEED;
+use crate::state::{EventConfig, PayoutAccrual};
+
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey)]
+pub struct OpenPayoutAccrual<'info> {
+    /// Pays rent; usually the recipient or the organizer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PayoutAccrual::INIT_SPACE,
+        seeds = [PAYOUT_ACCRUAL_SEED, event_config.key().as_ref(), recipient.as_ref()],
+        bump
+    )]
+    pub payout_accrual: Account<'info, PayoutAccrual>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open the accrual account a recipient's payouts are credited to.
+pub fn open_payout_accrual(ctx: Context<OpenPayoutAccrual>, recipient: Pubkey) -> Result<()> {
+    let accrual = &mut ctx.accounts.payout_accrual;
+    accrual.event_config = ctx.accounts.event_config.key();
+    accrual.recipient = recipient;
+    accrual.owed = 0;
+    accrual.claimed = 0;
+    accrual.bump = ctx.bumps.payout_accrual;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{PRICE_FEED_SEED, PROTOCOL_SEED};
+use crate::errors::EncoreError;
+use crate::state::{PriceFeed, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct InitializePriceFeed<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ EncoreError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PriceFeed::INIT_SPACE,
+        seeds = [PRICE_FEED_SEED],
+        bump
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the SOL/USD price feed singleton. Only the protocol admin may call this.
+///
+/// # Operations
+/// 1. Create price feed PDA
+/// 2. Set the oracle authority allowed to push prices
+pub fn initialize_price_feed(
+    ctx: Context<InitializePriceFeed>,
+    oracle_authority: Pubkey,
+) -> Result<()> {
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.oracle_authority = oracle_authority;
+    price_feed.usd_micros_per_sol = 0;
+    price_feed.updated_at = 0;
+    price_feed.bump = ctx.bumps.price_feed;
+
+    msg!("✅ Price feed initialized, oracle: {:?}", oracle_authority);
+
+    Ok(())
+}
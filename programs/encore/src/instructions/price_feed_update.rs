@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PRICE_FEED_SEED;
+use crate::errors::EncoreError;
+use crate::events::PriceFeedUpdated;
+use crate::state::PriceFeed;
+
+#[derive(Accounts)]
+pub struct UpdatePriceFeed<'info> {
+    pub oracle_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PRICE_FEED_SEED],
+        bump = price_feed.bump,
+        has_one = oracle_authority @ EncoreError::Unauthorized
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+}
+
+/// Push a new SOL/USD price. Only the oracle authority may call this.
+pub fn update_price_feed(ctx: Context<UpdatePriceFeed>, usd_micros_per_sol: u64) -> Result<()> {
+    require!(usd_micros_per_sol > 0, EncoreError::InvalidPriceFeed);
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.usd_micros_per_sol = usd_micros_per_sol;
+    price_feed.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(PriceFeedUpdated {
+        usd_micros_per_sol,
+        updated_at: price_feed.updated_at,
+    });
+
+    Ok(())
+}
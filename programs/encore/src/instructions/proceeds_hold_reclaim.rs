@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LEDGER_SEED, PROCEEDS_HOLD_SEED};
+use crate::errors::EncoreError;
+use crate::events::ProceedsReleased;
+use crate::state::{EventLedger, HoldStatus, LedgerEntryKind, ProceedsHold};
+
+#[derive(Accounts)]
+pub struct ReclaimHeldProceeds<'info> {
+    /// Buyer whose ticket was voided
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROCEEDS_HOLD_SEED, proceeds_hold.listing.as_ref()],
+        bump = proceeds_hold.bump,
+        has_one = buyer @ EncoreError::Unauthorized,
+        close = buyer,
+    )]
+    pub proceeds_hold: Account<'info, ProceedsHold>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, proceeds_hold.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+}
+
+/// Reclaim held resale proceeds after the purchased ticket was voided.
+pub fn reclaim_held_proceeds(ctx: Context<ReclaimHeldProceeds>) -> Result<()> {
+    let hold = &ctx.accounts.proceeds_hold;
+    require!(
+        hold.status == HoldStatus::Voided,
+        EncoreError::ProceedsNotReclaimable
+    );
+
+    ctx.accounts
+        .ledger
+        .record(LedgerEntryKind::EscrowRefund, hold.amount)?;
+
+    emit!(ProceedsReleased {
+        proceeds_hold: hold.key(),
+        recipient: hold.buyer,
+        amount: hold.amount,
+        status: hold.status,
+    });
+
+    msg!(
+        "💰 Refunded {} lamports of held proceeds to buyer",
+        hold.amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PROCEEDS_HOLD_SEED;
+use crate::errors::EncoreError;
+use crate::events::ProceedsReleased;
+use crate::state::{EventConfig, HoldStatus, ProceedsHold};
+
+#[derive(Accounts)]
+pub struct WithdrawHeldProceeds<'info> {
+    /// Seller owed the proceeds
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(address = proceeds_hold.event_config)]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [PROCEEDS_HOLD_SEED, proceeds_hold.listing.as_ref()],
+        bump = proceeds_hold.bump,
+        has_one = seller @ EncoreError::Unauthorized,
+        close = seller,
+    )]
+    pub proceeds_hold: Account<'info, ProceedsHold>,
+}
+
+/// Withdraw held resale proceeds.
+///
+/// Allowed once the buyer's ticket was redeemed, or the event date has
+/// passed without the ticket being voided.
+pub fn withdraw_held_proceeds(ctx: Context<WithdrawHeldProceeds>) -> Result<()> {
+    let hold = &ctx.accounts.proceeds_hold;
+    let event_passed = Clock::get()?.unix_timestamp >= ctx.accounts.event_config.event_timestamp;
+    require!(
+        hold.status == HoldStatus::Redeemed || (hold.status == HoldStatus::Held && event_passed),
+        EncoreError::ProceedsStillHeld
+    );
+
+    emit!(ProceedsReleased {
+        proceeds_hold: hold.key(),
+        recipient: hold.seller,
+        amount: hold.amount,
+        status: hold.status,
+    });
+
+    msg!(
+        "💰 Released {} lamports of held proceeds to seller",
+        hold.amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FEATURE_STRICT_INVARIANTS, FEATURE_TEST_MODE, FEATURE_V1_TREES};
+
+#[derive(Accounts)]
+pub struct GetVersion {}
+
+/// What the deployed build supports, returned by `get_version`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ProgramInfo {
+    /// Crate semantic version, e.g. "0.1.0"
+    pub version: String,
+
+    /// Cargo features compiled in, as `FEATURE_*` bits
+    pub features: u64,
+}
+
+impl ProgramInfo {
+    pub fn current() -> Self {
+        let mut features = 0;
+        if cfg!(feature = "v1-trees") {
+            features |= FEATURE_V1_TREES;
+        }
+        if cfg!(feature = "test-mode") {
+            features |= FEATURE_TEST_MODE;
+        }
+        if cfg!(feature = "strict-invariants") {
+            features |= FEATURE_STRICT_INVARIANTS;
+        }
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            features,
+        }
+    }
+}
+
+/// Report the program's version and compiled-in features.
+///
+/// Takes no accounts and changes nothing, so clients and relayers simulate
+/// it and read the return data to decide how to build transactions for the
+/// deployed program rather than guessing from the IDL.
+pub fn get_version(_ctx: Context<GetVersion>) -> Result<ProgramInfo> {
+    let info = ProgramInfo::current();
+    msg!("Encore {} (features {:#x})", info.version, info.features);
+    Ok(info)
+}
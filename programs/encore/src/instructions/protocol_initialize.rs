@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_PROTOCOL_FEE_BPS, METRICS_SEED, PROTOCOL_SEED, TREASURY_SEED};
+use crate::errors::EncoreError;
+use crate::program::Encore;
+use crate::state::{ProtocolConfig, ProtocolMetrics, ProtocolTreasury};
+
+#[derive(Accounts)]
+pub struct InitializeProtocol<'info> {
+    /// Must be the program's upgrade authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ EncoreError::UpgradeAuthorityRequired
+    )]
+    pub program: Program<'info, Encore>,
+
+    /// The program's data account, holding its upgrade authority
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(payer.key())
+            @ EncoreError::UpgradeAuthorityRequired
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProtocolConfig::INIT_SPACE,
+        seeds = [PROTOCOL_SEED],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProtocolTreasury::INIT_SPACE,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProtocolMetrics::INIT_SPACE,
+        seeds = [METRICS_SEED],
+        bump
+    )]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the protocol config singleton.
+///
+/// Only the program's upgrade authority can initialize the protocol, so
+/// nobody can front-run the deployment and install themselves as admin.
+///
+/// # Operations
+/// 1. Create protocol config, treasury and metrics PDAs
+/// 2. Set admin, fees and initial policy flags
+pub fn initialize_protocol(
+    ctx: Context<InitializeProtocol>,
+    admin: Pubkey,
+    void_requires_admin: bool,
+    mint_fee_bps: u16,
+    sale_fee_bps: u16,
+) -> Result<()> {
+    require!(
+        mint_fee_bps <= MAX_PROTOCOL_FEE_BPS && sale_fee_bps <= MAX_PROTOCOL_FEE_BPS,
+        EncoreError::InvalidProtocolFee
+    );
+
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.admin = admin;
+    protocol_config.void_requires_admin = void_requires_admin;
+    protocol_config.mint_fee_bps = mint_fee_bps;
+    protocol_config.sale_fee_bps = sale_fee_bps;
+    protocol_config.cancellation_oracle = None;
+    protocol_config.bump = ctx.bumps.protocol_config;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.mint_fees = 0;
+    treasury.sale_fees = 0;
+    treasury.penalty_fees = 0;
+    treasury.withdrawn = 0;
+    treasury.bump = ctx.bumps.treasury;
+
+    let metrics = &mut ctx.accounts.metrics;
+    metrics.events_created = 0;
+    metrics.tickets_minted = 0;
+    metrics.resales = 0;
+    metrics.resale_volume = 0;
+    metrics.fees_collected = 0;
+    metrics.bump = ctx.bumps.metrics;
+
+    msg!("✅ Protocol initialized, admin: {:?}", admin);
+
+    Ok(())
+}
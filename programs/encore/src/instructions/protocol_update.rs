@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_PROTOCOL_FEE_BPS, PROTOCOL_SEED};
+use crate::errors::EncoreError;
+use crate::state::ProtocolConfig;
+
+#[derive(Accounts)]
+pub struct UpdateProtocol<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROTOCOL_SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ EncoreError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+/// Update protocol-wide settings. Only the current admin may call this.
+pub fn update_protocol(
+    ctx: Context<UpdateProtocol>,
+    new_admin: Option<Pubkey>,
+    void_requires_admin: Option<bool>,
+    mint_fee_bps: Option<u16>,
+    sale_fee_bps: Option<u16>,
+    cancellation_oracle: Option<Option<Pubkey>>,
+) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+
+    if let Some(admin) = new_admin {
+        protocol_config.admin = admin;
+    }
+
+    if let Some(required) = void_requires_admin {
+        protocol_config.void_requires_admin = required;
+    }
+
+    if let Some(fee_bps) = mint_fee_bps {
+        require!(
+            fee_bps <= MAX_PROTOCOL_FEE_BPS,
+            EncoreError::InvalidProtocolFee
+        );
+        protocol_config.mint_fee_bps = fee_bps;
+    }
+
+    if let Some(fee_bps) = sale_fee_bps {
+        require!(
+            fee_bps <= MAX_PROTOCOL_FEE_BPS,
+            EncoreError::InvalidProtocolFee
+        );
+        protocol_config.sale_fee_bps = fee_bps;
+    }
+
+    if let Some(oracle) = cancellation_oracle {
+        protocol_config.cancellation_oracle = oracle;
+    }
+
+    msg!("✅ Protocol config updated");
+
+    Ok(())
+}
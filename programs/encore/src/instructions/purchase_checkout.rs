@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use anchor_lang::Discriminator;
+
+use crate::constants::MAX_CHECKOUT_EVENTS;
+use crate::errors::EncoreError;
+use crate::events::CheckoutCompleted;
+use crate::instructions::ticket_mint::{MINT_BUYER_INDEX, MINT_EVENT_INDEX};
+
+#[derive(Accounts)]
+pub struct Checkout<'info> {
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Instructions sysvar, used to read the cart's mints
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Settle a multi-event cart (day passes, camping, parking) in one
+/// transaction with a single receipt.
+///
+/// Put `checkout` before the cart's `mint_ticket` instructions. Every
+/// later mint must be the buyer's, from an event in `event_configs`, and
+/// each of those events must be minted exactly once. The transaction
+/// lands or fails as a whole, so the cart is atomic across organizers.
+///
+/// # Fitting a transaction
+/// A `mint_ticket` carries a validity proof and some 30 accounts, so the
+/// cart is built as a versioned transaction whose accounts come from
+/// address lookup tables: the programs, trees and sysvars shared by every
+/// mint, plus each event's PDAs. Even then only `MAX_CHECKOUT_EVENTS`
+/// mints fit in 1232 bytes, so the cart is batched per event: one mint
+/// each, with a companion seat for a second ticket to the same event.
+/// Larger carts are split into several checkouts.
+///
+/// # Operations
+/// 1. Read every later instruction from this program; all must be mints
+/// 2. Check buyer and events match the cart, one mint per event
+/// 3. Require the mints' combined `purchase_price` to be at most `max_total`
+///    (add-ons are priced by each event and not included)
+/// 4. Emit one `CheckoutCompleted` receipt for the cart
+pub fn checkout(ctx: Context<Checkout>, event_configs: Vec<Pubkey>, max_total: u64) -> Result<()> {
+    require!(
+        !event_configs.is_empty() && event_configs.len() <= MAX_CHECKOUT_EVENTS,
+        EncoreError::InvalidCheckout
+    );
+
+    let buyer = ctx.accounts.buyer.key();
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.to_account_info();
+    let current = ix_sysvar::load_current_index_checked(&instructions_sysvar)? as usize;
+
+    let mut minted = vec![false; event_configs.len()];
+    let mut tickets: u32 = 0;
+    let mut total: u64 = 0;
+
+    let mut index = current + 1;
+    while let Ok(ix) = ix_sysvar::load_instruction_at_checked(index, &instructions_sysvar) {
+        index += 1;
+        if ix.program_id != crate::ID {
+            continue;
+        }
+        require!(
+            ix.data
+                .starts_with(crate::instruction::MintTicket::DISCRIMINATOR),
+            EncoreError::InvalidCheckout
+        );
+
+        let mint_buyer = ix.accounts.get(MINT_BUYER_INDEX).map(|meta| meta.pubkey);
+        let mint_event = ix.accounts.get(MINT_EVENT_INDEX).map(|meta| meta.pubkey);
+        require!(mint_buyer == Some(buyer), EncoreError::InvalidCheckout);
+        let position = event_configs
+            .iter()
+            .position(|event| Some(*event) == mint_event)
+            .ok_or(EncoreError::InvalidCheckout)?;
+        require!(!minted[position], EncoreError::InvalidCheckout);
+        minted[position] = true;
+
+        let args = crate::instruction::MintTicket::try_from_slice(
+            &ix.data[crate::instruction::MintTicket::DISCRIMINATOR.len()..],
+        )
+        .map_err(|_| EncoreError::InvalidCheckout)?;
+        // A companion seat is issued at no charge
+        total = total
+            .checked_add(args.purchase_price)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        tickets += if args.companion.is_some() { 2 } else { 1 };
+    }
+
+    require!(minted.iter().all(|m| *m), EncoreError::InvalidCheckout);
+    require!(total <= max_total, EncoreError::CheckoutTotalExceeded);
+
+    emit!(CheckoutCompleted {
+        buyer,
+        event_configs,
+        tickets,
+        total_lamports: total,
+    });
+
+    msg!("✅ Checkout: {} tickets for {} lamports", tickets, total);
+
+    Ok(())
+}
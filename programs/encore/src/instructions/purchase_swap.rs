@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use anchor_lang::Discriminator;
+use anchor_spl::token::{self, spl_token::native_mint, Token, TokenAccount};
+
+use crate::constants::JUPITER_PROGRAM_ID;
+use crate::errors::EncoreError;
+use crate::instructions::listing_claim::CLAIM_BUYER_INDEX;
+use crate::instructions::ticket_mint::MINT_BUYER_INDEX;
+
+#[derive(Accounts)]
+pub struct SwapForPurchase<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Buyer's wrapped SOL account receiving the swap output; closed into
+    /// the buyer's lamports afterwards
+    #[account(
+        mut,
+        token::mint = native_mint::ID,
+        token::authority = buyer,
+    )]
+    pub wsol_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Jupiter aggregator program
+    #[account(address = JUPITER_PROGRAM_ID)]
+    pub jupiter_program: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar, used to find the purchase this swap funds
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Swap any token the buyer holds into SOL through Jupiter, right before
+/// a purchase.
+///
+/// The route is built off-chain (Jupiter's swap API) with the buyer as
+/// user and `wsol_account` as destination; its instruction data is passed
+/// as `route_data` and its accounts as remaining accounts. The next
+/// instruction in the transaction must be `mint_ticket` or `claim_listing`
+/// from this program, so the swap and the purchase land or fail together,
+/// and its buyer must be the swap's signer, so the output funds that
+/// purchase and not someone else's.
+///
+/// # Settlement currency
+/// Events settle in SOL (`init_event` rejects settlement mints), so SOL is
+/// the currency both purchases are paid in. An event settling in a token
+/// would need the route to end in that mint instead.
+///
+/// # Operations
+/// 1. Check the next instruction is a purchase by the buyer
+/// 2. CPI the route into Jupiter
+/// 3. Require at least `min_out` lamports of output
+/// 4. Close `wsol_account` so the output is spendable SOL
+pub fn swap_for_purchase<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapForPurchase<'info>>,
+    route_data: Vec<u8>,
+    min_out: u64,
+) -> Result<()> {
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.to_account_info();
+    let current = ix_sysvar::load_current_index_checked(&instructions_sysvar)?;
+    let next = ix_sysvar::load_instruction_at_checked(current as usize + 1, &instructions_sysvar)
+        .map_err(|_| EncoreError::PurchaseRequired)?;
+    require!(next.program_id == crate::ID, EncoreError::PurchaseRequired);
+    let buyer_index = if next
+        .data
+        .starts_with(crate::instruction::MintTicket::DISCRIMINATOR)
+    {
+        MINT_BUYER_INDEX
+    } else if next
+        .data
+        .starts_with(crate::instruction::ClaimListing::DISCRIMINATOR)
+    {
+        CLAIM_BUYER_INDEX
+    } else {
+        return err!(EncoreError::PurchaseRequired);
+    };
+    require!(
+        next.accounts.get(buyer_index).map(|meta| meta.pubkey) == Some(ctx.accounts.buyer.key()),
+        EncoreError::PurchaseBuyerMismatch
+    );
+
+    let balance_before = ctx.accounts.wsol_account.amount;
+
+    let route_accounts: Vec<AccountMeta> = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: *account.key,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    invoke(
+        &Instruction {
+            program_id: JUPITER_PROGRAM_ID,
+            accounts: route_accounts,
+            data: route_data,
+        },
+        ctx.remaining_accounts,
+    )?;
+
+    ctx.accounts.wsol_account.reload()?;
+    let received = ctx
+        .accounts
+        .wsol_account
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    require!(received >= min_out, EncoreError::SwapOutputTooLow);
+
+    // Unwrap: closing a native account hands its lamports to the buyer
+    token::close_account(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.wsol_account.to_account_info(),
+            destination: ctx.accounts.buyer.to_account_info(),
+            authority: ctx.accounts.buyer.to_account_info(),
+        },
+    ))?;
+
+    msg!("💰 Swapped into {} lamports for purchase", received);
+
+    Ok(())
+}
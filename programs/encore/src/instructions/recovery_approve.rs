@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GUARDIAN_SET_SEED, TICKET_RECOVERY_SEED};
+use crate::errors::EncoreError;
+use crate::events::RecoveryApproved;
+use crate::state::{GuardianSet, TicketRecovery};
+
+#[derive(Accounts)]
+#[instruction(ticket_commitment: [u8; 32])]
+pub struct ApproveRecovery<'info> {
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [GUARDIAN_SET_SEED, guardian_set.holder.as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    /// Opened by the first approval
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        space = 8 + TicketRecovery::INIT_SPACE,
+        seeds = [
+            TICKET_RECOVERY_SEED,
+            guardian_set.key().as_ref(),
+            ticket_commitment.as_ref(),
+        ],
+        bump
+    )]
+    pub recovery: Account<'info, TicketRecovery>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Approve recovering a guarded ticket to `new_owner_commitment`
+/// (guardians only).
+///
+/// The first approval opens the recovery; later ones must name the same
+/// commitment. The approval that meets the threshold starts the veto
+/// window, after which anyone can run `recover_ticket`.
+pub fn approve_recovery(
+    ctx: Context<ApproveRecovery>,
+    ticket_commitment: [u8; 32],
+    new_owner_commitment: [u8; 32],
+) -> Result<()> {
+    let guardian = ctx.accounts.guardian.key();
+    let guardian_set = &ctx.accounts.guardian_set;
+    let recovery = &mut ctx.accounts.recovery;
+
+    require!(
+        guardian_set.is_guardian(&guardian),
+        EncoreError::NotGuardian
+    );
+    require!(
+        guardian_set.guarded(&ticket_commitment).is_some(),
+        EncoreError::TicketNotGuarded
+    );
+
+    if recovery.approvals.is_empty() {
+        recovery.guardian_set = guardian_set.key();
+        recovery.ticket_commitment = ticket_commitment;
+        recovery.new_owner_commitment = new_owner_commitment;
+        recovery.unlocks_at = None;
+        recovery.proposer = guardian;
+        recovery.bump = ctx.bumps.recovery;
+    } else {
+        require!(
+            recovery.new_owner_commitment == new_owner_commitment,
+            EncoreError::RecoveryMismatch
+        );
+        require!(
+            !recovery.approvals.contains(&guardian),
+            EncoreError::RecoveryAlreadyApproved
+        );
+        // Approvals from removed guardians no longer hold a place
+        recovery.approvals.retain(|g| guardian_set.is_guardian(g));
+    }
+    recovery.approvals.push(guardian);
+
+    let approvals = recovery.current_approvals(guardian_set);
+    if recovery.unlocks_at.is_none() && approvals >= guardian_set.threshold as usize {
+        recovery.unlocks_at = Some(
+            Clock::get()?
+                .unix_timestamp
+                .checked_add(guardian_set.recovery_delay_seconds)
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+        );
+    }
+
+    emit!(RecoveryApproved {
+        guardian_set: guardian_set.key(),
+        ticket_commitment,
+        guardian,
+        approvals: approvals as u8,
+        unlocks_at: recovery.unlocks_at,
+    });
+
+    Ok(())
+}
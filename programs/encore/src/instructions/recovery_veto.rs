@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GUARDIAN_SET_SEED, TICKET_RECOVERY_SEED};
+use crate::errors::EncoreError;
+use crate::events::RecoveryVetoed;
+use crate::state::{GuardianSet, TicketRecovery};
+
+#[derive(Accounts)]
+pub struct VetoRecovery<'info> {
+    pub holder: Signer<'info>,
+
+    /// CHECK: Guardian who opened the recovery, receives its rent
+    #[account(mut, address = recovery.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GUARDIAN_SET_SEED, holder.key().as_ref()],
+        bump = guardian_set.bump,
+        has_one = holder @ EncoreError::Unauthorized
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(
+        mut,
+        seeds = [
+            TICKET_RECOVERY_SEED,
+            guardian_set.key().as_ref(),
+            recovery.ticket_commitment.as_ref(),
+        ],
+        bump = recovery.bump,
+        close = proposer,
+    )]
+    pub recovery: Account<'info, TicketRecovery>,
+}
+
+/// Cancel a pending recovery of one of the holder's tickets (holder only).
+///
+/// Works at any point before `recover_ticket` runs, so a holder who still
+/// has their wallet can always stop guardians acting without them.
+pub fn veto_recovery(ctx: Context<VetoRecovery>) -> Result<()> {
+    emit!(RecoveryVetoed {
+        guardian_set: ctx.accounts.guardian_set.key(),
+        ticket_commitment: ctx.accounts.recovery.ticket_commitment,
+    });
+
+    Ok(())
+}
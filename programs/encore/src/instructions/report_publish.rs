@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, LEDGER_SEED};
+use crate::errors::EncoreError;
+use crate::events::{ReportDiscrepancy, ReportPublished};
+use crate::state::{EventConfig, EventLedger, SettlementReport};
+
+#[derive(Accounts)]
+pub struct PublishReport<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+}
+
+/// Publish a signed settlement report for the event.
+///
+/// The organizer signs the transaction over `report` and its hash (see
+/// `SettlementReport::hash`). The figures are compared with the event's
+/// stats counters and ledger totals; any difference emits
+/// `ReportDiscrepancy` next to the `ReportPublished` record. Publishing a
+/// mismatching report is not an error, so the discrepancy stays on record.
+pub fn publish_report(
+    ctx: Context<PublishReport>,
+    report: SettlementReport,
+    report_hash: [u8; 32],
+) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    require!(
+        report.hash(&event_config.key()) == report_hash,
+        EncoreError::ReportHashMismatch
+    );
+
+    let on_chain = SettlementReport::from_counters(event_config, &ctx.accounts.ledger)?;
+    let matches_counters = report == on_chain;
+
+    emit!(ReportPublished {
+        event_config: event_config.key(),
+        report_hash,
+        report,
+        matches_counters,
+        published_at: Clock::get()?.unix_timestamp,
+    });
+
+    if !matches_counters {
+        emit!(ReportDiscrepancy {
+            event_config: event_config.key(),
+            report_hash,
+            reported: report,
+            on_chain,
+        });
+        msg!("⚠️ Report does not match on-chain counters");
+    } else {
+        msg!("✅ Report published and matches on-chain counters");
+    }
+
+    Ok(())
+}
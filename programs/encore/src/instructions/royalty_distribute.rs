@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::bps;
+use crate::constants::{PAYOUT_ACCRUAL_SEED, ROYALTY_VAULT_SEED};
+use crate::errors::EncoreError;
+use crate::events::RoyaltiesDistributed;
+use crate::state::{EventConfig, PayoutAccrual, RoyaltyVault};
+
+#[derive(Accounts)]
+pub struct DistributeRoyalties<'info> {
+    #[account(address = royalty_vault.event_config)]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [ROYALTY_VAULT_SEED, event_config.key().as_ref()],
+        bump = royalty_vault.bump,
+    )]
+    pub royalty_vault: Account<'info, RoyaltyVault>,
+    // remaining_accounts: each split recipient's `PayoutAccrual` (writable),
+    // in split order
+}
+
+/// Credit pending royalties to the split recipients' payout accruals.
+///
+/// Permissionless. Each recipient is credited `pending * bps / 10000` and
+/// claims it with `claim_payout`; rounding dust stays pending for the next
+/// run.
+pub fn distribute_royalties<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeRoyalties<'info>>,
+) -> Result<()> {
+    let royalty = ctx
+        .accounts
+        .event_config
+        .royalty
+        .as_ref()
+        .ok_or(EncoreError::InvalidRoyaltyConfig)?;
+    require!(
+        ctx.remaining_accounts.len() == royalty.splits.len(),
+        EncoreError::RoyaltyRecipientMismatch
+    );
+
+    let vault = &mut ctx.accounts.royalty_vault;
+    let pending = vault.pending;
+    let mut paid = 0u64;
+
+    for (split, info) in royalty.splits.iter().zip(ctx.remaining_accounts) {
+        let mut accrual = Account::<PayoutAccrual>::try_from(info)?;
+        let expected = Pubkey::create_program_address(
+            &[
+                PAYOUT_ACCRUAL_SEED,
+                vault.event_config.as_ref(),
+                split.recipient.as_ref(),
+                &[accrual.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| EncoreError::PayoutAccrualMismatch)?;
+        require_keys_eq!(*info.key, expected, EncoreError::RoyaltyRecipientMismatch);
+
+        let share = bps::share(pending, split.bps as u64);
+        if share > 0 {
+            vault.sub_lamports(share)?;
+            accrual.add_lamports(share)?;
+            accrual.owed = accrual
+                .owed
+                .checked_add(share)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            accrual.exit(&crate::ID)?;
+            paid += share;
+        }
+    }
+
+    vault.pending = pending - paid;
+    vault.distributed = vault
+        .distributed
+        .checked_add(paid)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(RoyaltiesDistributed {
+        event_config: vault.event_config,
+        amount: paid,
+        recipients: royalty.splits.len() as u8,
+    });
+
+    msg!("💰 Distributed {} lamports of royalties", paid);
+
+    Ok(())
+}
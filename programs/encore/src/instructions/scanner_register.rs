@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ENTRANCE_STATS_SEED, EVENT_SEED, SCANNER_SEED};
+use crate::errors::EncoreError;
+use crate::events::ScannerUpdated;
+use crate::state::{EntranceStats, EventConfig, Scanner};
+
+#[derive(Accounts)]
+#[instruction(device_key: Pubkey)]
+pub struct RegisterScanner<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Scanner::INIT_SPACE,
+        seeds = [SCANNER_SEED, event_config.key().as_ref(), device_key.as_ref()],
+        bump
+    )]
+    pub scanner: Account<'info, Scanner>,
+
+    /// Created with the event's first scanner
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + EntranceStats::INIT_SPACE,
+        seeds = [ENTRANCE_STATS_SEED, event_config.key().as_ref()],
+        bump
+    )]
+    pub entrance_stats: Account<'info, EntranceStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Register a gate device for the event's entrances in `zones` until
+/// `expires_at` (event authority only). The first registration also
+/// creates the event's `EntranceStats`.
+pub fn register_scanner(
+    ctx: Context<RegisterScanner>,
+    device_key: Pubkey,
+    zones: u32,
+    expires_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(zones != 0 && expires_at > now, EncoreError::InvalidScanner);
+
+    let scanner = &mut ctx.accounts.scanner;
+    scanner.event_config = ctx.accounts.event_config.key();
+    scanner.device_key = device_key;
+    scanner.zones = zones;
+    scanner.expires_at = expires_at;
+    scanner.registered_at = now;
+    scanner.bump = ctx.bumps.scanner;
+
+    let entrance_stats = &mut ctx.accounts.entrance_stats;
+    entrance_stats.event_config = scanner.event_config;
+    entrance_stats.bump = ctx.bumps.entrance_stats;
+
+    emit!(ScannerUpdated {
+        event_config: scanner.event_config,
+        device_key,
+        zones,
+        expires_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, SCANNER_SEED};
+use crate::errors::EncoreError;
+use crate::events::ScannerUpdated;
+use crate::state::{EventConfig, Scanner};
+
+#[derive(Accounts)]
+pub struct RevokeScanner<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [SCANNER_SEED, event_config.key().as_ref(), scanner.device_key.as_ref()],
+        bump = scanner.bump,
+    )]
+    pub scanner: Account<'info, Scanner>,
+}
+
+/// Withdraw a scanner's access to the entrances in `zones` (event
+/// authority only). Revoking every zone disables the device; the account
+/// is kept as a record.
+pub fn revoke_scanner(ctx: Context<RevokeScanner>, zones: u32) -> Result<()> {
+    let scanner = &mut ctx.accounts.scanner;
+    scanner.zones &= !zones;
+
+    emit!(ScannerUpdated {
+        event_config: scanner.event_config,
+        device_key: scanner.device_key,
+        zones: scanner.zones,
+        expires_at: scanner.expires_at,
+    });
+
+    msg!(
+        "✅ Scanner {} now covers zones {:#b}",
+        scanner.device_key,
+        scanner.zones
+    );
+
+    Ok(())
+}
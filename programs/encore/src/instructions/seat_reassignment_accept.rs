@@ -0,0 +1,236 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{CREDIT_SEED, EVENT_SEED, LEDGER_SEED, SEAT_REASSIGNMENT_SEED, TICKET_SEED};
+use crate::errors::EncoreError;
+use crate::events::{RefundCreditIssued, SeatReassignmentResolved};
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::state::{
+    EventConfig, EventLedger, FieldValue, LedgerEntryKind, Nullifier, OwnershipMode, PrivateTicket,
+    RefundCredit, SeatReassignment,
+};
+
+#[derive(Accounts)]
+pub struct AcceptSeatReassignment<'info> {
+    /// Ticket holder accepting the new seat (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner, receives the offer's rent
+    #[account(mut)]
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEAT_REASSIGNMENT_SEED,
+            event_config.key().as_ref(),
+            &seat_reassignment.ticket_id.to_le_bytes(),
+        ],
+        bump = seat_reassignment.bump,
+        close = event_owner,
+    )]
+    pub seat_reassignment: Account<'info, SeatReassignment>,
+}
+
+/// Accept the organizer's seat offer for a ticket.
+///
+/// The ticket is nullified and reissued to `new_owner_commitment` with the
+/// offered custom fields; everything else carries over, including the
+/// name commitment, since the holder doesn't change. Offers with
+/// compensation also create a refund credit for the holder.
+///
+/// # Operations
+/// 1. Verify holder owns the ticket via commitment
+/// 2. CREATE nullifier + CREATE reissued ticket (+ CREATE credit) + CLOSE ticket
+/// 3. Close the offer, returning its rent to the organizer
+pub fn accept_seat_reassignment<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptSeatReassignment<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    holder_secret: [u8; 32],
+    new_owner_commitment: [u8; 32],
+    new_ticket_address_seed: [u8; 32],
+    credit_address_seed: [u8; 32],
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let holder = &ctx.accounts.holder;
+    let seat_reassignment = &ctx.accounts.seat_reassignment;
+    let ticket_id = seat_reassignment.ticket_id;
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+
+    // commitment = SHA256(owner_pubkey || secret)
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(holder.key.as_ref());
+    commitment_input.extend_from_slice(&holder_secret);
+    let owner_commitment = hash(&commitment_input).to_bytes();
+
+    // --- Light Protocol CPI Setup ---
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Create nullifier ---
+    let nullifier_seed = hash(&holder_secret);
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_reissue_sequence()?;
+
+    // --- Step 2: Reissue the ticket on the new seat ---
+    let (new_ticket_address, new_ticket_seed) = derive_address(
+        &[TICKET_SEED, new_ticket_address_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut new_ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(new_ticket_address),
+        output_state_tree_index,
+    );
+    new_ticket_account.event_config = event_config.key();
+    new_ticket_account.ticket_id = ticket_id;
+    new_ticket_account.owner_commitment = new_owner_commitment;
+    new_ticket_account.original_price = original_price;
+    new_ticket_account.usd_micros_per_sol = usd_micros_per_sol;
+    new_ticket_account.name_commitment = name_commitment;
+    new_ticket_account.link_id = link_id;
+    new_ticket_account.custom_fields = seat_reassignment.custom_fields.clone();
+    new_ticket_account.addons = addons;
+    new_ticket_account.provenance = PrivateTicket::next_provenance(&provenance, &nullifier_address);
+
+    // --- Step 3: Close the old ticket (proves the ticket id and data) ---
+    let ticket_account = LightAccount::<PrivateTicket>::new_close(
+        &crate::ID,
+        &ticket_account_meta,
+        PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id,
+            owner_commitment,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            ownership: OwnershipMode::Bound,
+        },
+    )?;
+
+    // --- Execute CPI ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    // New addresses: nullifier (index 0), ticket (index 1), credit (index 2)
+    let mut new_addresses = vec![
+        address_tree_info.into_new_address_params_assigned_packed(nullifier_address_seed, Some(0)),
+        address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(1)),
+    ];
+    let mut cpi = LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(new_ticket_account)?;
+
+    let compensation = seat_reassignment.compensation;
+    let expires_at = Clock::get()?
+        .unix_timestamp
+        .saturating_add(event_config.credit_validity_seconds as i64);
+    if compensation > 0 {
+        let (credit_address, credit_seed) = derive_address(
+            &[CREDIT_SEED, credit_address_seed.as_ref()],
+            &address_tree_pubkey,
+            &crate::ID,
+        );
+        let mut credit_account = LightAccount::<RefundCredit>::new_init(
+            &crate::ID,
+            Some(credit_address),
+            output_state_tree_index,
+        );
+        credit_account.organizer = event_config.authority;
+        credit_account.owner = holder.key();
+        credit_account.amount = compensation;
+        credit_account.expires_at = expires_at;
+        cpi = cpi.with_light_account(credit_account)?;
+        new_addresses
+            .push(address_tree_info.into_new_address_params_assigned_packed(credit_seed, Some(2)));
+    }
+
+    cpi.with_light_account(ticket_account)?
+        .with_new_addresses(&new_addresses)
+        .invoke(light_cpi_accounts)?;
+
+    if compensation > 0 {
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::CreditIssued, compensation)?;
+
+        emit!(RefundCreditIssued {
+            event_config: event_config.key(),
+            organizer: event_config.authority,
+            owner: holder.key(),
+            amount: compensation,
+            expires_at,
+            memo_hash: None,
+        });
+    }
+
+    emit!(SeatReassignmentResolved {
+        event_config: event_config.key(),
+        ticket_id,
+        accepted: true,
+    });
+
+    msg!("✅ Ticket {} moved to its new seat", ticket_id);
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, SEAT_REASSIGNMENT_SEED};
+use crate::errors::EncoreError;
+use crate::events::SeatReassignmentProposed;
+use crate::state::{fields_match_schema, EventConfig, FieldValue, SeatReassignment};
+
+#[derive(Accounts)]
+#[instruction(ticket_id: u32)]
+pub struct ProposeSeatReassignment<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Re-proposing before the holder accepts replaces the offer
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + SeatReassignment::INIT_SPACE,
+        seeds = [
+            SEAT_REASSIGNMENT_SEED,
+            event_config.key().as_ref(),
+            &ticket_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub seat_reassignment: Account<'info, SeatReassignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Offer a ticket holder a new seat (event authority only).
+///
+/// `custom_fields` replaces the ticket's values in full and must fit the
+/// event's schema. A non-zero `compensation` is paid as a refund credit on
+/// acceptance, so the event must have credits enabled.
+pub fn propose_seat_reassignment(
+    ctx: Context<ProposeSeatReassignment>,
+    ticket_id: u32,
+    custom_fields: Vec<FieldValue>,
+    compensation: u64,
+) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    require!(
+        ticket_id > 0 && ticket_id <= event_config.tickets_minted,
+        EncoreError::InvalidSeatReassignment
+    );
+    require!(
+        !custom_fields.is_empty()
+            && fields_match_schema(&event_config.custom_fields, &custom_fields),
+        EncoreError::InvalidCustomFields
+    );
+    require!(
+        compensation == 0 || event_config.credit_validity_seconds > 0,
+        EncoreError::CreditsNotEnabled
+    );
+
+    let seat_reassignment = &mut ctx.accounts.seat_reassignment;
+    seat_reassignment.event_config = event_config.key();
+    seat_reassignment.ticket_id = ticket_id;
+    seat_reassignment.custom_fields = custom_fields.clone();
+    seat_reassignment.compensation = compensation;
+    seat_reassignment.proposed_at = Clock::get()?.unix_timestamp;
+    seat_reassignment.bump = ctx.bumps.seat_reassignment;
+
+    emit!(SeatReassignmentProposed {
+        event_config: event_config.key(),
+        ticket_id,
+        custom_fields,
+        compensation,
+    });
+
+    Ok(())
+}
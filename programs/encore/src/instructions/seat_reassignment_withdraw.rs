@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, SEAT_REASSIGNMENT_SEED};
+use crate::errors::EncoreError;
+use crate::events::SeatReassignmentResolved;
+use crate::state::{EventConfig, SeatReassignment};
+
+#[derive(Accounts)]
+pub struct WithdrawSeatReassignment<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEAT_REASSIGNMENT_SEED,
+            event_config.key().as_ref(),
+            &seat_reassignment.ticket_id.to_le_bytes(),
+        ],
+        bump = seat_reassignment.bump,
+        close = authority,
+    )]
+    pub seat_reassignment: Account<'info, SeatReassignment>,
+}
+
+/// Withdraw an unaccepted seat offer (event authority only).
+pub fn withdraw_seat_reassignment(ctx: Context<WithdrawSeatReassignment>) -> Result<()> {
+    emit!(SeatReassignmentResolved {
+        event_config: ctx.accounts.event_config.key(),
+        ticket_id: ctx.accounts.seat_reassignment.ticket_id,
+        accepted: false,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, SPONSOR_POOL_SEED};
+use crate::errors::EncoreError;
+use crate::events::SponsorPoolUpdated;
+use crate::state::{EventConfig, SponsorPool};
+
+#[derive(Accounts)]
+pub struct ConfigureSponsorPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Created on first configuration; later calls change the terms
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + SponsorPool::INIT_SPACE,
+        seeds = [SPONSOR_POOL_SEED, event_config.key().as_ref()],
+        bump
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set who relays sponsored mints and how much of their fees the event's
+/// sponsor pool covers (event authority only). Fund the pool with
+/// `fund_sponsor_pool`.
+pub fn configure_sponsor_pool(
+    ctx: Context<ConfigureSponsorPool>,
+    relayer: Pubkey,
+    lamports_per_mint: u64,
+    max_per_user: u64,
+) -> Result<()> {
+    require!(
+        lamports_per_mint > 0 && lamports_per_mint <= max_per_user,
+        EncoreError::InvalidSponsorPool
+    );
+
+    let sponsor_pool = &mut ctx.accounts.sponsor_pool;
+    sponsor_pool.event_config = ctx.accounts.event_config.key();
+    sponsor_pool.relayer = relayer;
+    sponsor_pool.lamports_per_mint = lamports_per_mint;
+    sponsor_pool.max_per_user = max_per_user;
+    sponsor_pool.bump = ctx.bumps.sponsor_pool;
+
+    emit!(SponsorPoolUpdated {
+        event_config: sponsor_pool.event_config,
+        relayer,
+        lamports_per_mint,
+        max_per_user,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, System};
+
+use crate::constants::SPONSOR_POOL_SEED;
+use crate::errors::EncoreError;
+use crate::state::SponsorPool;
+
+#[derive(Accounts)]
+pub struct FundSponsorPool<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SPONSOR_POOL_SEED, sponsor_pool.event_config.as_ref()],
+        bump = sponsor_pool.bump,
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add lamports to an event's sponsor pool. Anyone may fund it, usually
+/// the organizer.
+pub fn fund_sponsor_pool(ctx: Context<FundSponsorPool>, amount: u64) -> Result<()> {
+    require!(amount > 0, EncoreError::InvalidSponsorPool);
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.sponsor_pool.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let sponsor_pool = &mut ctx.accounts.sponsor_pool;
+    sponsor_pool.funded = sponsor_pool
+        .funded
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    msg!("💰 Sponsor pool funded with {} lamports", amount);
+
+    Ok(())
+}
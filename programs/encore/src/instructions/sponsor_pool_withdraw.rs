@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, SPONSOR_POOL_SEED};
+use crate::errors::EncoreError;
+use crate::state::{EventConfig, SponsorPool};
+
+#[derive(Accounts)]
+pub struct WithdrawSponsorPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [SPONSOR_POOL_SEED, event_config.key().as_ref()],
+        bump = sponsor_pool.bump,
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+}
+
+/// Take unspent lamports back out of the sponsor pool (event authority
+/// only). The pool keeps its rent.
+pub fn withdraw_sponsor_pool(ctx: Context<WithdrawSponsorPool>, amount: u64) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(8 + SponsorPool::INIT_SPACE);
+    let available = ctx
+        .accounts
+        .sponsor_pool
+        .to_account_info()
+        .lamports()
+        .saturating_sub(rent);
+    require!(amount <= available, EncoreError::SponsorPoolEmpty);
+
+    ctx.accounts.sponsor_pool.sub_lamports(amount)?;
+    ctx.accounts.authority.add_lamports(amount)?;
+
+    msg!("💰 Withdrew {} lamports from the sponsor pool", amount);
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{EVENT_SEED, TICKET_SEED};
+use crate::errors::EncoreError;
+use crate::events::TicketBound;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::state::{EventConfig, FieldValue, Nullifier, OwnershipMode, PrivateTicket};
+
+#[derive(Accounts)]
+pub struct BindTicket<'info> {
+    /// The holder's new wallet (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Bind an `Unbound` ticket to the holder's wallet.
+///
+/// The holder reveals the ticket's secret from the wallet they want to
+/// hold it with. The secret is nullified and the ticket reissued `Bound`
+/// to `new_owner_commitment`, which must use a fresh secret: the old one
+/// was exposed to whoever printed or passed on the ticket, and reusing it
+/// would leave a ticket whose nullifier already exists.
+///
+/// # Operations
+/// 1. CLOSE the unbound ticket (proves it commits to the secret alone)
+/// 2. CREATE nullifier for the old secret
+/// 3. CREATE the bound ticket
+pub fn bind_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, BindTicket<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    secret: [u8; 32],
+    new_owner_commitment: [u8; 32],
+    new_ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let holder = &ctx.accounts.holder;
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+
+    // The bound ticket must not reuse the exposed secret
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(holder.key.as_ref());
+    commitment_input.extend_from_slice(&secret);
+    require!(
+        hash(&commitment_input).to_bytes() != new_owner_commitment,
+        EncoreError::SecretRotationRequired
+    );
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Close the unbound ticket ---
+    let ticket_account = LightAccount::<PrivateTicket>::new_close(
+        &crate::ID,
+        &ticket_account_meta,
+        PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id,
+            owner_commitment: PrivateTicket::unbound_commitment(&secret),
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields: custom_fields.clone(),
+            addons,
+            ownership: OwnershipMode::Unbound,
+        },
+    )?;
+
+    // --- Step 2: Create nullifier ---
+    let nullifier_seed = hash(&secret);
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_reissue_sequence()?;
+
+    // --- Step 3: Reissue the ticket bound to the wallet ---
+    let (new_ticket_address, new_ticket_seed) = derive_address(
+        &[TICKET_SEED, new_ticket_address_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut new_ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(new_ticket_address),
+        output_state_tree_index,
+    );
+    new_ticket_account.event_config = event_config.key();
+    new_ticket_account.ticket_id = ticket_id;
+    new_ticket_account.owner_commitment = new_owner_commitment;
+    new_ticket_account.original_price = original_price;
+    new_ticket_account.usd_micros_per_sol = usd_micros_per_sol;
+    new_ticket_account.name_commitment = name_commitment;
+    new_ticket_account.link_id = link_id;
+    new_ticket_account.custom_fields = custom_fields;
+    new_ticket_account.addons = addons;
+    new_ticket_account.provenance = PrivateTicket::next_provenance(&provenance, &nullifier_address);
+    new_ticket_account.ownership = OwnershipMode::Bound;
+
+    // --- Execute CPI ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    // New addresses: nullifier (index 0), ticket (index 1)
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(new_ticket_account)?
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[
+            address_tree_info
+                .into_new_address_params_assigned_packed(nullifier_address_seed, Some(0)),
+            address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(1)),
+        ])
+        .invoke(light_cpi_accounts)?;
+
+    emit!(TicketBound {
+        event_config: event_config.key(),
+        ticket_id,
+    });
+
+    msg!("✅ Ticket {} bound to its holder's wallet", ticket_id);
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{DISCLOSURE_SEED, EVENT_SEED};
+use crate::errors::EncoreError;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::state::{EventConfig, FieldValue, OwnershipMode, PrivateTicket, TicketDisclosure};
+
+#[derive(Accounts)]
+pub struct DiscloseTicket<'info> {
+    /// Ticket holder (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Publish a watch-only disclosure of a ticket (see `TicketDisclosure`).
+///
+/// The holder generates a view key off-chain, hands it to the third party
+/// and passes `TicketDisclosure::address_seed(view_key)` as
+/// `view_key_seed`. The ticket is rewritten unchanged to prove the holder
+/// can open it, as in `verify_ticket`; its address, and so the disclosure,
+/// stays valid until it is transferred or redeemed.
+///
+/// A ticket can carry several disclosures, one per view key.
+///
+/// # Operations
+/// 1. UPDATE ticket unchanged (proves ownership)
+/// 2. CREATE disclosure at the view key's address
+pub fn disclose_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, DiscloseTicket<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    holder_secret: [u8; 32],
+    ownership: OwnershipMode,
+    view_key_seed: [u8; 32],
+) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    let holder = ctx.accounts.holder.key();
+
+    let owner_commitment = match ownership {
+        OwnershipMode::Bound => {
+            // commitment = SHA256(owner_pubkey || secret)
+            let mut commitment_input = Vec::with_capacity(64);
+            commitment_input.extend_from_slice(holder.as_ref());
+            commitment_input.extend_from_slice(&holder_secret);
+            hash(&commitment_input).to_bytes()
+        }
+        OwnershipMode::Key(key) => {
+            require_keys_eq!(key, holder, EncoreError::NotTicketOwner);
+            key.to_bytes()
+        }
+        OwnershipMode::Unbound => return err!(EncoreError::UnboundTicket),
+    };
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Rewrite the ticket unchanged ---
+    let ticket_account = LightAccount::<PrivateTicket>::new_mut(
+        &crate::ID,
+        &ticket_account_meta,
+        PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id,
+            owner_commitment,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            ownership,
+        },
+    )?;
+
+    // --- Step 2: Create the disclosure ---
+    let (disclosure_address, disclosure_address_seed) = derive_address(
+        &[DISCLOSURE_SEED, view_key_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut disclosure_account = LightAccount::<TicketDisclosure>::new_init(
+        &crate::ID,
+        Some(disclosure_address),
+        output_state_tree_index,
+    );
+    disclosure_account.holder = holder;
+    disclosure_account.event_config = event_config.key();
+    disclosure_account.ticket_address = ticket_account_meta.address;
+    disclosure_account.ticket_id = ticket_id;
+
+    // --- Execute CPI ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(disclosure_account)?
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[address_tree_info
+            .into_new_address_params_assigned_packed(disclosure_address_seed, Some(0))])
+        .invoke(light_cpi_accounts)?;
+
+    msg!("✅ Ticket {} disclosed", ticket_id);
+
+    Ok(())
+}
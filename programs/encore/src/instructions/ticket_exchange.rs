@@ -0,0 +1,336 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::system_program;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{
+    CREDIT_SEED, EVENT_SEED, LEDGER_SEED, METRICS_SEED, TICKET_EXCHANGE_SEED, TICKET_SEED,
+};
+use crate::errors::EncoreError;
+use crate::events::{RefundCreditIssued, TicketExchanged, TicketIndexed, TicketMinted};
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::invariants;
+use crate::state::{
+    EventConfig, EventLedger, FieldValue, LedgerEntryKind, Nullifier, OwnershipMode, PrivateTicket,
+    ProtocolMetrics, RefundCredit, TicketExchange,
+};
+
+#[derive(Accounts)]
+pub struct ExchangeTicket<'info> {
+    /// Ticket holder exchanging the ticket (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Owner of the event being exchanged out of (not required to sign)
+    pub from_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, from_owner.key().as_ref()],
+        bump = from_event.bump,
+    )]
+    pub from_event: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, from_event.key().as_ref()],
+        bump = from_ledger.bump,
+    )]
+    pub from_ledger: Account<'info, EventLedger>,
+
+    /// CHECK: Replacement event's owner, receives any price difference
+    #[account(mut)]
+    pub to_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, to_owner.key().as_ref()],
+        bump = to_event.bump,
+    )]
+    pub to_event: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, to_event.key().as_ref()],
+        bump = to_ledger.bump,
+    )]
+    pub to_ledger: Account<'info, EventLedger>,
+
+    #[account(
+        mut,
+        seeds = [TICKET_EXCHANGE_SEED, from_event.key().as_ref()],
+        bump = ticket_exchange.bump,
+        has_one = to_event @ EncoreError::InvalidTicketExchange,
+    )]
+    pub ticket_exchange: Account<'info, TicketExchange>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Convert a ticket for a postponed or cancelled event into a ticket for
+/// its replacement.
+///
+/// The old ticket is nullified and a ticket for the replacement event is
+/// minted to `new_owner_commitment` from the supply the exchange set
+/// aside, at the exchange's price. Custom fields, add-ons and the name
+/// commitment belong to the old event and don't carry over. A linked seat
+/// is exchanged on its own and unlinked.
+///
+/// # Price difference
+/// Against the old ticket's original price:
+/// - a dearer replacement: the holder pays the difference to the
+///   replacement event's owner
+/// - a cheaper replacement: the holder gets a refund credit for the
+///   difference from the old event's organizer, so the old event must
+///   have credits enabled
+///
+/// # Operations
+/// 1. Validate the exchange is open and has tickets left
+/// 2. Verify holder owns the ticket via commitment
+/// 3. CREATE nullifier + CREATE replacement ticket (+ CREATE credit) + CLOSE ticket
+/// 4. Settle a dearer replacement in SOL
+pub fn exchange_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExchangeTicket<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    holder_secret: [u8; 32],
+    new_owner_commitment: [u8; 32],
+    new_ticket_address_seed: [u8; 32],
+    credit_address_seed: [u8; 32],
+) -> Result<()> {
+    let holder = &ctx.accounts.holder;
+    let from_event = &mut ctx.accounts.from_event;
+    let to_event = &mut ctx.accounts.to_event;
+    let ticket_exchange = &mut ctx.accounts.ticket_exchange;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now <= ticket_exchange.deadline && ticket_exchange.remaining() > 0,
+        EncoreError::TicketExchangeClosed
+    );
+    require!(!to_event.cancelled, EncoreError::EventCancelled);
+    require!(!to_event.closed, EncoreError::EventClosed);
+
+    let price = ticket_exchange.price_lamports;
+    let amount_due = price.saturating_sub(original_price);
+    let credited = original_price.saturating_sub(price);
+    require!(
+        credited == 0 || from_event.credit_validity_seconds > 0,
+        EncoreError::CreditsNotEnabled
+    );
+
+    // commitment = SHA256(owner_pubkey || secret)
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(holder.key.as_ref());
+    commitment_input.extend_from_slice(&holder_secret);
+    let owner_commitment = hash(&commitment_input).to_bytes();
+
+    // --- Light Protocol CPI Setup ---
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Create nullifier ---
+    let nullifier_seed = hash(&holder_secret);
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = from_event.key();
+    nullifier_account.sequence = from_event.next_nullifier_sequence()?;
+
+    // --- Step 2: Mint the replacement ticket ---
+    let new_ticket_id = to_event.tickets_minted + 1;
+    let (new_ticket_address, new_ticket_seed) = derive_address(
+        &[
+            TICKET_SEED,
+            to_event
+                .ticket_address_seed(&to_event.key(), new_ticket_id, new_ticket_address_seed)
+                .as_ref(),
+        ],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut new_ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(new_ticket_address),
+        output_state_tree_index,
+    );
+    new_ticket_account.event_config = to_event.key();
+    new_ticket_account.ticket_id = new_ticket_id;
+    new_ticket_account.owner_commitment = new_owner_commitment;
+    new_ticket_account.original_price = price;
+    new_ticket_account.provenance = PrivateTicket::next_provenance(&provenance, &nullifier_address);
+
+    // --- Step 3: Close the old ticket (proves the original price) ---
+    let ticket_account = LightAccount::<PrivateTicket>::new_close(
+        &crate::ID,
+        &ticket_account_meta,
+        PrivateTicket {
+            event_config: from_event.key(),
+            ticket_id,
+            owner_commitment,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            ownership: OwnershipMode::Bound,
+        },
+    )?;
+
+    // --- Execute CPI ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    // New addresses: nullifier (index 0), ticket (index 1), credit (index 2)
+    let mut new_addresses = vec![
+        address_tree_info.into_new_address_params_assigned_packed(nullifier_address_seed, Some(0)),
+        address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(1)),
+    ];
+    let mut cpi = LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(new_ticket_account)?;
+
+    let expires_at = now.saturating_add(from_event.credit_validity_seconds as i64);
+    if credited > 0 {
+        let (credit_address, credit_seed) = derive_address(
+            &[CREDIT_SEED, credit_address_seed.as_ref()],
+            &address_tree_pubkey,
+            &crate::ID,
+        );
+        let mut credit_account = LightAccount::<RefundCredit>::new_init(
+            &crate::ID,
+            Some(credit_address),
+            output_state_tree_index,
+        );
+        credit_account.organizer = from_event.authority;
+        credit_account.owner = holder.key();
+        credit_account.amount = credited;
+        credit_account.expires_at = expires_at;
+        cpi = cpi.with_light_account(credit_account)?;
+        new_addresses
+            .push(address_tree_info.into_new_address_params_assigned_packed(credit_seed, Some(2)));
+    }
+
+    cpi.with_light_account(ticket_account)?
+        .with_new_addresses(&new_addresses)
+        .invoke(light_cpi_accounts)?;
+
+    // --- Step 4: Settle the price difference ---
+    if amount_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: holder.to_account_info(),
+                    to: ctx.accounts.to_owner.to_account_info(),
+                },
+            ),
+            amount_due,
+        )?;
+        ctx.accounts
+            .to_ledger
+            .record(LedgerEntryKind::MintRevenue, amount_due)?;
+    }
+    if credited > 0 {
+        ctx.accounts
+            .from_ledger
+            .record(LedgerEntryKind::CreditIssued, credited)?;
+
+        emit!(RefundCreditIssued {
+            event_config: from_event.key(),
+            organizer: from_event.authority,
+            owner: holder.key(),
+            amount: credited,
+            expires_at,
+            memo_hash: None,
+        });
+    }
+
+    from_event.tickets_refunded = from_event
+        .tickets_refunded
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    to_event.tickets_minted = new_ticket_id;
+    ctx.accounts.metrics.record_mints(1)?;
+    to_event.allocated_supply -= 1;
+    ticket_exchange.exchanged += 1;
+
+    emit!(TicketIndexed {
+        event_config: to_event.key(),
+        ticket_id: new_ticket_id,
+        address: new_ticket_address,
+    });
+
+    let policy = to_event.emission_policy;
+    let public = policy.includes_commitments();
+    emit!(TicketMinted {
+        event_config: to_event.key(),
+        tickets_minted: to_event.tickets_minted,
+        ticket_id: public.then_some(new_ticket_id),
+        owner_commitment: public.then_some(new_owner_commitment),
+        purchase_price: policy.includes_prices().then_some(price),
+        purchase_price_usd_micros: None,
+        fees: None,
+        reference: None,
+    });
+
+    emit!(TicketExchanged {
+        from_event: from_event.key(),
+        to_event: to_event.key(),
+        amount_paid: amount_due,
+        amount_credited: credited,
+    });
+
+    msg!(
+        "✅ Ticket {} exchanged for ticket {} of {}",
+        ticket_id,
+        new_ticket_id,
+        to_event.key()
+    );
+
+    invariants::check_event(&ctx.accounts.from_event)?;
+    invariants::check_event(&ctx.accounts.to_event)?;
+
+    Ok(())
+}
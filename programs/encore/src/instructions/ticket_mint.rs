@@ -1,29 +1,64 @@
 #![allow(unexpected_cfgs)]
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::system_program;
 use light_sdk::{
     account::LightAccount,
-    address::v2::derive_address,
+    address::AddressSeed,
     cpi::{v2::CpiAccounts, CpiSigner, InvokeLightSystemProgram, LightCpiInstruction},
     derive_light_cpi_signer,
-    instruction::{PackedAddressTreeInfo, ValidityProof},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
 };
 
+use crate::address_tree::{check_address_tree, derive_address};
 use crate::constants::*;
 use crate::errors::EncoreError;
-use crate::events::TicketMinted;
-use crate::state::{EventConfig, PrivateTicket};
+use crate::events::{
+    GasSponsored, ProtocolFeeCollected, RefundCreditRedeemed, TicketIndexed, TicketMinted,
+};
+use crate::invariants;
+use crate::policy_hook::check_policy;
+use crate::proof_window::{check_proof_window, ProofWindow};
+use crate::state::{
+    fields_match_schema, EventConfig, EventLedger, FanAttestation, FeeBreakdown, FeeExemption,
+    FeeSource, FieldValue, IdentityCounter, InsuranceCoverage, LedgerEntryKind, OwnershipMode,
+    PolicyAction, PriceFeed, PrivateTicket, ProtocolConfig, ProtocolMetrics, ProtocolTreasury,
+    PurchaseReceipt, RefundCredit, SponsorPool,
+};
 
 pub const LIGHT_CPI_SIGNER: CpiSigner =
     derive_light_cpi_signer!("BjapcaBemidgideMDLWX4wujtnEETZknmNyv28uXVB7V");
 
+/// Positions of the buyer and event among `MintTicket`'s accounts, for
+/// instructions that introspect a mint
+pub(crate) const MINT_BUYER_INDEX: usize = 0;
+pub(crate) const MINT_EVENT_INDEX: usize = 2;
+
+/// Companion seat minted alongside the primary ticket as a linked pair.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CompanionSeat {
+    /// Companion ticket's owner commitment
+    pub owner_commitment: [u8; 32],
+
+    /// Random seed for the companion ticket address
+    pub ticket_address_seed: [u8; 32],
+}
+
 #[derive(Accounts)]
+#[instruction(
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    owner_commitment: [u8; 32],
+)]
 pub struct MintTicket<'info> {
     /// The buyer who is purchasing the ticket
     #[account(mut)]
     pub buyer: Signer<'info>,
 
-    /// CHECK: Event owner (not required to sign)
+    /// CHECK: Event owner (not required to sign), receives the purchase payment
+    #[account(mut)]
     pub event_owner: UncheckedAccount<'info>,
 
     #[account(
@@ -32,13 +67,349 @@ pub struct MintTicket<'info> {
         bump = event_config.bump,
     )]
     pub event_config: Account<'info, EventConfig>,
+
+    /// CHECK: Blocklist entry for the buyer; must not exist
+    #[account(
+        seeds = [BLOCKED_BUYER_SEED, event_config.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        constraint = blocked_buyer.data_is_empty() @ EncoreError::BuyerBlocked,
+    )]
+    pub blocked_buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Buyer's credential attestation, verified against
+    /// `event_config.required_credential` when the event requires one
+    pub attestation: Option<UncheckedAccount<'info>>,
+
+    /// Insurance cover for this ticket; pass to buy insurance at mint
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + InsuranceCoverage::INIT_SPACE,
+        seeds = [INSURANCE_SEED, event_config.key().as_ref(), &owner_commitment],
+        bump
+    )]
+    pub insurance_coverage: Option<Account<'info, InsuranceCoverage>>,
+
+    /// Insurance pool receiving the premium (required with `insurance_coverage`)
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_SEED, event_config.key().as_ref()],
+        bump
+    )]
+    pub insurance_pool: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    /// SOL/USD price feed; pass to record a fiat-valued `PurchaseReceipt`
+    #[account(seeds = [PRICE_FEED_SEED], bump = price_feed.bump)]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol treasury receiving the mint fee
+    #[account(mut, seeds = [TREASURY_SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
+    /// Fee exemption for this event or its organizer, if one was granted
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    /// Event authority co-signing a box-office sale; draws from the hold-back
+    #[account(address = event_config.authority @ EncoreError::Unauthorized)]
+    pub box_office: Option<Signer<'info>>,
+
+    /// CHECK: Must match `compliance_policy.hook_program` when one is set
+    pub policy_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Passed through to the policy hook, which validates it
+    pub policy_attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Solana Pay reference; only its key is used, echoed in the event
+    pub reference: Option<UncheckedAccount<'info>>,
+
+    /// Buyer's verified-fan attestation, admitting them to the fan lane
+    #[account(
+        seeds = [FAN_ATTESTATION_SEED, event_config.key().as_ref(), buyer.key().as_ref()],
+        bump = fan_attestation.bump,
+    )]
+    pub fan_attestation: Option<Account<'info, FanAttestation>>,
+
+    /// Relayer paying the transaction and Light fees instead of the buyer
+    #[account(mut)]
+    pub relayer: Option<Signer<'info>>,
+
+    /// Event's sponsor pool, reimbursing the relayer
+    #[account(
+        mut,
+        seeds = [SPONSOR_POOL_SEED, event_config.key().as_ref()],
+        bump = sponsor_pool.bump,
+    )]
+    pub sponsor_pool: Option<Account<'info, SponsorPool>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Checks a primary sale of `seats` tickets to `buyer` must pass before
+/// anything is created: event open, price and custom fields valid, supply
+/// available, presale allowlist, required credential and compliance hook.
+///
+/// Returns whether the sale draws from the box-office hold-back, which it
+/// does while `box_office` co-signs and the hold-back lasts. A `verified_fan`
+/// may also take the open fan-lane hold.
+pub(crate) fn check_primary_sale<'info>(
+    event_config: &Account<'info, EventConfig>,
+    buyer: &AccountInfo<'info>,
+    now: i64,
+    seats: u32,
+    purchase_price: u64,
+    custom_fields: &[FieldValue],
+    box_office: bool,
+    verified_fan: bool,
+    allowlist_proof: Option<&[[u8; 32]]>,
+    attestation: Option<&AccountInfo<'info>>,
+    policy_hook_program: Option<&AccountInfo<'info>>,
+    policy_attestation: Option<&AccountInfo<'info>>,
+) -> Result<bool> {
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(!event_config.closed, EncoreError::EventClosed);
+    require!(purchase_price > 0, EncoreError::InvalidPurchasePrice);
+    require!(
+        event_config.settles_in(None),
+        EncoreError::SettlementMintMismatch
+    );
+    require!(
+        fields_match_schema(&event_config.custom_fields, custom_fields),
+        EncoreError::InvalidCustomFields
+    );
+
+    let from_reserve = box_office && event_config.can_mint_from_reserve(seats);
+    if !from_reserve {
+        let available = if verified_fan {
+            event_config.can_mint_as_fan(seats, now)
+        } else {
+            event_config.can_mint(seats, now)
+        };
+        require!(available, EncoreError::MaxSupplyReached);
+    }
+
+    if let Some(presale) = event_config.presale {
+        if !from_reserve && presale.is_active(now) {
+            let proof = allowlist_proof.ok_or(EncoreError::NotOnPresaleAllowlist)?;
+            require!(
+                proof.len() <= MAX_SNAPSHOT_PROOF_LEN && presale.allows(buyer.key, proof),
+                EncoreError::NotOnPresaleAllowlist
+            );
+        }
+    }
+
+    if let Some(credential) = event_config.required_credential {
+        let attestation = attestation.ok_or(EncoreError::CredentialRequired)?;
+        require!(
+            credential.is_satisfied_by(attestation, buyer.key),
+            EncoreError::CredentialRequired
+        );
+    }
+
+    check_policy(
+        event_config,
+        buyer,
+        policy_hook_program,
+        policy_attestation,
+        PolicyAction::Mint,
+    )?;
+
+    Ok(from_reserve)
+}
+
+/// Load or create `buyer`'s identity counter for the current epoch and
+/// record a mint of `seats` on it, enforcing the per-person limit, the
+/// rolling rate limit and the per-slot cap.
+///
+/// Returns the counter and, when it's new, its address seed.
+pub(crate) fn record_identity_mint(
+    event_config: &Account<EventConfig>,
+    buyer: &Pubkey,
+    clock: &Clock,
+    seats: u32,
+    address_tree_pubkey: &Pubkey,
+    output_state_tree_index: u8,
+    identity_account_meta: Option<CompressedAccountMeta>,
+    current_identity: Option<IdentityCounter>,
+) -> Result<(LightAccount<IdentityCounter>, Option<AddressSeed>)> {
+    // Bucketed events key counters by epoch as well; the first mint of an
+    // epoch creates a fresh counter instead of proving and rewriting one
+    let event_key = event_config.key();
+    let epoch = event_config.identity_epoch(clock.unix_timestamp);
+    let epoch_bytes = epoch.to_le_bytes();
+    let mut identity_seeds: Vec<&[u8]> =
+        vec![IDENTITY_COUNTER_SEED, event_key.as_ref(), buyer.as_ref()];
+    if event_config.identity_epoch_seconds > 0 {
+        identity_seeds.push(&epoch_bytes);
+    }
+    let (identity_address, identity_seed) =
+        derive_address(&identity_seeds, address_tree_pubkey, &crate::ID);
+
+    let is_new_identity = identity_account_meta.is_none();
+    let mut identity_account = match (identity_account_meta, current_identity) {
+        (Some(meta), Some(current)) => {
+            require!(
+                meta.address == identity_address,
+                EncoreError::InvalidIdentityCounter
+            );
+            LightAccount::<IdentityCounter>::new_mut(&crate::ID, &meta, current)?
+        }
+        (None, None) => {
+            let mut account = LightAccount::<IdentityCounter>::new_init(
+                &crate::ID,
+                Some(identity_address),
+                output_state_tree_index,
+            );
+            account.event = event_config.key();
+            account.authority = *buyer;
+            account.epoch = epoch;
+            account
+        }
+        _ => return err!(EncoreError::InvalidIdentityCounter),
+    };
+
+    // Every seat counts, so a companion can't lift the buyer over the limit
+    require!(
+        identity_account.within_limit(seats, event_config.max_tickets_per_person),
+        EncoreError::MaxTicketsPerPersonReached
+    );
+
+    let now = clock.unix_timestamp;
+    let window = event_config.mint_rate_window_seconds;
+    if event_config.mint_rate_limit > 0 && window > 0 {
+        require!(
+            identity_account.mints_in_window(now, window) < event_config.mint_rate_limit,
+            EncoreError::MintRateLimited
+        );
+    }
+
+    // Per-slot cap, independent of the lifetime per-person limit. The tally
+    // lives on the counter rather than in the instruction list, so mints
+    // reached through CPI count too, and every seat counts like above
+    require!(
+        identity_account.within_slot_cap(clock.slot, seats, event_config.max_mints_per_slot),
+        EncoreError::TooManyMintsInSlot
+    );
+    identity_account.record_mint(now, window, clock.slot, seats);
+
+    Ok((identity_account, is_new_identity.then_some(identity_seed)))
+}
+
+/// Protocol fee on `amount_due` at mint, after any fee exemption.
+pub(crate) fn mint_protocol_fee(
+    amount_due: u64,
+    event_key: &Pubkey,
+    event_config: &EventConfig,
+    protocol_config: &ProtocolConfig,
+    fee_exemption: Option<&FeeExemption>,
+) -> Result<u64> {
+    let protocol_fee = protocol_config.mint_fee(amount_due);
+    match fee_exemption {
+        Some(exemption) => {
+            require!(
+                exemption.applies_to(event_key, &event_config.authority),
+                EncoreError::InvalidFeeExemption
+            );
+            Ok(exemption.apply(protocol_fee))
+        }
+        None => Ok(protocol_fee),
+    }
 }
 
 /// Mint a private ticket to a recipient.
 ///
 /// Commitment model: CREATE ticket with owner_commitment.
 /// owner_commitment = hash(owner_pubkey || secret)
-/// No spam prevention (max_tickets_per_person not enforced).
+///
+/// # Spam prevention
+/// The buyer's `IdentityCounter` is created on first mint and updated on
+/// every later mint (pass its meta + current data). It enforces
+/// `max_tickets_per_person`, counting a companion seat as a ticket, and the
+/// optional rolling mint rate limit.
+///
+/// For very large events `identity_epoch_seconds` buckets counters by
+/// epoch: each buyer gets a new counter address per epoch, so a buyer's
+/// first mint in an epoch only adds a new address to the proof already
+/// covering the ticket's, instead of an inclusion proof for an old counter
+/// that every mint keeps rewriting. Limits then apply per epoch.
+///
+/// # Payment
+/// The buyer pays `purchase_price`, plus the price of each add-on selected
+/// in `addons`, to the event owner. An optional `RefundCredit` from the same
+/// organizer is applied first; it is reduced or closed in the same CPI. The
+/// protocol mint fee is taken out of the amount paid and sent to the treasury.
+///
+/// # Ticket address
+/// Under `TicketAddressMode::Deterministic` the ticket (and companion)
+/// addresses come from the event and ticket id, and `ticket_address_seed`
+/// is ignored; otherwise the client's random seed is used.
+///
+/// # Enumeration
+/// Every issued ticket emits `TicketIndexed` (ticket id -> compressed
+/// address), regardless of the emission policy: the ticket's compressed
+/// account already carries both. Indexers enumerate an event's tickets by
+/// collecting these instead of replaying transactions.
+///
+/// # Box office
+/// Public mints can't touch the event's `reserved_supply`. When the event
+/// authority co-signs as `box_office`, the mint draws from that hold-back
+/// while it lasts, so the venue keeps day-of-show inventory.
+///
+/// # Proof of work
+/// When `pow_difficulty` is set, SHA256(buyer || pow_slot || pow_nonce)
+/// must have that many leading zero bits and `pow_slot` must be recent.
+///
+/// # Named tickets
+/// `name_commitment` personalizes the ticket at mint; it can also be set
+/// later with `personalize_ticket`.
+///
+/// # Custom fields
+/// `custom_fields` fills the event's custom-field schema (door time, parking
+/// pass, ...) in schema order, or is left empty. A companion seat gets the
+/// same values.
+///
+/// # Companion seats
+/// Passing `companion` mints a linked pair (e.g. wheelchair + companion).
+/// The companion seat takes the next ticket id, is issued at no charge, and
+/// shares the primary's address as `link_id`; the pair must transfer and
+/// redeem together.
+///
+/// # Compliance
+/// If the event's compliance policy names a hook program, it is asked to
+/// allow the mint for the buyer (see `policy_hook`).
+///
+/// # Verified fans
+/// Passing the buyer's `fan_attestation` lets the mint draw from the share
+/// of the current tranche the event's fan lane holds back (see
+/// `set_fan_lane`); everyone else is limited to the rest.
+///
+/// # Fee sponsorship
+/// A `relayer` may pay the transaction and Light fees in the buyer's
+/// place. With the event's `sponsor_pool` the pool reimburses it a fixed
+/// amount per mint, counted against the buyer's sponsorship limit on their
+/// `IdentityCounter`. The ticket price is still paid by the buyer.
+///
+/// # Presale
+/// While the event's presale runs, general mints need `allowlist_proof`
+/// showing the buyer is in the source event's snapshot (see `set_presale`).
+///
+/// # Fiat reference
+/// When `price_feed` is passed, a compressed `PurchaseReceipt` recording the
+/// USD value of the purchase is created next to the ticket, and the ticket
+/// keeps the feed's rate for USD-adjusted resale caps.
 pub fn mint_ticket<'info>(
     ctx: Context<'_, '_, '_, 'info, MintTicket<'info>>,
     proof: ValidityProof,
@@ -47,19 +418,86 @@ pub fn mint_ticket<'info>(
     owner_commitment: [u8; 32],
     purchase_price: u64,
     ticket_address_seed: [u8; 32],
+    identity_account_meta: Option<CompressedAccountMeta>,
+    current_identity: Option<IdentityCounter>,
+    pow_slot: u64,
+    pow_nonce: u64,
+    credit_account_meta: Option<CompressedAccountMeta>,
+    current_credit: Option<RefundCredit>,
+    name_commitment: Option<[u8; 32]>,
+    companion: Option<CompanionSeat>,
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+    proof_window: Option<ProofWindow>,
+    ownership: OwnershipMode,
 ) -> Result<()> {
+    check_proof_window(proof_window)?;
+
+    let buyer_key = ctx.accounts.buyer.key();
+    let clock = Clock::get()?;
+    let seats = if companion.is_some() { 2 } else { 1 };
+    require!(
+        ownership.admits(&owner_commitment)
+            && companion
+                .as_ref()
+                .is_none_or(|seat| ownership.admits(&seat.owner_commitment)),
+        EncoreError::InvalidOwnershipKey
+    );
+    let from_reserve = check_primary_sale(
+        &ctx.accounts.event_config,
+        ctx.accounts.buyer.as_ref(),
+        clock.unix_timestamp,
+        seats,
+        purchase_price,
+        &custom_fields,
+        ctx.accounts.box_office.is_some(),
+        ctx.accounts.fan_attestation.is_some(),
+        allowlist_proof.as_deref(),
+        ctx.accounts
+            .attestation
+            .as_ref()
+            .map(|attestation| attestation.as_ref()),
+        ctx.accounts
+            .policy_hook_program
+            .as_ref()
+            .map(|hook| hook.as_ref()),
+        ctx.accounts
+            .policy_attestation
+            .as_ref()
+            .map(|attestation| attestation.as_ref()),
+    )?;
+
     let event_config = &mut ctx.accounts.event_config;
+    if from_reserve {
+        event_config.reserved_minted += seats;
+    } else if ctx.accounts.fan_attestation.is_some() {
+        event_config.record_fan_mint(seats, clock.unix_timestamp);
+    }
 
-    require!(purchase_price > 0, EncoreError::InvalidPurchasePrice);
-    require!(event_config.can_mint(1), EncoreError::MaxSupplyReached);
+    if event_config.pow_difficulty > 0 {
+        require!(
+            pow_slot <= clock.slot && clock.slot - pow_slot <= POW_MAX_SLOT_AGE,
+            EncoreError::ProofOfWorkExpired
+        );
+
+        let mut pow_input = Vec::with_capacity(48);
+        pow_input.extend_from_slice(buyer_key.as_ref());
+        pow_input.extend_from_slice(&pow_slot.to_le_bytes());
+        pow_input.extend_from_slice(&pow_nonce.to_le_bytes());
+        require!(
+            event_config.meets_pow_difficulty(&hash(&pow_input).to_bytes()),
+            EncoreError::ProofOfWorkInvalid
+        );
+    }
 
     let ticket_id = event_config.tickets_minted + 1;
 
-    let light_cpi_accounts = CpiAccounts::new(
-        ctx.accounts.buyer.as_ref(),
-        ctx.remaining_accounts,
-        LIGHT_CPI_SIGNER,
-    );
+    let fee_payer = match ctx.accounts.relayer.as_ref() {
+        Some(relayer) => relayer.as_ref(),
+        None => ctx.accounts.buyer.as_ref(),
+    };
+    let light_cpi_accounts = CpiAccounts::new(fee_payer, ctx.remaining_accounts, LIGHT_CPI_SIGNER);
 
     msg!("Starting mint ticket...");
 
@@ -69,18 +507,25 @@ pub fn mint_ticket<'info>(
 
     msg!("Address tree: {:?}", address_tree_pubkey);
 
-    // Validate we're using V2 address tree for proper compression (skip in test mode)
-    #[cfg(not(feature = "test-mode"))]
-    if address_tree_pubkey.to_bytes() != light_sdk_types::ADDRESS_TREE_V2 {
-        msg!("Invalid address tree: must use Address Tree V2");
-        return Err(ProgramError::InvalidAccountData.into());
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Rate the ticket was bought at, for USD-adjusted resale caps ---
+    let price_feed = ctx.accounts.price_feed.as_ref();
+    if let Some(price_feed) = price_feed {
+        require!(
+            price_feed.is_fresh(clock.unix_timestamp, MAX_PRICE_AGE_SECONDS),
+            EncoreError::PriceFeedStale
+        );
     }
+    let usd_micros_per_sol = price_feed.map(|price_feed| price_feed.usd_micros_per_sol);
 
     // --- Private Ticket Logic ---
     let (ticket_address, ticket_seed) = derive_address(
         &[
             TICKET_SEED,
-            ticket_address_seed.as_ref(), // Random seed from client
+            event_config
+                .ticket_address_seed(&event_config.key(), ticket_id, ticket_address_seed)
+                .as_ref(),
         ],
         &address_tree_pubkey,
         &crate::ID,
@@ -95,25 +540,369 @@ pub fn mint_ticket<'info>(
     ticket_account.ticket_id = ticket_id;
     ticket_account.owner_commitment = owner_commitment;
     ticket_account.original_price = purchase_price;
+    ticket_account.usd_micros_per_sol = usd_micros_per_sol;
+    ticket_account.name_commitment = name_commitment;
+    ticket_account.link_id = companion.as_ref().map(|_| ticket_address);
+    ticket_account.custom_fields = custom_fields.clone();
+    ticket_account.addons = addons;
+    ticket_account.ownership = ownership;
+
+    let companion_account = match companion.as_ref() {
+        Some(seat) => {
+            let (address, seed) = derive_address(
+                &[
+                    TICKET_SEED,
+                    event_config
+                        .ticket_address_seed(
+                            &event_config.key(),
+                            ticket_id + 1,
+                            seat.ticket_address_seed,
+                        )
+                        .as_ref(),
+                ],
+                &address_tree_pubkey,
+                &crate::ID,
+            );
+            let mut account = LightAccount::<PrivateTicket>::new_init(
+                &crate::ID,
+                Some(address),
+                output_state_tree_index,
+            );
+            account.event_config = event_config.key();
+            account.ticket_id = ticket_id + 1;
+            account.owner_commitment = seat.owner_commitment;
+            account.original_price = 0; // Issued free with the primary
+            account.usd_micros_per_sol = usd_micros_per_sol;
+            account.name_commitment = None;
+            account.link_id = Some(ticket_address);
+            account.custom_fields = custom_fields;
+            account.ownership = ownership;
+            Some((account, seed, address))
+        }
+        None => None,
+    };
+
+    // --- Identity Counter Logic ---
+    let now = clock.unix_timestamp;
+    let (mut identity_account, new_identity_seed) = record_identity_mint(
+        event_config,
+        &buyer_key,
+        &clock,
+        seats,
+        &address_tree_pubkey,
+        output_state_tree_index,
+        identity_account_meta,
+        current_identity,
+    )?;
+
+    // --- Optional fee sponsorship, limited per buyer ---
+    let sponsored = match ctx.accounts.sponsor_pool.as_ref() {
+        Some(sponsor_pool) => {
+            let relayer = ctx
+                .accounts
+                .relayer
+                .as_ref()
+                .ok_or(EncoreError::InvalidSponsorPool)?;
+            require_keys_eq!(
+                relayer.key(),
+                sponsor_pool.relayer,
+                EncoreError::InvalidSponsorPool
+            );
+            let total = identity_account
+                .sponsored_lamports
+                .checked_add(sponsor_pool.lamports_per_mint)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            require!(
+                total <= sponsor_pool.max_per_user,
+                EncoreError::SponsorshipLimitReached
+            );
+            identity_account.sponsored_lamports = total;
+            sponsor_pool.lamports_per_mint
+        }
+        None => 0,
+    };
+
+    // --- Optional purchase receipt ---
+    let (receipt_address, receipt_seed) = derive_address(
+        &[RECEIPT_SEED, ticket_address.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let receipt_account = match price_feed {
+        Some(price_feed) => {
+            let mut account = LightAccount::<PurchaseReceipt>::new_init(
+                &crate::ID,
+                Some(receipt_address),
+                output_state_tree_index,
+            );
+            account.event_config = event_config.key();
+            account.ticket_id = ticket_id;
+            account.price_lamports = purchase_price;
+            account.price_usd_micros = price_feed.lamports_to_usd_micros(purchase_price);
+            account.usd_micros_per_sol = price_feed.usd_micros_per_sol;
+            account.minted_at = now;
+            Some(account)
+        }
+        None => None,
+    };
+    let purchase_price_usd_micros = receipt_account.as_ref().map(|r| r.price_usd_micros);
+
+    // --- Add-ons are paid together with the ticket ---
+    let addons_price = event_config.sell_addons(addons)?;
+
+    // --- Optional refund credit ---
+    let mut amount_due = purchase_price
+        .checked_add(addons_price)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let mut credit_applied = 0;
+    let mut credit_remaining = 0;
+    let credit_account = match (credit_account_meta, current_credit) {
+        (Some(meta), Some(credit)) => {
+            require_keys_eq!(
+                credit.organizer,
+                event_config.authority,
+                EncoreError::InvalidCredit
+            );
+            require_keys_eq!(credit.owner, buyer_key, EncoreError::InvalidCredit);
+            require!(now <= credit.expires_at, EncoreError::CreditExpired);
+
+            credit_applied = credit.amount.min(amount_due);
+            credit_remaining = credit.amount - credit_applied;
+            amount_due -= credit_applied;
+
+            if credit_remaining == 0 {
+                Some(LightAccount::<RefundCredit>::new_close(
+                    &crate::ID,
+                    &meta,
+                    credit,
+                )?)
+            } else {
+                let mut account = LightAccount::<RefundCredit>::new_mut(&crate::ID, &meta, credit)?;
+                account.amount = credit_remaining;
+                Some(account)
+            }
+        }
+        (None, None) => None,
+        _ => return err!(EncoreError::InvalidCredit),
+    };
 
     // --- Execute CPI ---
     use light_sdk::cpi::v2::LightSystemProgramCpi;
 
-    let ticket_params =
-        address_tree_info.into_new_address_params_assigned_packed(ticket_seed, Some(0));
+    // Ticket is output 0, identity counter is output 1, then the receipt
+    // and companion ticket when present
+    let mut next_output = 2;
+    let mut new_addresses =
+        vec![address_tree_info.into_new_address_params_assigned_packed(ticket_seed, Some(0))];
+    if let Some(identity_seed) = new_identity_seed {
+        new_addresses.push(
+            address_tree_info.into_new_address_params_assigned_packed(identity_seed, Some(1)),
+        );
+    }
+    if receipt_account.is_some() {
+        new_addresses.push(
+            address_tree_info
+                .into_new_address_params_assigned_packed(receipt_seed, Some(next_output)),
+        );
+        next_output += 1;
+    }
+    if let Some((_, companion_seed, _)) = companion_account.as_ref() {
+        new_addresses.push(
+            address_tree_info
+                .into_new_address_params_assigned_packed(*companion_seed, Some(next_output)),
+        );
+    }
 
-    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+    let mut cpi = LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
         .with_light_account(ticket_account)?
-        .with_new_addresses(&[ticket_params])
+        .with_light_account(identity_account)?;
+    if let Some(receipt_account) = receipt_account {
+        cpi = cpi.with_light_account(receipt_account)?;
+    }
+    let mut companion_address = None;
+    if let Some((companion_account, _, address)) = companion_account {
+        cpi = cpi.with_light_account(companion_account)?;
+        companion_address = Some(address);
+    }
+    if let Some(credit_account) = credit_account {
+        cpi = cpi.with_light_account(credit_account)?;
+    }
+    cpi.with_new_addresses(&new_addresses)
         .invoke(light_cpi_accounts)?;
 
-    event_config.tickets_minted = ticket_id;
+    event_config.tickets_minted = ticket_id + seats - 1;
+    ctx.accounts.metrics.record_mints(seats)?;
+
+    // --- Payment to organizer and protocol fee ---
+    let protocol_fee = mint_protocol_fee(
+        amount_due,
+        &event_config.key(),
+        event_config,
+        &ctx.accounts.protocol_config,
+        ctx.accounts.fee_exemption.as_deref(),
+    )?;
+    let organizer_revenue = amount_due - protocol_fee;
+    if organizer_revenue > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.event_owner.to_account_info(),
+                },
+            ),
+            organizer_revenue,
+        )?;
+    }
+
+    if protocol_fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            protocol_fee,
+        )?;
+        ctx.accounts
+            .treasury
+            .record_fee(FeeSource::Mint, protocol_fee)?;
+        ctx.accounts.metrics.record_fee(protocol_fee)?;
+
+        emit!(ProtocolFeeCollected {
+            event_config: event_config.key(),
+            source: FeeSource::Mint,
+            amount: protocol_fee,
+        });
+    }
+
+    let ledger = &mut ctx.accounts.ledger;
+    ledger.record(LedgerEntryKind::MintRevenue, organizer_revenue)?;
+    ledger.record(LedgerEntryKind::ProtocolFee, protocol_fee)?;
+    ledger.record(LedgerEntryKind::CreditRedeemed, credit_applied)?;
 
-    // Emit event (Sanitized)
+    if credit_applied > 0 {
+        emit!(RefundCreditRedeemed {
+            event_config: event_config.key(),
+            organizer: event_config.authority,
+            owner: buyer_key,
+            amount_applied: credit_applied,
+            amount_remaining: credit_remaining,
+        });
+    }
+
+    // --- Optional insurance ---
+    if let Some(coverage) = ctx.accounts.insurance_coverage.as_mut() {
+        let policy = event_config
+            .insurance_policy
+            .ok_or(EncoreError::InsuranceNotOffered)?;
+        let insurance_pool = ctx
+            .accounts
+            .insurance_pool
+            .as_ref()
+            .ok_or(EncoreError::InsuranceNotOffered)?;
+
+        let premium = policy.premium(purchase_price);
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: insurance_pool.to_account_info(),
+                },
+            ),
+            premium,
+        )?;
+
+        coverage.event_config = event_config.key();
+        coverage.holder = buyer_key;
+        coverage.owner_commitment = owner_commitment;
+        coverage.premium_lamports = premium;
+        coverage.payout_lamports = policy.payout(purchase_price);
+        coverage.cancel_deadline = event_config
+            .event_timestamp
+            .saturating_sub(policy.cancel_cutoff_seconds);
+        coverage.created_at = now;
+        coverage.bump = ctx.bumps.insurance_coverage.unwrap_or_default();
+
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::InsurancePremium, premium)?;
+
+        msg!("🛡️ Insurance purchased: {} lamports premium", premium);
+    }
+
+    // --- Reimburse the relayer from the sponsor pool ---
+    if let (Some(sponsor_pool), Some(relayer)) = (
+        ctx.accounts.sponsor_pool.as_mut(),
+        ctx.accounts.relayer.as_ref(),
+    ) {
+        let rent = Rent::get()?.minimum_balance(8 + SponsorPool::INIT_SPACE);
+        let available = sponsor_pool
+            .to_account_info()
+            .lamports()
+            .saturating_sub(rent);
+        require!(sponsored <= available, EncoreError::SponsorPoolEmpty);
+
+        sponsor_pool.sub_lamports(sponsored)?;
+        relayer.add_lamports(sponsored)?;
+        sponsor_pool.sponsored = sponsor_pool
+            .sponsored
+            .checked_add(sponsored)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        emit!(GasSponsored {
+            event_config: event_config.key(),
+            relayer: relayer.key(),
+            buyer: buyer_key,
+            amount: sponsored,
+        });
+    }
+
+    // Index entries let wallets enumerate the event's tickets from logs
+    emit!(TicketIndexed {
+        event_config: event_config.key(),
+        ticket_id,
+        address: ticket_address,
+    });
+    if let Some(address) = companion_address {
+        emit!(TicketIndexed {
+            event_config: event_config.key(),
+            ticket_id: ticket_id + 1,
+            address,
+        });
+    }
+
+    // Emit event, sanitized to the event's emission policy
+    let policy = event_config.emission_policy;
+    let public = policy.includes_commitments();
+    let priced = policy.includes_prices();
     emit!(TicketMinted {
         event_config: event_config.key(),
-        purchase_price,
+        tickets_minted: event_config.tickets_minted,
+        ticket_id: public.then_some(ticket_id),
+        owner_commitment: public.then_some(owner_commitment),
+        purchase_price: priced.then_some(purchase_price),
+        purchase_price_usd_micros: purchase_price_usd_micros.filter(|_| priced),
+        fees: priced.then_some(FeeBreakdown {
+            gross: amount_due,
+            face_value: purchase_price,
+            royalty: 0,
+            protocol_fee,
+            organizer_fee: addons_price,
+            net_to_seller: organizer_revenue,
+        }),
+        reference: ctx
+            .accounts
+            .reference
+            .as_ref()
+            .map(|reference| reference.key()),
     });
 
+    invariants::check_event(&ctx.accounts.event_config)?;
+
     Ok(())
 }
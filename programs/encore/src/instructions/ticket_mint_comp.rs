@@ -0,0 +1,189 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{
+    ALLOCATION_SEED, EVENT_SEED, METRICS_SEED, MINT_DELEGATE_SEED, TICKET_SEED,
+};
+use crate::errors::EncoreError;
+use crate::events::{TicketIndexed, TicketMinted};
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::invariants;
+use crate::state::{Allocation, EventConfig, MintDelegate, PrivateTicket, ProtocolMetrics};
+
+#[derive(Accounts)]
+pub struct MintCompTicket<'info> {
+    /// Registered service key or allocation partner (pays fees)
+    #[account(mut)]
+    pub delegate: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Pass to mint on a delegate's quota
+    #[account(
+        mut,
+        seeds = [MINT_DELEGATE_SEED, event_config.key().as_ref(), delegate.key().as_ref()],
+        bump = mint_delegate.bump,
+    )]
+    pub mint_delegate: Option<Account<'info, MintDelegate>>,
+
+    /// Pass instead to mint from the partner's allocation
+    #[account(
+        mut,
+        seeds = [ALLOCATION_SEED, event_config.key().as_ref(), delegate.key().as_ref()],
+        bump = allocation.bump,
+    )]
+    pub allocation: Option<Account<'info, Allocation>>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+}
+
+/// Issue a free ticket to `owner_commitment` on a mint delegate's quota or
+/// from a partner allocation.
+///
+/// For comps and airdrops run from an organizer's backend; several can be
+/// batched in one transaction. No payment, identity counter or purchase
+/// checks apply.
+///
+/// # Supply
+/// Delegate comps draw from the event's hold-back while it lasts, then from
+/// public supply. Allocation mints draw from the supply carved out for the
+/// partner, until the allocation's deadline.
+///
+/// # Operations
+/// 1. Validate the event is open and the delegate or allocation has room
+/// 2. CREATE ticket at original price 0
+/// 3. Count the mint against the delegate's quota or the allocation
+pub fn mint_comp_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintCompTicket<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    owner_commitment: [u8; 32],
+    ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(!event_config.closed, EncoreError::EventClosed);
+    let now = Clock::get()?.unix_timestamp;
+
+    match (
+        ctx.accounts.mint_delegate.as_mut(),
+        ctx.accounts.allocation.as_mut(),
+    ) {
+        (Some(mint_delegate), None) => {
+            require!(
+                mint_delegate.remaining() > 0,
+                EncoreError::MintDelegateQuotaExceeded
+            );
+            if event_config.can_mint_from_reserve(1) {
+                event_config.reserved_minted += 1;
+            } else {
+                require!(event_config.can_mint(1, now), EncoreError::MaxSupplyReached);
+            }
+            mint_delegate.minted += 1;
+        }
+        (None, Some(allocation)) => {
+            require!(
+                allocation.remaining() > 0 && now <= allocation.deadline,
+                EncoreError::AllocationExhausted
+            );
+            event_config.allocated_supply -= 1;
+            allocation.minted += 1;
+        }
+        _ => return err!(EncoreError::CompSourceRequired),
+    }
+
+    let ticket_id = event_config.tickets_minted + 1;
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.delegate.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    let (ticket_address, ticket_seed) = derive_address(
+        &[
+            TICKET_SEED,
+            event_config
+                .ticket_address_seed(&event_config.key(), ticket_id, ticket_address_seed)
+                .as_ref(),
+        ],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(ticket_address),
+        output_state_tree_index,
+    );
+    ticket_account.event_config = event_config.key();
+    ticket_account.ticket_id = ticket_id;
+    ticket_account.owner_commitment = owner_commitment;
+    ticket_account.original_price = 0;
+    ticket_account.name_commitment = None;
+    ticket_account.link_id = None;
+
+    // --- Execute CPI: CREATE ticket ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    let ticket_params =
+        address_tree_info.into_new_address_params_assigned_packed(ticket_seed, Some(0));
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[ticket_params])
+        .invoke(light_cpi_accounts)?;
+
+    event_config.tickets_minted = ticket_id;
+    ctx.accounts.metrics.record_mints(1)?;
+
+    emit!(TicketIndexed {
+        event_config: event_config.key(),
+        ticket_id,
+        address: ticket_address,
+    });
+
+    let policy = event_config.emission_policy;
+    let public = policy.includes_commitments();
+    emit!(TicketMinted {
+        event_config: event_config.key(),
+        tickets_minted: event_config.tickets_minted,
+        ticket_id: public.then_some(ticket_id),
+        owner_commitment: public.then_some(owner_commitment),
+        purchase_price: policy.includes_prices().then_some(0),
+        purchase_price_usd_micros: None,
+        fees: None,
+        reference: None,
+    });
+
+    msg!("✅ Comp ticket {} minted", ticket_id);
+
+    invariants::check_event(&ctx.accounts.event_config)?;
+
+    Ok(())
+}
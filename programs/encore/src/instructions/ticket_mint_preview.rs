@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    BLOCKED_BUYER_SEED, EVENT_SEED, FAN_ATTESTATION_SEED, MAX_PRICE_AGE_SECONDS, PRICE_FEED_SEED,
+    PROTOCOL_SEED,
+};
+use crate::errors::EncoreError;
+use crate::events::MintPreviewed;
+use crate::instructions::ticket_mint::{check_primary_sale, mint_protocol_fee};
+use crate::state::{
+    EventConfig, FanAttestation, FeeBreakdown, FeeExemption, FieldValue, PriceFeed, ProtocolConfig,
+};
+
+#[derive(Accounts)]
+pub struct PreviewMint<'info> {
+    /// Prospective buyer (simulations can skip signature verification)
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Event owner, used to derive the event PDA
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// CHECK: Blocklist entry for the buyer; must not exist
+    #[account(
+        seeds = [BLOCKED_BUYER_SEED, event_config.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        constraint = blocked_buyer.data_is_empty() @ EncoreError::BuyerBlocked,
+    )]
+    pub blocked_buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Buyer's credential attestation, as for `mint_ticket`
+    pub attestation: Option<UncheckedAccount<'info>>,
+
+    /// SOL/USD price feed; pass to preview the receipt's USD value
+    #[account(seeds = [PRICE_FEED_SEED], bump = price_feed.bump)]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Fee exemption for this event or its organizer, if one was granted
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    /// Event authority co-signing a box-office sale
+    #[account(address = event_config.authority @ EncoreError::Unauthorized)]
+    pub box_office: Option<Signer<'info>>,
+
+    /// CHECK: Must match `compliance_policy.hook_program` when one is set
+    pub policy_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Passed through to the policy hook, which validates it
+    pub policy_attestation: Option<UncheckedAccount<'info>>,
+
+    /// Buyer's verified-fan attestation, admitting them to the fan lane
+    #[account(
+        seeds = [FAN_ATTESTATION_SEED, event_config.key().as_ref(), buyer.key().as_ref()],
+        bump = fan_attestation.bump,
+    )]
+    pub fan_attestation: Option<Account<'info, FanAttestation>>,
+}
+
+/// Dry run of `mint_ticket`: runs the same checks and fee math and emits
+/// `MintPreviewed` with the exact amounts, changing nothing.
+///
+/// Meant for `simulateTransaction`, so a wallet can show the buyer the
+/// total (add-ons, refund credit, insurance, protocol fee) before asking
+/// for a signature. `credit_amount` is the balance of the refund credit
+/// the buyer would apply, 0 for none.
+///
+/// # Not covered
+/// Checks that depend on compressed state or per-submission data still run
+/// only at mint: the buyer's `IdentityCounter` limits, the refund credit's
+/// owner and expiry, proof of work and the per-transaction mint cap.
+pub fn preview_mint(
+    ctx: Context<PreviewMint>,
+    purchase_price: u64,
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    with_companion: bool,
+    credit_amount: u64,
+    insured: bool,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    let now = Clock::get()?.unix_timestamp;
+    let seats = if with_companion { 2 } else { 1 };
+
+    let from_reserve = check_primary_sale(
+        event_config,
+        ctx.accounts.buyer.as_ref(),
+        now,
+        seats,
+        purchase_price,
+        &custom_fields,
+        ctx.accounts.box_office.is_some(),
+        ctx.accounts.fan_attestation.is_some(),
+        allowlist_proof.as_deref(),
+        ctx.accounts
+            .attestation
+            .as_ref()
+            .map(|attestation| attestation.as_ref()),
+        ctx.accounts
+            .policy_hook_program
+            .as_ref()
+            .map(|hook| hook.as_ref()),
+        ctx.accounts
+            .policy_attestation
+            .as_ref()
+            .map(|attestation| attestation.as_ref()),
+    )?;
+
+    let purchase_price_usd_micros = match ctx.accounts.price_feed.as_ref() {
+        Some(price_feed) => {
+            require!(
+                price_feed.is_fresh(now, MAX_PRICE_AGE_SECONDS),
+                EncoreError::PriceFeedStale
+            );
+            Some(price_feed.lamports_to_usd_micros(purchase_price))
+        }
+        None => None,
+    };
+
+    let addons_price = event_config.quote_addons(addons)?;
+    let gross = purchase_price
+        .checked_add(addons_price)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let credit_applied = credit_amount.min(gross);
+    let amount_due = gross - credit_applied;
+
+    let protocol_fee = mint_protocol_fee(
+        amount_due,
+        &event_config.key(),
+        event_config,
+        &ctx.accounts.protocol_config,
+        ctx.accounts.fee_exemption.as_deref(),
+    )?;
+
+    let insurance_premium = if insured {
+        event_config
+            .insurance_policy
+            .ok_or(EncoreError::InsuranceNotOffered)?
+            .premium(purchase_price)
+    } else {
+        0
+    };
+
+    emit!(MintPreviewed {
+        event_config: event_config.key(),
+        buyer: ctx.accounts.buyer.key(),
+        seats,
+        from_reserve,
+        fees: FeeBreakdown {
+            gross: amount_due,
+            face_value: purchase_price,
+            royalty: 0,
+            protocol_fee,
+            organizer_fee: addons_price,
+            net_to_seller: amount_due - protocol_fee,
+        },
+        credit_applied,
+        insurance_premium,
+        total_due: amount_due
+            .checked_add(insurance_premium)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+        purchase_price_usd_micros,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,206 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{EVENT_SEED, TICKET_SEED};
+use crate::errors::EncoreError;
+use crate::events::TicketPersonalized;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::secret::is_derived_secret;
+use crate::state::{EventConfig, FieldValue, Nullifier, OwnershipMode, PrivateTicket};
+
+#[derive(Accounts)]
+pub struct PersonalizeTicket<'info> {
+    /// Ticket holder (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// CHECK: Instructions sysvar, required when the event sets a secret
+    /// derivation standard
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+/// Bind an attendee-name commitment to an unnamed ticket.
+///
+/// The input is an unnamed ticket whose commitment is recomputed from the
+/// signer and secret, so only the holder can personalize it, and only once.
+/// Revealing the secret spends it: the secret is nullified and the ticket
+/// reissued, named, to `new_owner_commitment`, which must use a fresh
+/// secret.
+///
+/// # Secret derivation
+/// When the event sets `secret_derivation`, the transaction must carry the
+/// holder's ed25519 signature over the standard message, and the secret
+/// must derive from it (see `SecretDerivation`). The signature lets anyone
+/// recompute the secret, which is why it's nullified here rather than left
+/// on a live ticket. The standard secret is spent with it, so the named
+/// ticket's secret is one the holder keeps like any transferred ticket's.
+///
+/// # Operations
+/// 1. Verify holder owns the ticket via commitment (and the secret follows
+///    the event's derivation standard, if set)
+/// 2. CREATE nullifier for the old secret
+/// 3. CREATE the named ticket
+/// 4. CLOSE the unnamed ticket
+pub fn personalize_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, PersonalizeTicket<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    holder_secret: [u8; 32],
+    name_commitment: [u8; 32],
+    new_owner_commitment: [u8; 32],
+    new_ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let holder = &ctx.accounts.holder;
+
+    // commitment = SHA256(owner_pubkey || secret)
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(holder.key.as_ref());
+    commitment_input.extend_from_slice(&holder_secret);
+    let owner_commitment = hash(&commitment_input).to_bytes();
+
+    // The named ticket must not reuse the revealed secret
+    require!(
+        owner_commitment != new_owner_commitment,
+        EncoreError::SecretRotationRequired
+    );
+
+    if let Some(derivation) = event_config.secret_derivation {
+        let instructions_sysvar = ctx
+            .accounts
+            .instructions_sysvar
+            .as_ref()
+            .ok_or(EncoreError::NonStandardSecret)?;
+        require!(
+            is_derived_secret(
+                instructions_sysvar,
+                derivation,
+                holder.key,
+                ticket_id,
+                &event_config.key(),
+                &holder_secret,
+            ),
+            EncoreError::NonStandardSecret
+        );
+    }
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // The input hash only matches an unnamed ticket owned by the signer
+    let ticket = PrivateTicket {
+        event_config: event_config.key(),
+        ticket_id,
+        owner_commitment,
+        original_price,
+        usd_micros_per_sol,
+        name_commitment: None,
+        link_id,
+        provenance,
+        custom_fields,
+        addons,
+        ownership: OwnershipMode::Bound,
+    };
+
+    // --- Step 1: Create nullifier ---
+    let nullifier_seed = hash(&holder_secret);
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_reissue_sequence()?;
+
+    // --- Step 2: Reissue the ticket with the name ---
+    let (new_ticket_address, new_ticket_seed) = derive_address(
+        &[TICKET_SEED, new_ticket_address_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut new_ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(new_ticket_address),
+        output_state_tree_index,
+    );
+    *new_ticket_account = ticket.reissue(
+        &nullifier_address,
+        new_owner_commitment,
+        OwnershipMode::Bound,
+    );
+    new_ticket_account.name_commitment = Some(name_commitment);
+
+    // --- Step 3: Close the unnamed ticket ---
+    let ticket_account =
+        LightAccount::<PrivateTicket>::new_close(&crate::ID, &ticket_account_meta, ticket)?;
+
+    // --- Execute CPI ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    // New addresses: nullifier (index 0), ticket (index 1)
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(new_ticket_account)?
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[
+            address_tree_info
+                .into_new_address_params_assigned_packed(nullifier_address_seed, Some(0)),
+            address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(1)),
+        ])
+        .invoke(light_cpi_accounts)?;
+
+    emit!(TicketPersonalized {
+        event_config: event_config.key(),
+        ticket_id,
+    });
+
+    msg!("✅ Ticket {} personalized", ticket_id);
+
+    Ok(())
+}
@@ -0,0 +1,206 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{EVENT_SEED, GUARDIAN_SET_SEED, TICKET_RECOVERY_SEED, TICKET_SEED};
+use crate::errors::EncoreError;
+use crate::events::TicketRecovered;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::state::{
+    EventConfig, FieldValue, GuardianSet, Nullifier, OwnershipMode, PrivateTicket, TicketRecovery,
+};
+
+#[derive(Accounts)]
+pub struct RecoverTicket<'info> {
+    /// Anyone may execute an unlocked recovery (pays fees)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Guardian who opened the recovery, receives its rent
+    #[account(mut, address = recovery.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [GUARDIAN_SET_SEED, guardian_set.holder.as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(
+        mut,
+        seeds = [
+            TICKET_RECOVERY_SEED,
+            guardian_set.key().as_ref(),
+            recovery.ticket_commitment.as_ref(),
+        ],
+        bump = recovery.bump,
+        close = proposer,
+    )]
+    pub recovery: Account<'info, TicketRecovery>,
+}
+
+/// Reissue a guarded ticket whose secret was lost, once its recovery has
+/// unlocked.
+///
+/// The ticket is nullified under the secret hash recorded when it was
+/// guarded, so anything keyed by the lost secret (listings, pending
+/// transfers) can't complete, and reissued to the recovery's commitment.
+/// The holder doesn't change, so the name commitment and link carry over.
+/// Recovery is allowed on cancelled events so the holder can still claim
+/// refunds.
+///
+/// # Operations
+/// 1. Check the recovery still has its approvals and its veto window passed
+/// 2. CLOSE the lost ticket (proves it carries the guarded commitment)
+/// 3. CREATE nullifier for the lost secret
+/// 4. CREATE the reissued ticket
+pub fn recover_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, RecoverTicket<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    new_ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    let recovery = &ctx.accounts.recovery;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        recovery.unlocks_at.is_some_and(|t| now >= t)
+            && recovery.current_approvals(guardian_set) >= guardian_set.threshold as usize,
+        EncoreError::RecoveryLocked
+    );
+    let secret_hash = guardian_set
+        .guarded(&recovery.ticket_commitment)
+        .ok_or(EncoreError::TicketNotGuarded)?
+        .secret_hash;
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.payer.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Close the lost ticket ---
+    let ticket_account = LightAccount::<PrivateTicket>::new_close(
+        &crate::ID,
+        &ticket_account_meta,
+        PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id,
+            owner_commitment: recovery.ticket_commitment,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields: custom_fields.clone(),
+            addons,
+            ownership: OwnershipMode::Bound,
+        },
+    )?;
+
+    // --- Step 2: Create nullifier ---
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, secret_hash.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_reissue_sequence()?;
+
+    // --- Step 3: Reissue the ticket ---
+    let (new_ticket_address, new_ticket_seed) = derive_address(
+        &[TICKET_SEED, new_ticket_address_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut new_ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(new_ticket_address),
+        output_state_tree_index,
+    );
+    new_ticket_account.event_config = event_config.key();
+    new_ticket_account.ticket_id = ticket_id;
+    new_ticket_account.owner_commitment = recovery.new_owner_commitment;
+    new_ticket_account.original_price = original_price;
+    new_ticket_account.usd_micros_per_sol = usd_micros_per_sol;
+    new_ticket_account.name_commitment = name_commitment;
+    new_ticket_account.link_id = link_id;
+    new_ticket_account.custom_fields = custom_fields;
+    new_ticket_account.addons = addons;
+    new_ticket_account.provenance = PrivateTicket::next_provenance(&provenance, &nullifier_address);
+    new_ticket_account.ownership = OwnershipMode::Bound;
+
+    // --- Execute CPI ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    // New addresses: nullifier (index 0), ticket (index 1)
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(new_ticket_account)?
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[
+            address_tree_info
+                .into_new_address_params_assigned_packed(nullifier_address_seed, Some(0)),
+            address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(1)),
+        ])
+        .invoke(light_cpi_accounts)?;
+
+    // The lost secret no longer guards anything
+    let ticket_commitment = recovery.ticket_commitment;
+    guardian_set
+        .tickets
+        .retain(|t| t.ticket_commitment != ticket_commitment);
+
+    emit!(TicketRecovered {
+        event_config: event_config.key(),
+        guardian_set: guardian_set.key(),
+        ticket_id,
+    });
+
+    msg!("✅ Ticket {} recovered by its guardians", ticket_id);
+
+    Ok(())
+}
@@ -0,0 +1,491 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{ENTRANCE_STATS_SEED, EVENT_SEED, REDEMPTION_RECEIPT_SEED};
+use crate::errors::EncoreError;
+use crate::events::TicketRedeemed;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::policy_hook::check_policy;
+use crate::proof_window::{check_proof_window, ProofWindow};
+use crate::state::{
+    EntranceStats, EventConfig, FieldValue, HoldStatus, Nullifier, OwnershipMode, PolicyAction,
+    PrivateTicket, ProceedsHold, RedemptionReceipt, Scanner,
+};
+
+/// The other half of a linked pair, redeemed in the same instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CompanionRedemption {
+    pub ticket_account_meta: CompressedAccountMeta,
+    pub ticket_id: u32,
+    pub original_price: u64,
+    pub usd_micros_per_sol: Option<u64>,
+    pub name_commitment: Option<[u8; 32]>,
+    pub provenance: [u8; 32],
+    pub custom_fields: Vec<FieldValue>,
+    /// Companion ticket's secret (same holder as the primary; unused for
+    /// keyed tickets)
+    pub secret: [u8; 32],
+}
+
+#[derive(Accounts)]
+pub struct RedeemTicket<'info> {
+    /// Ticket holder checking in (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// CHECK: Holder's credential attestation, verified against
+    /// `event_config.required_credential` when the event requires one
+    pub credential_attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: ID-verification attestation for the ticket's name commitment,
+    /// verified against `event_config.id_verification`. Named tickets only.
+    pub attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: As `attestation`, for a named companion ticket
+    pub companion_attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Must match `compliance_policy.hook_program` when one is set
+    pub policy_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Passed through to the policy hook, which validates it
+    pub policy_attestation: Option<UncheckedAccount<'info>>,
+
+    /// Held resale proceeds for this ticket; pass to release them to the seller
+    #[account(
+        mut,
+        constraint = proceeds_hold.event_config == event_config.key() @ EncoreError::ProceedsHoldMismatch,
+    )]
+    pub proceeds_hold: Option<Account<'info, ProceedsHold>>,
+
+    /// Registered gate device; required when the event sets `require_scanner`
+    #[account(
+        constraint = scanner.event_config == event_config.key() @ EncoreError::ScannerNotAuthorized,
+    )]
+    pub scanner: Option<Account<'info, Scanner>>,
+
+    /// The scanner's device key, co-signing the redemption
+    pub scanner_device: Option<Signer<'info>>,
+
+    /// Per-entrance counters; required when a scanner attests the zone
+    #[account(
+        mut,
+        seeds = [ENTRANCE_STATS_SEED, event_config.key().as_ref()],
+        bump = entrance_stats.bump,
+    )]
+    pub entrance_stats: Option<Account<'info, EntranceStats>>,
+}
+
+/// A named ticket needs the event's ID verifier to have attested to its
+/// name commitment.
+fn check_name_verified(
+    event_config: &EventConfig,
+    attestation: Option<&UncheckedAccount>,
+    name_commitment: Option<[u8; 32]>,
+) -> Result<()> {
+    let Some(name) = name_commitment else {
+        return Ok(());
+    };
+    let id_verification = event_config
+        .id_verification
+        .as_ref()
+        .ok_or(EncoreError::IdentityNotVerified)?;
+    let attestation = attestation.ok_or(EncoreError::IdentityNotVerified)?;
+    require!(
+        id_verification.is_satisfied_by(
+            &attestation.to_account_info(),
+            &Pubkey::new_from_array(name)
+        ),
+        EncoreError::IdentityNotVerified
+    );
+    Ok(())
+}
+
+/// The holder must present the event's required credential, if it has one.
+pub fn check_credential(
+    event_config: &EventConfig,
+    attestation: Option<&UncheckedAccount>,
+    holder: &Pubkey,
+) -> Result<()> {
+    let Some(credential) = event_config.required_credential else {
+        return Ok(());
+    };
+    let attestation = attestation.ok_or(EncoreError::CredentialRequired)?;
+    require!(
+        credential.is_satisfied_by(&attestation.to_account_info(), holder),
+        EncoreError::CredentialRequired
+    );
+    Ok(())
+}
+
+/// A scanning device must co-sign when the event requires it, or when a
+/// scanner is passed; it must cover `zone` and be unexpired. Returns the
+/// zone a scanner attested to.
+pub fn check_scanner(
+    event_config: &EventConfig,
+    scanner: Option<&Account<Scanner>>,
+    scanner_device: Option<&Signer>,
+    zone: u8,
+    now: i64,
+) -> Result<Option<u8>> {
+    if scanner.is_none() && !event_config.require_scanner {
+        return Ok(None);
+    }
+    let scanner = scanner.ok_or(EncoreError::ScannerNotAuthorized)?;
+    require!(
+        scanner_device.is_some_and(|device| device.key() == scanner.device_key)
+            && scanner.can_scan(zone, now),
+        EncoreError::ScannerNotAuthorized
+    );
+    Ok(Some(zone))
+}
+
+/// Count `tickets` against the entrance a scanner attested to. Unattested
+/// redemptions have no entrance and aren't counted.
+pub fn record_entrance(
+    entrance_stats: Option<&mut Account<EntranceStats>>,
+    zone: Option<u8>,
+    tickets: u32,
+    now: i64,
+) -> Result<()> {
+    let Some(zone) = zone else {
+        return Ok(());
+    };
+    entrance_stats
+        .ok_or(EncoreError::EntranceStatsRequired)?
+        .record(zone, tickets, now)
+}
+
+/// Redeem (check in) a ticket at the door.
+///
+/// # Named tickets
+/// If the ticket carries a `name_commitment`, the event's ID verifier must
+/// have attested to it: the attestation PDA is derived from the name
+/// commitment in place of a wallet (see `CredentialRequirement`).
+///
+/// # Credentials
+/// If the event sets a `required_credential` (an 18+ attestation, say),
+/// the holder must present a live attestation for their wallet at the door
+/// as well as at purchase.
+///
+/// # Linked pairs
+/// A ticket with a `link_id` is redeemed together with its companion, which
+/// must carry the same `link_id`; both are nullified and closed.
+///
+/// # Compliance
+/// If the event's compliance policy names a hook program, it is asked to
+/// allow the redemption (see `policy_hook`).
+///
+/// # Scanners
+/// With `require_scanner` set, a registered device for `zone` must co-sign
+/// (see `Scanner`); the zone is reported in `TicketRedeemed` and on the
+/// receipt, and counted in the event's `EntranceStats`.
+///
+/// # Keyed tickets
+/// Tickets held under `OwnershipMode::Key` are redeemed by their key
+/// signing; `holder_secret` is ignored and the nullifier derives from the
+/// ticket's address instead.
+///
+/// # Receipts
+/// Every redemption emits a `RedemptionReceipt` for gate devices to cache;
+/// with `store_receipt` it is also kept as a compressed account so it can
+/// be fetched later.
+///
+/// # Operations
+/// 1. Verify holder owns the ticket(s) via commitment
+/// 2. Check the holder's credential and the ID-verification attestation
+///    for named tickets
+/// 3. Run the event's policy hook, if any
+/// 4. CREATE nullifier(s) (the tickets can't be transferred afterwards)
+/// 5. CLOSE ticket(s)
+/// 6. Mark held resale proceeds for this ticket withdrawable by the seller
+/// 7. Emit (and optionally CREATE) the redemption receipt
+pub fn redeem_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemTicket<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    holder_secret: [u8; 32],
+    companion: Option<CompanionRedemption>,
+    store_receipt: bool,
+    zone: u8,
+    proof_window: Option<ProofWindow>,
+    ownership: OwnershipMode,
+) -> Result<()> {
+    check_proof_window(proof_window)?;
+
+    let event_config = &mut ctx.accounts.event_config;
+    let holder = &ctx.accounts.holder;
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(
+        link_id.is_some() == companion.is_some(),
+        EncoreError::LinkedTicketRequired
+    );
+
+    // commitment = SHA256(owner_pubkey || secret)
+    let commitment_of = |secret: &[u8; 32]| {
+        let mut commitment_input = Vec::with_capacity(64);
+        commitment_input.extend_from_slice(holder.key.as_ref());
+        commitment_input.extend_from_slice(secret);
+        hash(&commitment_input).to_bytes()
+    };
+
+    // Keyed tickets name the signer outright and are nullified by address,
+    // so the secret goes unused; unbound ones must be bound first
+    require!(
+        ownership != OwnershipMode::Unbound,
+        EncoreError::UnboundTicket
+    );
+    if let OwnershipMode::Key(key) = ownership {
+        require_keys_eq!(key, holder.key(), EncoreError::NotTicketOwner);
+    }
+    let spend_terms = |secret: &[u8; 32], ticket_address: &[u8; 32]| match ownership {
+        OwnershipMode::Key(key) => (
+            key.to_bytes(),
+            PrivateTicket::key_nullifier_seed(ticket_address),
+        ),
+        _ => (commitment_of(secret), hash(secret).to_bytes()),
+    };
+
+    let now = Clock::get()?.unix_timestamp;
+    let zone = check_scanner(
+        event_config,
+        ctx.accounts.scanner.as_ref(),
+        ctx.accounts.scanner_device.as_ref(),
+        zone,
+        now,
+    )?;
+
+    // --- Credential and named ticket checks ---
+    check_credential(
+        event_config,
+        ctx.accounts.credential_attestation.as_ref(),
+        holder.key,
+    )?;
+    check_name_verified(
+        event_config,
+        ctx.accounts.attestation.as_ref(),
+        name_commitment,
+    )?;
+    if let Some(companion) = companion.as_ref() {
+        check_name_verified(
+            event_config,
+            ctx.accounts.companion_attestation.as_ref(),
+            companion.name_commitment,
+        )?;
+    }
+
+    // --- Compliance hook ---
+    check_policy(
+        event_config,
+        holder.as_ref(),
+        ctx.accounts
+            .policy_hook_program
+            .as_ref()
+            .map(|hook| hook.as_ref()),
+        ctx.accounts
+            .policy_attestation
+            .as_ref()
+            .map(|attestation| attestation.as_ref()),
+        PolicyAction::Redeem,
+    )?;
+
+    // --- Light Protocol CPI Setup ---
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Create nullifier ---
+    let (owner_commitment, nullifier_seed) =
+        spend_terms(&holder_secret, &ticket_account_meta.address);
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_nullifier_sequence()?;
+
+    // --- Step 2: Close ticket ---
+    let ticket_account = LightAccount::<PrivateTicket>::new_close(
+        &crate::ID,
+        &ticket_account_meta,
+        PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id,
+            owner_commitment,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            ownership,
+        },
+    )?;
+
+    // --- Execute CPI ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    // Nullifiers first (index 0, then the companion's at 1), closes after
+    let mut new_addresses =
+        vec![address_tree_info
+            .into_new_address_params_assigned_packed(nullifier_address_seed, Some(0))];
+    let mut cpi = LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?;
+
+    let companion_ticket = match companion.as_ref() {
+        Some(companion) => {
+            let (companion_commitment, companion_nullifier_seed) =
+                spend_terms(&companion.secret, &companion.ticket_account_meta.address);
+            let (address, seed) = derive_address(
+                &[NULLIFIER_PREFIX, companion_nullifier_seed.as_ref()],
+                &address_tree_pubkey,
+                &crate::ID,
+            );
+            let mut companion_nullifier = LightAccount::<Nullifier>::new_init(
+                &crate::ID,
+                Some(address),
+                output_state_tree_index,
+            );
+            companion_nullifier.event_config = event_config.key();
+            companion_nullifier.sequence = event_config.next_nullifier_sequence()?;
+            cpi = cpi.with_light_account(companion_nullifier)?;
+            new_addresses
+                .push(address_tree_info.into_new_address_params_assigned_packed(seed, Some(1)));
+
+            // The input hash only matches a companion with the same link id
+            Some(LightAccount::<PrivateTicket>::new_close(
+                &crate::ID,
+                &companion.ticket_account_meta,
+                PrivateTicket {
+                    event_config: event_config.key(),
+                    ticket_id: companion.ticket_id,
+                    owner_commitment: companion_commitment,
+                    original_price: companion.original_price,
+                    usd_micros_per_sol: companion.usd_micros_per_sol,
+                    name_commitment: companion.name_commitment,
+                    link_id,
+                    provenance: companion.provenance,
+                    custom_fields: companion.custom_fields.clone(),
+                    addons: 0, // Add-ons are bought on the primary seat
+                    ownership,
+                },
+            )?)
+        }
+        None => None,
+    };
+
+    // Receipt output follows the nullifiers
+    let receipt = RedemptionReceipt::new(
+        event_config.key(),
+        ticket_id,
+        holder.key(),
+        nullifier_address,
+        now,
+        ctx.accounts.scanner.as_ref().map(|scanner| scanner.key()),
+        zone,
+    );
+    if store_receipt {
+        let (address, seed) = derive_address(
+            &[REDEMPTION_RECEIPT_SEED, nullifier_address.as_ref()],
+            &address_tree_pubkey,
+            &crate::ID,
+        );
+        let mut receipt_account = LightAccount::<RedemptionReceipt>::new_init(
+            &crate::ID,
+            Some(address),
+            output_state_tree_index,
+        );
+        receipt_account.event_config = receipt.event_config;
+        receipt_account.ticket_id = receipt.ticket_id;
+        receipt_account.holder = receipt.holder;
+        receipt_account.nullifier = receipt.nullifier;
+        receipt_account.redeemed_at = receipt.redeemed_at;
+        receipt_account.digest = receipt.digest;
+        receipt_account.scanner = receipt.scanner;
+        receipt_account.zone = receipt.zone;
+        cpi = cpi.with_light_account(receipt_account)?;
+        new_addresses.push(
+            address_tree_info
+                .into_new_address_params_assigned_packed(seed, Some(new_addresses.len() as u8)),
+        );
+    }
+
+    cpi = cpi.with_light_account(ticket_account)?;
+    if let Some(companion_ticket) = companion_ticket {
+        cpi = cpi.with_light_account(companion_ticket)?;
+    }
+    cpi.with_new_addresses(&new_addresses)
+        .invoke(light_cpi_accounts)?;
+
+    if let Some(hold) = ctx.accounts.proceeds_hold.as_mut() {
+        hold.settle(&ticket_account_meta.address, HoldStatus::Redeemed)?;
+    }
+
+    let redeemed = if companion.is_some() { 2 } else { 1 };
+    record_entrance(ctx.accounts.entrance_stats.as_mut(), zone, redeemed, now)?;
+
+    let event_config = &mut ctx.accounts.event_config;
+    event_config.tickets_redeemed = event_config
+        .tickets_redeemed
+        .checked_add(redeemed)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(TicketRedeemed {
+        event_config: event_config.key(),
+        ticket_id,
+        named: name_commitment.is_some(),
+        addons,
+        zone,
+    });
+
+    emit!(receipt);
+
+    msg!("✅ Ticket {} redeemed", ticket_id);
+
+    Ok(())
+}
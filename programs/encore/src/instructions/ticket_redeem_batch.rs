@@ -0,0 +1,243 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{ENTRANCE_STATS_SEED, EVENT_SEED, MAX_REDEEM_BATCH};
+use crate::errors::EncoreError;
+use crate::events::TicketRedeemed;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_redeem::{check_credential, check_scanner, record_entrance};
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::policy_hook::check_policy;
+use crate::state::{
+    EntranceStats, EventConfig, FieldValue, Nullifier, OwnershipMode, PolicyAction, PrivateTicket,
+    Scanner,
+};
+
+/// One unnamed ticket in a batch redemption.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BatchRedemption {
+    pub ticket_account_meta: CompressedAccountMeta,
+    pub ticket_id: u32,
+    pub original_price: u64,
+    pub usd_micros_per_sol: Option<u64>,
+    pub link_id: Option<[u8; 32]>,
+    pub provenance: [u8; 32],
+    pub custom_fields: Vec<FieldValue>,
+    pub addons: u8,
+    pub secret: [u8; 32],
+}
+
+#[derive(Accounts)]
+pub struct RedeemTicketsBatch<'info> {
+    /// Holder of every ticket in the batch (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// CHECK: Holder's credential attestation, verified against
+    /// `event_config.required_credential` when the event requires one
+    pub credential_attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Must match `compliance_policy.hook_program` when one is set
+    pub policy_hook_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Passed through to the policy hook, which validates it
+    pub policy_attestation: Option<UncheckedAccount<'info>>,
+
+    /// Registered gate device; required when the event sets `require_scanner`
+    #[account(
+        constraint = scanner.event_config == event_config.key() @ EncoreError::ScannerNotAuthorized,
+    )]
+    pub scanner: Option<Account<'info, Scanner>>,
+
+    /// The scanner's device key, co-signing the redemption
+    pub scanner_device: Option<Signer<'info>>,
+
+    /// Per-entrance counters; required when a scanner attests the zone
+    #[account(
+        mut,
+        seeds = [ENTRANCE_STATS_SEED, event_config.key().as_ref()],
+        bump = entrance_stats.bump,
+    )]
+    pub entrance_stats: Option<Account<'info, EntranceStats>>,
+}
+
+/// Redeem (check in) up to `MAX_REDEEM_BATCH` tickets held by one wallet
+/// in a single transaction, under one validity proof.
+///
+/// Only unnamed tickets are accepted; named tickets need an ID attestation
+/// each and go through `redeem_ticket`, as do tickets with held resale
+/// proceeds to release. Linked pairs must have both tickets in the batch.
+///
+/// # Operations
+/// 1. Validate the batch size and that linked tickets come in pairs
+/// 2. Check the scanner co-signature and the holder's credential, and run
+///    the event's policy hook, if any
+/// 3. Verify the holder owns every ticket via commitment
+/// 4. CREATE a nullifier per ticket, then CLOSE every ticket
+pub fn redeem_tickets_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemTicketsBatch<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    tickets: Vec<BatchRedemption>,
+    zone: u8,
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let holder = &ctx.accounts.holder;
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(
+        !tickets.is_empty() && tickets.len() <= MAX_REDEEM_BATCH,
+        EncoreError::InvalidRedeemBatch
+    );
+    for ticket in tickets.iter() {
+        if let Some(link_id) = ticket.link_id {
+            require!(
+                tickets
+                    .iter()
+                    .filter(|other| other.link_id == Some(link_id))
+                    .count()
+                    == 2,
+                EncoreError::LinkedTicketRequired
+            );
+        }
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let zone = check_scanner(
+        event_config,
+        ctx.accounts.scanner.as_ref(),
+        ctx.accounts.scanner_device.as_ref(),
+        zone,
+        now,
+    )?;
+
+    check_credential(
+        event_config,
+        ctx.accounts.credential_attestation.as_ref(),
+        holder.key,
+    )?;
+
+    // --- Compliance hook ---
+    check_policy(
+        event_config,
+        holder.as_ref(),
+        ctx.accounts
+            .policy_hook_program
+            .as_ref()
+            .map(|hook| hook.as_ref()),
+        ctx.accounts
+            .policy_attestation
+            .as_ref()
+            .map(|attestation| attestation.as_ref()),
+        PolicyAction::Redeem,
+    )?;
+
+    // --- Light Protocol CPI Setup ---
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    // Nullifiers first (indexes 0..n), closes after
+    let mut new_addresses = Vec::with_capacity(tickets.len());
+    let mut cpi = LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof);
+    for (index, ticket) in tickets.iter().enumerate() {
+        let nullifier_seed = hash(&ticket.secret);
+        let (address, seed) = derive_address(
+            &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+            &address_tree_pubkey,
+            &crate::ID,
+        );
+        let mut nullifier_account =
+            LightAccount::<Nullifier>::new_init(&crate::ID, Some(address), output_state_tree_index);
+        nullifier_account.event_config = event_config.key();
+        nullifier_account.sequence = event_config.next_nullifier_sequence()?;
+        cpi = cpi.with_light_account(nullifier_account)?;
+        new_addresses.push(
+            address_tree_info.into_new_address_params_assigned_packed(seed, Some(index as u8)),
+        );
+    }
+
+    for ticket in tickets.iter() {
+        // commitment = SHA256(owner_pubkey || secret)
+        let mut commitment_input = Vec::with_capacity(64);
+        commitment_input.extend_from_slice(holder.key.as_ref());
+        commitment_input.extend_from_slice(&ticket.secret);
+
+        // The input hash only matches an unnamed ticket owned by the signer
+        let ticket_account = LightAccount::<PrivateTicket>::new_close(
+            &crate::ID,
+            &ticket.ticket_account_meta,
+            PrivateTicket {
+                event_config: event_config.key(),
+                ticket_id: ticket.ticket_id,
+                owner_commitment: hash(&commitment_input).to_bytes(),
+                original_price: ticket.original_price,
+                usd_micros_per_sol: ticket.usd_micros_per_sol,
+                name_commitment: None,
+                link_id: ticket.link_id,
+                provenance: ticket.provenance,
+                custom_fields: ticket.custom_fields.clone(),
+                addons: ticket.addons,
+                ownership: OwnershipMode::Bound,
+            },
+        )?;
+        cpi = cpi.with_light_account(ticket_account)?;
+    }
+
+    cpi.with_new_addresses(&new_addresses)
+        .invoke(light_cpi_accounts)?;
+
+    event_config.tickets_redeemed = event_config
+        .tickets_redeemed
+        .checked_add(tickets.len() as u32)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    record_entrance(
+        ctx.accounts.entrance_stats.as_mut(),
+        zone,
+        tickets.len() as u32,
+        now,
+    )?;
+
+    for ticket in tickets.iter() {
+        emit!(TicketRedeemed {
+            event_config: event_config.key(),
+            ticket_id: ticket.ticket_id,
+            named: false,
+            addons: ticket.addons,
+            zone,
+        });
+    }
+
+    msg!("✅ {} tickets redeemed", tickets.len());
+
+    Ok(())
+}
@@ -4,20 +4,45 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::hash::hash;
 use light_sdk::{
     account::LightAccount,
-    address::v2::derive_address,
     cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
-    instruction::{PackedAddressTreeInfo, ValidityProof},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
 };
 
-use crate::constants::TICKET_SEED;
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{
+    LEDGER_SEED, METRICS_SEED, PRICE_FEED_SEED, PROTOCOL_SEED, ROYALTY_VAULT_SEED, TICKET_SEED,
+    TRANSFER_REQUEST_SEED, TREASURY_SEED,
+};
 use crate::errors::EncoreError;
-use crate::events::TicketTransferred;
+use crate::events::{ProtocolFeeCollected, TicketTransferred};
 use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
-use crate::state::{EventConfig, Nullifier, PrivateTicket};
+use crate::proof_window::{check_proof_window, ProofWindow};
+use crate::state::{
+    fields_match_schema, EventConfig, EventLedger, FeeExemption, FeeSource, FieldValue,
+    LedgerEntryKind, Nullifier, OwnershipMode, PriceFeed, PrivateTicket, ProtocolConfig,
+    ProtocolMetrics, ProtocolTreasury, RoyaltyVault, TransferKind, TransferPolicy, TransferRequest,
+};
 
 /// Prefix for nullifier address derivation
 pub const NULLIFIER_PREFIX: &[u8] = b"nullifier";
 
+/// The other half of a linked pair, transferred in the same instruction.
+/// Its ticket is closed with this data, like the primary's.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CompanionTransfer {
+    pub ticket_account_meta: CompressedAccountMeta,
+    pub ticket_id: u32,
+    pub original_price: u64,
+    pub usd_micros_per_sol: Option<u64>,
+    pub name_commitment: Option<[u8; 32]>,
+    pub provenance: [u8; 32],
+    pub custom_fields: Vec<FieldValue>,
+    /// Companion ticket's secret (same owner as the primary)
+    pub secret: [u8; 32],
+    pub new_owner_commitment: [u8; 32],
+    pub new_ticket_address_seed: [u8; 32],
+}
+
 #[derive(Accounts)]
 pub struct TransferTicket<'info> {
     /// The seller who is transferring (also pays fees)
@@ -34,38 +59,232 @@ pub struct TransferTicket<'info> {
         bump = event_config.bump,
     )]
     pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol treasury receiving the sale fee on declared sales
+    #[account(mut, seeds = [TREASURY_SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
+    /// Fee exemption for this event or its organizer, if one was granted
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    /// Recipient paying a declared sale price through the program. Required
+    /// for sales when the event sets `require_paid_sales`.
+    #[account(mut)]
+    pub buyer: Option<Signer<'info>>,
+
+    /// Approved request, required under `TransferPolicy::ApprovalRequired`.
+    /// Closed to the seller once used.
+    #[account(
+        mut,
+        seeds = [
+            TRANSFER_REQUEST_SEED,
+            event_config.key().as_ref(),
+            &transfer_request.secret_hash,
+        ],
+        bump = transfer_request.bump,
+        close = seller,
+    )]
+    pub transfer_request: Option<Account<'info, TransferRequest>>,
+
+    /// SOL/USD feed, required for sales when the event's resale cap is
+    /// USD-adjusted
+    #[account(seeds = [PRICE_FEED_SEED], bump = price_feed.bump)]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    /// Collects the resale royalty on declared sales, required when the
+    /// event sets one
+    #[account(
+        mut,
+        seeds = [ROYALTY_VAULT_SEED, event_config.key().as_ref()],
+        bump = royalty_vault.bump,
+    )]
+    pub royalty_vault: Option<Account<'info, RoyaltyVault>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay a declared sale's `royalty` from `payer` into the event's vault.
+pub(crate) fn pay_royalty<'info>(
+    system_program: &Program<'info, System>,
+    payer: AccountInfo<'info>,
+    royalty_vault: Option<&mut Account<'info, RoyaltyVault>>,
+    royalty: u64,
+) -> Result<()> {
+    if royalty == 0 {
+        return Ok(());
+    }
+    let vault = royalty_vault.ok_or(EncoreError::RoyaltyVaultRequired)?;
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: payer,
+                to: vault.to_account_info(),
+            },
+        ),
+        royalty,
+    )?;
+    vault.pending = vault
+        .pending
+        .checked_add(royalty)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Checks a transfer of the ticket behind `secret_hash` must pass:
+/// organizer approval under `ApprovalRequired`, carried-over custom fields
+/// against the schema, and for declared sales a positive price outside the
+/// resale blackout and within the resale cap.
+pub(crate) fn check_transfer_terms(
+    event_config: &EventConfig,
+    transfer_request: Option<&TransferRequest>,
+    holder: &Pubkey,
+    secret_hash: &[u8; 32],
+    new_owner_commitment: &[u8; 32],
+    kind: TransferKind,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    custom_fields: &[FieldValue],
+    price_feed: Option<&PriceFeed>,
+) -> Result<()> {
+    // Approval-gated events need a matching approved request
+    if let TransferPolicy::ApprovalRequired { .. } = event_config.transfer_policy {
+        let request = transfer_request.ok_or(EncoreError::TransferApprovalRequired)?;
+        require!(
+            request.approved_at.is_some(),
+            EncoreError::TransferNotApproved
+        );
+        require!(
+            request.holder == *holder
+                && request.secret_hash == *secret_hash
+                && request.new_owner_commitment == *new_owner_commitment
+                && request.kind == kind,
+            EncoreError::TransferRequestMismatch
+        );
+    }
+
+    // Carried-over custom fields must still fit the event's schema
+    require!(
+        fields_match_schema(&event_config.custom_fields, custom_fields),
+        EncoreError::InvalidCustomFields
+    );
+
+    // Declared sales must carry a price within the resale cap
+    if let TransferKind::Sale { price_lamports } = kind {
+        let now = Clock::get()?.unix_timestamp;
+        require!(price_lamports > 0, EncoreError::InvalidPrice);
+        require!(
+            !event_config.in_resale_blackout(now),
+            EncoreError::ResaleBlackout
+        );
+        let cap_base =
+            event_config.resale_cap_base(original_price, usd_micros_per_sol, price_feed, now)?;
+        let max_allowed = event_config.calculate_max_resale_price(cap_base);
+        require!(price_lamports <= max_allowed, EncoreError::ExceedsResaleCap);
+    }
+
+    Ok(())
 }
 
 /// Transfer a private ticket using Commitment + Nullifier pattern.
 ///
 /// # Privacy Model
 /// - Seller proves ownership by SIGNING + revealing SECRET
-/// - Commitment verified: hash(owner_pubkey || secret) == ticket.owner_commitment
+/// - Commitment verified: the ticket is closed under
+///   hash(owner_pubkey || secret), so the CPI fails unless that is its
+///   commitment
 /// - Nullifier prevents double-spend: CREATE account at hash("nullifier" || secret)
 /// - Buyer's identity hidden - only their new_commitment stored
 ///
-/// # Operations (all CREATEs - no burns/mutations)
-/// 1. Verify ownership via commitment
+/// The spent ticket's data is proven by closing it, so the new ticket
+/// carries exactly its price, fields and add-ons.
+///
+/// # Transfer kinds
+/// A `Gift` moves the ticket with no consideration. A `Sale` declares the
+/// off-chain price: it must respect the resale cap, and the seller pays the
+/// protocol sale fee and royalty on it. Under a USD-adjusted cap (`resale_cap_usd_rate`)
+/// the cap applies to face value re-priced at the `price_feed` rate. Sales
+/// are refused during the event's resale
+/// blackout after the on-sale; gifts are not.
+///
+/// # Payment leg
+/// When `buyer` signs, a sale is settled in the same instruction: the buyer
+/// pays the price, the sale fee and the event royalty are deducted and the
+/// rest goes to the seller. Organizers can make this mandatory for sales
+/// with `require_paid_sales`. Without a payment leg the seller pays both.
+///
+/// # Royalties
+/// Declared sales pay the event royalty on the price, as marketplace sales
+/// do, into the event's `RoyaltyVault`.
+///
+/// # Approval
+/// Under `TransferPolicy::ApprovalRequired` the seller must pass a
+/// `TransferRequest` the organizer approved for this exact secret, recipient
+/// commitment and kind.
+///
+/// # Linked pairs
+/// A ticket with a `link_id` must move with its companion: both secrets are
+/// nullified, both spent tickets are closed and both new tickets keep the
+/// shared `link_id`. The link is part of the closed ticket's data, so a
+/// linked ticket can't be passed off as unlinked.
+///
+/// # Provenance
+/// The new ticket's `provenance` extends the spent ticket's with its
+/// nullifier address (see `PrivateTicket::next_provenance`).
+///
+/// # Operations
+/// 1. Verify ownership via commitment and organizer approval if required
 /// 2. CREATE nullifier (prevents reuse of this secret)
-/// 3. CREATE new ticket with buyer's commitment
+/// 3. CREATE new ticket with buyer's commitment (and the same for a companion)
+/// 4. CLOSE the spent ticket (and its companion)
+/// 5. Settle the payment leg if any, charge the sale fee and royalty on
+///    declared sales and record the transfer kind
 pub fn transfer_ticket<'info>(
     ctx: Context<'_, '_, '_, 'info, TransferTicket<'info>>,
     proof: ValidityProof,
     address_tree_info: PackedAddressTreeInfo,
     output_state_tree_index: u8,
-    // Existing ticket data (for verification)
+    // Existing ticket, closed with this data (the CPI fails on a mismatch)
+    ticket_account_meta: CompressedAccountMeta,
     current_ticket_id: u32,
     current_original_price: u64,
+    current_usd_micros_per_sol: Option<u64>,
+    current_name_commitment: Option<[u8; 32]>,
+    current_provenance: [u8; 32],
+    current_custom_fields: Vec<FieldValue>,
+    current_addons: u8,
     // Seller reveals secret to prove ownership
     seller_secret: [u8; 32],
     // Buyer's new commitment
     new_owner_commitment: [u8; 32],
     // Random seed for new ticket address
     new_ticket_address_seed: [u8; 32],
-    // Optional resale price for cap enforcement
-    resale_price: Option<u64>,
+    // Gift, or a sale at a declared price
+    kind: TransferKind,
+    // Shared link id of a linked pair, and the companion moving with it
+    link_id: Option<[u8; 32]>,
+    companion: Option<CompanionTransfer>,
+    proof_window: Option<ProofWindow>,
+    // Whether the recipient's commitment binds a wallet
+    new_ownership: OwnershipMode,
 ) -> Result<()> {
-    let event_config = &ctx.accounts.event_config;
+    check_proof_window(proof_window)?;
+
+    let event_config = &mut ctx.accounts.event_config;
     let seller = &ctx.accounts.seller;
 
     // --- Step 1: Verify ownership via commitment ---
@@ -73,11 +292,23 @@ pub fn transfer_ticket<'info>(
     let mut commitment_input = Vec::with_capacity(64);
     commitment_input.extend_from_slice(seller.key().as_ref());
     commitment_input.extend_from_slice(&seller_secret);
-    let _computed_commitment = hash(&commitment_input);
+    let computed_commitment = hash(&commitment_input).to_bytes();
 
-    // The commitment is verified implicitly via the proof - the ticket with this
-    // commitment must exist for the proof to be valid. The CPI will fail if the
-    // ticket data doesn't match what's in the Merkle tree.
+    // The spent ticket as claimed. Closing it below makes the CPI check the
+    // claim against the state tree, commitment included.
+    let ticket = PrivateTicket {
+        event_config: event_config.key(),
+        ticket_id: current_ticket_id,
+        owner_commitment: computed_commitment,
+        original_price: current_original_price,
+        usd_micros_per_sol: current_usd_micros_per_sol,
+        name_commitment: current_name_commitment,
+        link_id,
+        provenance: current_provenance,
+        custom_fields: current_custom_fields,
+        addons: current_addons,
+        ownership: OwnershipMode::Bound,
+    };
 
     let light_cpi_accounts = CpiAccounts::new(
         ctx.accounts.seller.as_ref(),
@@ -90,17 +321,50 @@ pub fn transfer_ticket<'info>(
         .get_tree_pubkey(&light_cpi_accounts)
         .map_err(|_| EncoreError::InvalidAddressTree)?;
 
-    // Validate V2 address tree (skip in test mode)
-    #[cfg(not(feature = "test-mode"))]
-    if address_tree_pubkey.to_bytes() != light_sdk_types::ADDRESS_TREE_V2 {
-        msg!("Invalid address tree: must use V2");
-        return Err(ProgramError::InvalidAccountData.into());
-    }
+    check_address_tree(&address_tree_pubkey)?;
+
+    check_transfer_terms(
+        event_config,
+        ctx.accounts.transfer_request.as_deref(),
+        &seller.key(),
+        &hash(&seller_secret).to_bytes(),
+        &new_owner_commitment,
+        kind,
+        ticket.original_price,
+        ticket.usd_micros_per_sol,
+        &ticket.custom_fields,
+        ctx.accounts.price_feed.as_deref(),
+    )?;
+
+    // A companion's fields must fit the schema too
+    require!(
+        companion.as_ref().is_none_or(|companion| {
+            fields_match_schema(&event_config.custom_fields, &companion.custom_fields)
+        }),
+        EncoreError::InvalidCustomFields
+    );
+
+    // Linked tickets only move as a pair
+    require!(
+        link_id.is_some() == companion.is_some(),
+        EncoreError::LinkedTicketRequired
+    );
+    require!(
+        new_ownership.admits(&new_owner_commitment)
+            && companion
+                .as_ref()
+                .is_none_or(|companion| { new_ownership.admits(&companion.new_owner_commitment) }),
+        EncoreError::InvalidOwnershipKey
+    );
 
-    // Check resale cap if price provided
-    if let Some(price) = resale_price {
-        let max_allowed = event_config.calculate_max_resale_price(current_original_price);
-        require!(price <= max_allowed, EncoreError::ExceedsResaleCap);
+    if let TransferKind::Sale { .. } = kind {
+        require!(
+            ctx.accounts.buyer.is_some() || !event_config.require_paid_sales,
+            EncoreError::PaidTransferRequired
+        );
+    } else {
+        // Gifts carry no payment
+        require!(ctx.accounts.buyer.is_none(), EncoreError::InvalidPrice);
     }
 
     // --- Step 2: Create nullifier ---
@@ -115,11 +379,13 @@ pub fn transfer_ticket<'info>(
     );
     msg!("Nullifier address: {:?}", nullifier_address);
 
-    let nullifier_account = LightAccount::<Nullifier>::new_init(
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
         &crate::ID,
         Some(nullifier_address),
         output_state_tree_index,
     );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_nullifier_sequence()?;
 
     // --- Step 3: Create new ticket with buyer's commitment ---
     let (new_ticket_address, new_ticket_seed) = derive_address(
@@ -134,28 +400,201 @@ pub fn transfer_ticket<'info>(
         Some(new_ticket_address),
         output_state_tree_index,
     );
-    new_ticket_account.event_config = event_config.key();
-    new_ticket_account.ticket_id = current_ticket_id; // Preserve ticket ID
-    new_ticket_account.owner_commitment = new_owner_commitment; // Buyer's commitment
-    new_ticket_account.original_price = current_original_price; // Preserve for resale cap
+    *new_ticket_account = ticket.reissue(&nullifier_address, new_owner_commitment, new_ownership);
 
-    // --- Execute CPI: CREATE nullifier + CREATE new ticket ---
+    // --- Step 4: Close the spent ticket ---
+    let ticket_account =
+        LightAccount::<PrivateTicket>::new_close(&crate::ID, &ticket_account_meta, ticket)?;
+
+    // --- Execute CPI: CREATE nullifier + CREATE new ticket, CLOSE old ---
     use light_sdk::cpi::v2::LightSystemProgramCpi;
 
     // Two new addresses: nullifier (index 0) and new ticket (index 1)
-    let nullifier_params =
-        address_tree_info.into_new_address_params_assigned_packed(nullifier_address_seed, Some(0));
-    let new_ticket_params =
-        address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(1));
+    let mut new_addresses = vec![
+        address_tree_info.into_new_address_params_assigned_packed(nullifier_address_seed, Some(0)),
+        address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(1)),
+    ];
 
-    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+    let mut cpi = LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
         .with_light_account(nullifier_account)? // CREATE nullifier
-        .with_light_account(new_ticket_account)? // CREATE new ticket
-        .with_new_addresses(&[nullifier_params, new_ticket_params])
+        .with_light_account(new_ticket_account)?; // CREATE new ticket
+
+    // Companion nullifier (index 2) and new companion ticket (index 3)
+    let mut companion_account = None;
+    if let Some(companion) = companion.as_ref() {
+        let mut commitment_input = Vec::with_capacity(64);
+        commitment_input.extend_from_slice(seller.key().as_ref());
+        commitment_input.extend_from_slice(&companion.secret);
+        let spent_companion = PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id: companion.ticket_id,
+            owner_commitment: hash(&commitment_input).to_bytes(),
+            original_price: companion.original_price,
+            usd_micros_per_sol: companion.usd_micros_per_sol,
+            name_commitment: companion.name_commitment,
+            link_id,
+            provenance: companion.provenance,
+            custom_fields: companion.custom_fields.clone(),
+            addons: 0, // Companion seats carry no add-ons
+            ownership: OwnershipMode::Bound,
+        };
+        let companion_nullifier_seed = hash(&companion.secret);
+        let (address, seed) = derive_address(
+            &[NULLIFIER_PREFIX, companion_nullifier_seed.as_ref()],
+            &address_tree_pubkey,
+            &crate::ID,
+        );
+        let companion_nullifier_address = address;
+        let mut companion_nullifier =
+            LightAccount::<Nullifier>::new_init(&crate::ID, Some(address), output_state_tree_index);
+        companion_nullifier.event_config = event_config.key();
+        companion_nullifier.sequence = event_config.next_nullifier_sequence()?;
+        new_addresses
+            .push(address_tree_info.into_new_address_params_assigned_packed(seed, Some(2)));
+
+        let (address, seed) = derive_address(
+            &[TICKET_SEED, companion.new_ticket_address_seed.as_ref()],
+            &address_tree_pubkey,
+            &crate::ID,
+        );
+        let mut companion_ticket = LightAccount::<PrivateTicket>::new_init(
+            &crate::ID,
+            Some(address),
+            output_state_tree_index,
+        );
+        *companion_ticket = spent_companion.reissue(
+            &companion_nullifier_address,
+            companion.new_owner_commitment,
+            new_ownership,
+        );
+        new_addresses
+            .push(address_tree_info.into_new_address_params_assigned_packed(seed, Some(3)));
+        companion_account = Some(LightAccount::<PrivateTicket>::new_close(
+            &crate::ID,
+            &companion.ticket_account_meta,
+            spent_companion,
+        )?);
+
+        cpi = cpi
+            .with_light_account(companion_nullifier)?
+            .with_light_account(companion_ticket)?;
+    }
+
+    // Closed accounts go after every new account
+    cpi = cpi.with_light_account(ticket_account)?; // CLOSE spent ticket
+    if let Some(companion_account) = companion_account {
+        cpi = cpi.with_light_account(companion_account)?; // CLOSE spent companion
+    }
+    cpi.with_new_addresses(&new_addresses)
         .invoke(light_cpi_accounts)?;
 
+    // --- Step 5: Payment leg and sale fee ---
+    let mut protocol_fee = ctx.accounts.protocol_config.sale_fee(kind.price_lamports());
+    if let Some(exemption) = ctx.accounts.fee_exemption.as_ref() {
+        require!(
+            exemption.applies_to(&event_config.key(), &event_config.authority),
+            EncoreError::InvalidFeeExemption
+        );
+        protocol_fee = exemption.apply(protocol_fee);
+    }
+
+    let royalty = event_config
+        .royalty
+        .as_ref()
+        .map_or(0, |royalty| royalty.royalty(kind.price_lamports()));
+
+    // The buyer pays the fee and royalty out of the price; otherwise the
+    // seller pays them
+    let fee_payer = match ctx.accounts.buyer.as_ref() {
+        Some(buyer) => {
+            let seller_proceeds = kind
+                .price_lamports()
+                .checked_sub(protocol_fee)
+                .and_then(|v| v.checked_sub(royalty))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: buyer.to_account_info(),
+                        to: seller.to_account_info(),
+                    },
+                ),
+                seller_proceeds,
+            )?;
+            ctx.accounts
+                .ledger
+                .record(LedgerEntryKind::ResaleSettlement, seller_proceeds)?;
+            buyer.to_account_info()
+        }
+        None => seller.to_account_info(),
+    };
+
+    pay_royalty(
+        &ctx.accounts.system_program,
+        fee_payer.clone(),
+        ctx.accounts.royalty_vault.as_mut(),
+        royalty,
+    )?;
+    if royalty > 0 {
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::Royalty, royalty)?;
+    }
+
+    if protocol_fee > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: fee_payer,
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            protocol_fee,
+        )?;
+        ctx.accounts
+            .treasury
+            .record_fee(FeeSource::Sale, protocol_fee)?;
+        ctx.accounts.metrics.record_fee(protocol_fee)?;
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::ProtocolFee, protocol_fee)?;
+
+        emit!(ProtocolFeeCollected {
+            event_config: event_config.key(),
+            source: FeeSource::Sale,
+            amount: protocol_fee,
+        });
+    }
+
+    let paid_in_protocol = ctx.accounts.buyer.is_some();
+    let event_config = &mut ctx.accounts.event_config;
+    // Counted per ticket moved, so the stats match the nullifiers created
+    let tickets = if companion.is_some() { 2 } else { 1 };
+    let count = match kind {
+        TransferKind::Gift => &mut event_config.gift_transfers,
+        TransferKind::Sale { .. } => &mut event_config.sale_transfers,
+    };
+    *count = count
+        .checked_add(tickets)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if let TransferKind::Sale { price_lamports } = kind {
+        ctx.accounts.metrics.record_resale(price_lamports)?;
+    }
+
+    let policy = event_config.emission_policy;
+    let public = policy.includes_commitments();
+    let priced = policy.includes_prices();
     emit!(TicketTransferred {
         event_config: event_config.key(),
+        gift_transfers: event_config.gift_transfers,
+        sale_transfers: event_config.sale_transfers,
+        ticket_id: public.then_some(current_ticket_id),
+        new_owner_commitment: public.then_some(new_owner_commitment),
+        kind: priced.then_some(kind),
+        protocol_fee: priced.then_some(protocol_fee),
+        paid_in_protocol: priced.then_some(paid_in_protocol),
     });
 
     msg!("✅ Transfer complete: nullifier created, new ticket issued");
@@ -0,0 +1,119 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{
+        v2::{CpiAccounts, LightSystemProgramCpi},
+        InvokeLightSystemProgram, LightCpiInstruction,
+    },
+    instruction::{PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{EVENT_SEED, PENDING_TRANSFER_SEED, TICKET_SEED};
+use crate::errors::EncoreError;
+use crate::events::PendingTransferCancelled;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::state::{EventConfig, PendingTransfer, PrivateTicket, TransferKind};
+
+#[derive(Accounts)]
+pub struct CancelPendingTransfer<'info> {
+    /// Holder who prepared the transfer, receives the rent
+    #[account(mut, address = pending_transfer.holder)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner, used to derive the event PDA
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            PENDING_TRANSFER_SEED,
+            event_config.key().as_ref(),
+            &pending_transfer.secret_hash,
+        ],
+        bump = pending_transfer.bump,
+        close = holder,
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+}
+
+/// Abandon a prepared transfer that was never finalized.
+///
+/// Preparing spent the ticket, so it's reissued to the holder under
+/// `new_owner_commitment`, a fresh secret of theirs. The transfer the
+/// prepare counted becomes a reissue. An approval used to prepare it is
+/// not restored.
+pub fn cancel_pending_transfer<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelPendingTransfer<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    new_ticket_address_seed: [u8; 32],
+    new_owner_commitment: [u8; 32],
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let pending_transfer = &ctx.accounts.pending_transfer;
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Reissue the ticket to the holder ---
+    let (new_ticket_address, new_ticket_seed) = derive_address(
+        &[TICKET_SEED, new_ticket_address_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut new_ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(new_ticket_address),
+        output_state_tree_index,
+    );
+    *new_ticket_account = pending_transfer.ticket(new_owner_commitment);
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(new_ticket_account)?
+        .with_new_addresses(&[
+            address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(0))
+        ])
+        .invoke(light_cpi_accounts)?;
+
+    let count = match pending_transfer.kind {
+        TransferKind::Gift => &mut event_config.gift_transfers,
+        TransferKind::Sale { .. } => &mut event_config.sale_transfers,
+    };
+    *count = count
+        .checked_sub(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    event_config.tickets_reissued = event_config
+        .tickets_reissued
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(PendingTransferCancelled {
+        event_config: event_config.key(),
+        pending_transfer: pending_transfer.key(),
+    });
+
+    msg!("✅ Pending transfer cancelled, ticket reissued to its holder");
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{
+        v2::{CpiAccounts, LightSystemProgramCpi},
+        InvokeLightSystemProgram, LightCpiInstruction,
+    },
+    instruction::{PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{
+    EVENT_SEED, LEDGER_SEED, METRICS_SEED, PENDING_TRANSFER_SEED, PROTOCOL_SEED,
+    ROYALTY_VAULT_SEED, TICKET_SEED, TREASURY_SEED,
+};
+use crate::errors::EncoreError;
+use crate::events::{ProtocolFeeCollected, TicketTransferred};
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::pay_royalty;
+use crate::state::{
+    EventConfig, EventLedger, FeeExemption, FeeSource, LedgerEntryKind, PendingTransfer,
+    PrivateTicket, ProtocolConfig, ProtocolMetrics, ProtocolTreasury, RoyaltyVault, TransferKind,
+};
+
+#[derive(Accounts)]
+pub struct FinalizeTransfer<'info> {
+    /// Holder who prepared the transfer (pays fees), receives the rent
+    #[account(mut, address = pending_transfer.holder)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner, used to derive the event PDA
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol treasury receiving the sale fee on declared sales
+    #[account(mut, seeds = [TREASURY_SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+
+    /// Fee exemption for this event or its organizer, if one was granted
+    pub fee_exemption: Option<Account<'info, FeeExemption>>,
+
+    #[account(
+        mut,
+        seeds = [
+            PENDING_TRANSFER_SEED,
+            event_config.key().as_ref(),
+            &pending_transfer.secret_hash,
+        ],
+        bump = pending_transfer.bump,
+        close = holder,
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+
+    /// Collects the resale royalty on declared sales, required when the
+    /// event sets one
+    #[account(
+        mut,
+        seeds = [ROYALTY_VAULT_SEED, event_config.key().as_ref()],
+        bump = royalty_vault.bump,
+    )]
+    pub royalty_vault: Option<Account<'info, RoyaltyVault>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Second phase of a two-phase transfer: CREATE the recipient's ticket for
+/// a prepared transfer.
+///
+/// Preparing already spent the ticket, so this CPI only creates one
+/// address. Nothing is re-validated; if it fails (stale proof, congestion)
+/// the pending transfer is untouched and the holder retries with a new
+/// proof.
+///
+/// # Operations
+/// 1. CREATE new ticket with the recipient's commitment
+/// 2. Charge the sale fee and royalty on declared sales
+/// 3. Close the pending transfer, returning its rent to the holder
+pub fn finalize_transfer<'info>(
+    ctx: Context<'_, '_, '_, 'info, FinalizeTransfer<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    new_ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let event_config = &ctx.accounts.event_config;
+    let pending_transfer = &ctx.accounts.pending_transfer;
+    let kind = pending_transfer.kind;
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Create new ticket with the recipient's commitment ---
+    let (new_ticket_address, new_ticket_seed) = derive_address(
+        &[TICKET_SEED, new_ticket_address_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut new_ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(new_ticket_address),
+        output_state_tree_index,
+    );
+    *new_ticket_account = pending_transfer.ticket(pending_transfer.new_owner_commitment);
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(new_ticket_account)?
+        .with_new_addresses(&[
+            address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(0))
+        ])
+        .invoke(light_cpi_accounts)?;
+
+    // --- Step 2: Sale fee and royalty, paid by the holder ---
+    let royalty = event_config
+        .royalty
+        .as_ref()
+        .map_or(0, |royalty| royalty.royalty(kind.price_lamports()));
+    pay_royalty(
+        &ctx.accounts.system_program,
+        ctx.accounts.holder.to_account_info(),
+        ctx.accounts.royalty_vault.as_mut(),
+        royalty,
+    )?;
+    if royalty > 0 {
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::Royalty, royalty)?;
+    }
+
+    let mut protocol_fee = ctx.accounts.protocol_config.sale_fee(kind.price_lamports());
+    if let Some(exemption) = ctx.accounts.fee_exemption.as_ref() {
+        require!(
+            exemption.applies_to(&event_config.key(), &event_config.authority),
+            EncoreError::InvalidFeeExemption
+        );
+        protocol_fee = exemption.apply(protocol_fee);
+    }
+
+    if protocol_fee > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.holder.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            protocol_fee,
+        )?;
+        ctx.accounts
+            .treasury
+            .record_fee(FeeSource::Sale, protocol_fee)?;
+        ctx.accounts.metrics.record_fee(protocol_fee)?;
+        ctx.accounts
+            .ledger
+            .record(LedgerEntryKind::ProtocolFee, protocol_fee)?;
+
+        emit!(ProtocolFeeCollected {
+            event_config: event_config.key(),
+            source: FeeSource::Sale,
+            amount: protocol_fee,
+        });
+    }
+
+    if let TransferKind::Sale { price_lamports } = kind {
+        ctx.accounts.metrics.record_resale(price_lamports)?;
+    }
+
+    let policy = event_config.emission_policy;
+    let public = policy.includes_commitments();
+    let priced = policy.includes_prices();
+    emit!(TicketTransferred {
+        event_config: event_config.key(),
+        gift_transfers: event_config.gift_transfers,
+        sale_transfers: event_config.sale_transfers,
+        ticket_id: public.then_some(pending_transfer.ticket_id),
+        new_owner_commitment: public.then_some(pending_transfer.new_owner_commitment),
+        kind: priced.then_some(kind),
+        protocol_fee: priced.then_some(protocol_fee),
+        paid_in_protocol: priced.then_some(false),
+    });
+
+    msg!("✅ Transfer finalized: new ticket issued");
+
+    Ok(())
+}
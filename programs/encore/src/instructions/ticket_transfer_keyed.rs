@@ -0,0 +1,193 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{EVENT_SEED, TICKET_SEED};
+use crate::errors::EncoreError;
+use crate::events::TicketTransferred;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::NULLIFIER_PREFIX;
+use crate::state::{
+    fields_match_schema, EventConfig, FieldValue, Nullifier, OwnershipMode, PrivateTicket,
+    TransferKind, TransferPolicy,
+};
+
+#[derive(Accounts)]
+pub struct TransferKeyedTicket<'info> {
+    /// The ticket's ownership key (also pays fees)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+}
+
+/// Gift a `Key` ticket, signed by its ownership key.
+///
+/// The ticket is closed (its data names the signer as the key) and
+/// nullified by address, then reissued to `new_owner_commitment` under
+/// `new_ownership`. Moving it to a `Bound` commitment, the holder's own
+/// included, is how a keyed ticket reaches the secret-based flows.
+///
+/// Keyed tickets move as gifts on open-transfer events only; linked pairs
+/// aren't supported.
+///
+/// # Operations
+/// 1. CLOSE the keyed ticket
+/// 2. CREATE nullifier for its address
+/// 3. CREATE the new ticket
+pub fn transfer_keyed_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferKeyedTicket<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    new_owner_commitment: [u8; 32],
+    new_ownership: OwnershipMode,
+    new_ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let owner = ctx.accounts.owner.key();
+
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(
+        event_config.transfer_policy == TransferPolicy::Open,
+        EncoreError::TransferApprovalRequired
+    );
+    require!(
+        fields_match_schema(&event_config.custom_fields, &custom_fields),
+        EncoreError::InvalidCustomFields
+    );
+    require!(
+        new_ownership.admits(&new_owner_commitment),
+        EncoreError::InvalidOwnershipKey
+    );
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.owner.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 1: Close the keyed ticket (only matches the signer's key) ---
+    let ticket_account = LightAccount::<PrivateTicket>::new_close(
+        &crate::ID,
+        &ticket_account_meta,
+        PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id,
+            owner_commitment: owner.to_bytes(),
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id: None,
+            provenance,
+            custom_fields: custom_fields.clone(),
+            addons,
+            ownership: OwnershipMode::Key(owner),
+        },
+    )?;
+
+    // --- Step 2: Create nullifier ---
+    let nullifier_seed = PrivateTicket::key_nullifier_seed(&ticket_account_meta.address);
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, nullifier_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_nullifier_sequence()?;
+
+    // --- Step 3: Create the recipient's ticket ---
+    let (new_ticket_address, new_ticket_seed) = derive_address(
+        &[TICKET_SEED, new_ticket_address_seed.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut new_ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(new_ticket_address),
+        output_state_tree_index,
+    );
+    new_ticket_account.event_config = event_config.key();
+    new_ticket_account.ticket_id = ticket_id;
+    new_ticket_account.owner_commitment = new_owner_commitment;
+    new_ticket_account.original_price = original_price;
+    new_ticket_account.usd_micros_per_sol = usd_micros_per_sol;
+    new_ticket_account.name_commitment = None; // New holder personalizes
+    new_ticket_account.custom_fields = custom_fields;
+    new_ticket_account.addons = addons;
+    new_ticket_account.provenance = PrivateTicket::next_provenance(&provenance, &nullifier_address);
+    new_ticket_account.ownership = new_ownership;
+
+    // --- Execute CPI ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    // New addresses: nullifier (index 0), ticket (index 1)
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(new_ticket_account)?
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[
+            address_tree_info
+                .into_new_address_params_assigned_packed(nullifier_address_seed, Some(0)),
+            address_tree_info.into_new_address_params_assigned_packed(new_ticket_seed, Some(1)),
+        ])
+        .invoke(light_cpi_accounts)?;
+
+    event_config.gift_transfers = event_config
+        .gift_transfers
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let policy = event_config.emission_policy;
+    let public = policy.includes_commitments();
+    let priced = policy.includes_prices();
+    emit!(TicketTransferred {
+        event_config: event_config.key(),
+        gift_transfers: event_config.gift_transfers,
+        sale_transfers: event_config.sale_transfers,
+        ticket_id: public.then_some(ticket_id),
+        new_owner_commitment: public.then_some(new_owner_commitment),
+        kind: priced.then_some(TransferKind::Gift),
+        protocol_fee: priced.then_some(0),
+        paid_in_protocol: priced.then_some(false),
+    });
+
+    msg!("✅ Keyed ticket {} transferred", ticket_id);
+
+    Ok(())
+}
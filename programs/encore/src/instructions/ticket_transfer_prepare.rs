@@ -0,0 +1,235 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{
+        v2::{CpiAccounts, LightSystemProgramCpi},
+        InvokeLightSystemProgram, LightCpiInstruction,
+    },
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{EVENT_SEED, PENDING_TRANSFER_SEED, PRICE_FEED_SEED, TRANSFER_REQUEST_SEED};
+use crate::errors::EncoreError;
+use crate::events::TransferPrepared;
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::instructions::ticket_transfer::{check_transfer_terms, NULLIFIER_PREFIX};
+use crate::state::{
+    EventConfig, FieldValue, Nullifier, OwnershipMode, PendingTransfer, PriceFeed, PrivateTicket,
+    TransferKind, TransferRequest,
+};
+
+#[derive(Accounts)]
+#[instruction(secret_hash: [u8; 32])]
+pub struct PrepareTransfer<'info> {
+    /// Current holder, pays the pending transfer's rent
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner, used to derive the event PDA
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Approved request, required under `TransferPolicy::ApprovalRequired`.
+    /// Closed to the holder once used.
+    #[account(
+        mut,
+        seeds = [
+            TRANSFER_REQUEST_SEED,
+            event_config.key().as_ref(),
+            &transfer_request.secret_hash,
+        ],
+        bump = transfer_request.bump,
+        close = holder,
+    )]
+    pub transfer_request: Option<Account<'info, TransferRequest>>,
+
+    /// SOL/USD feed, required for sales when the event's resale cap is
+    /// USD-adjusted
+    #[account(seeds = [PRICE_FEED_SEED], bump = price_feed.bump)]
+    pub price_feed: Option<Account<'info, PriceFeed>>,
+
+    /// One pending transfer per ticket: a second prepare fails here
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + PendingTransfer::INIT_SPACE,
+        seeds = [PENDING_TRANSFER_SEED, event_config.key().as_ref(), &secret_hash],
+        bump
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// First phase of a two-phase transfer: validate the transfer and spend
+/// the ticket, leaving the recipient's ticket to `finalize_transfer`.
+///
+/// `transfer_ticket` does everything in one transaction; with priority
+/// fees and lookup tables on top of its Light CPI it can run out of
+/// compute when the network is congested. This phase runs all of its
+/// checks (approval, custom field schema, resale blackout and cap), then
+/// CREATEs the nullifier and CLOSEs the spent ticket, and records the
+/// ticket in a `PendingTransfer`. `finalize_transfer` only creates the new
+/// ticket, and can be retried with a fresh proof until it lands.
+/// `cancel_pending_transfer` reissues the ticket to the holder instead.
+///
+/// The secret revealed here is spent in the same transaction, and the
+/// nullifier blocks every other spend path (listings, redemption, a
+/// second transfer) while the transfer is pending.
+///
+/// Sales settle off-chain here (the seller pays the sale fee and royalty
+/// at finalize), so events with `require_paid_sales` only allow gifts.
+/// Linked pairs move with `transfer_ticket`.
+pub fn prepare_transfer<'info>(
+    ctx: Context<'_, '_, '_, 'info, PrepareTransfer<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    secret_hash: [u8; 32],
+    holder_secret: [u8; 32],
+    new_owner_commitment: [u8; 32],
+    kind: TransferKind,
+    current_ticket_id: u32,
+    current_original_price: u64,
+    current_usd_micros_per_sol: Option<u64>,
+    current_name_commitment: Option<[u8; 32]>,
+    current_provenance: [u8; 32],
+    current_custom_fields: Vec<FieldValue>,
+    current_addons: u8,
+) -> Result<()> {
+    let event_config = &mut ctx.accounts.event_config;
+    let holder = ctx.accounts.holder.key();
+
+    // Revealing the secret authorizes spending the ticket, as in a transfer
+    require!(
+        hash(&holder_secret).to_bytes() == secret_hash,
+        EncoreError::NotTicketOwner
+    );
+
+    check_transfer_terms(
+        event_config,
+        ctx.accounts.transfer_request.as_deref(),
+        &holder,
+        &secret_hash,
+        &new_owner_commitment,
+        kind,
+        current_original_price,
+        current_usd_micros_per_sol,
+        &current_custom_fields,
+        ctx.accounts.price_feed.as_deref(),
+    )?;
+    if let TransferKind::Sale { .. } = kind {
+        require!(
+            !event_config.require_paid_sales,
+            EncoreError::PaidTransferRequired
+        );
+    }
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // The spent ticket: commitment = SHA256(holder || secret)
+    let mut commitment_input = Vec::with_capacity(64);
+    commitment_input.extend_from_slice(holder.as_ref());
+    commitment_input.extend_from_slice(&holder_secret);
+    let ticket = PrivateTicket {
+        event_config: event_config.key(),
+        ticket_id: current_ticket_id,
+        owner_commitment: hash(&commitment_input).to_bytes(),
+        original_price: current_original_price,
+        usd_micros_per_sol: current_usd_micros_per_sol,
+        name_commitment: current_name_commitment,
+        link_id: None,
+        provenance: current_provenance,
+        custom_fields: current_custom_fields,
+        addons: current_addons,
+        ownership: OwnershipMode::Bound,
+    };
+
+    // --- Step 1: Create nullifier ---
+    let (nullifier_address, nullifier_address_seed) = derive_address(
+        &[NULLIFIER_PREFIX, secret_hash.as_ref()],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut nullifier_account = LightAccount::<Nullifier>::new_init(
+        &crate::ID,
+        Some(nullifier_address),
+        output_state_tree_index,
+    );
+    nullifier_account.event_config = event_config.key();
+    nullifier_account.sequence = event_config.next_nullifier_sequence()?;
+
+    // The transfer is counted once the ticket is spent
+    let count = match kind {
+        TransferKind::Gift => &mut event_config.gift_transfers,
+        TransferKind::Sale { .. } => &mut event_config.sale_transfers,
+    };
+    *count = count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // The ticket finalizing issues to the recipient
+    let reissued = ticket.reissue(
+        &nullifier_address,
+        new_owner_commitment,
+        OwnershipMode::Bound,
+    );
+
+    // --- Step 2: Close the spent ticket ---
+    let ticket_account =
+        LightAccount::<PrivateTicket>::new_close(&crate::ID, &ticket_account_meta, ticket)?;
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(nullifier_account)?
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[address_tree_info
+            .into_new_address_params_assigned_packed(nullifier_address_seed, Some(0))])
+        .invoke(light_cpi_accounts)?;
+
+    let pending_transfer = &mut ctx.accounts.pending_transfer;
+    pending_transfer.event_config = event_config.key();
+    pending_transfer.holder = holder;
+    pending_transfer.secret_hash = secret_hash;
+    pending_transfer.new_owner_commitment = new_owner_commitment;
+    pending_transfer.kind = kind;
+    pending_transfer.ticket_id = reissued.ticket_id;
+    pending_transfer.original_price = reissued.original_price;
+    pending_transfer.usd_micros_per_sol = reissued.usd_micros_per_sol;
+    pending_transfer.provenance = reissued.provenance;
+    pending_transfer.custom_fields = reissued.custom_fields;
+    pending_transfer.addons = reissued.addons;
+    pending_transfer.prepared_at = Clock::get()?.unix_timestamp;
+    pending_transfer.bump = ctx.bumps.pending_transfer;
+
+    emit!(TransferPrepared {
+        event_config: event_config.key(),
+        pending_transfer: pending_transfer.key(),
+        holder,
+    });
+
+    msg!("✅ Transfer prepared: nullifier created, ticket spent");
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{EVENT_SEED, GATE_USE_SEED};
+use crate::errors::EncoreError;
+use crate::gate::{gate_use_seed, TicketGate, TicketPass};
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::state::{EventConfig, FieldValue, GateUse, OwnershipMode, PrivateTicket};
+
+#[derive(Accounts)]
+pub struct VerifyTicket<'info> {
+    /// Ticket holder (also pays fees)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Event owner (not required to sign)
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    /// Gated program's signer (typically a PDA); required for single-use
+    /// passes so only it can use up its scope
+    pub verifier: Option<Signer<'info>>,
+}
+
+/// Prove the holder has a live ticket that passes `gate`, without
+/// spending it. Returns a `TicketPass` (see `crate::gate`).
+///
+/// The ticket is rewritten unchanged, which only succeeds for a ticket
+/// that exists and whose commitment the holder can open, so it must be
+/// unredeemed and unspent. Its address stays the same; clients refetch
+/// it before the next use.
+///
+/// # Single use
+/// With `use_scope`, the `verifier` co-signs and a `GateUse` is created
+/// for (verifier, scope, seat): the same seat can't pass that verifier's
+/// scope twice, even after changing hands.
+///
+/// # Operations
+/// 1. Check the ticket's data against the gate
+/// 2. UPDATE ticket unchanged (proves ownership) (+ CREATE gate use)
+pub fn verify_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, VerifyTicket<'info>>,
+    proof: ValidityProof,
+    address_tree_info: Option<PackedAddressTreeInfo>,
+    output_state_tree_index: u8,
+    ticket_account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    holder_secret: [u8; 32],
+    ownership: OwnershipMode,
+    gate: TicketGate,
+    use_scope: Option<[u8; 32]>,
+) -> Result<TicketPass> {
+    let event_config = &ctx.accounts.event_config;
+    let holder = ctx.accounts.holder.key();
+
+    let owner_commitment = match ownership {
+        OwnershipMode::Bound => {
+            // commitment = SHA256(owner_pubkey || secret)
+            let mut commitment_input = Vec::with_capacity(64);
+            commitment_input.extend_from_slice(holder.as_ref());
+            commitment_input.extend_from_slice(&holder_secret);
+            hash(&commitment_input).to_bytes()
+        }
+        OwnershipMode::Key(key) => {
+            require_keys_eq!(key, holder, EncoreError::NotTicketOwner);
+            key.to_bytes()
+        }
+        OwnershipMode::Unbound => return err!(EncoreError::UnboundTicket),
+    };
+
+    let ticket = PrivateTicket {
+        event_config: event_config.key(),
+        ticket_id,
+        owner_commitment,
+        original_price,
+        usd_micros_per_sol,
+        name_commitment,
+        link_id,
+        provenance,
+        custom_fields,
+        addons,
+        ownership,
+    };
+    require!(gate.admits(&ticket), EncoreError::TicketGateNotMet);
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.holder.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    // --- Step 1: Rewrite the ticket unchanged ---
+    let ticket_account =
+        LightAccount::<PrivateTicket>::new_mut(&crate::ID, &ticket_account_meta, ticket)?;
+
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    let cpi = LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(ticket_account)?;
+
+    // --- Step 2: Use up the verifier's scope for this seat ---
+    let single_use = match use_scope {
+        Some(scope) => {
+            let verifier = ctx
+                .accounts
+                .verifier
+                .as_ref()
+                .ok_or(EncoreError::VerifierRequired)?
+                .key();
+            let address_tree_info = address_tree_info.ok_or(EncoreError::InvalidAddressTree)?;
+            let address_tree_pubkey = address_tree_info
+                .get_tree_pubkey(&light_cpi_accounts)
+                .map_err(|_| EncoreError::InvalidAddressTree)?;
+            check_address_tree(&address_tree_pubkey)?;
+
+            let seed = gate_use_seed(&verifier, &scope, &event_config.key(), ticket_id);
+            let (gate_use_address, gate_use_address_seed) = derive_address(
+                &[GATE_USE_SEED, seed.as_ref()],
+                &address_tree_pubkey,
+                &crate::ID,
+            );
+            let mut gate_use_account = LightAccount::<GateUse>::new_init(
+                &crate::ID,
+                Some(gate_use_address),
+                output_state_tree_index,
+            );
+            gate_use_account.verifier = verifier;
+            gate_use_account.event_config = event_config.key();
+
+            // Outputs: ticket (index 0), gate use (index 1)
+            cpi.with_light_account(gate_use_account)?
+                .with_new_addresses(&[address_tree_info
+                    .into_new_address_params_assigned_packed(gate_use_address_seed, Some(1))])
+                .invoke(light_cpi_accounts)?;
+            Some((verifier, scope))
+        }
+        None => {
+            cpi.invoke(light_cpi_accounts)?;
+            None
+        }
+    };
+
+    msg!("✅ Ticket {} passed the gate", ticket_id);
+
+    Ok(TicketPass {
+        gate,
+        holder,
+        ticket_id,
+        single_use,
+    })
+}
@@ -0,0 +1,191 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{account_meta::CompressedAccountMeta, ValidityProof},
+};
+
+use crate::constants::{EVENT_SEED, PROTOCOL_SEED};
+use crate::errors::EncoreError;
+use crate::events::{TicketVoided, WaitlistSeatReleased};
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::invariants;
+use crate::memo::memo_hash;
+use crate::state::{
+    EventConfig, FieldValue, HoldStatus, OwnershipMode, PrivateTicket, ProceedsHold,
+    ProtocolConfig, VoidReason, Waitlist,
+};
+
+#[derive(Accounts)]
+pub struct VoidTicket<'info> {
+    /// Event authority voiding the ticket (also pays fees)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol admin co-signer, required when `protocol_config.void_requires_admin` is set
+    pub protocol_admin: Option<Signer<'info>>,
+
+    /// Waitlist of the voided ticket's tier; pass to release the seat to it
+    #[account(
+        mut,
+        constraint = waitlist.event_config == event_config.key() @ EncoreError::Unauthorized,
+    )]
+    pub waitlist: Option<Account<'info, Waitlist>>,
+
+    /// Held resale proceeds for this ticket; pass to let the buyer reclaim them
+    #[account(
+        mut,
+        constraint = proceeds_hold.event_config == event_config.key() @ EncoreError::ProceedsHoldMismatch,
+    )]
+    pub proceeds_hold: Option<Account<'info, ProceedsHold>>,
+
+    /// CHECK: Instructions sysvar; pass to attach the transaction's SPL memo
+    #[account(address = ix_sysvar::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+/// Void a fraudulent ticket (chargeback, stolen card).
+///
+/// The organizer cannot know the holder's secret, so instead of creating a
+/// nullifier the ticket's compressed account is closed directly. The proof
+/// must show the ticket with exactly these fields exists in the state tree.
+/// Every instruction that spends a ticket closes it too, so a voided ticket
+/// can't be transferred, sold, redeemed or surrendered afterwards.
+///
+/// # Memo
+/// An SPL memo in the same transaction is validated and its hash recorded
+/// in `TicketVoided`; events with `require_void_memo` reject voids without
+/// one (pass `instructions_sysvar`).
+///
+/// # Operations
+/// 1. Check admin co-signature and the memo if the event requires it
+/// 2. CLOSE the ticket compressed account
+/// 3. Record the void on the event and emit the reason code
+/// 4. Release the seat to the tier's waitlist, if one was passed
+/// 5. Mark held resale proceeds for this ticket reclaimable by the buyer
+pub fn void_ticket<'info>(
+    ctx: Context<'_, '_, '_, 'info, VoidTicket<'info>>,
+    proof: ValidityProof,
+    account_meta: CompressedAccountMeta,
+    ticket_id: u32,
+    owner_commitment: [u8; 32],
+    original_price: u64,
+    usd_micros_per_sol: Option<u64>,
+    name_commitment: Option<[u8; 32]>,
+    link_id: Option<[u8; 32]>,
+    provenance: [u8; 32],
+    custom_fields: Vec<FieldValue>,
+    addons: u8,
+    ownership: OwnershipMode,
+    reason: VoidReason,
+) -> Result<()> {
+    let protocol_config = &ctx.accounts.protocol_config;
+    if protocol_config.void_requires_admin {
+        let admin = ctx
+            .accounts
+            .protocol_admin
+            .as_ref()
+            .ok_or(EncoreError::AdminCosignRequired)?;
+        require_keys_eq!(
+            admin.key(),
+            protocol_config.admin,
+            EncoreError::Unauthorized
+        );
+    }
+
+    let memo = memo_hash(ctx.accounts.instructions_sysvar.as_deref())?;
+    let event_config = &mut ctx.accounts.event_config;
+    require!(
+        memo.is_some() || !event_config.require_void_memo,
+        EncoreError::MemoRequired
+    );
+    require!(
+        ticket_id > 0 && ticket_id <= event_config.tickets_minted,
+        EncoreError::InvalidTicket
+    );
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.authority.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    // The input hash only matches if every field is exactly what was minted
+    let ticket_account = LightAccount::<PrivateTicket>::new_close(
+        &crate::ID,
+        &account_meta,
+        PrivateTicket {
+            event_config: event_config.key(),
+            ticket_id,
+            owner_commitment,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            ownership,
+        },
+    )?;
+
+    // --- Execute CPI: CLOSE ticket ---
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(ticket_account)?
+        .invoke(light_cpi_accounts)?;
+
+    event_config.tickets_voided = event_config
+        .tickets_voided
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(TicketVoided {
+        event_config: event_config.key(),
+        ticket_id,
+        reason,
+        voided_at: Clock::get()?.unix_timestamp,
+        memo_hash: memo,
+    });
+
+    if let Some(waitlist) = ctx.accounts.waitlist.as_mut() {
+        waitlist.open_seats = waitlist
+            .open_seats
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        emit!(WaitlistSeatReleased {
+            waitlist: waitlist.key(),
+            tier: waitlist.tier,
+            open_seats: waitlist.open_seats,
+        });
+    }
+
+    if let Some(hold) = ctx.accounts.proceeds_hold.as_mut() {
+        hold.settle(&account_meta.address, HoldStatus::Voided)?;
+    }
+
+    msg!("✅ Ticket {} voided: {:?}", ticket_id, reason);
+
+    invariants::check_event(&ctx.accounts.event_config)?;
+
+    Ok(())
+}
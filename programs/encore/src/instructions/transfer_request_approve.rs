@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::TRANSFER_REQUEST_SEED;
+use crate::errors::EncoreError;
+use crate::events::TransferRequestReviewed;
+use crate::state::{EventConfig, TransferRequest};
+
+#[derive(Accounts)]
+pub struct ApproveTransferRequest<'info> {
+    /// Event authority or the policy's approver
+    pub approver: Signer<'info>,
+
+    #[account(address = transfer_request.event_config)]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            TRANSFER_REQUEST_SEED,
+            transfer_request.event_config.as_ref(),
+            &transfer_request.secret_hash,
+        ],
+        bump = transfer_request.bump,
+    )]
+    pub transfer_request: Account<'info, TransferRequest>,
+}
+
+/// Approve a pending transfer request so the holder can execute it.
+pub fn approve_transfer_request(ctx: Context<ApproveTransferRequest>) -> Result<()> {
+    let approver = &ctx.accounts.approver;
+    require!(
+        ctx.accounts
+            .event_config
+            .can_approve_transfers(approver.key),
+        EncoreError::Unauthorized
+    );
+
+    let transfer_request = &mut ctx.accounts.transfer_request;
+    transfer_request.approved_at = Some(Clock::get()?.unix_timestamp);
+
+    emit!(TransferRequestReviewed {
+        event_config: transfer_request.event_config,
+        transfer_request: transfer_request.key(),
+        reviewer: approver.key(),
+        approved: true,
+    });
+
+    msg!("✅ Transfer request approved");
+
+    Ok(())
+}
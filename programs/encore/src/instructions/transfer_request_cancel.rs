@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::TRANSFER_REQUEST_SEED;
+use crate::errors::EncoreError;
+use crate::events::TransferRequestReviewed;
+use crate::state::{EventConfig, TransferRequest};
+
+#[derive(Accounts)]
+pub struct CancelTransferRequest<'info> {
+    /// Holder withdrawing the request, or an approver rejecting it
+    pub signer: Signer<'info>,
+
+    /// CHECK: Request creator, receives the rent
+    #[account(mut, address = transfer_request.holder)]
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(address = transfer_request.event_config)]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            TRANSFER_REQUEST_SEED,
+            transfer_request.event_config.as_ref(),
+            &transfer_request.secret_hash,
+        ],
+        bump = transfer_request.bump,
+        close = holder,
+    )]
+    pub transfer_request: Account<'info, TransferRequest>,
+}
+
+/// Withdraw (holder) or reject (approver) a transfer request, returning
+/// its rent to the holder.
+pub fn cancel_transfer_request(ctx: Context<CancelTransferRequest>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    require!(
+        signer == ctx.accounts.holder.key()
+            || ctx.accounts.event_config.can_approve_transfers(&signer),
+        EncoreError::Unauthorized
+    );
+
+    emit!(TransferRequestReviewed {
+        event_config: ctx.accounts.event_config.key(),
+        transfer_request: ctx.accounts.transfer_request.key(),
+        reviewer: signer,
+        approved: false,
+    });
+
+    msg!("✅ Transfer request cancelled");
+
+    Ok(())
+}
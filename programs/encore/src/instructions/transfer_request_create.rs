@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::TRANSFER_REQUEST_SEED;
+use crate::events::TransferRequested;
+use crate::state::{EventConfig, TransferKind, TransferRequest};
+
+#[derive(Accounts)]
+#[instruction(secret_hash: [u8; 32])]
+pub struct CreateTransferRequest<'info> {
+    /// Ticket holder asking to transfer (pays rent)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + TransferRequest::INIT_SPACE,
+        seeds = [TRANSFER_REQUEST_SEED, event_config.key().as_ref(), &secret_hash],
+        bump
+    )]
+    pub transfer_request: Account<'info, TransferRequest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Ask the organizer to approve a transfer.
+///
+/// Ownership is not checked here: `transfer_ticket` still requires the
+/// holder to reveal the secret behind `secret_hash` when it executes.
+pub fn create_transfer_request(
+    ctx: Context<CreateTransferRequest>,
+    secret_hash: [u8; 32],
+    new_owner_commitment: [u8; 32],
+    kind: TransferKind,
+) -> Result<()> {
+    let transfer_request = &mut ctx.accounts.transfer_request;
+    transfer_request.event_config = ctx.accounts.event_config.key();
+    transfer_request.holder = ctx.accounts.holder.key();
+    transfer_request.secret_hash = secret_hash;
+    transfer_request.new_owner_commitment = new_owner_commitment;
+    transfer_request.kind = kind;
+    transfer_request.requested_at = Clock::get()?.unix_timestamp;
+    transfer_request.approved_at = None;
+    transfer_request.bump = ctx.bumps.transfer_request;
+
+    emit!(TransferRequested {
+        event_config: transfer_request.event_config,
+        transfer_request: transfer_request.key(),
+        holder: transfer_request.holder,
+        kind,
+    });
+
+    msg!("✅ Transfer request submitted");
+
+    Ok(())
+}
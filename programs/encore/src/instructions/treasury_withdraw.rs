@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{PROTOCOL_SEED, TREASURY_SEED};
+use crate::errors::EncoreError;
+use crate::events::TreasuryWithdrawn;
+use crate::state::{ProtocolConfig, ProtocolTreasury};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [PROTOCOL_SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ EncoreError::Unauthorized
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    /// CHECK: Any account chosen by the admin to receive the funds
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+/// Withdraw collected protocol fees. Only the protocol admin may call this.
+///
+/// # Operations
+/// 1. Check the treasury stays rent-exempt after the withdrawal
+/// 2. Move lamports from the treasury to the recipient
+/// 3. Record the withdrawal
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+    let rent_minimum = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available = treasury_info.lamports().saturating_sub(rent_minimum);
+    require!(
+        amount > 0 && amount <= available,
+        EncoreError::InsufficientTreasuryBalance
+    );
+
+    // Treasury is program-owned, so lamports are moved directly
+    treasury_info.sub_lamports(amount)?;
+    ctx.accounts.recipient.add_lamports(amount)?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.withdrawn = treasury
+        .withdrawn
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    emit!(TreasuryWithdrawn {
+        admin: ctx.accounts.admin.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+    });
+
+    msg!("💰 Withdrew {} lamports from treasury", amount);
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{EVENT_SEED, WAITLIST_SEED};
+use crate::errors::EncoreError;
+use crate::state::{EventConfig, Waitlist};
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct CreateWaitlist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [EVENT_SEED, authority.key().as_ref()],
+        bump = event_config.bump,
+        has_one = authority @ EncoreError::Unauthorized
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Waitlist::INIT_SPACE,
+        seeds = [WAITLIST_SEED, event_config.key().as_ref(), &[tier]],
+        bump
+    )]
+    pub waitlist: Account<'info, Waitlist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a waitlist for a tier at a fixed price.
+pub fn create_waitlist(ctx: Context<CreateWaitlist>, tier: u8, price_lamports: u64) -> Result<()> {
+    require!(price_lamports > 0, EncoreError::InvalidPrice);
+    require!(
+        ctx.accounts.event_config.settles_in(None),
+        EncoreError::SettlementMintMismatch
+    );
+
+    let waitlist = &mut ctx.accounts.waitlist;
+    waitlist.event_config = ctx.accounts.event_config.key();
+    waitlist.tier = tier;
+    waitlist.price_lamports = price_lamports;
+    waitlist.head = 0;
+    waitlist.tail = 0;
+    waitlist.open_seats = 0;
+    waitlist.bump = ctx.bumps.waitlist;
+
+    msg!("✅ Waitlist opened for tier {}", tier);
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::{BLOCKED_BUYER_SEED, LEDGER_SEED, WAITLIST_ENTRY_SEED, WAITLIST_SEED};
+use crate::errors::EncoreError;
+use crate::events::WaitlistJoined;
+use crate::state::{EventLedger, LedgerEntryKind, Waitlist, WaitlistEntry};
+
+#[derive(Accounts)]
+pub struct JoinWaitlist<'info> {
+    /// Buyer joining the waitlist
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, waitlist.event_config.as_ref(), &[waitlist.tier]],
+        bump = waitlist.bump,
+    )]
+    pub waitlist: Account<'info, Waitlist>,
+
+    /// Waitlist entry, also holds the buyer's payment
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + WaitlistEntry::INIT_SPACE,
+        seeds = [WAITLIST_ENTRY_SEED, waitlist.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+
+    /// CHECK: Blocklist entry for the buyer on the event; must not exist
+    #[account(
+        seeds = [BLOCKED_BUYER_SEED, waitlist.event_config.as_ref(), buyer.key().as_ref()],
+        bump,
+        constraint = blocked_buyer.data_is_empty() @ EncoreError::BuyerBlocked,
+    )]
+    pub blocked_buyer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, waitlist.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Join a tier's waitlist, escrowing the waitlist price.
+///
+/// When a seat in the tier is freed, `promote_waitlist` mints a ticket to
+/// `owner_commitment` for the entry at the head of the list.
+pub fn join_waitlist(
+    ctx: Context<JoinWaitlist>,
+    owner_commitment: [u8; 32],
+    ticket_address_seed: [u8; 32],
+) -> Result<()> {
+    let waitlist = &mut ctx.accounts.waitlist;
+    let price = waitlist.price_lamports;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.waitlist_entry.to_account_info(),
+            },
+        ),
+        price,
+    )?;
+
+    ctx.accounts
+        .ledger
+        .record(LedgerEntryKind::EscrowDeposit, price)?;
+
+    let position = waitlist.tail;
+    waitlist.tail += 1;
+
+    let entry = &mut ctx.accounts.waitlist_entry;
+    entry.waitlist = waitlist.key();
+    entry.buyer = ctx.accounts.buyer.key();
+    entry.owner_commitment = owner_commitment;
+    entry.ticket_address_seed = ticket_address_seed;
+    entry.position = position;
+    entry.amount = price;
+    entry.withdrawn = false;
+    entry.joined_at = Clock::get()?.unix_timestamp;
+    entry.bump = ctx.bumps.waitlist_entry;
+
+    emit!(WaitlistJoined {
+        waitlist: waitlist.key(),
+        buyer: entry.buyer,
+        position,
+    });
+
+    msg!("✅ Joined waitlist at position {}", position);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LEDGER_SEED, WAITLIST_ENTRY_SEED};
+use crate::errors::EncoreError;
+use crate::state::{EventLedger, LedgerEntryKind, Waitlist, WaitlistEntry};
+
+#[derive(Accounts)]
+pub struct LeaveWaitlist<'info> {
+    /// Buyer leaving the waitlist
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(address = waitlist_entry.waitlist)]
+    pub waitlist: Account<'info, Waitlist>,
+
+    #[account(
+        mut,
+        seeds = [WAITLIST_ENTRY_SEED, waitlist.key().as_ref(), buyer.key().as_ref()],
+        bump = waitlist_entry.bump,
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, waitlist.event_config.as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+}
+
+/// Leave the waitlist and get the payment back.
+///
+/// The entry stays (holding only rent) so positions are preserved; it is
+/// closed when promotion reaches and skips it.
+pub fn leave_waitlist(ctx: Context<LeaveWaitlist>) -> Result<()> {
+    let entry = &mut ctx.accounts.waitlist_entry;
+    require!(!entry.withdrawn, EncoreError::WaitlistEntryWithdrawn);
+
+    let amount = entry.amount;
+    entry.sub_lamports(amount)?;
+    ctx.accounts.buyer.add_lamports(amount)?;
+
+    entry.amount = 0;
+    entry.withdrawn = true;
+
+    ctx.accounts
+        .ledger
+        .record(LedgerEntryKind::EscrowRefund, amount)?;
+
+    msg!("💰 Refunded {} lamports to waitlisted buyer", amount);
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{v2::CpiAccounts, InvokeLightSystemProgram, LightCpiInstruction},
+    instruction::{PackedAddressTreeInfo, ValidityProof},
+};
+
+use crate::address_tree::{check_address_tree, derive_address};
+use crate::constants::{
+    EVENT_SEED, LEDGER_SEED, METRICS_SEED, PROTOCOL_SEED, TICKET_SEED, TREASURY_SEED,
+    WAITLIST_ENTRY_SEED, WAITLIST_SEED,
+};
+use crate::errors::EncoreError;
+use crate::events::{ProtocolFeeCollected, TicketIndexed, WaitlistPromoted};
+use crate::instructions::ticket_mint::LIGHT_CPI_SIGNER;
+use crate::invariants;
+use crate::state::{
+    EventConfig, EventLedger, FeeSource, LedgerEntryKind, PrivateTicket, ProtocolConfig,
+    ProtocolMetrics, ProtocolTreasury, Waitlist, WaitlistEntry,
+};
+
+#[derive(Accounts)]
+pub struct PromoteWaitlist<'info> {
+    /// Keeper advancing the waitlist (pays fees)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Event owner, receives the waitlisted payment
+    #[account(mut)]
+    pub event_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event_owner.key().as_ref()],
+        bump = event_config.bump,
+    )]
+    pub event_config: Account<'info, EventConfig>,
+
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event_config.key().as_ref(), &[waitlist.tier]],
+        bump = waitlist.bump,
+    )]
+    pub waitlist: Account<'info, Waitlist>,
+
+    /// Entry at the head of the waitlist - closed and rent returned to buyer
+    #[account(
+        mut,
+        seeds = [WAITLIST_ENTRY_SEED, waitlist.key().as_ref(), waitlist_entry.buyer.as_ref()],
+        bump = waitlist_entry.bump,
+        close = buyer,
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+
+    /// CHECK: Waitlisted buyer, receives the closed account's rent
+    #[account(mut, address = waitlist_entry.buyer)]
+    pub buyer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED, event_config.key().as_ref()],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, EventLedger>,
+
+    #[account(seeds = [PROTOCOL_SEED], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Protocol treasury receiving the mint fee
+    #[account(mut, seeds = [TREASURY_SEED], bump = treasury.bump)]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    /// Protocol-wide totals
+    #[account(mut, seeds = [METRICS_SEED], bump = metrics.bump)]
+    pub metrics: Account<'info, ProtocolMetrics>,
+}
+
+/// Give a freed seat to the head of a tier's waitlist.
+/// Permissionless, so keepers can promote as soon as a seat is released.
+///
+/// A withdrawn entry at the head is skipped (closed) without using the
+/// seat; call again for the next position.
+///
+/// # Operations
+/// 1. Validate the entry is at the head and a seat is open
+/// 2. CREATE ticket with the entry's commitment
+/// 3. Pay the escrowed price to the organizer, minus the protocol mint fee
+pub fn promote_waitlist<'info>(
+    ctx: Context<'_, '_, '_, 'info, PromoteWaitlist<'info>>,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+) -> Result<()> {
+    let waitlist = &mut ctx.accounts.waitlist;
+    let entry = &ctx.accounts.waitlist_entry;
+
+    require!(
+        entry.position == waitlist.head,
+        EncoreError::NotWaitlistHead
+    );
+
+    if entry.withdrawn {
+        waitlist.head += 1;
+        msg!("Skipped withdrawn entry at position {}", entry.position);
+        return Ok(());
+    }
+
+    let event_config = &mut ctx.accounts.event_config;
+    require!(!event_config.cancelled, EncoreError::EventCancelled);
+    require!(waitlist.open_seats > 0, EncoreError::NoOpenSeats);
+
+    waitlist.head += 1;
+    waitlist.open_seats -= 1;
+
+    let ticket_id = event_config
+        .tickets_minted
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.payer.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    let address_tree_pubkey = address_tree_info
+        .get_tree_pubkey(&light_cpi_accounts)
+        .map_err(|_| EncoreError::InvalidAddressTree)?;
+
+    check_address_tree(&address_tree_pubkey)?;
+
+    // --- Step 2: Create ticket ---
+    let (ticket_address, ticket_seed) = derive_address(
+        &[
+            TICKET_SEED,
+            event_config
+                .ticket_address_seed(&event_config.key(), ticket_id, entry.ticket_address_seed)
+                .as_ref(),
+        ],
+        &address_tree_pubkey,
+        &crate::ID,
+    );
+
+    let mut ticket_account = LightAccount::<PrivateTicket>::new_init(
+        &crate::ID,
+        Some(ticket_address),
+        output_state_tree_index,
+    );
+    ticket_account.event_config = event_config.key();
+    ticket_account.ticket_id = ticket_id;
+    ticket_account.owner_commitment = entry.owner_commitment;
+    ticket_account.original_price = entry.amount;
+    ticket_account.name_commitment = None;
+    ticket_account.link_id = None;
+
+    use light_sdk::cpi::v2::LightSystemProgramCpi;
+
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, proof)
+        .with_light_account(ticket_account)?
+        .with_new_addresses(&[
+            address_tree_info.into_new_address_params_assigned_packed(ticket_seed, Some(0))
+        ])
+        .invoke(light_cpi_accounts)?;
+
+    event_config.tickets_minted = ticket_id;
+    ctx.accounts.metrics.record_mints(1)?;
+
+    // --- Step 3: Pay organizer and protocol fee from the entry's escrow ---
+    let amount = entry.amount;
+    let protocol_fee = ctx.accounts.protocol_config.mint_fee(amount);
+    let organizer_revenue = amount - protocol_fee;
+
+    // Entry is program-owned, so lamports are moved directly
+    entry.sub_lamports(amount)?;
+    ctx.accounts.event_owner.add_lamports(organizer_revenue)?;
+    if protocol_fee > 0 {
+        ctx.accounts.treasury.add_lamports(protocol_fee)?;
+        ctx.accounts
+            .treasury
+            .record_fee(FeeSource::Mint, protocol_fee)?;
+        ctx.accounts.metrics.record_fee(protocol_fee)?;
+
+        emit!(ProtocolFeeCollected {
+            event_config: event_config.key(),
+            source: FeeSource::Mint,
+            amount: protocol_fee,
+        });
+    }
+
+    let ledger = &mut ctx.accounts.ledger;
+    ledger.record(LedgerEntryKind::MintRevenue, organizer_revenue)?;
+    ledger.record(LedgerEntryKind::ProtocolFee, protocol_fee)?;
+
+    emit!(TicketIndexed {
+        event_config: event_config.key(),
+        ticket_id,
+        address: ticket_address,
+    });
+
+    emit!(WaitlistPromoted {
+        event_config: event_config.key(),
+        waitlist: waitlist.key(),
+        tier: waitlist.tier,
+        buyer: entry.buyer,
+        ticket_id,
+    });
+
+    msg!("✅ Waitlisted buyer promoted to ticket {}", ticket_id);
+
+    invariants::check_event(&ctx.accounts.event_config)?;
+
+    Ok(())
+}
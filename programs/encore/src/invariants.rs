@@ -0,0 +1,105 @@
+//! Cross-field invariants checked at the end of state-changing instructions.
+//!
+//! The checks only run when the program is built with the
+//! `strict-invariants` feature; otherwise every function returns `Ok(())`
+//! and compiles away. Enable the feature for integration tests and devnet
+//! deployments so state corruption fails the transaction that caused it
+//! rather than surfacing instructions later.
+//!
+//! Checks are lower bounds where outside parties can add lamports: anyone
+//! can transfer SOL into an escrow or refund account, so only a shortfall
+//! against the recorded deposits is a violation.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::EncoreError;
+use crate::state::{EventConfig, Listing, ListingStatus, PartialRefund};
+
+fn ensure(holds: bool, invariant: &str) -> Result<()> {
+    if !holds {
+        msg!("❌ Invariant violated: {}", invariant);
+        return err!(EncoreError::InvariantViolated);
+    }
+    Ok(())
+}
+
+/// Supply counters stay within `max_supply` and the hold-back.
+pub fn check_event(event_config: &EventConfig) -> Result<()> {
+    if !cfg!(feature = "strict-invariants") {
+        return Ok(());
+    }
+    let retired = event_config
+        .tickets_voided
+        .checked_add(event_config.tickets_refunded);
+    let active = retired.and_then(|retired| event_config.tickets_minted.checked_sub(retired));
+    ensure(
+        active.is_some_and(|active| active <= event_config.max_supply),
+        "tickets_active <= max_supply",
+    )?;
+    ensure(
+        event_config
+            .tickets_minted
+            .checked_add(event_config.allocated_supply)
+            .is_some_and(|total| total <= event_config.max_supply),
+        "tickets_minted + allocated_supply <= max_supply",
+    )?;
+    ensure(
+        event_config.reserved_minted <= event_config.reserved_supply
+            && event_config.reserved_minted <= event_config.tickets_minted,
+        "reserved_minted <= reserved_supply, tickets_minted",
+    )
+}
+
+/// Listing status moved along a legal edge. Completed and Cancelled are
+/// terminal.
+pub fn check_listing_transition(before: &ListingStatus, after: &ListingStatus) -> Result<()> {
+    if !cfg!(feature = "strict-invariants") {
+        return Ok(());
+    }
+    let legal = matches!(
+        (before, after),
+        (ListingStatus::Active, ListingStatus::Active)
+            | (ListingStatus::Active, ListingStatus::Claimed(_))
+            | (ListingStatus::Active, ListingStatus::Cancelled)
+            | (ListingStatus::Claimed(_), ListingStatus::Active)
+            | (ListingStatus::Claimed(_), ListingStatus::Claimed(_))
+            | (ListingStatus::Claimed(_), ListingStatus::Completed)
+            | (ListingStatus::Claimed(_), ListingStatus::Cancelled)
+    );
+    ensure(legal, "legal listing status transition")
+}
+
+/// A SOL claim's escrow holds at least the listing price.
+pub fn check_escrow(listing: &Listing, escrow_lamports: u64) -> Result<()> {
+    if !cfg!(feature = "strict-invariants") {
+        return Ok(());
+    }
+    let deposited = match listing.claim() {
+        Some(claim) if claim.payment_mint.is_none() => listing.price_lamports,
+        _ => 0,
+    };
+    ensure(
+        escrow_lamports >= deposited,
+        "escrow lamports >= claimed price",
+    )
+}
+
+/// A partial refund holds everything funded and not yet claimed on top of
+/// its rent.
+pub fn check_partial_refund(partial_refund: &Account<PartialRefund>) -> Result<()> {
+    if !cfg!(feature = "strict-invariants") {
+        return Ok(());
+    }
+    let rent = Rent::get()?.minimum_balance(8 + PartialRefund::INIT_SPACE);
+    let available = partial_refund
+        .to_account_info()
+        .lamports()
+        .saturating_sub(rent);
+    ensure(
+        partial_refund
+            .funded
+            .checked_sub(partial_refund.claimed)
+            .is_some_and(|outstanding| outstanding <= available),
+        "partial refund balance >= funded - claimed",
+    )
+}
@@ -1,13 +1,40 @@
+// `#[program]` generates IDL instructions that resize with the deprecated
+// `AccountInfo::realloc`
+#![allow(deprecated)]
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
-use light_sdk::instruction::{PackedAddressTreeInfo, ValidityProof};
+use light_sdk::instruction::{
+    account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof,
+};
 
+pub mod address_tree;
+pub mod bps;
 pub mod constants;
 pub mod errors;
 pub mod events;
+pub mod gate;
 pub mod instructions;
+pub mod invariants;
+pub mod light_error;
+pub mod memo;
+pub mod policy_hook;
+pub mod proof_window;
+pub mod secret;
+pub mod signature;
 pub mod state;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
 
+use gate::{TicketGate, TicketPass};
 use instructions::*;
+use proof_window::ProofWindow;
+use secret::SecretDerivation;
+use state::{
+    CompliancePolicy, CredentialRequirement, EmissionPolicy, FieldDef, FieldValue, IdentityCounter,
+    InsurancePolicy, OwnershipMode, PaymentOption, RefundCredit, ReleaseTranche, RoyaltyConfig,
+    SettlementReport, SignedOrder, TicketAddressMode, TransferKind, TransferPolicy, VoidReason,
+};
 
 declare_id!("BjapcaBemidgideMDLWX4wujtnEETZknmNyv28uXVB7V");
 
@@ -24,6 +51,7 @@ pub mod encore {
         event_description: String,
         max_tickets_per_person: u8,
         event_timestamp: i64,
+        settlement_mint: Option<Pubkey>,
     ) -> Result<()> {
         instructions::create_event(
             ctx,
@@ -34,11 +62,78 @@ pub mod encore {
             event_description,
             max_tickets_per_person,
             event_timestamp,
+            settlement_mint,
+        )
+    }
+
+    pub fn create_event_idempotent(
+        ctx: Context<CreateEventIdempotent>,
+        max_supply: u32,
+        resale_cap_bps: u32,
+        event_name: String,
+        event_location: String,
+        event_description: String,
+        max_tickets_per_person: u8,
+        event_timestamp: i64,
+        settlement_mint: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_event_idempotent(
+            ctx,
+            max_supply,
+            resale_cap_bps,
+            event_name,
+            event_location,
+            event_description,
+            max_tickets_per_person,
+            event_timestamp,
+            settlement_mint,
         )
     }
 
-    pub fn update_event(ctx: Context<UpdateEvent>, resale_cap_bps: Option<u32>) -> Result<()> {
-        instructions::update_event(ctx, resale_cap_bps)
+    pub fn update_event(
+        ctx: Context<UpdateEvent>,
+        resale_cap_bps: Option<u32>,
+        mint_rate_limit: Option<u8>,
+        mint_rate_window_seconds: Option<u32>,
+        max_mints_per_slot: Option<u8>,
+        pow_difficulty: Option<u8>,
+        credit_validity_seconds: Option<u32>,
+        transfer_policy: Option<TransferPolicy>,
+        require_paid_sales: Option<bool>,
+        escrow_resale_proceeds: Option<bool>,
+        emission_policy: Option<EmissionPolicy>,
+        reserved_supply: Option<u32>,
+        require_void_memo: Option<bool>,
+        ticket_address_mode: Option<TicketAddressMode>,
+        secret_derivation: Option<Option<SecretDerivation>>,
+        require_scanner: Option<bool>,
+        resale_blackout_seconds: Option<u32>,
+        fire_code_capacity: Option<u32>,
+        door_release_step: Option<u32>,
+        identity_epoch_seconds: Option<u32>,
+    ) -> Result<()> {
+        instructions::update_event(
+            ctx,
+            resale_cap_bps,
+            mint_rate_limit,
+            mint_rate_window_seconds,
+            max_mints_per_slot,
+            pow_difficulty,
+            credit_validity_seconds,
+            transfer_policy,
+            require_paid_sales,
+            escrow_resale_proceeds,
+            emission_policy,
+            reserved_supply,
+            require_void_memo,
+            ticket_address_mode,
+            secret_derivation,
+            require_scanner,
+            resale_blackout_seconds,
+            fire_code_capacity,
+            door_release_step,
+            identity_epoch_seconds,
+        )
     }
 
     pub fn mint_ticket<'info>(
@@ -49,6 +144,19 @@ pub mod encore {
         owner_commitment: [u8; 32],
         purchase_price: u64,
         ticket_address_seed: [u8; 32],
+        identity_account_meta: Option<CompressedAccountMeta>,
+        current_identity: Option<IdentityCounter>,
+        pow_slot: u64,
+        pow_nonce: u64,
+        credit_account_meta: Option<CompressedAccountMeta>,
+        current_credit: Option<RefundCredit>,
+        name_commitment: Option<[u8; 32]>,
+        companion: Option<CompanionSeat>,
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+        proof_window: Option<ProofWindow>,
+        ownership: OwnershipMode,
     ) -> Result<()> {
         instructions::mint_ticket(
             ctx,
@@ -58,6 +166,19 @@ pub mod encore {
             owner_commitment,
             purchase_price,
             ticket_address_seed,
+            identity_account_meta,
+            current_identity,
+            pow_slot,
+            pow_nonce,
+            credit_account_meta,
+            current_credit,
+            name_commitment,
+            companion,
+            custom_fields,
+            addons,
+            allowlist_proof,
+            proof_window,
+            ownership,
         )
     }
 
@@ -70,70 +191,130 @@ pub mod encore {
         proof: ValidityProof,
         address_tree_info: PackedAddressTreeInfo,
         output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
         current_ticket_id: u32,
         current_original_price: u64,
+        current_usd_micros_per_sol: Option<u64>,
+        current_name_commitment: Option<[u8; 32]>,
+        current_provenance: [u8; 32],
+        current_custom_fields: Vec<FieldValue>,
+        current_addons: u8,
         seller_secret: [u8; 32],
         new_owner_commitment: [u8; 32],
         new_ticket_address_seed: [u8; 32],
-        resale_price: Option<u64>,
+        kind: TransferKind,
+        link_id: Option<[u8; 32]>,
+        companion: Option<CompanionTransfer>,
+        proof_window: Option<ProofWindow>,
+        new_ownership: OwnershipMode,
     ) -> Result<()> {
         instructions::transfer_ticket(
             ctx,
             proof,
             address_tree_info,
             output_state_tree_index,
+            ticket_account_meta,
             current_ticket_id,
             current_original_price,
+            current_usd_micros_per_sol,
+            current_name_commitment,
+            current_provenance,
+            current_custom_fields,
+            current_addons,
             seller_secret,
             new_owner_commitment,
             new_ticket_address_seed,
-            resale_price,
+            kind,
+            link_id,
+            companion,
+            proof_window,
+            new_ownership,
         )
     }
 
     pub fn create_listing(
         ctx: Context<CreateListing>,
         ticket_commitment: [u8; 32],
-        encrypted_secret: [u8; 32],
+        nullifier_seed: [u8; 32],
         price_lamports: u64,
         event_config: Pubkey,
         ticket_id: u32,
         ticket_address_seed: [u8; 32],
         ticket_bump: u8,
+        official_exchange: Option<u64>,
+        seller_bond: u64,
+        auto_relist: bool,
+        original_price: u64,
     ) -> Result<()> {
         instructions::create_listing(
             ctx,
             ticket_commitment,
-            encrypted_secret,
+            nullifier_seed,
             price_lamports,
             event_config,
             ticket_id,
             ticket_address_seed,
             ticket_bump,
+            official_exchange,
+            seller_bond,
+            auto_relist,
+            original_price,
         )
     }
 
-    pub fn claim_listing(ctx: Context<ClaimListing>, buyer_commitment: [u8; 32]) -> Result<()> {
-        instructions::claim_listing(ctx, buyer_commitment)
+    pub fn claim_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimListing<'info>>,
+        buyer_commitment: [u8; 32],
+        client_nonce: u64,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        proof_window: Option<ProofWindow>,
+    ) -> Result<()> {
+        instructions::claim_listing(
+            ctx,
+            buyer_commitment,
+            client_nonce,
+            proof,
+            address_tree_info,
+            proof_window,
+        )
     }
 
     pub fn complete_sale<'info>(
         ctx: Context<'_, '_, '_, 'info, CompleteSale<'info>>,
         proof: ValidityProof,
+        unspent_proof: ValidityProof,
         address_tree_info: PackedAddressTreeInfo,
         output_state_tree_index: u8,
         new_ticket_address_seed: [u8; 32],
         ticket_bump: u8,
-        seller_secret: [u8; 32],
+        ticket_account_meta: CompressedAccountMeta,
+        current_original_price: u64,
+        current_usd_micros_per_sol: Option<u64>,
+        current_name_commitment: Option<[u8; 32]>,
+        current_provenance: [u8; 32],
+        current_custom_fields: Vec<FieldValue>,
+        current_addons: u8,
+        proof_window: Option<ProofWindow>,
+        queued_refunds: u8,
     ) -> Result<()> {
         instructions::complete_sale(
             ctx,
             proof,
+            unspent_proof,
             address_tree_info,
             output_state_tree_index,
             new_ticket_address_seed,
             ticket_bump,
-            seller_secret,
+            ticket_account_meta,
+            current_original_price,
+            current_usd_micros_per_sol,
+            current_name_commitment,
+            current_provenance,
+            current_custom_fields,
+            current_addons,
+            proof_window,
+            queued_refunds,
         )
     }
 
@@ -145,6 +326,10 @@ pub mod encore {
         instructions::close_listing(ctx)
     }
 
+    pub fn collect_listing(ctx: Context<CollectListing>) -> Result<()> {
+        instructions::collect_listing(ctx)
+    }
+
     pub fn cancel_claim(ctx: Context<CancelClaim>) -> Result<()> {
         instructions::cancel_claim(ctx)
     }
@@ -156,4 +341,1327 @@ pub mod encore {
     pub fn release_claim(ctx: Context<ReleaseClaim>) -> Result<()> {
         instructions::release_claim(ctx)
     }
+
+    /// Queue a backup claim behind a listing's active claim.
+    pub fn queue_claim(ctx: Context<QueueClaim>, buyer_commitment: [u8; 32]) -> Result<()> {
+        instructions::queue_claim(ctx, buyer_commitment)
+    }
+
+    /// Promote the head of the claim queue once the listing is Active again.
+    pub fn promote_queued_claim<'info>(
+        ctx: Context<'_, '_, '_, 'info, PromoteQueuedClaim<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+    ) -> Result<()> {
+        instructions::promote_queued_claim(ctx, proof, address_tree_info)
+    }
+
+    pub fn withdraw_queued_claim(ctx: Context<WithdrawQueuedClaim>) -> Result<()> {
+        instructions::withdraw_queued_claim(ctx)
+    }
+
+    /// Refund a queued claim after its listing is finished.
+    pub fn refund_queued_claim(ctx: Context<RefundQueuedClaim>) -> Result<()> {
+        instructions::refund_queued_claim(ctx)
+    }
+
+    pub fn initialize_protocol(
+        ctx: Context<InitializeProtocol>,
+        admin: Pubkey,
+        void_requires_admin: bool,
+        mint_fee_bps: u16,
+        sale_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::initialize_protocol(
+            ctx,
+            admin,
+            void_requires_admin,
+            mint_fee_bps,
+            sale_fee_bps,
+        )
+    }
+
+    pub fn update_protocol(
+        ctx: Context<UpdateProtocol>,
+        new_admin: Option<Pubkey>,
+        void_requires_admin: Option<bool>,
+        mint_fee_bps: Option<u16>,
+        sale_fee_bps: Option<u16>,
+        cancellation_oracle: Option<Option<Pubkey>>,
+    ) -> Result<()> {
+        instructions::update_protocol(
+            ctx,
+            new_admin,
+            void_requires_admin,
+            mint_fee_bps,
+            sale_fee_bps,
+            cancellation_oracle,
+        )
+    }
+
+    /// Void a fraudulent ticket by closing its compressed account.
+    /// - Event authority signs (protocol admin co-signs if required)
+    /// - Reason code is recorded in the `TicketVoided` event
+    pub fn void_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoidTicket<'info>>,
+        proof: ValidityProof,
+        account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        owner_commitment: [u8; 32],
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        ownership: OwnershipMode,
+        reason: VoidReason,
+    ) -> Result<()> {
+        instructions::void_ticket(
+            ctx,
+            proof,
+            account_meta,
+            ticket_id,
+            owner_commitment,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            ownership,
+            reason,
+        )
+    }
+
+    pub fn add_blocked_buyer(ctx: Context<AddBlockedBuyer>, wallet: Pubkey) -> Result<()> {
+        instructions::add_blocked_buyer(ctx, wallet)
+    }
+
+    pub fn remove_blocked_buyer(ctx: Context<RemoveBlockedBuyer>) -> Result<()> {
+        instructions::remove_blocked_buyer(ctx)
+    }
+
+    pub fn set_required_credential(
+        ctx: Context<SetRequiredCredential>,
+        required_credential: Option<CredentialRequirement>,
+    ) -> Result<()> {
+        instructions::set_required_credential(ctx, required_credential)
+    }
+
+    pub fn cancel_event(ctx: Context<CancelEvent>) -> Result<()> {
+        instructions::cancel_event(ctx)
+    }
+
+    pub fn set_insurance_policy(
+        ctx: Context<SetInsurancePolicy>,
+        insurance_policy: Option<InsurancePolicy>,
+    ) -> Result<()> {
+        instructions::set_insurance_policy(ctx, insurance_policy)
+    }
+
+    pub fn claim_insurance<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimInsurance<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        holder_secret: [u8; 32],
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+    ) -> Result<()> {
+        instructions::claim_insurance(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            holder_secret,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+        )
+    }
+
+    pub fn issue_refund_credit<'info>(
+        ctx: Context<'_, '_, '_, 'info, IssueRefundCredit<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        holder_secret: [u8; 32],
+        credit_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::issue_refund_credit(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            holder_secret,
+            credit_address_seed,
+        )
+    }
+
+    pub fn initialize_price_feed(
+        ctx: Context<InitializePriceFeed>,
+        oracle_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_price_feed(ctx, oracle_authority)
+    }
+
+    pub fn update_price_feed(ctx: Context<UpdatePriceFeed>, usd_micros_per_sol: u64) -> Result<()> {
+        instructions::update_price_feed(ctx, usd_micros_per_sol)
+    }
+
+    /// Withdraw collected protocol fees (protocol admin only).
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::withdraw_treasury(ctx, amount)
+    }
+
+    pub fn add_fee_exemption(
+        ctx: Context<AddFeeExemption>,
+        subject: Pubkey,
+        discount_bps: u16,
+    ) -> Result<()> {
+        instructions::add_fee_exemption(ctx, subject, discount_bps)
+    }
+
+    pub fn remove_fee_exemption(ctx: Context<RemoveFeeExemption>) -> Result<()> {
+        instructions::remove_fee_exemption(ctx)
+    }
+
+    /// Settle a seller-signed off-chain order: buyer pays and receives the ticket atomically.
+    pub fn fill_signed_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillSignedOrder<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        order: SignedOrder,
+        seller_secret: [u8; 32],
+        buyer_commitment: [u8; 32],
+        new_ticket_address_seed: [u8; 32],
+        client_nonce: u64,
+        ticket_account_meta: CompressedAccountMeta,
+        current_name_commitment: Option<[u8; 32]>,
+        current_provenance: [u8; 32],
+        current_custom_fields: Vec<FieldValue>,
+        current_addons: u8,
+    ) -> Result<()> {
+        instructions::fill_signed_order(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            order,
+            seller_secret,
+            buyer_commitment,
+            new_ticket_address_seed,
+            client_nonce,
+            ticket_account_meta,
+            current_name_commitment,
+            current_provenance,
+            current_custom_fields,
+            current_addons,
+        )
+    }
+
+    /// Close an event after it has taken place (or was cancelled).
+    pub fn close_event(ctx: Context<CloseEvent>) -> Result<()> {
+        instructions::close_event(ctx)
+    }
+
+    /// Burn identity counters and nullifiers of a closed event.
+    pub fn prune_event_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, PruneEventAccounts<'info>>,
+        proof: ValidityProof,
+        identity_counters: Vec<PrunableIdentityCounter>,
+        nullifiers: Vec<PrunableNullifier>,
+    ) -> Result<()> {
+        instructions::prune_event_accounts(ctx, proof, identity_counters, nullifiers)
+    }
+
+    pub fn create_transfer_request(
+        ctx: Context<CreateTransferRequest>,
+        secret_hash: [u8; 32],
+        new_owner_commitment: [u8; 32],
+        kind: TransferKind,
+    ) -> Result<()> {
+        instructions::create_transfer_request(ctx, secret_hash, new_owner_commitment, kind)
+    }
+
+    pub fn approve_transfer_request(ctx: Context<ApproveTransferRequest>) -> Result<()> {
+        instructions::approve_transfer_request(ctx)
+    }
+
+    pub fn cancel_transfer_request(ctx: Context<CancelTransferRequest>) -> Result<()> {
+        instructions::cancel_transfer_request(ctx)
+    }
+
+    pub fn set_id_verification(
+        ctx: Context<SetIdVerification>,
+        id_verification: Option<CredentialRequirement>,
+    ) -> Result<()> {
+        instructions::set_id_verification(ctx, id_verification)
+    }
+
+    pub fn personalize_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, PersonalizeTicket<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        holder_secret: [u8; 32],
+        name_commitment: [u8; 32],
+        new_owner_commitment: [u8; 32],
+        new_ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::personalize_ticket(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            holder_secret,
+            name_commitment,
+            new_owner_commitment,
+            new_ticket_address_seed,
+        )
+    }
+
+    pub fn redeem_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemTicket<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        holder_secret: [u8; 32],
+        companion: Option<CompanionRedemption>,
+        store_receipt: bool,
+        zone: u8,
+        proof_window: Option<ProofWindow>,
+        ownership: OwnershipMode,
+    ) -> Result<()> {
+        instructions::redeem_ticket(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            holder_secret,
+            companion,
+            store_receipt,
+            zone,
+            proof_window,
+            ownership,
+        )
+    }
+
+    pub fn create_waitlist(
+        ctx: Context<CreateWaitlist>,
+        tier: u8,
+        price_lamports: u64,
+    ) -> Result<()> {
+        instructions::create_waitlist(ctx, tier, price_lamports)
+    }
+
+    pub fn join_waitlist(
+        ctx: Context<JoinWaitlist>,
+        owner_commitment: [u8; 32],
+        ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::join_waitlist(ctx, owner_commitment, ticket_address_seed)
+    }
+
+    pub fn leave_waitlist(ctx: Context<LeaveWaitlist>) -> Result<()> {
+        instructions::leave_waitlist(ctx)
+    }
+
+    pub fn promote_waitlist<'info>(
+        ctx: Context<'_, '_, '_, 'info, PromoteWaitlist<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+    ) -> Result<()> {
+        instructions::promote_waitlist(ctx, proof, address_tree_info, output_state_tree_index)
+    }
+
+    pub fn set_compliance_policy(
+        ctx: Context<SetCompliancePolicy>,
+        compliance_policy: Option<CompliancePolicy>,
+    ) -> Result<()> {
+        instructions::set_compliance_policy(ctx, compliance_policy)
+    }
+
+    pub fn withdraw_held_proceeds(ctx: Context<WithdrawHeldProceeds>) -> Result<()> {
+        instructions::withdraw_held_proceeds(ctx)
+    }
+
+    pub fn reclaim_held_proceeds(ctx: Context<ReclaimHeldProceeds>) -> Result<()> {
+        instructions::reclaim_held_proceeds(ctx)
+    }
+
+    pub fn publish_report(
+        ctx: Context<PublishReport>,
+        report: SettlementReport,
+        report_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::publish_report(ctx, report, report_hash)
+    }
+
+    pub fn set_royalty(ctx: Context<SetRoyalty>, royalty: Option<RoyaltyConfig>) -> Result<()> {
+        instructions::set_royalty(ctx, royalty)
+    }
+
+    pub fn distribute_royalties<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeRoyalties<'info>>,
+    ) -> Result<()> {
+        instructions::distribute_royalties(ctx)
+    }
+
+    pub fn open_payout_accrual(ctx: Context<OpenPayoutAccrual>, recipient: Pubkey) -> Result<()> {
+        instructions::open_payout_accrual(ctx, recipient)
+    }
+
+    pub fn claim_payout(ctx: Context<ClaimPayout>) -> Result<()> {
+        instructions::claim_payout(ctx)
+    }
+
+    pub fn audit_nullifiers(ctx: Context<AuditNullifiers>) -> Result<()> {
+        instructions::audit_nullifiers(ctx)
+    }
+
+    pub fn set_custom_fields(
+        ctx: Context<SetCustomFields>,
+        custom_fields: Vec<FieldDef>,
+    ) -> Result<()> {
+        instructions::set_custom_fields(ctx, custom_fields)
+    }
+
+    pub fn add_addon(
+        ctx: Context<AddAddOn>,
+        name: [u8; 16],
+        price_lamports: u64,
+        supply: u32,
+    ) -> Result<()> {
+        instructions::add_addon(ctx, name, price_lamports, supply)
+    }
+
+    pub fn set_release_schedule(
+        ctx: Context<SetReleaseSchedule>,
+        release_schedule: Vec<ReleaseTranche>,
+    ) -> Result<()> {
+        instructions::set_release_schedule(ctx, release_schedule)
+    }
+
+    pub fn set_payment_options(
+        ctx: Context<SetPaymentOptions>,
+        options: Vec<PaymentOption>,
+    ) -> Result<()> {
+        instructions::set_payment_options(ctx, options)
+    }
+
+    pub fn swap_for_purchase<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapForPurchase<'info>>,
+        route_data: Vec<u8>,
+        min_out: u64,
+    ) -> Result<()> {
+        instructions::swap_for_purchase(ctx, route_data, min_out)
+    }
+
+    pub fn redeem_tickets_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemTicketsBatch<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        tickets: Vec<BatchRedemption>,
+        zone: u8,
+    ) -> Result<()> {
+        instructions::redeem_tickets_batch(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            tickets,
+            zone,
+        )
+    }
+
+    pub fn register_scanner(
+        ctx: Context<RegisterScanner>,
+        device_key: Pubkey,
+        zones: u32,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::register_scanner(ctx, device_key, zones, expires_at)
+    }
+
+    pub fn revoke_scanner(ctx: Context<RevokeScanner>, zones: u32) -> Result<()> {
+        instructions::revoke_scanner(ctx, zones)
+    }
+
+    pub fn sweep_expired_claim(ctx: Context<SweepExpiredClaim>) -> Result<()> {
+        instructions::sweep_expired_claim(ctx)
+    }
+
+    pub fn open_partial_refund(
+        ctx: Context<OpenPartialRefund>,
+        refund_bps: u16,
+        claim_deadline: i64,
+        total_owed: u64,
+    ) -> Result<()> {
+        instructions::open_partial_refund(ctx, refund_bps, claim_deadline, total_owed)
+    }
+
+    pub fn fund_partial_refund(ctx: Context<FundPartialRefund>, amount: u64) -> Result<()> {
+        instructions::fund_partial_refund(ctx, amount)
+    }
+
+    pub fn claim_partial_refund<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimPartialRefund<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        holder_secret: [u8; 32],
+        min_payout: u64,
+    ) -> Result<()> {
+        instructions::claim_partial_refund(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            holder_secret,
+            min_payout,
+        )
+    }
+
+    pub fn top_up_partial_refund<'info>(
+        ctx: Context<'_, '_, '_, 'info, TopUpPartialRefund<'info>>,
+        proof: ValidityProof,
+        claim_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        owed: u64,
+        paid: u64,
+        min_payout: u64,
+    ) -> Result<()> {
+        instructions::top_up_partial_refund(
+            ctx,
+            proof,
+            claim_account_meta,
+            ticket_id,
+            owed,
+            paid,
+            min_payout,
+        )
+    }
+
+    pub fn preview_partial_refund(
+        ctx: Context<PreviewPartialRefund>,
+        original_price: u64,
+    ) -> Result<()> {
+        instructions::preview_partial_refund(ctx, original_price)
+    }
+
+    pub fn close_partial_refund(ctx: Context<ClosePartialRefund>) -> Result<()> {
+        instructions::close_partial_refund(ctx)
+    }
+
+    pub fn checkout(
+        ctx: Context<Checkout>,
+        event_configs: Vec<Pubkey>,
+        max_total: u64,
+    ) -> Result<()> {
+        instructions::checkout(ctx, event_configs, max_total)
+    }
+
+    pub fn register_mint_delegate(
+        ctx: Context<RegisterMintDelegate>,
+        delegate: Pubkey,
+        quota: u32,
+    ) -> Result<()> {
+        instructions::register_mint_delegate(ctx, delegate, quota)
+    }
+
+    pub fn revoke_mint_delegate(ctx: Context<RevokeMintDelegate>) -> Result<()> {
+        instructions::revoke_mint_delegate(ctx)
+    }
+
+    pub fn mint_comp_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintCompTicket<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        owner_commitment: [u8; 32],
+        ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::mint_comp_ticket(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            owner_commitment,
+            ticket_address_seed,
+        )
+    }
+
+    pub fn create_allocation(
+        ctx: Context<CreateAllocation>,
+        partner: Pubkey,
+        size: u32,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::create_allocation(ctx, partner, size, deadline)
+    }
+
+    pub fn return_allocation(ctx: Context<ReturnAllocation>) -> Result<()> {
+        instructions::return_allocation(ctx)
+    }
+
+    pub fn set_resale_cap_base(ctx: Context<SetResaleCapBase>, usd_adjusted: bool) -> Result<()> {
+        instructions::set_resale_cap_base(ctx, usd_adjusted)
+    }
+
+    pub fn get_version(ctx: Context<GetVersion>) -> Result<ProgramInfo> {
+        instructions::get_version(ctx)
+    }
+
+    pub fn publish_snapshot(ctx: Context<PublishSnapshot>, snapshot_root: [u8; 32]) -> Result<()> {
+        instructions::publish_snapshot(ctx, snapshot_root)
+    }
+
+    pub fn set_presale(ctx: Context<SetPresale>, ends_at: i64) -> Result<()> {
+        instructions::set_presale(ctx, ends_at)
+    }
+
+    pub fn create_gift<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateGift<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        claim_key: Pubkey,
+        ticket_account_meta: CompressedAccountMeta,
+        current_ticket_id: u32,
+        current_original_price: u64,
+        current_usd_micros_per_sol: Option<u64>,
+        current_name_commitment: Option<[u8; 32]>,
+        current_provenance: [u8; 32],
+        current_custom_fields: Vec<FieldValue>,
+        current_addons: u8,
+        sender_secret: [u8; 32],
+        return_commitment: [u8; 32],
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_gift(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            claim_key,
+            ticket_account_meta,
+            current_ticket_id,
+            current_original_price,
+            current_usd_micros_per_sol,
+            current_name_commitment,
+            current_provenance,
+            current_custom_fields,
+            current_addons,
+            sender_secret,
+            return_commitment,
+            expires_at,
+        )
+    }
+
+    pub fn claim_gift<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimGift<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        new_owner_commitment: [u8; 32],
+        new_ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::claim_gift(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            new_owner_commitment,
+            new_ticket_address_seed,
+        )
+    }
+
+    pub fn cancel_gift<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelGift<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        new_owner_commitment: [u8; 32],
+        new_ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::cancel_gift(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            new_owner_commitment,
+            new_ticket_address_seed,
+        )
+    }
+
+    pub fn expire_gift<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExpireGift<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        new_ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::expire_gift(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            new_ticket_address_seed,
+        )
+    }
+
+    pub fn post_announcement(
+        ctx: Context<PostAnnouncement>,
+        content_hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        instructions::post_announcement(ctx, content_hash, uri)
+    }
+
+    pub fn release_door_capacity(ctx: Context<ReleaseDoorCapacity>, amount: u32) -> Result<()> {
+        instructions::release_door_capacity(ctx, amount)
+    }
+
+    pub fn propose_seat_reassignment(
+        ctx: Context<ProposeSeatReassignment>,
+        ticket_id: u32,
+        custom_fields: Vec<FieldValue>,
+        compensation: u64,
+    ) -> Result<()> {
+        instructions::propose_seat_reassignment(ctx, ticket_id, custom_fields, compensation)
+    }
+
+    pub fn accept_seat_reassignment<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptSeatReassignment<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        holder_secret: [u8; 32],
+        new_owner_commitment: [u8; 32],
+        new_ticket_address_seed: [u8; 32],
+        credit_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::accept_seat_reassignment(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            holder_secret,
+            new_owner_commitment,
+            new_ticket_address_seed,
+            credit_address_seed,
+        )
+    }
+
+    pub fn withdraw_seat_reassignment(ctx: Context<WithdrawSeatReassignment>) -> Result<()> {
+        instructions::withdraw_seat_reassignment(ctx)
+    }
+
+    pub fn oracle_cancel_event(
+        ctx: Context<OracleCancelEvent>,
+        attestation_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::oracle_cancel_event(ctx, attestation_hash)
+    }
+
+    pub fn register_fiat_provider(
+        ctx: Context<RegisterFiatProvider>,
+        psp: Pubkey,
+        settlement_seconds: u32,
+        max_price: u64,
+    ) -> Result<()> {
+        instructions::register_fiat_provider(ctx, psp, settlement_seconds, max_price)
+    }
+
+    pub fn create_fiat_hold<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateFiatHold<'info>>,
+        owner_commitment: [u8; 32],
+        original_price: u64,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        identity_account_meta: Option<CompressedAccountMeta>,
+        current_identity: Option<IdentityCounter>,
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+    ) -> Result<()> {
+        instructions::create_fiat_hold(
+            ctx,
+            owner_commitment,
+            original_price,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            identity_account_meta,
+            current_identity,
+            allowlist_proof,
+        )
+    }
+
+    pub fn finalize_fiat_hold<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeFiatHold<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::finalize_fiat_hold(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_address_seed,
+        )
+    }
+
+    pub fn clawback_fiat_hold(ctx: Context<ClawbackFiatHold>) -> Result<()> {
+        instructions::clawback_fiat_hold(ctx)
+    }
+
+    pub fn publish_holder_count(
+        ctx: Context<PublishHolderCount>,
+        distinct_holders: u32,
+        holders_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::publish_holder_count(ctx, distinct_holders, holders_root)
+    }
+
+    pub fn preview_mint(
+        ctx: Context<PreviewMint>,
+        purchase_price: u64,
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        with_companion: bool,
+        credit_amount: u64,
+        insured: bool,
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+    ) -> Result<()> {
+        instructions::preview_mint(
+            ctx,
+            purchase_price,
+            custom_fields,
+            addons,
+            with_companion,
+            credit_amount,
+            insured,
+            allowlist_proof,
+        )
+    }
+
+    pub fn preview_complete_sale(ctx: Context<PreviewCompleteSale>) -> Result<()> {
+        instructions::preview_complete_sale(ctx)
+    }
+
+    pub fn prepare_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, PrepareTransfer<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        secret_hash: [u8; 32],
+        holder_secret: [u8; 32],
+        new_owner_commitment: [u8; 32],
+        kind: TransferKind,
+        current_ticket_id: u32,
+        current_original_price: u64,
+        current_usd_micros_per_sol: Option<u64>,
+        current_name_commitment: Option<[u8; 32]>,
+        current_provenance: [u8; 32],
+        current_custom_fields: Vec<FieldValue>,
+        current_addons: u8,
+    ) -> Result<()> {
+        instructions::prepare_transfer(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            secret_hash,
+            holder_secret,
+            new_owner_commitment,
+            kind,
+            current_ticket_id,
+            current_original_price,
+            current_usd_micros_per_sol,
+            current_name_commitment,
+            current_provenance,
+            current_custom_fields,
+            current_addons,
+        )
+    }
+
+    pub fn finalize_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeTransfer<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        new_ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::finalize_transfer(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            new_ticket_address_seed,
+        )
+    }
+
+    pub fn cancel_pending_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelPendingTransfer<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        new_ticket_address_seed: [u8; 32],
+        new_owner_commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::cancel_pending_transfer(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            new_ticket_address_seed,
+            new_owner_commitment,
+        )
+    }
+
+    pub fn set_fan_lane(
+        ctx: Context<SetFanLane>,
+        share_bps: u16,
+        window_seconds: u32,
+    ) -> Result<()> {
+        instructions::set_fan_lane(ctx, share_bps, window_seconds)
+    }
+
+    pub fn issue_fan_attestation(ctx: Context<IssueFanAttestation>, fan: Pubkey) -> Result<()> {
+        instructions::issue_fan_attestation(ctx, fan)
+    }
+
+    pub fn revoke_fan_attestation(ctx: Context<RevokeFanAttestation>) -> Result<()> {
+        instructions::revoke_fan_attestation(ctx)
+    }
+
+    pub fn configure_sponsor_pool(
+        ctx: Context<ConfigureSponsorPool>,
+        relayer: Pubkey,
+        lamports_per_mint: u64,
+        max_per_user: u64,
+    ) -> Result<()> {
+        instructions::configure_sponsor_pool(ctx, relayer, lamports_per_mint, max_per_user)
+    }
+
+    pub fn fund_sponsor_pool(ctx: Context<FundSponsorPool>, amount: u64) -> Result<()> {
+        instructions::fund_sponsor_pool(ctx, amount)
+    }
+
+    pub fn withdraw_sponsor_pool(ctx: Context<WithdrawSponsorPool>, amount: u64) -> Result<()> {
+        instructions::withdraw_sponsor_pool(ctx, amount)
+    }
+
+    pub fn bind_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, BindTicket<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        secret: [u8; 32],
+        new_owner_commitment: [u8; 32],
+        new_ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::bind_ticket(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            secret,
+            new_owner_commitment,
+            new_ticket_address_seed,
+        )
+    }
+
+    pub fn transfer_keyed_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferKeyedTicket<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        new_owner_commitment: [u8; 32],
+        new_ownership: OwnershipMode,
+        new_ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::transfer_keyed_ticket(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            provenance,
+            custom_fields,
+            addons,
+            new_owner_commitment,
+            new_ownership,
+            new_ticket_address_seed,
+        )
+    }
+
+    pub fn configure_guardian_set(
+        ctx: Context<ConfigureGuardianSet>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+        recovery_delay_seconds: i64,
+    ) -> Result<()> {
+        instructions::configure_guardian_set(ctx, guardians, threshold, recovery_delay_seconds)
+    }
+
+    pub fn guard_ticket(ctx: Context<GuardTicket>, secret: [u8; 32]) -> Result<()> {
+        instructions::guard_ticket(ctx, secret)
+    }
+
+    pub fn unguard_ticket(ctx: Context<UnguardTicket>, ticket_commitment: [u8; 32]) -> Result<()> {
+        instructions::unguard_ticket(ctx, ticket_commitment)
+    }
+
+    pub fn approve_recovery(
+        ctx: Context<ApproveRecovery>,
+        ticket_commitment: [u8; 32],
+        new_owner_commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::approve_recovery(ctx, ticket_commitment, new_owner_commitment)
+    }
+
+    pub fn veto_recovery(ctx: Context<VetoRecovery>) -> Result<()> {
+        instructions::veto_recovery(ctx)
+    }
+
+    pub fn recover_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, RecoverTicket<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        new_ticket_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::recover_ticket(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            new_ticket_address_seed,
+        )
+    }
+
+    pub fn refund_cancelled_claim(ctx: Context<RefundCancelledClaim>) -> Result<()> {
+        instructions::refund_cancelled_claim(ctx)
+    }
+
+    pub fn open_ticket_exchange(
+        ctx: Context<OpenTicketExchange>,
+        price_lamports: u64,
+        size: u32,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::open_ticket_exchange(ctx, price_lamports, size, deadline)
+    }
+
+    pub fn return_ticket_exchange(ctx: Context<ReturnTicketExchange>) -> Result<()> {
+        instructions::return_ticket_exchange(ctx)
+    }
+
+    pub fn exchange_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExchangeTicket<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        holder_secret: [u8; 32],
+        new_owner_commitment: [u8; 32],
+        new_ticket_address_seed: [u8; 32],
+        credit_address_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::exchange_ticket(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            holder_secret,
+            new_owner_commitment,
+            new_ticket_address_seed,
+            credit_address_seed,
+        )
+    }
+
+    pub fn verify_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerifyTicket<'info>>,
+        proof: ValidityProof,
+        address_tree_info: Option<PackedAddressTreeInfo>,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        holder_secret: [u8; 32],
+        ownership: OwnershipMode,
+        gate: TicketGate,
+        use_scope: Option<[u8; 32]>,
+    ) -> Result<TicketPass> {
+        instructions::verify_ticket(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            holder_secret,
+            ownership,
+            gate,
+            use_scope,
+        )
+    }
+
+    pub fn disclose_ticket<'info>(
+        ctx: Context<'_, '_, '_, 'info, DiscloseTicket<'info>>,
+        proof: ValidityProof,
+        address_tree_info: PackedAddressTreeInfo,
+        output_state_tree_index: u8,
+        ticket_account_meta: CompressedAccountMeta,
+        ticket_id: u32,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        name_commitment: Option<[u8; 32]>,
+        link_id: Option<[u8; 32]>,
+        provenance: [u8; 32],
+        custom_fields: Vec<FieldValue>,
+        addons: u8,
+        holder_secret: [u8; 32],
+        ownership: OwnershipMode,
+        view_key_seed: [u8; 32],
+    ) -> Result<()> {
+        instructions::disclose_ticket(
+            ctx,
+            proof,
+            address_tree_info,
+            output_state_tree_index,
+            ticket_account_meta,
+            ticket_id,
+            original_price,
+            usd_micros_per_sol,
+            name_commitment,
+            link_id,
+            provenance,
+            custom_fields,
+            addons,
+            holder_secret,
+            ownership,
+            view_key_seed,
+        )
+    }
+
+    pub fn revoke_disclosure<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeDisclosure<'info>>,
+        proof: ValidityProof,
+        disclosure_account_meta: CompressedAccountMeta,
+        event_config: Pubkey,
+        ticket_address: [u8; 32],
+        ticket_id: u32,
+    ) -> Result<()> {
+        instructions::revoke_disclosure(
+            ctx,
+            proof,
+            disclosure_account_meta,
+            event_config,
+            ticket_address,
+            ticket_id,
+        )
+    }
+
+    pub fn register_domain(
+        ctx: Context<RegisterDomain>,
+        domain_hash: [u8; 32],
+        domain: String,
+        release_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::register_domain(ctx, domain_hash, domain, release_hash)
+    }
+
+    pub fn remove_domain(ctx: Context<RemoveDomain>) -> Result<()> {
+        instructions::remove_domain(ctx)
+    }
 }
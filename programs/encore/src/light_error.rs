@@ -0,0 +1,88 @@
+use anchor_lang::solana_program::instruction::InstructionError;
+
+use crate::errors::EncoreError;
+
+/// Light error codes with an Encore meaning, as of light-protocol 0.17.
+///
+/// A failed Light CPI aborts the transaction with the Light program's own
+/// custom error code, so the program can't catch and rewrap it; clients
+/// translate the failure with `from_instruction_error` instead. Light's
+/// codes start at 10000 and never collide with `EncoreError`'s.
+pub mod codes {
+    /// `BloomFilterError::Full`: the address (a nullifier or ticket) is
+    /// already in the tree's input queue
+    pub const ADDRESS_ALREADY_EXISTS: &[u32] = &[14201];
+    /// `VerifierError::ProofVerificationFailed`: the proof's root was
+    /// rotated out of the tree's root history, or the proof is for other
+    /// inputs
+    pub const PROOF_INVALID: &[u32] = &[13006];
+    /// `BatchedMerkleTreeError::TreeIsFull`
+    pub const TREE_FULL: &[u32] = &[14310];
+}
+
+/// The `EncoreError` a Light custom error code stands for, if any.
+///
+/// Addresses only collide on nullifiers (tickets use fresh seeds), so an
+/// existing address means the ticket was already spent.
+pub fn from_light_code(code: u32) -> Option<EncoreError> {
+    if codes::ADDRESS_ALREADY_EXISTS.contains(&code) {
+        Some(EncoreError::TicketAlreadyTransferred)
+    } else if codes::PROOF_INVALID.contains(&code) {
+        Some(EncoreError::StaleProof)
+    } else if codes::TREE_FULL.contains(&code) {
+        Some(EncoreError::TreeCapacityExceeded)
+    } else {
+        None
+    }
+}
+
+/// The `EncoreError` a failed instruction's Light CPI error stands for.
+/// Errors Encore raised itself already carry their own code.
+pub fn from_instruction_error(error: &InstructionError) -> Option<EncoreError> {
+    match *error {
+        InstructionError::Custom(code) => from_light_code(code),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapped(code: u32) -> Option<u32> {
+        from_instruction_error(&InstructionError::Custom(code)).map(u32::from)
+    }
+
+    #[test]
+    fn address_exists_is_already_transferred() {
+        for &code in codes::ADDRESS_ALREADY_EXISTS {
+            assert_eq!(
+                mapped(code),
+                Some(EncoreError::TicketAlreadyTransferred.into())
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_proof_is_stale() {
+        for &code in codes::PROOF_INVALID {
+            assert_eq!(mapped(code), Some(EncoreError::StaleProof.into()));
+        }
+    }
+
+    #[test]
+    fn full_tree_is_capacity_exceeded() {
+        for &code in codes::TREE_FULL {
+            assert_eq!(mapped(code), Some(EncoreError::TreeCapacityExceeded.into()));
+        }
+    }
+
+    #[test]
+    fn other_errors_are_unmapped() {
+        assert_eq!(mapped(12_015), None);
+        assert_eq!(
+            from_instruction_error(&InstructionError::InvalidArgument).map(u32::from),
+            None
+        );
+    }
+}
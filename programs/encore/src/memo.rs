@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+
+use crate::constants::{MAX_MEMO_LEN, MEMO_PROGRAM_ID};
+use crate::errors::EncoreError;
+
+/// Hash of the SPL memo carried by the transaction, if any.
+///
+/// The memo program instruction must sit in the same transaction; the
+/// first one found is used. It must be non-empty UTF-8 of at most
+/// `MAX_MEMO_LEN` bytes. Events record the hash so the justification
+/// stays tied to the settlement without repeating its text.
+pub fn memo_hash(instructions_sysvar: Option<&AccountInfo>) -> Result<Option<[u8; 32]>> {
+    let Some(instructions_sysvar) = instructions_sysvar else {
+        return Ok(None);
+    };
+
+    let mut index = 0;
+    while let Ok(ix) = ix_sysvar::load_instruction_at_checked(index, instructions_sysvar) {
+        if ix.program_id == MEMO_PROGRAM_ID {
+            require!(
+                !ix.data.is_empty()
+                    && ix.data.len() <= MAX_MEMO_LEN
+                    && std::str::from_utf8(&ix.data).is_ok(),
+                EncoreError::InvalidMemo
+            );
+            return Ok(Some(hash(&ix.data).to_bytes()));
+        }
+        index += 1;
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+
+use crate::errors::EncoreError;
+use crate::state::{EventConfig, PolicyAction};
+
+/// Arguments passed to a policy hook's `check_policy` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PolicyCheck {
+    pub action: PolicyAction,
+    pub event_config: Pubkey,
+    pub subject: Pubkey,
+}
+
+/// Ask the event's policy hook, if any, to allow `action` by `subject`.
+///
+/// The hook is called as an Anchor-style `check_policy` instruction with
+/// accounts `[event_config, subject, attestation?]`, all read-only. It
+/// rejects by failing, which aborts the transaction.
+pub fn check_policy<'info>(
+    event_config: &Account<'info, EventConfig>,
+    subject: &AccountInfo<'info>,
+    hook_program: Option<&AccountInfo<'info>>,
+    attestation: Option<&AccountInfo<'info>>,
+    action: PolicyAction,
+) -> Result<()> {
+    let Some(hook) = event_config
+        .compliance_policy
+        .as_ref()
+        .and_then(|policy| policy.hook_program)
+    else {
+        return Ok(());
+    };
+
+    let hook_program = hook_program.ok_or(EncoreError::PolicyHookRequired)?;
+    require_keys_eq!(*hook_program.key, hook, EncoreError::PolicyHookRequired);
+
+    let mut data = hash(b"global:check_policy").to_bytes()[..8].to_vec();
+    PolicyCheck {
+        action,
+        event_config: event_config.key(),
+        subject: *subject.key,
+    }
+    .serialize(&mut data)?;
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(event_config.key(), false),
+        AccountMeta::new_readonly(*subject.key, false),
+    ];
+    let mut account_infos = vec![event_config.to_account_info(), subject.clone()];
+    if let Some(attestation) = attestation {
+        accounts.push(AccountMeta::new_readonly(*attestation.key, false));
+        account_infos.push(attestation.clone());
+    }
+    account_infos.push(hook_program.clone());
+
+    invoke(
+        &Instruction {
+            program_id: hook,
+            accounts,
+            data,
+        },
+        &account_infos,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EncoreError;
+use crate::events::ProofWindowExpired;
+
+/// Slots a validity proof is trusted for, set by the client when it
+/// fetches the proof.
+///
+/// Light keeps a bounded history of tree roots; a proof against a root
+/// that has rotated out fails inside the CPI with an opaque verifier
+/// error. Binding the proof to a slot window lets the program reject it
+/// up front with `ProofExpired`, before any work is done.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProofWindow {
+    /// Slot the client fetched the proof at
+    pub proof_slot: u64,
+
+    /// Slots after `proof_slot` the proof is accepted for
+    pub max_age_slots: u64,
+}
+
+/// Reject a proof whose window has passed. Instructions called without a
+/// window skip the check.
+///
+/// The `ProofWindowExpired` event lands in the failed transaction's logs
+/// (and in simulation), carrying the current slot so clients can refetch
+/// the proof and retry without guessing.
+pub fn check_proof_window(window: Option<ProofWindow>) -> Result<()> {
+    let Some(window) = window else {
+        return Ok(());
+    };
+    let current_slot = Clock::get()?.slot;
+    let expires_at_slot = window.proof_slot.saturating_add(window.max_age_slots);
+    if current_slot > expires_at_slot {
+        emit!(ProofWindowExpired {
+            proof_slot: window.proof_slot,
+            expires_at_slot,
+            current_slot,
+        });
+        return err!(EncoreError::ProofExpired);
+    }
+    Ok(())
+}
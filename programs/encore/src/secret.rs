@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::signature::find_ed25519_signature;
+
+/// Versions of the ticket secret derivation standard.
+///
+/// A holder's secret is `hash(wallet_sign(message))`. Ed25519 signatures
+/// are deterministic, so the wallet alone can re-derive any of its ticket
+/// secrets. Clients derive secrets with `message` and `derive_secret` so
+/// every wallet produces compatible secrets.
+///
+/// There's one standard secret per wallet and ticket id, so once it's
+/// spent (personalizing reveals and nullifies it) the ticket is reissued
+/// under a secret the holder keeps themselves.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum SecretDerivation {
+    /// `"ticket:{ticket_id}:{event_config}"`, event in base58
+    V1,
+}
+
+impl SecretDerivation {
+    /// Message the holder's wallet signs to derive the ticket's secret.
+    pub fn message(&self, ticket_id: u32, event_config: &Pubkey) -> Vec<u8> {
+        match self {
+            SecretDerivation::V1 => format!("ticket:{}:{}", ticket_id, event_config).into_bytes(),
+        }
+    }
+}
+
+/// Secret derived from the wallet's signature over the derivation message.
+pub fn derive_secret(signature: &[u8; 64]) -> [u8; 32] {
+    hash(signature).to_bytes()
+}
+
+/// Whether `secret` is `wallet`'s standard secret for the ticket: the
+/// transaction must carry an ed25519 instruction with `wallet`'s signature
+/// over the derivation message, and the secret must hash from it.
+pub fn is_derived_secret(
+    instructions_sysvar: &AccountInfo,
+    derivation: SecretDerivation,
+    wallet: &Pubkey,
+    ticket_id: u32,
+    event_config: &Pubkey,
+    secret: &[u8; 32],
+) -> bool {
+    let message = derivation.message(ticket_id, event_config);
+    find_ed25519_signature(instructions_sysvar, wallet, &message)
+        .is_some_and(|signature| derive_secret(&signature) == *secret)
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions as ix_sysvar;
+
+/// Size of one signature offsets entry in an ed25519 program instruction.
+const ED25519_OFFSETS_LEN: usize = 14;
+/// Start of the offsets entries (after num_signatures + padding).
+const ED25519_OFFSETS_START: usize = 2;
+/// Instruction index meaning "data lives in this same instruction".
+const ED25519_CURRENT_IX: u16 = u16::MAX;
+
+fn read_u16(data: &[u8], at: usize) -> Option<u16> {
+    data.get(at..at + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// The signature of the offsets entry at `base`, if it is over `message`
+/// by `signer`.
+///
+/// Only entries whose signature, key and message are embedded in the
+/// ed25519 instruction itself are considered.
+fn ed25519_entry_signature(
+    data: &[u8],
+    base: usize,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Option<[u8; 64]> {
+    let sig_offset = read_u16(data, base)? as usize;
+    let sig_ix = read_u16(data, base + 2)?;
+    let pk_offset = read_u16(data, base + 4)? as usize;
+    let pk_ix = read_u16(data, base + 6)?;
+    let msg_offset = read_u16(data, base + 8)? as usize;
+    let msg_size = read_u16(data, base + 10)? as usize;
+    let msg_ix = read_u16(data, base + 12)?;
+
+    if sig_ix != ED25519_CURRENT_IX || pk_ix != ED25519_CURRENT_IX || msg_ix != ED25519_CURRENT_IX {
+        return None;
+    }
+
+    if data.get(pk_offset..pk_offset + 32)? != signer.as_ref()
+        || data.get(msg_offset..msg_offset + msg_size)? != message
+    {
+        return None;
+    }
+    data.get(sig_offset..sig_offset + 64)?.try_into().ok()
+}
+
+/// The signature an ed25519 program instruction verifies for `signer` over `message`.
+fn ed25519_ix_signature(data: &[u8], signer: &Pubkey, message: &[u8]) -> Option<[u8; 64]> {
+    let count = data.first().copied().unwrap_or(0) as usize;
+    (0..count).find_map(|i| {
+        let base = ED25519_OFFSETS_START + i * ED25519_OFFSETS_LEN;
+        ed25519_entry_signature(data, base, signer, message)
+    })
+}
+
+/// Find the signature by `signer` over `message` that an ed25519 program
+/// instruction in this transaction verifies.
+pub fn find_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Option<[u8; 64]> {
+    let mut index = 0;
+    while let Ok(ix) = ix_sysvar::load_instruction_at_checked(index, instructions_sysvar) {
+        if ix.program_id == ed25519_program::ID {
+            if let Some(signature) = ed25519_ix_signature(&ix.data, signer, message) {
+                return Some(signature);
+            }
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Check that the transaction carries an ed25519 program instruction
+/// verifying `signer`'s signature over `message`.
+///
+/// The ed25519 program itself rejects the transaction if a signature is
+/// invalid, so finding a matching entry is enough.
+pub fn has_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> bool {
+    find_ed25519_signature(instructions_sysvar, signer, message).is_some()
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Add-on product sold with tickets (parking, merch, meet-and-greet).
+///
+/// Tickets record the add-ons bought as a bitmask over the event's add-on
+/// list, so add-ons are only ever appended.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AddOn {
+    /// Product name, zero-padded UTF-8
+    pub name: [u8; 16],
+
+    pub price_lamports: u64,
+
+    /// Units available and units sold
+    pub supply: u32,
+    pub sold: u32,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Block of an event's supply carved out for a partner (sponsor, travel
+/// partner), minted by the partner's own key.
+///
+/// Unminted tickets count in `EventConfig::allocated_supply`, out of reach
+/// of public mints, until the allocation is returned after its deadline.
+#[account]
+#[derive(InitSpace)]
+pub struct Allocation {
+    /// Event the allocation is carved from
+    pub event_config: Pubkey,
+
+    /// Partner key that mints from the allocation
+    pub partner: Pubkey,
+
+    /// Tickets carved out
+    pub size: u32,
+
+    /// Tickets minted so far
+    pub minted: u32,
+
+    /// After this time the organizer can return what's unminted
+    pub deadline: i64,
+
+    /// PDA bump for allocation derivation
+    pub bump: u8,
+}
+
+impl Allocation {
+    pub fn remaining(&self) -> u32 {
+        self.size.saturating_sub(self.minted)
+    }
+}
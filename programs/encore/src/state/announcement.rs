@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::MAX_ANNOUNCEMENT_URI_LEN;
+
+/// Per-event feed of official organizer notices (rescheduling, entry
+/// instructions).
+///
+/// Notices themselves live off-chain at `uri`; each post is emitted as
+/// `AnnouncementPosted` and folded into `head`, so wallets can check that a
+/// fetched notice matches its `content_hash` and that the sequence of
+/// posts they were shown is the one the organizer published.
+#[account]
+#[derive(InitSpace)]
+pub struct Announcements {
+    /// Event the notices are for
+    pub event_config: Pubkey,
+
+    /// Number of notices posted
+    pub count: u32,
+
+    /// Hash chain over every post: `next_head(previous, content_hash, uri)`
+    pub head: [u8; 32],
+
+    /// Latest notice, for clients that only show the current one
+    pub latest_hash: [u8; 32],
+    #[max_len(MAX_ANNOUNCEMENT_URI_LEN)]
+    pub latest_uri: String,
+    pub posted_at: i64,
+
+    /// PDA bump for announcements derivation
+    pub bump: u8,
+}
+
+impl Announcements {
+    pub fn next_head(head: &[u8; 32], content_hash: &[u8; 32], uri: &str) -> [u8; 32] {
+        hashv(&[head, content_hash, uri.as_bytes()]).to_bytes()
+    }
+}
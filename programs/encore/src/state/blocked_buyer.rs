@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Blocklist entry for a wallet on a specific event.
+///
+/// Existence is the flag: `mint_ticket` and `claim_listing` derive this PDA
+/// for the signer and reject if the account has been created.
+#[account]
+#[derive(InitSpace)]
+pub struct BlockedBuyer {
+    /// Event the block applies to
+    pub event_config: Pubkey,
+
+    /// Blocked wallet
+    pub wallet: Pubkey,
+
+    /// When the wallet was blocked
+    pub blocked_at: i64,
+
+    /// PDA bump for blocklist entry derivation
+    pub bump: u8,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Record of a client-supplied nonce, making a retried request a no-op.
+///
+/// Existence is the flag: the PDA is created with `init`, so resubmitting a
+/// request with the same nonce fails instead of claiming or paying twice.
+#[account]
+#[derive(InitSpace)]
+pub struct ClientNonce {
+    /// Wallet that used the nonce
+    pub owner: Pubkey,
+
+    /// Client-chosen nonce / order id
+    pub nonce: u64,
+
+    /// When the nonce was consumed
+    pub used_at: i64,
+
+    /// PDA bump for nonce derivation
+    pub bump: u8,
+}
+
+impl ClientNonce {
+    pub fn consume(&mut self, owner: Pubkey, nonce: u64, now: i64, bump: u8) {
+        self.owner = owner;
+        self.nonce = nonce;
+        self.used_at = now;
+        self.bump = bump;
+    }
+}
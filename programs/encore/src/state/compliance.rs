@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_POLICY_REGIONS, MAX_POLICY_VENUES};
+
+/// Whether the listed regions/venues are the only ones allowed or are barred.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PolicyMode {
+    Allow,
+    Deny,
+}
+
+/// Machine-readable restrictions an event publishes for compliance engines.
+///
+/// Encore stores but does not interpret the lists. When `hook_program` is
+/// set, mint and redeem CPI into it (see `policy_hook`) and the hook
+/// decides, so no jurisdiction's rules are hard-coded here.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq, Debug)]
+pub struct CompliancePolicy {
+    pub mode: PolicyMode,
+
+    /// ISO 3166-1 alpha-2 country codes
+    #[max_len(MAX_POLICY_REGIONS)]
+    pub regions: Vec<[u8; 2]>,
+
+    /// Organizer-defined venue codes
+    #[max_len(MAX_POLICY_VENUES)]
+    pub venues: Vec<[u8; 8]>,
+
+    /// Program that checks mints and redemptions against this policy
+    pub hook_program: Option<Pubkey>,
+}
+
+/// Action a policy hook is asked to allow.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PolicyAction {
+    Mint,
+    Redeem,
+}
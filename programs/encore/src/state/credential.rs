@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::ATTESTATION_SEED;
+
+/// Credential an event requires holders to present (e.g. an 18+ attestation).
+///
+/// Attestations follow a PDA convention on the issuer program:
+/// `[ATTESTATION_SEED, schema, holder]`, owned by `issuer_program`.
+/// An issuer revokes a credential by closing the account.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CredentialRequirement {
+    /// Program that issues and owns attestation accounts
+    pub issuer_program: Pubkey,
+
+    /// Schema (credential type) the attestation must be issued under
+    pub schema: Pubkey,
+}
+
+impl CredentialRequirement {
+    /// True if `attestation` is a live attestation of this schema for `holder`.
+    pub fn is_satisfied_by(&self, attestation: &AccountInfo, holder: &Pubkey) -> bool {
+        let (expected, _) = Pubkey::find_program_address(
+            &[ATTESTATION_SEED, self.schema.as_ref(), holder.as_ref()],
+            &self.issuer_program,
+        );
+
+        *attestation.key == expected
+            && *attestation.owner == self.issuer_program
+            && !attestation.data_is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn satisfied(
+        requirement: &CredentialRequirement,
+        key: &Pubkey,
+        owner: &Pubkey,
+        data: &mut [u8],
+        holder: &Pubkey,
+    ) -> bool {
+        let mut lamports = 1;
+        let attestation = AccountInfo::new(key, false, false, &mut lamports, data, owner, false, 0);
+        requirement.is_satisfied_by(&attestation, holder)
+    }
+
+    #[test]
+    fn requires_the_holders_live_attestation() {
+        let requirement = CredentialRequirement {
+            issuer_program: Pubkey::new_from_array([1; 32]),
+            schema: Pubkey::new_from_array([2; 32]),
+        };
+        let holder = Pubkey::new_from_array([3; 32]);
+        let other = Pubkey::new_from_array([4; 32]);
+        let (address, _) = Pubkey::find_program_address(
+            &[
+                ATTESTATION_SEED,
+                requirement.schema.as_ref(),
+                holder.as_ref(),
+            ],
+            &requirement.issuer_program,
+        );
+        let issuer = requirement.issuer_program;
+
+        assert!(satisfied(
+            &requirement,
+            &address,
+            &issuer,
+            &mut [1],
+            &holder
+        ));
+        // Someone else's attestation
+        assert!(!satisfied(
+            &requirement,
+            &address,
+            &issuer,
+            &mut [1],
+            &other
+        ));
+        // Not owned by the issuer
+        assert!(!satisfied(
+            &requirement,
+            &address,
+            &other,
+            &mut [1],
+            &holder
+        ));
+        // Revoked: the issuer closed the account
+        assert!(!satisfied(
+            &requirement,
+            &address,
+            &issuer,
+            &mut [],
+            &holder
+        ));
+    }
+}
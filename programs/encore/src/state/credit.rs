@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use light_sdk::LightDiscriminator;
+
+/// Refund credit stored as compressed account.
+///
+/// Issued instead of a cash refund when an event is cancelled, and
+/// redeemable as payment on future mints from the same organizer.
+#[event]
+#[derive(Clone, Debug, Default, LightDiscriminator)]
+pub struct RefundCredit {
+    /// Organizer (event authority) who honors the credit
+    pub organizer: Pubkey,
+
+    /// Wallet allowed to spend the credit
+    pub owner: Pubkey,
+
+    /// Remaining value in lamports
+    pub amount: u64,
+
+    /// Credit can't be spent after this timestamp
+    pub expires_at: i64,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Type of an organizer-defined ticket field.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FieldKind {
+    U64,   // Counts, flags, unix times (e.g. door time)
+    Str32, // Short codes, zero-padded UTF-8 (e.g. parking lot)
+}
+
+/// One entry of an event's custom-field schema.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FieldDef {
+    /// Field name, zero-padded UTF-8
+    pub key: [u8; 16],
+    pub kind: FieldKind,
+}
+
+/// A custom-field value stored on a ticket, positionally matching the schema.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FieldValue {
+    U64(u64),
+    Str32([u8; 32]),
+}
+
+impl FieldValue {
+    pub fn kind(&self) -> FieldKind {
+        match self {
+            FieldValue::U64(_) => FieldKind::U64,
+            FieldValue::Str32(_) => FieldKind::Str32,
+        }
+    }
+}
+
+/// True if `values` fit `schema`: either unset (empty) or one value of the
+/// declared kind per field.
+pub fn fields_match_schema(schema: &[FieldDef], values: &[FieldValue]) -> bool {
+    values.is_empty()
+        || (values.len() == schema.len()
+            && schema
+                .iter()
+                .zip(values)
+                .all(|(def, value)| def.kind == value.kind()))
+}
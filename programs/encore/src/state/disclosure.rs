@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use light_sdk::LightDiscriminator;
+
+use crate::constants::VIEW_KEY_DOMAIN;
+
+/// Compressed, holder-published disclosure of one ticket to whoever holds
+/// its view key: an employer reimbursing travel, a parent who paid.
+///
+/// Lives at `[DISCLOSURE_SEED, address_seed(view_key)]`. The viewer derives
+/// the address from the key and reads the ticket at `ticket_address`: while
+/// it exists, `holder` still holds it unredeemed, on the terms it carries.
+/// Transfers and redemption close that address, so the disclosure lapses
+/// with them. The view key opens nothing else, so the viewer can't move or
+/// redeem the ticket.
+#[event]
+#[derive(Clone, Debug, Default, LightDiscriminator)]
+pub struct TicketDisclosure {
+    /// Wallet that proved it held the ticket when disclosing
+    pub holder: Pubkey,
+
+    /// Event the ticket belongs to
+    pub event_config: Pubkey,
+
+    /// Compressed address of the disclosed ticket
+    pub ticket_address: [u8; 32],
+
+    pub ticket_id: u32,
+}
+
+impl TicketDisclosure {
+    /// Address seed for the disclosure a view key opens. Holders publish
+    /// this rather than the key.
+    pub fn address_seed(view_key: &[u8; 32]) -> [u8; 32] {
+        hashv(&[VIEW_KEY_DOMAIN, view_key]).to_bytes()
+    }
+}
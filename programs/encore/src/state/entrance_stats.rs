@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ENTRANCES;
+use crate::errors::EncoreError;
+
+/// Per-entrance check-in counters for an event, indexed by scanner zone.
+///
+/// Created with the event's first scanner and bumped by every
+/// scanner-attested redemption, so venue operations can watch gate
+/// throughput from chain data.
+#[account]
+#[derive(InitSpace)]
+pub struct EntranceStats {
+    /// Event the counters are for
+    pub event_config: Pubkey,
+
+    /// Tickets redeemed through each entrance
+    pub redeemed: [u32; MAX_ENTRANCES],
+
+    /// Last redemption at each entrance, zero if none yet
+    pub last_redeemed_at: [i64; MAX_ENTRANCES],
+
+    /// PDA bump for stats derivation
+    pub bump: u8,
+}
+
+impl EntranceStats {
+    /// Byte offset (discriminator included) for `memcmp` filters
+    pub const EVENT_CONFIG_OFFSET: usize = 8;
+
+    pub fn record(&mut self, zone: u8, tickets: u32, now: i64) -> Result<()> {
+        let zone = zone as usize;
+        require!(zone < MAX_ENTRANCES, EncoreError::ScannerNotAuthorized);
+        self.redeemed[zone] = self.redeemed[zone]
+            .checked_add(tickets)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.last_redeemed_at[zone] = now;
+        Ok(())
+    }
+}
@@ -1,12 +1,51 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 
+use crate::bps;
+use crate::constants::{
+    MAX_ADDONS, MAX_CUSTOM_FIELDS, MAX_PRICE_AGE_SECONDS, MAX_RELEASE_TRANCHES,
+};
+use crate::errors::EncoreError;
+use crate::secret::SecretDerivation;
+use crate::state::{
+    AddOn, CompliancePolicy, CredentialRequirement, FanLane, FieldDef, InsurancePolicy, Presale,
+    PriceFeed, RoyaltyConfig, TransferPolicy,
+};
+
+/// Per-event configuration and stats counters.
+///
+/// Fields before `resale_cap_usd_rate` are fixed-size and sit at stable
+/// offsets, so RPC `memcmp` filters can select events by authority or
+/// lifecycle flags (see the `*_OFFSET` constants). Keep new fixed-size
+/// fields after the counters and variable-size ones further down.
 #[account]
 #[derive(InitSpace)]
 pub struct EventConfig {
     pub authority: Pubkey,
+    pub cancelled: bool,
+    pub closed: bool,
     pub max_supply: u32,
     pub tickets_minted: u32,
+    /// Box-office hold-back: only organizer-signed mints draw from it
+    pub reserved_supply: u32,
+    pub reserved_minted: u32,
+    /// Partner allocation tickets not yet minted or returned
+    pub allocated_supply: u32,
+    pub tickets_voided: u32,
+    pub tickets_redeemed: u32,
+    pub tickets_refunded: u32,
+    pub marketplace_sales: u32,
+    pub nullifiers_created: u64,
+    pub gift_transfers: u32,
+    pub sale_transfers: u32,
+    /// Tickets reissued to the same holder: seat reassignments, wallet
+    /// binds, recoveries and personalizations
+    pub tickets_reissued: u32,
     pub resale_cap_bps: u32,
+    /// SOL/USD rate (micro-USD per SOL) face values were set at. When set,
+    /// the resale cap applies to face value re-priced at the current rate,
+    /// from each ticket's own purchase rate where it recorded one.
+    pub resale_cap_usd_rate: Option<u64>,
 
     #[max_len(64)]
     pub event_name: String,
@@ -15,6 +54,51 @@ pub struct EventConfig {
     #[max_len(200)]
     pub event_description: String,
     pub max_tickets_per_person: u8,
+    pub mint_rate_limit: u8,
+    pub mint_rate_window_seconds: u32,
+    /// Length of an `IdentityCounter` epoch (0 = one counter per buyer)
+    pub identity_epoch_seconds: u32,
+    /// Tickets one buyer may mint per slot, companion seats included
+    /// (0 = no cap)
+    pub max_mints_per_slot: u8,
+    pub pow_difficulty: u8,
+    pub required_credential: Option<CredentialRequirement>,
+    pub id_verification: Option<CredentialRequirement>,
+    pub insurance_policy: Option<InsurancePolicy>,
+    pub transfer_policy: TransferPolicy,
+    pub require_paid_sales: bool,
+    pub escrow_resale_proceeds: bool,
+    pub require_void_memo: bool,
+    pub require_scanner: bool,
+    /// Seconds after the public on-sale during which resale is disabled
+    pub resale_blackout_seconds: u32,
+    /// Venue fire-code ceiling on `max_supply` for door releases (0 = off),
+    /// the most one release may add, and the total added at the door
+    pub fire_code_capacity: u32,
+    pub door_release_step: u32,
+    pub door_released: u32,
+    pub emission_policy: EmissionPolicy,
+    pub ticket_address_mode: TicketAddressMode,
+    pub secret_derivation: Option<SecretDerivation>,
+    pub royalty: Option<RoyaltyConfig>,
+    #[max_len(MAX_CUSTOM_FIELDS)]
+    pub custom_fields: Vec<FieldDef>,
+    #[max_len(MAX_ADDONS)]
+    pub addons: Vec<AddOn>,
+    #[max_len(MAX_RELEASE_TRANCHES)]
+    pub release_schedule: Vec<ReleaseTranche>,
+    pub compliance_policy: Option<CompliancePolicy>,
+    /// Merkle root of this event's attendance or holder snapshot, for later
+    /// events to use as a presale allowlist
+    pub snapshot_root: Option<[u8; 32]>,
+    pub presale: Option<Presale>,
+    pub fan_lane: Option<FanLane>,
+    /// SPL mint the event's own payments (primary sales, refunds,
+    /// royalties) settle in, or None for SOL. Declared at creation and never
+    /// changed; only SOL is accepted for now. Resale claims may still pay in
+    /// a listing's payment options, which settle between buyer and seller.
+    pub settlement_mint: Option<Pubkey>,
+    pub credit_validity_seconds: u32,
     pub event_timestamp: i64,
     pub created_at: i64,
     pub updated_at: i64,
@@ -22,30 +106,428 @@ pub struct EventConfig {
 }
 
 impl EventConfig {
-    pub fn can_mint(&self, amount: u32) -> bool {
+    /// Byte offsets (discriminator included) for `memcmp` filters
+    pub const AUTHORITY_OFFSET: usize = 8;
+    pub const CANCELLED_OFFSET: usize = Self::AUTHORITY_OFFSET + 32;
+    pub const CLOSED_OFFSET: usize = Self::CANCELLED_OFFSET + 1;
+
+    /// Whether a payment in `mint` (None for SOL) is in the event's
+    /// settlement currency.
+    pub fn settles_in(&self, mint: Option<Pubkey>) -> bool {
+        self.settlement_mint == mint
+    }
+
+    /// True if `amount` more tickets fit in the supply released at `now`,
+    /// leaving the box-office hold-back, partner allocations and the open
+    /// verified-fan hold untouched.
+    pub fn can_mint(&self, amount: u32, now: i64) -> bool {
+        self.fits_released(amount, now, self.fan_held(now))
+    }
+
+    /// `can_mint` for a buyer with a `FanAttestation`, who may also take
+    /// the verified-fan hold.
+    pub fn can_mint_as_fan(&self, amount: u32, now: i64) -> bool {
+        self.fits_released(amount, now, 0)
+    }
+
+    fn fits_released(&self, amount: u32, now: i64, held: u32) -> bool {
         self.tickets_minted
             .checked_add(amount)
-            .map(|total| total <= self.max_supply)
+            .and_then(|total| total.checked_add(self.reserve_remaining()))
+            .and_then(|total| total.checked_add(self.allocated_supply))
+            .and_then(|total| total.checked_add(held))
+            .map(|total| total <= self.released_supply(now))
             .unwrap_or(false)
     }
 
+    /// The release whose verified-fan window is open at `now`, as its
+    /// release time and supply.
+    pub fn fan_window(&self, now: i64) -> Option<(i64, u32)> {
+        let lane = self.fan_lane?;
+        let (release_at, supply) = if self.release_schedule.is_empty() {
+            (self.created_at, self.max_supply)
+        } else {
+            self.release_schedule
+                .iter()
+                .rev()
+                .find(|tranche| tranche.release_at <= now)
+                .map(|tranche| (tranche.release_at, tranche.supply))?
+        };
+        (now < release_at.saturating_add(lane.window_seconds as i64))
+            .then_some((release_at, supply))
+    }
+
+    /// Tickets of the open fan window's share not yet minted by fans.
+    pub fn fan_held(&self, now: i64) -> u32 {
+        let (Some(lane), Some((release_at, supply))) = (self.fan_lane, self.fan_window(now)) else {
+            return 0;
+        };
+        let share = bps::share(supply as u64, lane.share_bps as u64) as u32;
+        let taken = if lane.window_start == release_at {
+            lane.fan_minted
+        } else {
+            0
+        };
+        share.saturating_sub(taken)
+    }
+
+    /// Count `amount` tickets minted by a verified fan at `now` against the
+    /// open fan window, if any.
+    pub fn record_fan_mint(&mut self, amount: u32, now: i64) {
+        let Some((release_at, _)) = self.fan_window(now) else {
+            return;
+        };
+        if let Some(lane) = self.fan_lane.as_mut() {
+            if lane.window_start != release_at {
+                lane.window_start = release_at;
+                lane.fan_minted = 0;
+            }
+            lane.fan_minted = lane.fan_minted.saturating_add(amount);
+        }
+    }
+
+    /// Held-back tickets not yet sold by the box office.
+    pub fn reserve_remaining(&self) -> u32 {
+        self.reserved_supply.saturating_sub(self.reserved_minted)
+    }
+
+    /// True if the box office can mint `amount` from the hold-back. The
+    /// hold-back is available regardless of the release schedule.
+    pub fn can_mint_from_reserve(&self, amount: u32) -> bool {
+        amount <= self.reserve_remaining()
+            && self
+                .tickets_minted
+                .checked_add(amount)
+                .and_then(|total| total.checked_add(self.allocated_supply))
+                .is_some_and(|total| total <= self.max_supply)
+    }
+
+    /// True if `size` more tickets can be carved out for a partner
+    /// allocation without touching minted tickets or the hold-back.
+    pub fn can_allocate(&self, size: u32) -> bool {
+        self.tickets_minted
+            .checked_add(self.reserve_remaining())
+            .and_then(|total| total.checked_add(self.allocated_supply))
+            .and_then(|total| total.checked_add(size))
+            .is_some_and(|total| total <= self.max_supply)
+    }
+
+    /// Supply on sale at `now`: all of `max_supply` without a release
+    /// schedule, otherwise the tranches released so far plus door releases.
+    pub fn released_supply(&self, now: i64) -> u32 {
+        if self.release_schedule.is_empty() {
+            return self.max_supply;
+        }
+        self.release_schedule
+            .iter()
+            .filter(|tranche| tranche.release_at <= now)
+            .map(|tranche| tranche.supply)
+            .sum::<u32>()
+            .saturating_add(self.door_released)
+            .min(self.max_supply)
+    }
+
+    /// Start of the public on-sale: the first release tranche, or event
+    /// creation without a schedule.
+    pub fn on_sale_at(&self) -> i64 {
+        self.release_schedule
+            .first()
+            .map(|tranche| tranche.release_at)
+            .unwrap_or(self.created_at)
+    }
+
+    /// True while priced resale is blacked out after the on-sale.
+    pub fn in_resale_blackout(&self, now: i64) -> bool {
+        now < self
+            .on_sale_at()
+            .saturating_add(self.resale_blackout_seconds as i64)
+    }
+
+    /// Total price of the add-ons selected in `mask`, checking each is
+    /// still in stock.
+    pub fn quote_addons(&self, mask: u8) -> Result<u64> {
+        // Bits past the end of the list select nothing that exists
+        require!(
+            (mask as u32) >> self.addons.len() == 0,
+            EncoreError::InvalidAddOn
+        );
+        let mut total = 0u64;
+        for (index, addon) in self.addons.iter().enumerate() {
+            if mask & (1 << index) == 0 {
+                continue;
+            }
+            require!(addon.sold < addon.supply, EncoreError::AddOnSoldOut);
+            total = total
+                .checked_add(addon.price_lamports)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+        Ok(total)
+    }
+
+    /// Sell one unit of each add-on selected in `mask`, returning their total price.
+    pub fn sell_addons(&mut self, mask: u8) -> Result<u64> {
+        let total = self.quote_addons(mask)?;
+        for (index, addon) in self.addons.iter_mut().enumerate() {
+            if mask & (1 << index) != 0 {
+                addon.sold += 1;
+            }
+        }
+        Ok(total)
+    }
+
+    /// Epoch whose `IdentityCounter` a mint at `now` uses. Always 0 unless
+    /// the event buckets counters with `identity_epoch_seconds`.
+    pub fn identity_epoch(&self, now: i64) -> u32 {
+        if self.identity_epoch_seconds == 0 {
+            return 0;
+        }
+        let elapsed = now.saturating_sub(self.created_at).max(0) as u64;
+        (elapsed / self.identity_epoch_seconds as u64).min(u32::MAX as u64) as u32
+    }
+
+    /// Sequence number for the next nullifier created for this event.
+    pub fn next_nullifier_sequence(&mut self) -> Result<u64> {
+        let sequence = self.nullifiers_created;
+        self.nullifiers_created = sequence
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(sequence)
+    }
+
+    /// Sequence number for the nullifier of a ticket reissued in place,
+    /// counting the reissue so the audit stays balanced.
+    pub fn next_reissue_sequence(&mut self) -> Result<u64> {
+        self.tickets_reissued = self
+            .tickets_reissued
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.next_nullifier_sequence()
+    }
+
+    /// Tickets whose nullifier the stats account for: every transfer, sale,
+    /// reissue, redemption and refund claim spends exactly one ticket secret.
+    pub fn nullified_tickets(&self) -> u64 {
+        self.gift_transfers as u64
+            + self.sale_transfers as u64
+            + self.tickets_reissued as u64
+            + self.marketplace_sales as u64
+            + self.tickets_redeemed as u64
+            + self.tickets_refunded as u64
+    }
+
+    /// True if `signer` may approve transfer requests for this event.
+    pub fn can_approve_transfers(&self, signer: &Pubkey) -> bool {
+        match self.transfer_policy {
+            TransferPolicy::Open => false,
+            TransferPolicy::ApprovalRequired { approver } => {
+                *signer == self.authority || *signer == approver
+            }
+        }
+    }
 
+    /// True if `hash` has at least `pow_difficulty` leading zero bits.
+    pub fn meets_pow_difficulty(&self, hash: &[u8; 32]) -> bool {
+        let mut zero_bits = 0u32;
+        for byte in hash {
+            if *byte == 0 {
+                zero_bits += 8;
+            } else {
+                zero_bits += byte.leading_zeros();
+                break;
+            }
+        }
+        zero_bits >= self.pow_difficulty as u32
+    }
 
     pub fn is_valid_resale_price(&self, original_price: u64, proposed_price: u64) -> bool {
-        let max_price = original_price
-            .checked_mul(self.resale_cap_bps as u64)
-            .and_then(|v| v.checked_div(10000));
+        proposed_price <= self.calculate_max_resale_price(original_price)
+    }
 
-        match max_price {
-            Some(max) => proposed_price <= max,
-            None => false,
+    /// Seed a newly issued ticket's address is derived from, as
+    /// `[TICKET_SEED, seed]`. Random mode uses the client's seed;
+    /// deterministic mode uses hash(event_config || ticket_id), so indexers
+    /// can locate an issued ticket by id.
+    pub fn ticket_address_seed(
+        &self,
+        event_config: &Pubkey,
+        ticket_id: u32,
+        client_seed: [u8; 32],
+    ) -> [u8; 32] {
+        match self.ticket_address_mode {
+            TicketAddressMode::Random => client_seed,
+            TicketAddressMode::Deterministic => {
+                hashv(&[event_config.as_ref(), &ticket_id.to_le_bytes()]).to_bytes()
+            }
         }
     }
 
+    /// Face value the resale cap applies to: `original_price` as paid, or,
+    /// under `resale_cap_usd_rate`, its USD value re-priced in lamports at
+    /// the feed's current rate. The USD value is taken at the ticket's own
+    /// purchase rate (`usd_micros_per_sol`) when it recorded one, else at
+    /// the event's.
+    pub fn resale_cap_base(
+        &self,
+        original_price: u64,
+        usd_micros_per_sol: Option<u64>,
+        price_feed: Option<&PriceFeed>,
+        now: i64,
+    ) -> Result<u64> {
+        let Some(event_rate) = self.resale_cap_usd_rate else {
+            return Ok(original_price);
+        };
+        let face_rate = usd_micros_per_sol.unwrap_or(event_rate);
+        let price_feed = price_feed.ok_or(EncoreError::PriceFeedRequired)?;
+        require!(
+            price_feed.is_fresh(now, MAX_PRICE_AGE_SECONDS),
+            EncoreError::PriceFeedStale
+        );
+        let base = bps::mul_div(original_price, face_rate, price_feed.usd_micros_per_sol)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(base)
+    }
+
+    /// Highest resale price for a ticket with this face value, rounded
+    /// down; saturates rather than failing for caps above 1x.
     pub fn calculate_max_resale_price(&self, original_price: u64) -> u64 {
-        original_price
-            .checked_mul(self.resale_cap_bps as u64)
-            .and_then(|v| v.checked_div(10000))
-            .unwrap_or(0)
+        bps::share(original_price, self.resale_cap_bps as u64)
+    }
+}
+
+/// How much `TicketMinted` / `TicketTransferred` reveal for an event.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace, Default,
+)]
+pub enum EmissionPolicy {
+    /// Ticket ids, commitments and prices (public mint feeds)
+    Public,
+    /// Prices and transfer kinds, no ticket ids or commitments
+    #[default]
+    Standard,
+    /// Only the event's running counters
+    Aggregate,
+}
+
+impl EmissionPolicy {
+    pub fn includes_prices(&self) -> bool {
+        *self != EmissionPolicy::Aggregate
+    }
+
+    pub fn includes_commitments(&self) -> bool {
+        *self == EmissionPolicy::Public
+    }
+}
+
+/// How `mint_ticket` and `waitlist_promote` derive a new ticket's address.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace, Default,
+)]
+pub enum TicketAddressMode {
+    /// Client-random seed: issued tickets can't be linked to their id
+    #[default]
+    Random,
+    /// hash(event_config || ticket_id): issued tickets are found by id.
+    /// Resales still move the ticket to a client-random address.
+    Deterministic,
+}
+
+/// Supply put on sale at `release_at`, part of an event's release schedule.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct ReleaseTranche {
+    pub release_at: i64,
+    pub supply: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An event with every field zeroed: no options, empty lists
+    fn event() -> EventConfig {
+        let data = vec![0u8; 8 + EventConfig::INIT_SPACE];
+        EventConfig::try_deserialize_unchecked(&mut data.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn resale_cap_is_a_share_of_face_value() {
+        let mut event_config = event();
+        event_config.resale_cap_bps = 15_000; // 1.5x
+        assert_eq!(event_config.calculate_max_resale_price(1_000), 1_500);
+        assert!(event_config.is_valid_resale_price(1_000, 1_500));
+        assert!(!event_config.is_valid_resale_price(1_000, 1_501));
+
+        // The cap follows the face value it's given, so an inflated one
+        // lifts it: callers take face value from the spent ticket
+        assert!(event_config.is_valid_resale_price(2_000, 1_501));
+    }
+
+    #[test]
+    fn resale_cap_base_reprices_usd_face_values() {
+        let mut event_config = event();
+        assert_eq!(
+            event_config
+                .resale_cap_base(1_000, Some(100_000_000), None, 0)
+                .unwrap(),
+            1_000
+        );
+
+        // Bought at $100/SOL, SOL now at $200: half the lamports
+        event_config.resale_cap_usd_rate = Some(100_000_000);
+        let price_feed = PriceFeed {
+            oracle_authority: Pubkey::default(),
+            usd_micros_per_sol: 200_000_000,
+            updated_at: 1_000,
+            bump: 0,
+        };
+        assert_eq!(
+            event_config
+                .resale_cap_base(1_000, None, Some(&price_feed), 1_000)
+                .unwrap(),
+            500
+        );
+        assert!(event_config
+            .resale_cap_base(1_000, None, None, 1_000)
+            .is_err());
+        assert!(event_config
+            .resale_cap_base(
+                1_000,
+                None,
+                Some(&price_feed),
+                1_000 + MAX_PRICE_AGE_SECONDS + 1
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn resale_cap_base_prefers_the_ticket_purchase_rate() {
+        let mut event_config = event();
+        event_config.resale_cap_usd_rate = Some(100_000_000);
+        let price_feed = PriceFeed {
+            oracle_authority: Pubkey::default(),
+            usd_micros_per_sol: 200_000_000,
+            updated_at: 1_000,
+            bump: 0,
+        };
+
+        // Bought at $300/SOL although the event pinned $100/SOL: the
+        // ticket's USD value is 1.5x the lamports at today's $200/SOL
+        assert_eq!(
+            event_config
+                .resale_cap_base(1_000, Some(300_000_000), Some(&price_feed), 1_000)
+                .unwrap(),
+            1_500
+        );
+    }
+
+    #[test]
+    fn reissues_keep_the_nullifier_audit_balanced() {
+        let mut event_config = event();
+        assert_eq!(event_config.next_nullifier_sequence().unwrap(), 0);
+        event_config.gift_transfers += 1;
+        assert_eq!(event_config.next_reissue_sequence().unwrap(), 1);
+
+        assert_eq!(event_config.tickets_reissued, 1);
+        assert_eq!(event_config.nullifiers_created, 2);
+        assert_eq!(event_config.nullified_tickets(), 2);
     }
 }
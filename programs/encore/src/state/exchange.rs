@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Replacement-event supply set aside for holders of a postponed or
+/// cancelled event, who convert their tickets with `exchange_ticket`.
+///
+/// Agreed by both organizers: `from_event`'s lets its tickets go, and
+/// `to_event`'s carves `size` tickets out of its supply (counted in
+/// `EventConfig::allocated_supply`) until the exchange is returned after
+/// its deadline.
+#[account]
+#[derive(InitSpace)]
+pub struct TicketExchange {
+    /// Event whose tickets can be exchanged
+    pub from_event: Pubkey,
+
+    /// Replacement event the new tickets are minted into
+    pub to_event: Pubkey,
+
+    /// Face value of a replacement ticket; differences from the old
+    /// ticket's price are settled on exchange
+    pub price_lamports: u64,
+
+    /// Replacement tickets carved out
+    pub size: u32,
+
+    /// Tickets exchanged so far
+    pub exchanged: u32,
+
+    /// After this time the unused supply can be returned
+    pub deadline: i64,
+
+    /// PDA bump for exchange derivation
+    pub bump: u8,
+}
+
+impl TicketExchange {
+    pub fn remaining(&self) -> u32 {
+        self.size.saturating_sub(self.exchanged)
+    }
+}
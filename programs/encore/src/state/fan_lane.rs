@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Priority lane holding a share of each supply tranche for verified fans.
+///
+/// For `window_seconds` after a tranche is released (after the event's
+/// creation when there's no schedule), `share_bps` of its supply can only
+/// be minted by buyers holding a `FanAttestation`. Whatever fans haven't
+/// taken when the window closes returns to general sale. Windows are
+/// expected not to overlap the next tranche.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FanLane {
+    /// Share of each tranche held for fans
+    pub share_bps: u16,
+
+    /// How long the hold lasts after each release
+    pub window_seconds: u32,
+
+    /// Release time of the tranche `fan_minted` counts against
+    pub window_start: i64,
+
+    /// Tickets fans minted in that tranche's window
+    pub fan_minted: u32,
+}
+
+/// Organizer-issued attestation that `fan` is a verified fan of the event,
+/// admitting them to its `FanLane`. Revoked by closing it.
+#[account]
+#[derive(InitSpace)]
+pub struct FanAttestation {
+    /// Event the attestation is for
+    pub event_config: Pubkey,
+
+    /// Attested buyer wallet
+    pub fan: Pubkey,
+
+    /// When the organizer issued it
+    pub issued_at: i64,
+
+    /// PDA bump for attestation derivation
+    pub bump: u8,
+}
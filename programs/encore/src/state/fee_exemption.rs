@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::bps;
+
+/// Protocol fee discount granted to an organizer or a single event.
+///
+/// `subject` is either an organizer (event authority) or an event config.
+/// A `discount_bps` of 10000 waives the fee entirely.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeExemption {
+    /// Organizer or event config the discount applies to
+    pub subject: Pubkey,
+
+    /// Share of the protocol fee waived, in basis points
+    pub discount_bps: u16,
+
+    /// When the exemption was granted
+    pub granted_at: i64,
+
+    /// PDA bump for fee exemption derivation
+    pub bump: u8,
+}
+
+impl FeeExemption {
+    pub fn applies_to(&self, event_config: &Pubkey, organizer: &Pubkey) -> bool {
+        self.subject == *event_config || self.subject == *organizer
+    }
+
+    /// Fee remaining after the discount.
+    pub fn apply(&self, fee: u64) -> u64 {
+        let (_waived, remaining) = bps::split(fee, self.discount_bps as u64);
+        remaining
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+/// Fiat payment processor (PSP) an organizer lets sell tickets for an
+/// event through custodial holds.
+///
+/// `psp` is usually a PDA of the PSP's own program, so mints happen by CPI
+/// once its off-chain checkout succeeds.
+#[account]
+#[derive(InitSpace)]
+pub struct FiatProvider {
+    /// Event the PSP sells for
+    pub event_config: Pubkey,
+
+    /// Key the PSP signs holds and clawbacks with
+    pub psp: Pubkey,
+
+    /// Chargeback window: holds finalize this long after creation
+    pub settlement_seconds: u32,
+
+    /// Highest `original_price` the PSP may set on a hold
+    pub max_price: u64,
+
+    /// Lifetime holds by outcome
+    pub held: u32,
+    pub finalized: u32,
+    pub clawed_back: u32,
+
+    /// PDA bump for provider derivation
+    pub bump: u8,
+}
+
+/// Ticket sold for fiat, held until the settlement window passes.
+///
+/// Takes its ticket id from supply at creation. `finalize_fiat_hold` issues
+/// the ticket to `owner_commitment` afterwards; before that the PSP can
+/// claw it back when a payment is reversed, voiding the ticket id.
+#[account]
+#[derive(InitSpace)]
+pub struct FiatHold {
+    /// Event the ticket belongs to
+    pub event_config: Pubkey,
+
+    /// PSP that sold the ticket, receives the rent back
+    pub psp: Pubkey,
+
+    /// Ticket data for the issued ticket
+    pub ticket_id: u32,
+    pub owner_commitment: [u8; 32],
+    pub original_price: u64,
+
+    /// Chargebacks are no longer honored after this
+    pub finalizes_at: i64,
+
+    /// When the hold was created
+    pub created_at: i64,
+
+    /// PDA bump for hold derivation
+    pub bump: u8,
+}
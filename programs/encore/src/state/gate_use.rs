@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use light_sdk::LightDiscriminator;
+
+/// Compressed record that a verifier accepted a ticket under a scope.
+///
+/// Like a `Nullifier`, existence is the proof: it lives at
+/// `gate::gate_use_seed(verifier, scope, event, ticket_id)`, so a second
+/// single-use `verify_ticket` for the same seat fails. Kept apart from
+/// nullifiers, which count spent tickets.
+#[event]
+#[derive(Clone, Debug, Default, LightDiscriminator)]
+pub struct GateUse {
+    /// Program or key that accepted the ticket
+    pub verifier: Pubkey,
+
+    /// Event the ticket belongs to
+    pub event_config: Pubkey,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CUSTOM_FIELDS;
+use crate::state::FieldValue;
+
+/// Ticket held for a gift link, between the sender nullifying it and the
+/// recipient claiming it.
+///
+/// The link carries a one-off claim keypair; only its public key is stored
+/// and the account is derived from it. Claiming needs the claim key's
+/// signature rather than a revealed code, so a claim seen in flight can't
+/// be replayed to someone else's commitment.
+#[account]
+#[derive(InitSpace)]
+pub struct Gift {
+    /// Holder who gifted the ticket, can cancel until it's claimed
+    pub sender: Pubkey,
+
+    /// Public key of the link's claim keypair
+    pub claim_key: Pubkey,
+
+    /// Ticket data carried over to the recipient's ticket
+    pub event_config: Pubkey,
+    pub ticket_id: u32,
+    pub original_price: u64,
+    pub usd_micros_per_sol: Option<u64>,
+    #[max_len(MAX_CUSTOM_FIELDS)]
+    pub custom_fields: Vec<FieldValue>,
+    pub addons: u8,
+
+    /// Provenance of the issued ticket, already extended with the
+    /// sender's nullifier
+    pub provenance: [u8; 32],
+
+    /// Sender's fresh commitment the ticket returns to if the link isn't
+    /// claimed by `expires_at`
+    pub return_commitment: [u8; 32],
+    pub expires_at: i64,
+
+    /// When the gift was created
+    pub created_at: i64,
+
+    /// PDA bump for gift derivation
+    pub bump: u8,
+}
+
+impl Gift {
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_GUARDED_TICKETS, MAX_GUARDIANS};
+
+/// A holder's recovery guardians.
+///
+/// Any `threshold` of the guardians can recover one of the holder's
+/// guarded tickets to a new commitment, once `recovery_delay_seconds` have
+/// passed without the holder vetoing.
+#[account]
+#[derive(InitSpace)]
+pub struct GuardianSet {
+    /// Wallet whose tickets the guardians can recover
+    pub holder: Pubkey,
+
+    /// Distinct guardian wallets
+    #[max_len(MAX_GUARDIANS)]
+    pub guardians: Vec<Pubkey>,
+
+    /// Approvals needed to start a recovery
+    pub threshold: u8,
+
+    /// Veto window between the last needed approval and recovery
+    pub recovery_delay_seconds: i64,
+
+    /// Tickets the holder has put under guard
+    #[max_len(MAX_GUARDED_TICKETS)]
+    pub tickets: Vec<GuardedTicket>,
+
+    /// PDA bump for guardian set derivation
+    pub bump: u8,
+}
+
+impl GuardianSet {
+    pub fn is_guardian(&self, key: &Pubkey) -> bool {
+        self.guardians.contains(key)
+    }
+
+    pub fn guarded(&self, ticket_commitment: &[u8; 32]) -> Option<&GuardedTicket> {
+        self.tickets
+            .iter()
+            .find(|t| t.ticket_commitment == *ticket_commitment)
+    }
+}
+
+/// A ticket the holder proved they own while they still had its secret.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct GuardedTicket {
+    /// The ticket's owner commitment
+    pub ticket_commitment: [u8; 32],
+
+    /// hash(secret): the ticket's nullifier seed
+    pub secret_hash: [u8; 32],
+}
+
+/// Guardians' pending recovery of one guarded ticket.
+///
+/// Locks in once `threshold` guardians approve; `recover_ticket` can run
+/// from `unlocks_at`, and the holder can veto until then.
+#[account]
+#[derive(InitSpace)]
+pub struct TicketRecovery {
+    /// Guardian set recovering the ticket
+    pub guardian_set: Pubkey,
+
+    /// Commitment of the ticket being recovered
+    pub ticket_commitment: [u8; 32],
+
+    /// Commitment the ticket is reissued to
+    pub new_owner_commitment: [u8; 32],
+
+    /// Guardians that approved, checked against the current set
+    #[max_len(MAX_GUARDIANS)]
+    pub approvals: Vec<Pubkey>,
+
+    /// Set when the threshold is first met
+    pub unlocks_at: Option<i64>,
+
+    /// Guardian who opened the recovery and gets its rent back
+    pub proposer: Pubkey,
+
+    /// PDA bump for recovery derivation
+    pub bump: u8,
+}
+
+impl TicketRecovery {
+    /// Approvals from guardians still in `guardian_set`
+    pub fn current_approvals(&self, guardian_set: &GuardianSet) -> usize {
+        self.approvals
+            .iter()
+            .filter(|g| guardian_set.is_guardian(g))
+            .count()
+    }
+}
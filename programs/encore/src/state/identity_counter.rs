@@ -10,6 +10,127 @@ pub struct IdentityCounter {
     /// The user this counter tracks (for limit enforcement)
     pub authority: Pubkey,
 
-    /// Total tickets minted by this user for this event
-    pub tickets_minted: u8,
+    /// Epoch the counter covers, when the event buckets counters by
+    /// `identity_epoch_seconds`
+    pub epoch: u32,
+
+    /// Total tickets minted by this user for this event, companion seats
+    /// included
+    pub tickets_minted: u32,
+
+    /// Timestamp of the most recent mint
+    pub last_mint_at: i64,
+
+    /// Start of the current rate-limit window
+    pub window_start: i64,
+
+    /// Mints recorded in the current rate-limit window
+    pub window_mints: u8,
+
+    /// Fees the event's `SponsorPool` has covered for this user
+    pub sponsored_lamports: u64,
+
+    /// Slot of the most recent mint
+    pub last_mint_slot: u64,
+
+    /// Tickets minted in `last_mint_slot`, companion seats included
+    pub slot_mints: u32,
+}
+
+impl IdentityCounter {
+    /// Whether `seats` more tickets keep this user within
+    /// `max_per_person`, every seat counted; zero means no limit.
+    pub fn within_limit(&self, seats: u32, max_per_person: u8) -> bool {
+        max_per_person == 0 || self.tickets_minted + seats <= max_per_person as u32
+    }
+
+    /// Mints counted against the rate limit at `now`.
+    /// A window that has fully elapsed no longer counts.
+    pub fn mints_in_window(&self, now: i64, window_seconds: u32) -> u8 {
+        if now.saturating_sub(self.window_start) >= window_seconds as i64 {
+            0
+        } else {
+            self.window_mints
+        }
+    }
+
+    /// Tickets already minted in `slot`.
+    pub fn mints_in_slot(&self, slot: u64) -> u32 {
+        if self.last_mint_slot == slot {
+            self.slot_mints
+        } else {
+            0
+        }
+    }
+
+    /// Whether `seats` more tickets in `slot` stay within `max_per_slot`,
+    /// every seat counted; zero means no cap.
+    pub fn within_slot_cap(&self, slot: u64, seats: u32, max_per_slot: u8) -> bool {
+        max_per_slot == 0 || self.mints_in_slot(slot) + seats <= max_per_slot as u32
+    }
+
+    /// Record a mint of `seats` tickets at `now` in `slot`, starting a new
+    /// window if the previous one elapsed.
+    pub fn record_mint(&mut self, now: i64, window_seconds: u32, slot: u64, seats: u32) {
+        let in_window = self.mints_in_window(now, window_seconds);
+        if in_window == 0 {
+            self.window_start = now;
+        }
+        self.window_mints = in_window.saturating_add(1);
+        self.slot_mints = self.mints_in_slot(slot).saturating_add(seats);
+        self.last_mint_slot = slot;
+        self.tickets_minted = self.tickets_minted.saturating_add(seats);
+        self.last_mint_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_seat_counts_against_the_limit() {
+        let mut counter = IdentityCounter::default();
+        assert!(counter.within_limit(2, 2));
+        counter.record_mint(100, 0, 1, 2); // Ticket and companion
+        assert_eq!(counter.tickets_minted, 2);
+        assert!(!counter.within_limit(1, 2));
+        assert!(counter.within_limit(1, 3));
+        assert!(counter.within_limit(50, 0));
+    }
+
+    #[test]
+    fn slot_tally_resets_on_a_new_slot() {
+        let mut counter = IdentityCounter::default();
+        counter.record_mint(100, 0, 7, 1);
+        counter.record_mint(100, 0, 7, 1);
+        assert_eq!(counter.mints_in_slot(7), 2);
+        assert_eq!(counter.mints_in_slot(8), 0);
+        counter.record_mint(101, 0, 8, 1);
+        assert_eq!(counter.mints_in_slot(8), 1);
+    }
+
+    #[test]
+    fn slot_cap_counts_every_seat() {
+        let mut counter = IdentityCounter::default();
+        assert!(counter.within_slot_cap(7, 2, 2));
+        assert!(!counter.within_slot_cap(7, 3, 2));
+        counter.record_mint(100, 0, 7, 2); // Ticket and companion
+        assert_eq!(counter.mints_in_slot(7), 2);
+        assert!(!counter.within_slot_cap(7, 1, 2));
+        assert!(counter.within_slot_cap(8, 2, 2));
+        assert!(counter.within_slot_cap(7, 50, 0));
+    }
+
+    #[test]
+    fn rate_window_expires() {
+        let mut counter = IdentityCounter::default();
+        counter.record_mint(1_000, 600, 1, 1);
+        counter.record_mint(1_300, 600, 2, 1);
+        assert_eq!(counter.mints_in_window(1_599, 600), 2);
+        assert_eq!(counter.mints_in_window(1_600, 600), 0);
+        counter.record_mint(1_600, 600, 3, 1);
+        assert_eq!(counter.window_start, 1_600);
+        assert_eq!(counter.window_mints, 1);
+    }
 }
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::bps;
+
+/// Ticket-insurance terms an organizer offers on an event.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InsurancePolicy {
+    /// Premium charged at mint, in basis points of the purchase price
+    pub premium_bps: u16,
+
+    /// Payout on a valid claim, in basis points of the purchase price
+    pub payout_bps: u16,
+
+    /// Buyers may cancel until this many seconds before the event starts
+    pub cancel_cutoff_seconds: i64,
+}
+
+impl InsurancePolicy {
+    pub fn premium(&self, purchase_price: u64) -> u64 {
+        bps::share(purchase_price, self.premium_bps as u64)
+    }
+
+    pub fn payout(&self, purchase_price: u64) -> u64 {
+        bps::share(purchase_price, self.payout_bps as u64)
+    }
+}
+
+/// Insurance cover bought with a single ticket, keyed by its mint commitment.
+///
+/// Closed (rent returned to the holder) when the claim is paid.
+#[account]
+#[derive(InitSpace)]
+pub struct InsuranceCoverage {
+    /// Event the insured ticket belongs to
+    pub event_config: Pubkey,
+
+    /// Wallet that bought the cover and receives the payout
+    pub holder: Pubkey,
+
+    /// Commitment of the insured ticket at mint
+    pub owner_commitment: [u8; 32],
+
+    /// Premium paid into the insurance pool
+    pub premium_lamports: u64,
+
+    /// Amount paid out on a valid claim
+    pub payout_lamports: u64,
+
+    /// Last moment the holder may cancel while the event is still on
+    pub cancel_deadline: i64,
+
+    /// When the cover was bought
+    pub created_at: i64,
+
+    /// PDA bump for coverage derivation
+    pub bump: u8,
+}
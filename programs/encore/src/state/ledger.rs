@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::events::LedgerEntryRecorded;
+
+/// Kind of financial movement recorded on an event's ledger.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LedgerEntryKind {
+    MintRevenue,      // Buyer paid organizer at mint
+    CreditRedeemed,   // Refund credit applied at mint
+    CreditIssued,     // Refund credit issued on cancellation
+    InsurancePremium, // Premium paid into insurance pool
+    InsurancePayout,  // Claim paid out of insurance pool
+    EscrowDeposit,    // Buyer funded a marketplace escrow
+    EscrowRefund,     // Escrow returned to buyer
+    ResaleSettlement, // Escrow released to seller
+    ProtocolFee,      // Fee sent to the protocol treasury
+    Royalty,          // Resale royalty sent to the royalty vault
+    PartialRefund,    // Partial refund paid to a ticket holder
+}
+
+/// Itemized amounts of one settlement, exactly as transferred on-chain, so
+/// accounting integrations don't re-derive the bps math.
+///
+/// `gross` always equals `royalty + protocol_fee + net_to_seller`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct FeeBreakdown {
+    /// Amount the buyer paid, net of any refund credit applied
+    pub gross: u64,
+    /// Ticket face value within `gross`
+    pub face_value: u64,
+    /// Resale royalty sent to the royalty vault
+    pub royalty: u64,
+    /// Protocol fee sent to the treasury
+    pub protocol_fee: u64,
+    /// Organizer charges beyond face value (add-ons at mint)
+    pub organizer_fee: u64,
+    /// What the seller receives (the organizer, at mint)
+    pub net_to_seller: u64,
+}
+
+/// Append-only ledger of every financial movement tied to an event.
+///
+/// Running totals live here; each movement also emits a
+/// `LedgerEntryRecorded` with a gap-free sequence number so auditors can
+/// reconcile the entry stream against the totals.
+#[account]
+#[derive(InitSpace)]
+pub struct EventLedger {
+    /// Event this ledger belongs to
+    pub event_config: Pubkey,
+
+    /// Number of entries recorded (next entry's sequence number)
+    pub entry_count: u64,
+
+    pub mint_revenue: u64,
+    pub credits_redeemed: u64,
+    pub credits_issued: u64,
+    pub insurance_premiums: u64,
+    pub insurance_payouts: u64,
+    pub escrow_deposits: u64,
+    pub escrow_refunds: u64,
+    pub resale_settlements: u64,
+    pub protocol_fees: u64,
+    pub royalties: u64,
+    pub partial_refunds: u64,
+
+    /// PDA bump for ledger derivation
+    pub bump: u8,
+}
+
+impl EventLedger {
+    /// Byte offset (discriminator included) for `memcmp` filters
+    pub const EVENT_CONFIG_OFFSET: usize = 8;
+
+    /// Add a movement to the running totals and emit it as the next entry.
+    /// Zero-amount movements are not recorded.
+    pub fn record(&mut self, kind: LedgerEntryKind, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let total = match kind {
+            LedgerEntryKind::MintRevenue => &mut self.mint_revenue,
+            LedgerEntryKind::CreditRedeemed => &mut self.credits_redeemed,
+            LedgerEntryKind::CreditIssued => &mut self.credits_issued,
+            LedgerEntryKind::InsurancePremium => &mut self.insurance_premiums,
+            LedgerEntryKind::InsurancePayout => &mut self.insurance_payouts,
+            LedgerEntryKind::EscrowDeposit => &mut self.escrow_deposits,
+            LedgerEntryKind::EscrowRefund => &mut self.escrow_refunds,
+            LedgerEntryKind::ResaleSettlement => &mut self.resale_settlements,
+            LedgerEntryKind::ProtocolFee => &mut self.protocol_fees,
+            LedgerEntryKind::Royalty => &mut self.royalties,
+            LedgerEntryKind::PartialRefund => &mut self.partial_refunds,
+        };
+        *total = total
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        emit!(LedgerEntryRecorded {
+            event_config: self.event_config,
+            sequence: self.entry_count,
+            kind,
+            amount,
+            recorded_at: Clock::get()?.unix_timestamp,
+        });
+
+        self.entry_count += 1;
+        Ok(())
+    }
+}
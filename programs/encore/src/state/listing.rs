@@ -1,55 +1,306 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::MAX_PAYMENT_OPTIONS;
+use crate::state::{EventConfig, ProtocolConfig};
 
 /// Marketplace listing for private ticket trading.
 ///
-/// Privacy: Seller and buyer identities are public, but ticket ownership
-/// is hidden via commitment model. Only the seller knows their secret.
+/// Privacy: Seller and buyer identities are public. The listed ticket is
+/// held under the seller's key (`OwnershipMode::Key`), so the sale
+/// completes on the seller's signature and no secret is revealed; the
+/// buyer's ticket is issued under their own commitment.
+///
+/// Layout: the event and seller lead the account and the status tag
+/// follows the fixed-size fields, so `memcmp` filters on
+/// `EVENT_CONFIG_OFFSET`, `SELLER_OFFSET` and `STATUS_OFFSET` answer
+/// queries like "all active listings for event X" without decoding.
 #[account]
+#[derive(InitSpace)]
 pub struct Listing {
+    /// Which event this ticket belongs to
+    pub event_config: Pubkey,
+
     /// Seller who receives payment
     pub seller: Pubkey,
 
-    /// The ticket being sold (commitment proves ownership)
+    /// The ticket being sold: its commitment, the seller's key bytes
     pub ticket_commitment: [u8; 32],
 
-    /// Encrypted secret: secret XOR hash(listing_pda)
-    /// Allows seller to prove ownership without revealing secret
-    pub encrypted_secret: [u8; 32],
+    /// The listed ticket's nullifier seed,
+    /// `PrivateTicket::key_nullifier_seed(ticket_address)`. Lets claims
+    /// check the ticket is unspent.
+    pub nullifier_seed: [u8; 32],
 
     /// Sale price in lamports
     pub price_lamports: u64,
 
-    /// Which event this ticket belongs to
-    pub event_config: Pubkey,
-
     /// Which ticket ID within the event
     pub ticket_id: u32,
 
-    /// Claim data
-    pub buyer: Option<Pubkey>, // Who claimed the listing
-    pub buyer_commitment: Option<[u8; 32]>, // Buyer's new commitment
-    pub claimed_at: Option<i64>,            // Timestamp for timeout
+    /// Seller's listing counter at creation, part of the PDA seeds
+    pub nonce: u64,
 
-    /// Current status of the listing
-    pub status: ListingStatus,
+    /// Backup claim queue: positions [queue_head, queue_tail) are waiting
+    pub queue_head: u32,
+    pub queue_tail: u32,
+
+    /// Official exchange listing: price is pinned to face value plus fees
+    /// and the seller receives exactly `face_value_lamports`
+    pub official_exchange: bool,
+    pub face_value_lamports: u64,
+
+    /// Lamports the seller posted as a completion bond, held on this
+    /// account and forfeited if a claim times out
+    pub seller_bond: u64,
+
+    /// Return to Active on the same terms when a claim falls through,
+    /// without waiting for the seller
+    pub auto_relist: bool,
 
     /// When the listing was created
     pub created_at: i64,
 
     /// PDA bump for listing address derivation
     pub bump: u8,
+
+    /// Current status of the listing, carrying the claim while Claimed.
+    /// Kept after the fixed-size fields above so they, and its tag byte,
+    /// have stable offsets.
+    pub status: ListingStatus,
+
+    /// SPL mints accepted besides SOL, each at its own price
+    #[max_len(MAX_PAYMENT_OPTIONS)]
+    pub payment_options: Vec<PaymentOption>,
+
+    /// When the listing was last Completed or Cancelled; anyone may close
+    /// it `LISTING_GC_GRACE_SECONDS` later
+    pub settled_at: i64,
+}
+
+/// An SPL mint a listing accepts, priced in that mint's base units.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct PaymentOption {
+    pub mint: Pubkey,
+    pub price: u64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+impl Listing {
+    /// Byte offsets (discriminator included) for `memcmp` filters
+    pub const EVENT_CONFIG_OFFSET: usize = 8;
+    pub const SELLER_OFFSET: usize = Self::EVENT_CONFIG_OFFSET + 32;
+    /// Tag byte of `status`, compared against `ListingStatus::*_TAG`.
+    /// Sum of every field above it: update when adding one.
+    pub const STATUS_OFFSET: usize = Self::SELLER_OFFSET
+        + 32 // seller
+        + 32 // ticket_commitment
+        + 32 // nullifier_seed
+        + 8 // price_lamports
+        + 4 // ticket_id
+        + 8 // nonce
+        + 4 // queue_head
+        + 4 // queue_tail
+        + 1 // official_exchange
+        + 8 // face_value_lamports
+        + 8 // seller_bond
+        + 1 // auto_relist
+        + 8 // created_at
+        + 1; // bump
+
+    /// Hash of the economic terms a buyer locks funds against: the ticket,
+    /// every price it can be paid at and what the seller is held to.
+    pub fn terms_hash(&self) -> [u8; 32] {
+        // Fixed-size fields first, so the trailing options need no length
+        let mut payment_options = Vec::with_capacity(self.payment_options.len() * 40);
+        for option in &self.payment_options {
+            payment_options.extend_from_slice(option.mint.as_ref());
+            payment_options.extend_from_slice(&option.price.to_le_bytes());
+        }
+        hashv(&[
+            self.seller.as_ref(),
+            &self.ticket_commitment,
+            &self.nullifier_seed,
+            &self.price_lamports.to_le_bytes(),
+            self.event_config.as_ref(),
+            &self.ticket_id.to_le_bytes(),
+            &[self.official_exchange as u8],
+            &self.face_value_lamports.to_le_bytes(),
+            &self.seller_bond.to_le_bytes(),
+            &[self.auto_relist as u8],
+            &payment_options,
+        ])
+        .to_bytes()
+    }
+
+    /// The active claim, if the listing is Claimed.
+    pub fn claim(&self) -> Option<&ListingClaim> {
+        match &self.status {
+            ListingStatus::Claimed(claim) => Some(claim),
+            _ => None,
+        }
+    }
+
+    /// Price an official exchange listing must carry: face value plus the
+    /// protocol sale fee and any event royalty, both taken on face value.
+    pub fn exchange_price(
+        face_value_lamports: u64,
+        protocol_config: &ProtocolConfig,
+        event_config: &EventConfig,
+    ) -> Option<u64> {
+        let royalty = event_config
+            .royalty
+            .as_ref()
+            .map(|royalty| royalty.royalty(face_value_lamports))
+            .unwrap_or(0);
+        face_value_lamports
+            .checked_add(protocol_config.sale_fee(face_value_lamports))?
+            .checked_add(royalty)
+    }
+
+    /// Price in `mint` base units, if the listing accepts it.
+    pub fn payment_price(&self, mint: &Pubkey) -> Option<u64> {
+        self.payment_options
+            .iter()
+            .find(|option| option.mint == *mint)
+            .map(|option| option.price)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == ListingStatus::Active
+    }
+}
+
+/// Buyer's claim on a listing, held in `ListingStatus::Claimed`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct ListingClaim {
+    /// Who claimed the listing
+    pub buyer: Pubkey,
+
+    /// Buyer's new commitment for ticket transfer
+    pub buyer_commitment: [u8; 32],
+
+    /// Timestamp for timeout
+    pub claimed_at: i64,
+
+    /// `Listing::terms_hash()` when the claim was made
+    pub terms_hash: [u8; 32],
+
+    /// SPL mint the buyer paid in, or None for SOL
+    pub payment_mint: Option<Pubkey>,
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace, Default,
+)]
 pub enum ListingStatus {
-    Active,    // For sale
-    Claimed,   // Buyer locked, awaiting payment
-    Completed, // Sold
-    Cancelled, // Seller cancelled
+    #[default]
+    Active, // For sale
+    Claimed(ListingClaim), // Buyer locked, awaiting payment
+    Completed,             // Sold
+    Cancelled,             // Seller cancelled
+}
+
+impl ListingStatus {
+    /// Borsh variant tags, as stored at `Listing::STATUS_OFFSET`
+    pub const ACTIVE_TAG: u8 = 0;
+    pub const CLAIMED_TAG: u8 = 1;
+    pub const COMPLETED_TAG: u8 = 2;
+    pub const CANCELLED_TAG: u8 = 3;
 }
 
-impl Default for ListingStatus {
-    fn default() -> Self {
-        ListingStatus::Active
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(status: ListingStatus) -> Listing {
+        Listing {
+            event_config: Pubkey::new_from_array([1; 32]),
+            seller: Pubkey::new_from_array([2; 32]),
+            ticket_commitment: [3; 32],
+            nullifier_seed: [4; 32],
+            price_lamports: 5,
+            ticket_id: 6,
+            nonce: 7,
+            queue_head: 8,
+            queue_tail: 9,
+            official_exchange: true,
+            face_value_lamports: 10,
+            seller_bond: 11,
+            auto_relist: true,
+            created_at: 12,
+            bump: 13,
+            status,
+            payment_options: Vec::new(),
+            settled_at: 14,
+        }
+    }
+
+    #[test]
+    fn filter_offsets_match_serialized_layout() {
+        let claim = ListingClaim {
+            buyer: Pubkey::new_from_array([15; 32]),
+            buyer_commitment: [16; 32],
+            claimed_at: 17,
+            terms_hash: [18; 32],
+            payment_mint: None,
+        };
+        for (status, tag) in [
+            (ListingStatus::Active, ListingStatus::ACTIVE_TAG),
+            (ListingStatus::Claimed(claim), ListingStatus::CLAIMED_TAG),
+            (ListingStatus::Completed, ListingStatus::COMPLETED_TAG),
+            (ListingStatus::Cancelled, ListingStatus::CANCELLED_TAG),
+        ] {
+            let mut data = Vec::new();
+            listing(status).try_serialize(&mut data).unwrap();
+
+            assert_eq!(data[Listing::STATUS_OFFSET], tag);
+            assert_eq!(
+                data[Listing::SELLER_OFFSET..Listing::SELLER_OFFSET + 32],
+                [2; 32]
+            );
+            assert_eq!(
+                data[Listing::EVENT_CONFIG_OFFSET..Listing::EVENT_CONFIG_OFFSET + 32],
+                [1; 32]
+            );
+        }
+    }
+
+    #[test]
+    fn terms_hash_covers_every_term() {
+        let base = listing(ListingStatus::Active);
+        let option = PaymentOption {
+            mint: Pubkey::new_from_array([19; 32]),
+            price: 20,
+        };
+        let changes: [fn(&mut Listing); 11] = [
+            |l| l.seller = Pubkey::new_from_array([21; 32]),
+            |l| l.ticket_commitment = [21; 32],
+            |l| l.nullifier_seed = [21; 32],
+            |l| l.price_lamports += 1,
+            |l| l.event_config = Pubkey::new_from_array([21; 32]),
+            |l| l.ticket_id += 1,
+            |l| l.official_exchange = !l.official_exchange,
+            |l| l.face_value_lamports += 1,
+            |l| l.seller_bond += 1,
+            |l| l.auto_relist = !l.auto_relist,
+            |l| {
+                l.payment_options.push(PaymentOption {
+                    mint: Pubkey::new_from_array([19; 32]),
+                    price: 20,
+                })
+            },
+        ];
+        for change in changes {
+            let mut changed = base.clone();
+            change(&mut changed);
+            assert_ne!(changed.terms_hash(), base.terms_hash());
+        }
+
+        // Re-pricing an accepted mint changes the terms too
+        let mut priced = base.clone();
+        priced.payment_options.push(option);
+        let mut repriced = priced.clone();
+        repriced.payment_options[0].price += 1;
+        assert_ne!(repriced.terms_hash(), priced.terms_hash());
     }
 }
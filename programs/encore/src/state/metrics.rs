@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// Protocol-wide running totals, so dashboards read one account instead
+/// of indexing every event's history.
+///
+/// Bumped by the instructions that create events, mint tickets, settle
+/// resales and collect protocol fees; nothing else writes it.
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolMetrics {
+    pub events_created: u64,
+
+    /// Tickets issued by every path: mints, comps, waitlist promotions,
+    /// fiat holds and exchanges
+    pub tickets_minted: u64,
+
+    /// Settled resales and their gross value in lamports. Resales paid in
+    /// an SPL mint count in `resales` only.
+    pub resales: u64,
+    pub resale_volume: u64,
+
+    /// Protocol fees collected, in lamports (see `ProtocolTreasury` for
+    /// the breakdown)
+    pub fees_collected: u64,
+
+    /// PDA bump for metrics derivation
+    pub bump: u8,
+}
+
+impl ProtocolMetrics {
+    fn add(total: &mut u64, amount: u64) -> Result<()> {
+        *total = total
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_event(&mut self) -> Result<()> {
+        Self::add(&mut self.events_created, 1)
+    }
+
+    pub fn record_mints(&mut self, tickets: u32) -> Result<()> {
+        Self::add(&mut self.tickets_minted, tickets as u64)
+    }
+
+    pub fn record_resale(&mut self, price_lamports: u64) -> Result<()> {
+        Self::add(&mut self.resales, 1)?;
+        Self::add(&mut self.resale_volume, price_lamports)
+    }
+
+    pub fn record_fee(&mut self, amount: u64) -> Result<()> {
+        Self::add(&mut self.fees_collected, amount)
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Backend service key allowed to issue comp tickets for an event.
+///
+/// Lets an organizer run comps and airdrops from a web server without
+/// putting the event authority's key there. Each delegate mints at most
+/// `quota` tickets; revoking closes the account.
+#[account]
+#[derive(InitSpace)]
+pub struct MintDelegate {
+    /// Event the delegate mints for
+    pub event_config: Pubkey,
+
+    /// Service key that signs comp mints
+    pub delegate: Pubkey,
+
+    /// Lifetime tickets the delegate may mint
+    pub quota: u32,
+
+    /// Tickets minted so far
+    pub minted: u32,
+
+    /// PDA bump for delegate derivation
+    pub bump: u8,
+}
+
+impl MintDelegate {
+    pub fn remaining(&self) -> u32 {
+        self.quota.saturating_sub(self.minted)
+    }
+}
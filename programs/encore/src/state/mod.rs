@@ -1,11 +1,97 @@
 //! State account definitions
 
+pub mod addon;
+pub mod allocation;
+pub mod announcement;
+pub mod blocked_buyer;
+pub mod client_nonce;
+pub mod compliance;
+pub mod credential;
+pub mod credit;
+pub mod custom_field;
+pub mod disclosure;
+pub mod entrance_stats;
 pub mod event_config;
+pub mod exchange;
+pub mod fan_lane;
+pub mod fee_exemption;
+pub mod fiat_hold;
+pub mod gate_use;
+pub mod gift;
+pub mod guardian;
+pub mod identity_counter;
+pub mod insurance;
+pub mod ledger;
 pub mod listing;
+pub mod metrics;
+pub mod mint_delegate;
 pub mod nullifier;
+pub mod order;
+pub mod partial_refund;
+pub mod payout;
+pub mod presale;
+pub mod price_feed;
+pub mod proceeds_hold;
+pub mod protocol_config;
+pub mod queued_claim;
+pub mod receipt;
+pub mod registered_domain;
+pub mod report;
+pub mod reputation;
+pub mod royalty;
+pub mod scanner;
+pub mod seat_reassignment;
+pub mod seller_counter;
+pub mod sponsor_pool;
 pub mod ticket;
+pub mod transfer;
+pub mod treasury;
+pub mod waitlist;
 
+pub use addon::*;
+pub use allocation::*;
+pub use announcement::*;
+pub use blocked_buyer::*;
+pub use client_nonce::*;
+pub use compliance::*;
+pub use credential::*;
+pub use credit::*;
+pub use custom_field::*;
+pub use disclosure::*;
+pub use entrance_stats::*;
 pub use event_config::*;
+pub use exchange::*;
+pub use fan_lane::*;
+pub use fee_exemption::*;
+pub use fiat_hold::*;
+pub use gate_use::*;
+pub use gift::*;
+pub use guardian::*;
+pub use identity_counter::*;
+pub use insurance::*;
+pub use ledger::*;
 pub use listing::*;
+pub use metrics::*;
+pub use mint_delegate::*;
 pub use nullifier::*;
+pub use order::*;
+pub use partial_refund::*;
+pub use payout::*;
+pub use presale::*;
+pub use price_feed::*;
+pub use proceeds_hold::*;
+pub use protocol_config::*;
+pub use queued_claim::*;
+pub use receipt::*;
+pub use registered_domain::*;
+pub use report::*;
+pub use reputation::*;
+pub use royalty::*;
+pub use scanner::*;
+pub use seat_reassignment::*;
+pub use seller_counter::*;
+pub use sponsor_pool::*;
 pub use ticket::*;
+pub use transfer::*;
+pub use treasury::*;
+pub use waitlist::*;
@@ -3,13 +3,24 @@ use light_sdk::LightDiscriminator;
 
 /// Nullifier account - prevents double-spending of tickets.
 ///
-/// This is an empty struct - existence is the proof.
+/// Existence is the proof.
 /// When a ticket is transferred:
 /// 1. Compute nullifier_seed = hash("nullifier" || seller_secret)
 /// 2. CREATE nullifier account at derived address
 /// 3. If address already exists → transfer fails (double-spend prevented)
 ///
 /// The nullifier is derived from the seller's secret, which is unique per ticket.
+///
+/// # Audit
+/// Each nullifier records its event and a gap-free per-event `sequence`
+/// (see `EventConfig::next_nullifier_sequence`), so an indexer can check
+/// that the nullifiers it sees for an event are exactly `0..nullifiers_created`.
 #[event]
 #[derive(Clone, Debug, Default, LightDiscriminator)]
-pub struct Nullifier {}
+pub struct Nullifier {
+    /// Event whose ticket was spent
+    pub event_config: Pubkey,
+
+    /// Position among the event's nullifiers
+    pub sequence: u64,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::SIGNED_ORDER_DOMAIN;
+
+/// Sell order signed off-chain by the ticket holder.
+///
+/// The buyer submits it to `fill_signed_order` together with an ed25519
+/// program instruction carrying the seller's signature over `message()`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SignedOrder {
+    /// The ticket being sold (commitment proves ownership)
+    pub ticket_commitment: [u8; 32],
+
+    /// Which ticket ID within the event
+    pub ticket_id: u32,
+
+    /// Ticket's original price and purchase rate, used for resale cap
+    /// enforcement
+    pub original_price: u64,
+    pub usd_micros_per_sol: Option<u64>,
+
+    /// Sale price in lamports
+    pub price_lamports: u64,
+
+    /// Order can't be filled after this timestamp
+    pub expires_at: i64,
+
+    /// Seller-chosen nonce making each order unique
+    pub nonce: u64,
+}
+
+impl SignedOrder {
+    /// Bytes the seller signs: domain || event_config || borsh(order).
+    pub fn message(&self, event_config: &Pubkey) -> Result<Vec<u8>> {
+        let mut message = Vec::with_capacity(SIGNED_ORDER_DOMAIN.len() + 32 + 96);
+        message.extend_from_slice(SIGNED_ORDER_DOMAIN);
+        message.extend_from_slice(event_config.as_ref());
+        message.extend_from_slice(&self.try_to_vec()?);
+        Ok(message)
+    }
+}
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use light_sdk::LightDiscriminator;
+
+use crate::bps;
+
+/// Partial refund an organizer offers after a material change to an event
+/// (headliner cancels, venue downsizes) that doesn't warrant cancellation.
+///
+/// The account holds the funded lamports on top of its rent. Holders claim
+/// `refund_bps` of their ticket's original price and keep the ticket.
+///
+/// # Underfunding
+/// While the program holds less than the refunds still `outstanding`, a
+/// claim pays its refund pro rata: `refund * available / outstanding` (see
+/// `payout`). Claim order doesn't decide who is refunded: every ticket is
+/// paid the same fraction of what it's owed. The unpaid remainder is
+/// recorded on the ticket's `PartialRefundClaim` and added to `shortfall`,
+/// and `top_up_partial_refund` pays it out, on the same terms, once more
+/// funding arrives.
+#[account]
+#[derive(InitSpace)]
+pub struct PartialRefund {
+    /// Event the refund is for
+    pub event_config: Pubkey,
+
+    /// Refund per ticket, in basis points of its original price
+    pub refund_bps: u16,
+
+    /// Claims are accepted until this time; afterwards the organizer can
+    /// close the program and recover what's left
+    pub claim_deadline: i64,
+
+    /// Lifetime lamports funded and claimed
+    pub funded: u64,
+    pub claimed: u64,
+
+    /// Number of tickets refunded
+    pub claims: u32,
+
+    /// Refunds owed and not yet paid, claimed or not: declared by the
+    /// organizer at opening and reduced by every payment
+    pub outstanding: u64,
+
+    /// Remainders recorded on claims paid pro rata, awaiting a top-up
+    pub shortfall: u64,
+
+    /// When the program was opened
+    pub created_at: i64,
+
+    /// PDA bump for refund derivation
+    pub bump: u8,
+}
+
+impl PartialRefund {
+    /// Refund owed on a ticket bought at `original_price`.
+    pub fn refund(&self, original_price: u64) -> u64 {
+        bps::share(original_price, self.refund_bps as u64)
+    }
+
+    /// What a payment of `due` lamports pays while the program holds
+    /// `available`: all of it when the program covers everything
+    /// outstanding, otherwise `due * available / outstanding`. `due` counts
+    /// towards the total even if the organizer's figure left it out.
+    pub fn payout(&self, due: u64, available: u64) -> u64 {
+        let outstanding = self.outstanding.max(due);
+        if available >= outstanding {
+            return due;
+        }
+        (due as u128 * available as u128 / outstanding as u128) as u64
+    }
+}
+
+/// Marks a ticket as refunded under a partial refund program, stored as a
+/// compressed account at derive([PARTIAL_REFUND_SEED, partial_refund, ticket_id]).
+///
+/// Existence is the proof: a second claim for the same ticket, even after
+/// resale, fails to create the address. A claim paid pro rata keeps what
+/// it's still owed here for `top_up_partial_refund`.
+#[event]
+#[derive(Clone, Debug, Default, LightDiscriminator)]
+pub struct PartialRefundClaim {
+    /// Refund program the claim was paid from
+    pub partial_refund: Pubkey,
+
+    /// Ticket identifier within the event
+    pub ticket_id: u32,
+
+    /// Holder who claimed, and who top-ups are paid to
+    pub holder: Pubkey,
+
+    /// Refund owed on the ticket, and how much of it has been paid
+    pub owed: u64,
+    pub paid: u64,
+}
+
+impl PartialRefundClaim {
+    /// What the claim is still owed.
+    pub fn remaining(&self) -> u64 {
+        self.owed.saturating_sub(self.paid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(outstanding: u64) -> PartialRefund {
+        PartialRefund {
+            event_config: Pubkey::default(),
+            refund_bps: 2_500,
+            claim_deadline: 0,
+            funded: 0,
+            claimed: 0,
+            claims: 0,
+            outstanding,
+            shortfall: 0,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn pays_in_full_when_funded() {
+        let partial_refund = program(1_000);
+        assert_eq!(partial_refund.refund(4_000), 1_000);
+        assert_eq!(partial_refund.payout(250, 1_000), 250);
+        assert_eq!(partial_refund.payout(250, 5_000), 250);
+    }
+
+    #[test]
+    fn underfunded_pays_pro_rata_to_what_is_owed() {
+        // Half funded: a cheap and an expensive ticket both get half
+        let partial_refund = program(1_000);
+        assert_eq!(partial_refund.payout(100, 500), 50);
+        assert_eq!(partial_refund.payout(900, 500), 450);
+    }
+
+    #[test]
+    fn due_counts_even_when_left_out_of_the_total() {
+        let partial_refund = program(100);
+        assert_eq!(partial_refund.payout(400, 200), 200);
+    }
+
+    #[test]
+    fn remainder_is_what_was_not_paid() {
+        let claim = PartialRefundClaim {
+            owed: 900,
+            paid: 450,
+            ..Default::default()
+        };
+        assert_eq!(claim.remaining(), 450);
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Amount owed to one payout recipient of an event.
+///
+/// Settlement credits accruals instead of pushing transfers to every
+/// recipient; the recipient pulls with `claim_payout`. The account holds
+/// the owed lamports on top of its rent.
+#[account]
+#[derive(InitSpace)]
+pub struct PayoutAccrual {
+    /// Event the payouts come from
+    pub event_config: Pubkey,
+
+    /// Who may claim
+    pub recipient: Pubkey,
+
+    /// Credited and not yet claimed
+    pub owed: u64,
+
+    /// Lifetime amount claimed
+    pub claimed: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::SNAPSHOT_LEAF_DOMAIN;
+
+/// Presale open only to wallets in a past event's snapshot.
+///
+/// The root is copied from the source event when the presale is set, so
+/// the allowlist can't change under buyers if the source event republishes.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Presale {
+    /// Event whose attendance or holder snapshot is the allowlist
+    pub source_event: Pubkey,
+
+    /// Merkle root of the snapshot, over `snapshot_leaf(wallet)`
+    pub snapshot_root: [u8; 32],
+
+    /// General mints open at this time
+    pub ends_at: i64,
+}
+
+impl Presale {
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.ends_at
+    }
+
+    /// True if `wallet` is in the snapshot. Proof nodes are hashed with
+    /// their sibling in sorted order, so no path bits are needed.
+    pub fn allows(&self, wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        let root = proof.iter().fold(snapshot_leaf(wallet), |node, sibling| {
+            if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            }
+        });
+        root == self.snapshot_root
+    }
+}
+
+/// Snapshot leaf for `wallet`. Domain-separated so a leaf can't be passed
+/// off as an inner node.
+pub fn snapshot_leaf(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[SNAPSHOT_LEAF_DOMAIN, wallet.as_ref()]).to_bytes()
+}
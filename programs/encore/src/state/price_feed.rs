@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// SOL/USD reference price pushed by the protocol's oracle authority
+/// (e.g. a relayer forwarding Pyth updates).
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFeed {
+    /// Key allowed to push price updates
+    pub oracle_authority: Pubkey,
+
+    /// Price of 1 SOL in micro-USD (1 USD = 1_000_000)
+    pub usd_micros_per_sol: u64,
+
+    /// When the price was last updated
+    pub updated_at: i64,
+
+    /// PDA bump for price feed derivation
+    pub bump: u8,
+}
+
+impl PriceFeed {
+    pub fn is_fresh(&self, now: i64, max_age_seconds: i64) -> bool {
+        self.updated_at > 0 && now.saturating_sub(self.updated_at) <= max_age_seconds
+    }
+
+    /// Convert lamports to micro-USD at the current price.
+    pub fn lamports_to_usd_micros(&self, lamports: u64) -> u64 {
+        (lamports as u128)
+            .checked_mul(self.usd_micros_per_sol as u128)
+            .map(|v| v / 1_000_000_000)
+            .and_then(|v| u64::try_from(v).ok())
+            .unwrap_or(u64::MAX)
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EncoreError;
+
+/// What happened to the ticket a held sale delivered.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum HoldStatus {
+    Held,     // Waiting for check-in or the event date
+    Redeemed, // Buyer checked in; seller may withdraw
+    Voided,   // Ticket was voided; buyer may reclaim
+}
+
+/// Resale proceeds held back from the seller until the buyer checks in.
+///
+/// Created by `complete_sale` when the event sets `escrow_resale_proceeds`.
+/// The account itself holds the lamports. `ticket_address` is the buyer's
+/// new ticket, so `redeem_ticket` and `void_ticket` can settle the hold when
+/// they close that exact ticket.
+#[account]
+#[derive(InitSpace)]
+pub struct ProceedsHold {
+    /// Listing the sale completed
+    pub listing: Pubkey,
+
+    /// Event the ticket belongs to
+    pub event_config: Pubkey,
+
+    /// Seller owed the proceeds
+    pub seller: Pubkey,
+
+    /// Buyer refunded if the ticket is voided
+    pub buyer: Pubkey,
+
+    /// Compressed address of the ticket issued to the buyer
+    pub ticket_address: [u8; 32],
+
+    /// Lamports held (seller proceeds, after the protocol fee)
+    pub amount: u64,
+
+    pub status: HoldStatus,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ProceedsHold {
+    /// Settle the hold when its ticket is redeemed or voided.
+    pub fn settle(&mut self, ticket_address: &[u8; 32], status: HoldStatus) -> Result<()> {
+        require!(
+            self.ticket_address == *ticket_address,
+            EncoreError::ProceedsHoldMismatch
+        );
+        if self.status == HoldStatus::Held {
+            self.status = status;
+        }
+        Ok(())
+    }
+}
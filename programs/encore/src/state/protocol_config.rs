@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::bps;
+
+/// Protocol-wide configuration, a singleton PDA controlled by the protocol admin.
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolConfig {
+    /// Protocol admin allowed to co-sign sensitive organizer actions
+    pub admin: Pubkey,
+
+    /// When set, `void_ticket` must be co-signed by the protocol admin
+    pub void_requires_admin: bool,
+
+    /// Protocol fee on primary mints (basis points of the amount paid)
+    pub mint_fee_bps: u16,
+
+    /// Protocol fee on marketplace sales (basis points of the sale price)
+    pub sale_fee_bps: u16,
+
+    /// Force-majeure oracle allowed to cancel any event (weather, venue
+    /// closure) without the organizer, activating refunds
+    pub cancellation_oracle: Option<Pubkey>,
+
+    /// PDA bump for protocol config derivation
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub fn mint_fee(&self, amount: u64) -> u64 {
+        bps::share(amount, self.mint_fee_bps as u64)
+    }
+
+    pub fn sale_fee(&self, amount: u64) -> u64 {
+        bps::share(amount, self.sale_fee_bps as u64)
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Backup claim waiting behind a listing's active claim.
+///
+/// Holds the buyer's payment in its own lamports (escrow sub-account).
+/// Claims are ranked by `position`; when the active claim is cancelled or
+/// released, the claim at `listing.queue_head` is promoted.
+#[account]
+#[derive(InitSpace)]
+pub struct QueuedClaim {
+    /// Listing this claim is queued on
+    pub listing: Pubkey,
+
+    /// `created_at` of the listing, to detect a relisted PDA
+    pub listing_created_at: i64,
+
+    /// Event the listed ticket belongs to (for ledger updates)
+    pub event_config: Pubkey,
+
+    /// Buyer who queued, receives refunds
+    pub buyer: Pubkey,
+
+    /// Buyer's new commitment for ticket transfer
+    pub buyer_commitment: [u8; 32],
+
+    /// Rank in the queue (lower is served first)
+    pub position: u32,
+
+    /// Lamports escrowed on this account (excluding rent)
+    pub amount: u64,
+
+    /// Buyer withdrew; skipped on promotion
+    pub withdrawn: bool,
+
+    /// When the claim was queued
+    pub queued_at: i64,
+
+    /// PDA bump for queued claim derivation
+    pub bump: u8,
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use light_sdk::LightDiscriminator;
+
+/// Purchase receipt stored as compressed account, created at mint when a
+/// price feed is supplied.
+///
+/// Records the fiat value of the purchase so fiat-denominated policy
+/// (consumer protection, resale caps) can be audited later even if SOL moves.
+/// Address: derive([RECEIPT_SEED, ticket_address]). Holds no owner data.
+#[event]
+#[derive(Clone, Debug, Default, LightDiscriminator)]
+pub struct PurchaseReceipt {
+    /// Event the ticket belongs to
+    pub event_config: Pubkey,
+
+    /// Ticket identifier within the event
+    pub ticket_id: u32,
+
+    /// Price paid in lamports
+    pub price_lamports: u64,
+
+    /// Price paid in micro-USD at mint
+    pub price_usd_micros: u64,
+
+    /// SOL/USD rate used (micro-USD per SOL)
+    pub usd_micros_per_sol: u64,
+
+    /// Mint timestamp
+    pub minted_at: i64,
+}
+
+/// Redemption receipt, emitted by `redeem_ticket` and optionally stored as
+/// a compressed account at derive([REDEMPTION_RECEIPT_SEED, nullifier]).
+///
+/// Gate devices cache receipts from the logs. A secondary checkpoint
+/// verifies entry offline by matching a presented receipt's `digest`
+/// against its cache and having the holder sign a fresh challenge with the
+/// `holder` key.
+#[event]
+#[derive(Clone, Debug, Default, LightDiscriminator)]
+pub struct RedemptionReceipt {
+    /// Event the ticket belongs to
+    pub event_config: Pubkey,
+
+    /// Ticket identifier within the event
+    pub ticket_id: u32,
+
+    /// Wallet that checked in
+    pub holder: Pubkey,
+
+    /// Address of the ticket's nullifier, created at check-in
+    pub nullifier: [u8; 32],
+
+    /// Check-in timestamp
+    pub redeemed_at: i64,
+
+    /// hash of the fields above, what devices cache and compare
+    pub digest: [u8; 32],
+
+    /// Scanner and entrance that attested the check-in, if any. Recorded
+    /// for gate statistics; not part of the digest.
+    pub scanner: Option<Pubkey>,
+    pub zone: Option<u8>,
+}
+
+impl RedemptionReceipt {
+    pub fn new(
+        event_config: Pubkey,
+        ticket_id: u32,
+        holder: Pubkey,
+        nullifier: [u8; 32],
+        redeemed_at: i64,
+        scanner: Option<Pubkey>,
+        zone: Option<u8>,
+    ) -> Self {
+        let digest = hashv(&[
+            event_config.as_ref(),
+            &ticket_id.to_le_bytes(),
+            holder.as_ref(),
+            &nullifier,
+            &redeemed_at.to_le_bytes(),
+        ])
+        .to_bytes();
+        Self {
+            event_config,
+            ticket_id,
+            holder,
+            nullifier,
+            redeemed_at,
+            digest,
+            scanner,
+            zone,
+        }
+    }
+}
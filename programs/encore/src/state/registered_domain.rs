@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::constants::MAX_DOMAIN_LEN;
+
+/// A frontend domain the protocol admin vouches for.
+///
+/// Lives at `[DOMAIN_SEED, hash(domain)]`, so a wallet asked to sign an
+/// Encore transaction derives the address from the requesting origin and
+/// warns when nothing is registered there. Ticket secrets are derived from
+/// wallet signatures, so a phishing frontend that gets one signed can take
+/// the ticket.
+#[account]
+#[derive(InitSpace)]
+pub struct RegisteredDomain {
+    /// Lowercase host name, e.g. "app.encore.xyz"
+    #[max_len(MAX_DOMAIN_LEN)]
+    pub domain: String,
+
+    /// `RegisteredDomain::hash_domain(domain)`
+    pub domain_hash: [u8; 32],
+
+    /// Hash of the frontend build served from the domain, if the admin
+    /// pins one
+    pub release_hash: Option<[u8; 32]>,
+
+    /// When the domain was first registered
+    pub registered_at: i64,
+
+    /// PDA bump for domain derivation
+    pub bump: u8,
+}
+
+impl RegisteredDomain {
+    pub fn hash_domain(domain: &str) -> [u8; 32] {
+        hash(domain.as_bytes()).to_bytes()
+    }
+
+    /// A bare lowercase host name: no scheme, port, path or trailing dot,
+    /// so every client hashes an origin the same way.
+    pub fn is_canonical(domain: &str) -> bool {
+        !domain.is_empty()
+            && domain.len() <= MAX_DOMAIN_LEN
+            && domain.split('.').all(|label| {
+                !label.is_empty()
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label
+                        .bytes()
+                        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+            })
+    }
+}
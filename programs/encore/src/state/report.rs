@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::HOLDER_LEAF_DOMAIN;
+use crate::state::{EventConfig, EventLedger};
+
+/// Settlement summary an organizer publishes for sponsors and rights-holders.
+///
+/// Every field maps to an on-chain counter, so a published report can be
+/// checked against the event's stats and ledger in the same instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct SettlementReport {
+    /// Tickets sold (`tickets_minted`)
+    pub tickets_sold: u32,
+    pub tickets_voided: u32,
+    /// Attendance (`tickets_redeemed`)
+    pub tickets_redeemed: u32,
+    /// Primary sales revenue paid to the organizer
+    pub sales_lamports: u64,
+    /// Refunds issued as credits or paid from the insurance pool
+    pub refunds_lamports: u64,
+    /// Resale proceeds settled to sellers
+    pub resale_lamports: u64,
+}
+
+impl SettlementReport {
+    /// The report as the event's counters currently stand.
+    pub fn from_counters(event_config: &EventConfig, ledger: &EventLedger) -> Result<Self> {
+        Ok(Self {
+            tickets_sold: event_config.tickets_minted,
+            tickets_voided: event_config.tickets_voided,
+            tickets_redeemed: event_config.tickets_redeemed,
+            sales_lamports: ledger.mint_revenue,
+            refunds_lamports: ledger
+                .credits_issued
+                .checked_add(ledger.insurance_payouts)
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+            resale_lamports: ledger.resale_settlements,
+        })
+    }
+
+    /// Hash the organizer signs off on: SHA256 over the little-endian fields.
+    pub fn hash(&self, event_config: &Pubkey) -> [u8; 32] {
+        hashv(&[
+            event_config.as_ref(),
+            &self.tickets_sold.to_le_bytes(),
+            &self.tickets_voided.to_le_bytes(),
+            &self.tickets_redeemed.to_le_bytes(),
+            &self.sales_lamports.to_le_bytes(),
+            &self.refunds_lamports.to_le_bytes(),
+            &self.resale_lamports.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+}
+
+/// Leaf for `owner_commitment` in a published holder set. Domain-separated
+/// so a leaf can't be passed off as an inner node.
+///
+/// The set is built from live tickets as of `HolderCountPublished::as_of_nullifier`:
+/// each distinct owner commitment once, leaves sorted, pairs hashed in
+/// sorted order (as in presale snapshots), so anyone replaying the
+/// compressed ticket state can rebuild the same root.
+pub fn holder_leaf(owner_commitment: &[u8; 32]) -> [u8; 32] {
+    hashv(&[HOLDER_LEAF_DOMAIN, owner_commitment]).to_bytes()
+}
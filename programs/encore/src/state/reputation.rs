@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+/// Marketplace outcome counted on a wallet's reputation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReputationEntry {
+    CompletedSale,  // Seller completed a sale
+    SellerTimeout,  // Seller let a claim expire without completing
+    AbandonedClaim, // Buyer cancelled or never paid a claim
+}
+
+/// Per-wallet marketplace track record.
+///
+/// Created on first use by whichever listing instruction records an
+/// outcome for the wallet. Frontends read it to surface counterparty risk.
+#[account]
+#[derive(InitSpace)]
+pub struct Reputation {
+    /// Wallet this record belongs to
+    pub wallet: Pubkey,
+
+    pub completed_sales: u32,
+    pub seller_timeouts: u32,
+    pub abandoned_claims: u32,
+
+    /// Last time an outcome was recorded
+    pub updated_at: i64,
+
+    /// PDA bump for reputation derivation
+    pub bump: u8,
+}
+
+impl Reputation {
+    /// Count one outcome for `wallet`, initializing the record if new.
+    pub fn record(&mut self, wallet: Pubkey, bump: u8, entry: ReputationEntry) -> Result<()> {
+        self.wallet = wallet;
+        self.bump = bump;
+        let counter = match entry {
+            ReputationEntry::CompletedSale => &mut self.completed_sales,
+            ReputationEntry::SellerTimeout => &mut self.seller_timeouts,
+            ReputationEntry::AbandonedClaim => &mut self.abandoned_claims,
+        };
+        *counter = counter
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
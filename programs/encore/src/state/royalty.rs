@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::bps::{self, BPS_DENOMINATOR};
+use crate::constants::{MAX_ROYALTY_BPS, MAX_ROYALTY_SPLITS};
+
+/// One recipient's share of the royalty slice.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RoyaltySplit {
+    pub recipient: Pubkey,
+
+    /// Share of the royalty, in basis points; an event's shares sum to 10000
+    pub bps: u16,
+}
+
+/// Royalty an organizer takes on marketplace resales, split among recipients
+/// (headliner, opening act, songwriter fund, ...).
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq, Debug)]
+pub struct RoyaltyConfig {
+    /// Royalty on each resale, in basis points of the sale price
+    pub royalty_bps: u16,
+
+    #[max_len(MAX_ROYALTY_SPLITS)]
+    pub splits: Vec<RoyaltySplit>,
+}
+
+impl RoyaltyConfig {
+    pub fn is_valid(&self) -> bool {
+        let total: u32 = self.splits.iter().map(|split| split.bps as u32).sum();
+        self.royalty_bps > 0
+            && self.royalty_bps <= MAX_ROYALTY_BPS
+            && !self.splits.is_empty()
+            && self.splits.len() <= MAX_ROYALTY_SPLITS
+            && total == BPS_DENOMINATOR as u32
+    }
+
+    pub fn royalty(&self, sale_price: u64) -> u64 {
+        bps::share(sale_price, self.royalty_bps as u64)
+    }
+}
+
+/// Per-event vault collecting royalties from `complete_sale` and declared
+/// transfer sales.
+///
+/// Sales only add to `pending`, so settlement cost doesn't grow with the
+/// number of recipients; `distribute_royalties` credits the splits to their
+/// `PayoutAccrual`s.
+#[account]
+#[derive(InitSpace)]
+pub struct RoyaltyVault {
+    /// Event the vault belongs to
+    pub event_config: Pubkey,
+
+    /// Royalties collected and not yet distributed
+    pub pending: u64,
+
+    /// Lifetime royalties paid out to recipients
+    pub distributed: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// A gate device registered by the event organizer.
+///
+/// The organizer's signature on `register_scanner` is the attestation: it
+/// binds the device key to a set of entrances (`zones`, one bit per zone)
+/// until `expires_at`. A stolen device is cut off per entrance by
+/// `revoke_scanner` without touching the others.
+#[account]
+#[derive(InitSpace)]
+pub struct Scanner {
+    /// Event the scanner checks tickets for
+    pub event_config: Pubkey,
+
+    /// Key the device signs redemptions with
+    pub device_key: Pubkey,
+
+    /// Entrances the device may scan at, one bit per zone
+    pub zones: u32,
+
+    /// After this the device can't scan anywhere
+    pub expires_at: i64,
+
+    /// When the device was registered
+    pub registered_at: i64,
+
+    /// PDA bump for scanner derivation
+    pub bump: u8,
+}
+
+impl Scanner {
+    pub fn can_scan(&self, zone: u8, now: i64) -> bool {
+        zone < 32 && self.zones & (1 << zone) != 0 && now <= self.expires_at
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CUSTOM_FIELDS;
+use crate::state::FieldValue;
+
+/// Organizer's offer to move a ticket to a new seat after a production
+/// change (e.g. a stage extension removes rows A-C).
+///
+/// The seat lives in the ticket's custom fields. The holder accepts with
+/// `accept_seat_reassignment`, which reissues the ticket with `custom_fields`
+/// and, when `compensation` is set, a refund credit for that amount.
+#[account]
+#[derive(InitSpace)]
+pub struct SeatReassignment {
+    /// Event the ticket belongs to
+    pub event_config: Pubkey,
+
+    /// Ticket being moved
+    pub ticket_id: u32,
+
+    /// Full replacement custom-field values, in schema order
+    #[max_len(MAX_CUSTOM_FIELDS)]
+    pub custom_fields: Vec<FieldValue>,
+
+    /// Refund credit offered with the move, in lamports (0 = none)
+    pub compensation: u64,
+
+    /// When the organizer proposed the move
+    pub proposed_at: i64,
+
+    /// PDA bump for reassignment derivation
+    pub bump: u8,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Per-seller listing counter.
+///
+/// Each listing takes the current count as its nonce, so relisting the same
+/// commitment after a cancel derives a fresh listing address.
+#[account]
+#[derive(InitSpace)]
+pub struct SellerCounter {
+    /// Seller this counter belongs to
+    pub seller: Pubkey,
+
+    /// Number of listings created; the next listing's nonce
+    pub listing_count: u64,
+
+    /// PDA bump for counter derivation
+    pub bump: u8,
+}
+
+impl SellerCounter {
+    /// Take the next listing nonce.
+    pub fn next_nonce(&mut self) -> Result<u64> {
+        let nonce = self.listing_count;
+        self.listing_count = self
+            .listing_count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(nonce)
+    }
+}
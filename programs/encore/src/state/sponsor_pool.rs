@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Organizer-funded vault paying buyers' transaction and Light fees.
+///
+/// Holds its balance as lamports above rent. When a mint goes through the
+/// pool's `relayer`, the relayer pays the fees and the pool reimburses it
+/// `lamports_per_mint`, up to `max_per_user` per buyer as tracked on their
+/// `IdentityCounter`. Buyers then need no SOL beyond the ticket price.
+#[account]
+#[derive(InitSpace)]
+pub struct SponsorPool {
+    /// Event whose mints the pool sponsors
+    pub event_config: Pubkey,
+
+    /// Fee payer the pool reimburses
+    pub relayer: Pubkey,
+
+    /// Reimbursed per sponsored mint
+    pub lamports_per_mint: u64,
+
+    /// Most one buyer can be sponsored for over the event
+    pub max_per_user: u64,
+
+    /// Lifetime funding and reimbursements
+    pub funded: u64,
+    pub sponsored: u64,
+
+    /// PDA bump for pool derivation
+    pub bump: u8,
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 use light_sdk::LightDiscriminator;
 
+use crate::constants::{KEY_NULLIFIER_DOMAIN, UNBOUND_COMMITMENT_DOMAIN};
+use crate::state::FieldValue;
+
 /// Private ticket stored as compressed account.
 ///
 /// Privacy: `owner_commitment` hides who owns the ticket.
@@ -20,9 +24,182 @@ pub struct PrivateTicket {
 
     /// Owner commitment: hash(owner_pubkey || secret)
     /// Only the owner who knows their secret can prove ownership.
-    /// Secret is derived from: hash(wallet_sign("ticket:{ticket_id}:{event_config}"))
+    /// Secret is derived from: hash(wallet_sign("ticket:{ticket_id}:{event_config}")),
+    /// see `crate::secret::SecretDerivation`
     pub owner_commitment: [u8; 32],
 
     /// Original mint price (public for resale cap calculation)
     pub original_price: u64,
+
+    /// SOL/USD rate (micro-USD per SOL) the ticket was bought at, when a
+    /// price feed was passed at mint. A USD-adjusted resale cap re-prices
+    /// `original_price` from this rate, or from the event's
+    /// `resale_cap_usd_rate` for tickets without one.
+    pub usd_micros_per_sol: Option<u64>,
+
+    /// Attendee-name commitment for named tickets: hash(verified identity).
+    /// Checked against an ID-verification attestation at redemption; cleared
+    /// on transfer so the new holder must personalize again.
+    pub name_commitment: Option<[u8; 32]>,
+
+    /// Shared by a linked pair (e.g. wheelchair + companion seat): the
+    /// primary ticket's address. Linked tickets transfer and redeem together.
+    pub link_id: Option<[u8; 32]>,
+
+    /// Rolling chain-of-custody hash: zero at mint, then
+    /// `next_provenance(previous, nullifier_address)` on every ownership
+    /// change. Anyone holding the ticket's history can replay the chain;
+    /// the chain itself reveals nothing.
+    pub provenance: [u8; 32],
+
+    /// Values for the event's custom-field schema (`EventConfig::custom_fields`),
+    /// in schema order; empty when unset. Carried over on transfer.
+    pub custom_fields: Vec<FieldValue>,
+
+    /// Add-ons bought with the ticket, as a bitmask over `EventConfig::addons`.
+    /// Shown at check-in so the venue can hand them out; carried over on transfer.
+    pub addons: u8,
+
+    /// How `owner_commitment` is formed, see `OwnershipMode`
+    pub ownership: OwnershipMode,
+}
+
+impl PrivateTicket {
+    /// Provenance of the ticket issued when a ticket with `provenance` is
+    /// spent under `nullifier_address`.
+    pub fn next_provenance(provenance: &[u8; 32], nullifier_address: &[u8; 32]) -> [u8; 32] {
+        hashv(&[provenance, nullifier_address]).to_bytes()
+    }
+
+    /// The ticket issued to `owner_commitment` when this one is spent under
+    /// `nullifier_address`. Id, face value and purchase rate, link, fields
+    /// and add-ons carry over from the spent ticket, the name is cleared for
+    /// the new holder to personalize, and the provenance extends the spent
+    /// ticket's.
+    pub fn reissue(
+        &self,
+        nullifier_address: &[u8; 32],
+        owner_commitment: [u8; 32],
+        ownership: OwnershipMode,
+    ) -> PrivateTicket {
+        PrivateTicket {
+            owner_commitment,
+            name_commitment: None,
+            provenance: Self::next_provenance(&self.provenance, nullifier_address),
+            ownership,
+            ..self.clone()
+        }
+    }
+
+    /// Commitment of an `Unbound` ticket: hash(domain || secret).
+    pub fn unbound_commitment(secret: &[u8; 32]) -> [u8; 32] {
+        hashv(&[UNBOUND_COMMITMENT_DOMAIN, secret]).to_bytes()
+    }
+
+    /// Nullifier seed of a `Key` ticket at `ticket_address`, standing in
+    /// for hash(secret). Each transfer moves the ticket to a new address,
+    /// so every holding gets its own nullifier.
+    pub fn key_nullifier_seed(ticket_address: &[u8; 32]) -> [u8; 32] {
+        hashv(&[KEY_NULLIFIER_DOMAIN, ticket_address]).to_bytes()
+    }
+}
+
+/// Whether a ticket's commitment binds a wallet.
+///
+/// `Unbound` tickets commit to the secret alone, for holders without a
+/// wallet yet (kiosk sales, gifts to newcomers). Anyone who learns the
+/// secret holds the ticket, so they can't be redeemed, listed or otherwise
+/// used directly: the holder first calls `bind_ticket` from their new
+/// wallet, which spends the secret and reissues the ticket `Bound` under a
+/// fresh one.
+///
+/// `Key` tickets name an ownership key instead, for hardware wallets that
+/// refuse the arbitrary message signing secret derivation needs. The key
+/// signs to transfer (`transfer_keyed_ticket`) and redeem; there is no
+/// secret, so the holder is public and the ticket can't go through the
+/// secret-based flows (gifts, signed orders) until moved to a `Bound`
+/// commitment.
+///
+/// Marketplace listings take `Key` tickets only: the seller's signature
+/// completes the sale, so no secret is revealed in the transaction that
+/// hands the ticket to the buyer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum OwnershipMode {
+    /// hash(owner_pubkey || secret)
+    #[default]
+    Bound,
+    /// hash(UNBOUND_COMMITMENT_DOMAIN || secret)
+    Unbound,
+    /// The key's bytes
+    Key(Pubkey),
+}
+
+impl OwnershipMode {
+    /// Whether `owner_commitment` is well-formed for this mode. Only keyed
+    /// commitments can be checked; the others hide a secret.
+    pub fn admits(&self, owner_commitment: &[u8; 32]) -> bool {
+        match self {
+            OwnershipMode::Key(key) => key.to_bytes() == *owner_commitment,
+            OwnershipMode::Bound | OwnershipMode::Unbound => true,
+        }
+    }
+}
+
+/// Reason code recorded when the event authority voids a ticket.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VoidReason {
+    Chargeback,    // Payment reversed after mint
+    StolenPayment, // Paid with a stolen card or wallet
+    Fraud,         // Other fraudulent acquisition
+    Other,         // Organizer discretion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reissue_extends_provenance_and_clears_name() {
+        let spent = PrivateTicket {
+            ticket_id: 7,
+            owner_commitment: [1; 32],
+            original_price: 500,
+            name_commitment: Some([2; 32]),
+            link_id: Some([3; 32]),
+            provenance: [4; 32],
+            addons: 0b101,
+            ..Default::default()
+        };
+        let nullifier_address = [5; 32];
+        let issued = spent.reissue(&nullifier_address, [6; 32], OwnershipMode::Unbound);
+
+        assert_eq!(issued.ticket_id, 7);
+        assert_eq!(issued.original_price, 500);
+        assert_eq!(issued.link_id, Some([3; 32]));
+        assert_eq!(issued.addons, 0b101);
+        assert_eq!(issued.owner_commitment, [6; 32]);
+        assert_eq!(issued.ownership, OwnershipMode::Unbound);
+        assert_eq!(issued.name_commitment, None);
+        assert_eq!(
+            issued.provenance,
+            PrivateTicket::next_provenance(&[4; 32], &nullifier_address)
+        );
+    }
+
+    #[test]
+    fn keyed_holdings_get_their_own_nullifier() {
+        // A transfer moves the ticket to a new address, so the next
+        // holder's ticket can be spent again, but each holding only once
+        let first = PrivateTicket::key_nullifier_seed(&[1; 32]);
+        assert_eq!(first, PrivateTicket::key_nullifier_seed(&[1; 32]));
+        assert_ne!(first, PrivateTicket::key_nullifier_seed(&[2; 32]));
+    }
+
+    #[test]
+    fn keyed_commitment_must_be_the_key() {
+        let key = Pubkey::new_from_array([7; 32]);
+        assert!(OwnershipMode::Key(key).admits(&[7; 32]));
+        assert!(!OwnershipMode::Key(key).admits(&[8; 32]));
+        assert!(OwnershipMode::Bound.admits(&[8; 32]));
+    }
 }
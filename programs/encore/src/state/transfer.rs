@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CUSTOM_FIELDS;
+use crate::state::{FieldValue, OwnershipMode, PrivateTicket};
+
+/// Why a ticket changed hands in `transfer_ticket`.
+///
+/// Gifts carry no consideration and skip the resale cap and fees. Any
+/// declared sale is checked against the cap and charged the protocol sale fee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum TransferKind {
+    Gift,                         // No consideration
+    Sale { price_lamports: u64 }, // Settled off-chain at this price
+}
+
+impl TransferKind {
+    pub fn price_lamports(&self) -> u64 {
+        match self {
+            TransferKind::Gift => 0,
+            TransferKind::Sale { price_lamports } => *price_lamports,
+        }
+    }
+}
+
+/// Whether holders may transfer freely or need the organizer's sign-off.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace, Default,
+)]
+pub enum TransferPolicy {
+    #[default]
+    Open,
+    /// Each transfer needs an approved `TransferRequest`. The event
+    /// authority or `approver` may approve.
+    ApprovalRequired { approver: Pubkey },
+}
+
+/// Holder's request to transfer a ticket under `ApprovalRequired`.
+///
+/// Pins the exact transfer: `transfer_ticket` only executes once the request
+/// is approved and the revealed secret, new commitment and kind all match.
+#[account]
+#[derive(InitSpace)]
+pub struct TransferRequest {
+    /// Event the ticket belongs to
+    pub event_config: Pubkey,
+
+    /// Current holder who will execute the transfer
+    pub holder: Pubkey,
+
+    /// hash(secret) of the ticket being transferred
+    pub secret_hash: [u8; 32],
+
+    /// Recipient's commitment for the new ticket
+    pub new_owner_commitment: [u8; 32],
+
+    /// Gift or declared sale
+    pub kind: TransferKind,
+
+    /// When the holder submitted the request
+    pub requested_at: i64,
+
+    /// Set when the organizer approves
+    pub approved_at: Option<i64>,
+
+    /// PDA bump for request derivation
+    pub bump: u8,
+}
+
+/// First phase of a two-phase transfer: the ticket is spent, waiting for
+/// `finalize_transfer` to issue it to the recipient.
+///
+/// Keyed by the spent ticket's `secret_hash`, which is also its nullifier
+/// seed. Carries the ticket to issue, proven when preparing closed the
+/// spent ticket.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingTransfer {
+    /// Event the ticket belongs to
+    pub event_config: Pubkey,
+
+    /// Holder who prepared the transfer and finalizes it
+    pub holder: Pubkey,
+
+    /// hash(secret) of the ticket being transferred
+    pub secret_hash: [u8; 32],
+
+    /// Recipient's commitment for the new ticket
+    pub new_owner_commitment: [u8; 32],
+
+    /// Gift or declared sale
+    pub kind: TransferKind,
+
+    /// Ticket data carried to the new ticket; `provenance` already extends
+    /// the spent ticket's
+    pub ticket_id: u32,
+    pub original_price: u64,
+    pub usd_micros_per_sol: Option<u64>,
+    pub provenance: [u8; 32],
+    #[max_len(MAX_CUSTOM_FIELDS)]
+    pub custom_fields: Vec<FieldValue>,
+    pub addons: u8,
+
+    /// When the transfer was prepared
+    pub prepared_at: i64,
+
+    /// PDA bump for pending transfer derivation
+    pub bump: u8,
+}
+
+impl PendingTransfer {
+    /// The prepared ticket, issued to `owner_commitment`.
+    pub fn ticket(&self, owner_commitment: [u8; 32]) -> PrivateTicket {
+        PrivateTicket {
+            event_config: self.event_config,
+            ticket_id: self.ticket_id,
+            owner_commitment,
+            original_price: self.original_price,
+            usd_micros_per_sol: self.usd_micros_per_sol,
+            name_commitment: None,
+            link_id: None,
+            provenance: self.provenance,
+            custom_fields: self.custom_fields.clone(),
+            addons: self.addons,
+            ownership: OwnershipMode::Bound,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+/// Where a protocol fee was collected.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FeeSource {
+    Mint,
+    Sale,
+    Penalty,
+}
+
+/// Protocol treasury singleton. Fees are held as lamports on this account;
+/// the fields break revenue down by source.
+#[account]
+#[derive(InitSpace)]
+pub struct ProtocolTreasury {
+    /// Total fees collected from primary mints
+    pub mint_fees: u64,
+
+    /// Total fees collected from marketplace sales
+    pub sale_fees: u64,
+
+    /// Protocol share of forfeited seller bonds
+    pub penalty_fees: u64,
+
+    /// Total lamports withdrawn by the admin
+    pub withdrawn: u64,
+
+    /// PDA bump for treasury derivation
+    pub bump: u8,
+}
+
+impl ProtocolTreasury {
+    pub fn record_fee(&mut self, source: FeeSource, amount: u64) -> Result<()> {
+        let total = match source {
+            FeeSource::Mint => &mut self.mint_fees,
+            FeeSource::Sale => &mut self.sale_fees,
+            FeeSource::Penalty => &mut self.penalty_fees,
+        };
+        *total = total
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+/// Waitlist for one tier of an event.
+///
+/// Seats freed by voids are released into `open_seats`; each open seat lets
+/// `promote_waitlist` mint a ticket to the entry at `head`.
+#[account]
+#[derive(InitSpace)]
+pub struct Waitlist {
+    /// Event this waitlist belongs to
+    pub event_config: Pubkey,
+
+    /// Tier the waitlisted seats are in
+    pub tier: u8,
+
+    /// Price each entry escrows and pays on promotion
+    pub price_lamports: u64,
+
+    /// Entries at positions [head, tail) are waiting
+    pub head: u32,
+    pub tail: u32,
+
+    /// Freed seats not yet given to an entry
+    pub open_seats: u32,
+
+    /// PDA bump for waitlist derivation
+    pub bump: u8,
+}
+
+/// A buyer's place on a waitlist.
+///
+/// Holds the buyer's payment in its own lamports until promotion or
+/// withdrawal, like `QueuedClaim`.
+#[account]
+#[derive(InitSpace)]
+pub struct WaitlistEntry {
+    /// Waitlist this entry is on
+    pub waitlist: Pubkey,
+
+    /// Buyer who joined, receives refunds and rent
+    pub buyer: Pubkey,
+
+    /// Commitment for the ticket minted on promotion
+    pub owner_commitment: [u8; 32],
+
+    /// Random seed for the ticket address minted on promotion
+    pub ticket_address_seed: [u8; 32],
+
+    /// Rank on the waitlist (lower is served first)
+    pub position: u32,
+
+    /// Lamports escrowed on this account (excluding rent)
+    pub amount: u64,
+
+    /// Buyer withdrew; skipped on promotion
+    pub withdrawn: bool,
+
+    /// When the buyer joined
+    pub joined_at: i64,
+
+    /// PDA bump for entry derivation
+    pub bump: u8,
+}
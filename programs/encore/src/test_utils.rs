@@ -0,0 +1,422 @@
+//! Fixtures for program-test suites, built with the `test-utils` feature.
+//!
+//! The builders produce accounts in the state the matching instructions
+//! leave them in, so a suite can `set_account` an event or listing
+//! directly instead of replaying its setup transactions. The `*_data`
+//! functions encode instruction data for the common flows with the
+//! optional arguments left off.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::InstructionData;
+use light_sdk::instruction::{PackedAddressTreeInfo, ValidityProof};
+
+use crate::constants::{EVENT_SEED, LISTING_SEED};
+use crate::state::{
+    AddOn, EmissionPolicy, EventConfig, FieldDef, FieldValue, Listing, ListingStatus,
+    OwnershipMode, PrivateTicket, TicketAddressMode, TransferPolicy,
+};
+
+/// Account data (discriminator included) for an Anchor account.
+pub fn account_data<T: AccountSerialize>(account: &T) -> Vec<u8> {
+    let mut data = Vec::new();
+    account
+        .try_serialize(&mut data)
+        .expect("account serializes");
+    data
+}
+
+/// Owner commitment for `owner` holding a ticket under `secret`:
+/// SHA256(owner_pubkey || secret).
+pub fn owner_commitment(owner: &Pubkey, secret: &[u8; 32]) -> [u8; 32] {
+    let mut input = Vec::with_capacity(64);
+    input.extend_from_slice(owner.as_ref());
+    input.extend_from_slice(secret);
+    hash(&input).to_bytes()
+}
+
+/// The nullifier seed of a `Bound` ticket held with `secret`.
+pub fn secret_hash(secret: &[u8; 32]) -> [u8; 32] {
+    hash(secret).to_bytes()
+}
+
+/// Builds an `EventConfig` as `create_event` initializes it.
+pub struct EventConfigBuilder {
+    event_config: EventConfig,
+}
+
+impl EventConfigBuilder {
+    pub fn new(authority: Pubkey) -> Self {
+        let (_, bump) = Self::address(&authority);
+        Self {
+            event_config: EventConfig {
+                authority,
+                cancelled: false,
+                closed: false,
+                max_supply: 100,
+                tickets_minted: 0,
+                reserved_supply: 0,
+                reserved_minted: 0,
+                allocated_supply: 0,
+                tickets_voided: 0,
+                tickets_redeemed: 0,
+                tickets_refunded: 0,
+                marketplace_sales: 0,
+                nullifiers_created: 0,
+                gift_transfers: 0,
+                sale_transfers: 0,
+                tickets_reissued: 0,
+                resale_cap_bps: 11_000,
+                resale_cap_usd_rate: None,
+                event_name: "Test Event".to_string(),
+                event_location: "Test Venue".to_string(),
+                event_description: "Test event description".to_string(),
+                max_tickets_per_person: 4,
+                mint_rate_limit: 0,
+                mint_rate_window_seconds: 0,
+                identity_epoch_seconds: 0,
+                max_mints_per_slot: 0,
+                pow_difficulty: 0,
+                required_credential: None,
+                id_verification: None,
+                insurance_policy: None,
+                transfer_policy: TransferPolicy::Open,
+                require_paid_sales: false,
+                escrow_resale_proceeds: false,
+                require_void_memo: false,
+                require_scanner: false,
+                resale_blackout_seconds: 0,
+                fire_code_capacity: 0,
+                door_release_step: 0,
+                door_released: 0,
+                emission_policy: EmissionPolicy::Standard,
+                ticket_address_mode: TicketAddressMode::Random,
+                secret_derivation: None,
+                royalty: None,
+                custom_fields: Vec::new(),
+                addons: Vec::new(),
+                release_schedule: Vec::new(),
+                compliance_policy: None,
+                snapshot_root: None,
+                presale: None,
+                fan_lane: None,
+                settlement_mint: None,
+                credit_validity_seconds: 0,
+                event_timestamp: i64::MAX,
+                created_at: 0,
+                updated_at: 0,
+                bump,
+            },
+        }
+    }
+
+    /// The event PDA for `authority`, with its bump.
+    pub fn address(authority: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[EVENT_SEED, authority.as_ref()], &crate::ID)
+    }
+
+    pub fn max_supply(mut self, max_supply: u32) -> Self {
+        self.event_config.max_supply = max_supply;
+        self
+    }
+
+    pub fn tickets_minted(mut self, tickets_minted: u32) -> Self {
+        self.event_config.tickets_minted = tickets_minted;
+        self
+    }
+
+    pub fn resale_cap_bps(mut self, resale_cap_bps: u32) -> Self {
+        self.event_config.resale_cap_bps = resale_cap_bps;
+        self
+    }
+
+    pub fn max_tickets_per_person(mut self, max_tickets_per_person: u8) -> Self {
+        self.event_config.max_tickets_per_person = max_tickets_per_person;
+        self
+    }
+
+    pub fn transfer_policy(mut self, transfer_policy: TransferPolicy) -> Self {
+        self.event_config.transfer_policy = transfer_policy;
+        self
+    }
+
+    pub fn ticket_address_mode(mut self, ticket_address_mode: TicketAddressMode) -> Self {
+        self.event_config.ticket_address_mode = ticket_address_mode;
+        self
+    }
+
+    pub fn custom_fields(mut self, custom_fields: Vec<FieldDef>) -> Self {
+        self.event_config.custom_fields = custom_fields;
+        self
+    }
+
+    pub fn addons(mut self, addons: Vec<AddOn>) -> Self {
+        self.event_config.addons = addons;
+        self
+    }
+
+    pub fn event_timestamp(mut self, event_timestamp: i64) -> Self {
+        self.event_config.event_timestamp = event_timestamp;
+        self
+    }
+
+    pub fn cancelled(mut self, cancelled: bool) -> Self {
+        self.event_config.cancelled = cancelled;
+        self
+    }
+
+    pub fn build(self) -> EventConfig {
+        self.event_config
+    }
+
+    pub fn account_data(self) -> Vec<u8> {
+        account_data(&self.event_config)
+    }
+}
+
+/// Builds an Active `Listing` as `create_listing` initializes it.
+pub struct ListingBuilder {
+    listing: Listing,
+}
+
+impl ListingBuilder {
+    pub fn new(
+        event_config: Pubkey,
+        seller: Pubkey,
+        ticket_commitment: [u8; 32],
+        nullifier_seed: [u8; 32],
+        nonce: u64,
+    ) -> Self {
+        let (_, bump) = Self::address(&seller, &ticket_commitment, nonce);
+        Self {
+            listing: Listing {
+                event_config,
+                seller,
+                ticket_commitment,
+                nullifier_seed,
+                price_lamports: 1_000_000_000,
+                ticket_id: 0,
+                nonce,
+                queue_head: 0,
+                queue_tail: 0,
+                official_exchange: false,
+                face_value_lamports: 0,
+                seller_bond: 0,
+                auto_relist: false,
+                created_at: 0,
+                bump,
+                status: ListingStatus::Active,
+                payment_options: Vec::new(),
+                settled_at: 0,
+            },
+        }
+    }
+
+    /// The listing PDA for a seller's `nonce`th listing, with its bump.
+    pub fn address(seller: &Pubkey, ticket_commitment: &[u8; 32], nonce: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                LISTING_SEED,
+                seller.as_ref(),
+                ticket_commitment,
+                &nonce.to_le_bytes(),
+            ],
+            &crate::ID,
+        )
+    }
+
+    pub fn price_lamports(mut self, price_lamports: u64) -> Self {
+        self.listing.price_lamports = price_lamports;
+        self
+    }
+
+    pub fn ticket_id(mut self, ticket_id: u32) -> Self {
+        self.listing.ticket_id = ticket_id;
+        self
+    }
+
+    pub fn official_exchange(mut self, face_value_lamports: u64) -> Self {
+        self.listing.official_exchange = true;
+        self.listing.face_value_lamports = face_value_lamports;
+        self
+    }
+
+    pub fn seller_bond(mut self, seller_bond: u64) -> Self {
+        self.listing.seller_bond = seller_bond;
+        self
+    }
+
+    pub fn auto_relist(mut self, auto_relist: bool) -> Self {
+        self.listing.auto_relist = auto_relist;
+        self
+    }
+
+    pub fn status(mut self, status: ListingStatus) -> Self {
+        self.listing.status = status;
+        self
+    }
+
+    pub fn build(self) -> Listing {
+        self.listing
+    }
+
+    pub fn account_data(self) -> Vec<u8> {
+        account_data(&self.listing)
+    }
+}
+
+/// Builds a freshly minted `PrivateTicket`: zero provenance, no name or
+/// link.
+pub struct PrivateTicketBuilder {
+    ticket: PrivateTicket,
+}
+
+impl PrivateTicketBuilder {
+    pub fn new(event_config: Pubkey, ticket_id: u32, owner_commitment: [u8; 32]) -> Self {
+        Self {
+            ticket: PrivateTicket {
+                event_config,
+                ticket_id,
+                owner_commitment,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn original_price(mut self, original_price: u64) -> Self {
+        self.ticket.original_price = original_price;
+        self
+    }
+
+    pub fn name_commitment(mut self, name_commitment: [u8; 32]) -> Self {
+        self.ticket.name_commitment = Some(name_commitment);
+        self
+    }
+
+    pub fn link_id(mut self, link_id: [u8; 32]) -> Self {
+        self.ticket.link_id = Some(link_id);
+        self
+    }
+
+    pub fn provenance(mut self, provenance: [u8; 32]) -> Self {
+        self.ticket.provenance = provenance;
+        self
+    }
+
+    pub fn custom_fields(mut self, custom_fields: Vec<FieldValue>) -> Self {
+        self.ticket.custom_fields = custom_fields;
+        self
+    }
+
+    pub fn addons(mut self, addons: u8) -> Self {
+        self.ticket.addons = addons;
+        self
+    }
+
+    pub fn ownership(mut self, ownership: OwnershipMode) -> Self {
+        self.ticket.ownership = ownership;
+        self
+    }
+
+    pub fn build(self) -> PrivateTicket {
+        self.ticket
+    }
+}
+
+/// `create_event` instruction data.
+pub fn create_event_data(max_supply: u32, resale_cap_bps: u32, event_timestamp: i64) -> Vec<u8> {
+    crate::instruction::CreateEvent {
+        max_supply,
+        resale_cap_bps,
+        event_name: "Test Event".to_string(),
+        event_location: "Test Venue".to_string(),
+        event_description: "Test event description".to_string(),
+        max_tickets_per_person: 4,
+        event_timestamp,
+        settlement_mint: None,
+    }
+    .data()
+}
+
+/// `mint_ticket` instruction data for a plain mint: no identity counter,
+/// credit, companion, custom fields, add-ons or allowlist proof.
+/// `Key` tickets take the key's bytes as `owner_commitment`.
+pub fn mint_ticket_data(
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+    output_state_tree_index: u8,
+    owner_commitment: [u8; 32],
+    purchase_price: u64,
+    ticket_address_seed: [u8; 32],
+    ownership: OwnershipMode,
+) -> Vec<u8> {
+    crate::instruction::MintTicket {
+        proof,
+        address_tree_info,
+        output_state_tree_index,
+        owner_commitment,
+        purchase_price,
+        ticket_address_seed,
+        identity_account_meta: None,
+        current_identity: None,
+        pow_slot: 0,
+        pow_nonce: 0,
+        credit_account_meta: None,
+        current_credit: None,
+        name_commitment: None,
+        companion: None,
+        custom_fields: Vec::new(),
+        addons: 0,
+        allowlist_proof: None,
+        proof_window: None,
+        ownership,
+    }
+    .data()
+}
+
+/// `create_listing` instruction data for a plain SOL listing of a `Key`
+/// ticket: no official exchange pricing, bond or auto-relist.
+pub fn create_listing_data(
+    ticket: &PrivateTicket,
+    nullifier_seed: [u8; 32],
+    price_lamports: u64,
+    ticket_address_seed: [u8; 32],
+    ticket_bump: u8,
+) -> Vec<u8> {
+    crate::instruction::CreateListing {
+        ticket_commitment: ticket.owner_commitment,
+        nullifier_seed,
+        price_lamports,
+        event_config: ticket.event_config,
+        ticket_id: ticket.ticket_id,
+        ticket_address_seed,
+        ticket_bump,
+        official_exchange: None,
+        seller_bond: 0,
+        auto_relist: false,
+        original_price: ticket.original_price,
+    }
+    .data()
+}
+
+/// `claim_listing` instruction data.
+pub fn claim_listing_data(
+    buyer_commitment: [u8; 32],
+    client_nonce: u64,
+    proof: ValidityProof,
+    address_tree_info: PackedAddressTreeInfo,
+) -> Vec<u8> {
+    crate::instruction::ClaimListing {
+        buyer_commitment,
+        client_nonce,
+        proof,
+        address_tree_info,
+        proof_window: None,
+    }
+    .data()
+}
+
+/// `cancel_listing` instruction data.
+pub fn cancel_listing_data() -> Vec<u8> {
+    crate::instruction::CancelListing {}.data()
+}
@@ -0,0 +1,585 @@
+//! End-to-end lifecycle smoke test against a live cluster.
+//!
+//! Runs create event -> mint -> list -> claim -> complete -> redeem against
+//! a real RPC node and Photon indexer, checking on-chain state after each
+//! step. LightProgramTest fakes the indexer and prover; this catches the
+//! integration issues it can't (indexer lag, proof/root mismatches, tree
+//! selection).
+//!
+//! ```bash
+//! ENCORE_RPC_URL=https://api.devnet.solana.com \
+//! ENCORE_PHOTON_URL=https://devnet.helius-rpc.com \
+//! ENCORE_PHOTON_API_KEY=... \
+//! cargo test -p encore --features e2e --test e2e
+//! ```
+//!
+//! The payer (`ENCORE_PAYER`, default `~/.config/solana/id.json`) funds
+//! fresh keypairs for the organizer, seller and buyer, so every run starts
+//! from a new event. The protocol config, treasury and metrics must
+//! already be initialized on the target cluster.
+
+use anchor_lang::{AccountDeserialize, AnchorDeserialize, InstructionData, ToAccountMetas};
+use encore::{
+    constants::{
+        BLOCKED_BUYER_SEED, ESCROW_SEED, IDENTITY_COUNTER_SEED, LEDGER_SEED, METRICS_SEED,
+        PROTOCOL_SEED, REPUTATION_SEED, SELLER_COUNTER_SEED, TICKET_SEED, TREASURY_SEED,
+    },
+    instruction as encore_ix,
+    instructions::ticket_transfer::NULLIFIER_PREFIX,
+    state::{EventConfig, Listing, ListingStatus, OwnershipMode, PrivateTicket},
+    test_utils::{
+        claim_listing_data, create_event_data, create_listing_data, mint_ticket_data,
+        owner_commitment, secret_hash, EventConfigBuilder, ListingBuilder,
+    },
+};
+use light_client::{
+    indexer::{AddressWithTree, CompressedAccount, Indexer, TreeInfo},
+    rpc::{LightClient, LightClientConfig, Rpc},
+};
+use light_sdk::{
+    address::v2::derive_address,
+    instruction::{account_meta::CompressedAccountMeta, PackedAccounts, SystemAccountMetaConfig},
+};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_instruction, system_program,
+    sysvar::instructions as ix_sysvar,
+};
+
+const FUNDING_LAMPORTS: u64 = 200_000_000;
+const TICKET_PRICE: u64 = 1_000_000;
+const RESALE_PRICE: u64 = 1_100_000;
+
+type StepResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    if let Err(error) = run().await {
+        eprintln!("❌ e2e failed: {error}");
+        std::process::exit(1);
+    }
+    println!("✅ e2e lifecycle passed");
+}
+
+async fn run() -> StepResult<()> {
+    let mut rpc = connect().await?;
+    let payer = read_keypair_file(payer_path())?;
+
+    let (protocol_config, _) = Pubkey::find_program_address(&[PROTOCOL_SEED], &encore::ID);
+    let (treasury, _) = Pubkey::find_program_address(&[TREASURY_SEED], &encore::ID);
+    let (metrics, _) = Pubkey::find_program_address(&[METRICS_SEED], &encore::ID);
+    if rpc.get_account(protocol_config).await?.is_none() {
+        return Err("protocol config is not initialized on this cluster".into());
+    }
+
+    let organizer = Keypair::new();
+    let seller = Keypair::new();
+    let buyer = Keypair::new();
+    fund(&mut rpc, &payer, &[&organizer, &seller, &buyer]).await?;
+
+    let address_tree = rpc.get_address_tree_v2();
+
+    // --- 1. Create event ---
+    let (event_config, _) = EventConfigBuilder::address(&organizer.pubkey());
+    let (ledger, _) =
+        Pubkey::find_program_address(&[LEDGER_SEED, event_config.as_ref()], &encore::ID);
+    let event_timestamp = unix_now() + 30 * 24 * 60 * 60;
+    let instruction = Instruction {
+        program_id: encore::ID,
+        accounts: encore::accounts::CreateEvent {
+            authority: organizer.pubkey(),
+            event_config,
+            ledger,
+            metrics,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: create_event_data(10, 20_000, event_timestamp),
+    };
+    rpc.create_and_send_transaction(&[instruction], &organizer.pubkey(), &[&organizer])
+        .await?;
+
+    let event: EventConfig = fetch(&mut rpc, event_config).await?;
+    check(event.authority == organizer.pubkey(), "event authority")?;
+    check(event.tickets_minted == 0, "fresh event has no tickets")?;
+    println!("✅ 1/6 created event {event_config}");
+
+    // --- 2. Mint a ticket to the seller ---
+    // Listings take tickets held under the seller's key
+    let seller_commitment = seller.pubkey().to_bytes();
+    let ticket_seed = random_bytes();
+    let ticket_address = address(&[TICKET_SEED, &ticket_seed], &address_tree);
+    let identity_address = address(
+        &[
+            IDENTITY_COUNTER_SEED,
+            event_config.as_ref(),
+            seller.pubkey().as_ref(),
+        ],
+        &address_tree,
+    );
+
+    let mut remaining_accounts = light_accounts()?;
+    let proof = rpc
+        .get_validity_proof(
+            vec![],
+            vec![
+                AddressWithTree {
+                    address: ticket_address,
+                    tree: address_tree.tree,
+                },
+                AddressWithTree {
+                    address: identity_address,
+                    tree: address_tree.tree,
+                },
+            ],
+            None,
+        )
+        .await?
+        .value;
+    let packed = proof.pack_tree_infos(&mut remaining_accounts);
+    let output_state_tree_index = rpc
+        .get_random_state_tree_info()?
+        .pack_output_tree_index(&mut remaining_accounts)?;
+
+    let accounts = encore::accounts::MintTicket {
+        buyer: seller.pubkey(),
+        event_owner: organizer.pubkey(),
+        event_config,
+        blocked_buyer: blocked_buyer(&event_config, &seller.pubkey()),
+        attestation: None,
+        insurance_coverage: None,
+        insurance_pool: None,
+        ledger,
+        price_feed: None,
+        protocol_config,
+        treasury,
+        metrics,
+        fee_exemption: None,
+        box_office: None,
+        policy_hook_program: None,
+        policy_attestation: None,
+        reference: None,
+        fan_attestation: None,
+        relayer: None,
+        sponsor_pool: None,
+        system_program: system_program::ID,
+    };
+    let instruction = light_instruction(
+        accounts.to_account_metas(None),
+        remaining_accounts,
+        mint_ticket_data(
+            proof.proof,
+            packed.address_trees[0],
+            output_state_tree_index,
+            seller_commitment,
+            TICKET_PRICE,
+            ticket_seed,
+            OwnershipMode::Key(seller.pubkey()),
+        ),
+    );
+    rpc.create_and_send_transaction(&[instruction], &seller.pubkey(), &[&seller])
+        .await?;
+
+    let event: EventConfig = fetch(&mut rpc, event_config).await?;
+    check(event.tickets_minted == 1, "tickets_minted == 1")?;
+    let (_, ticket) = fetch_ticket(&mut rpc, ticket_address).await?;
+    check(
+        ticket.owner_commitment == seller_commitment,
+        "ticket owned by seller",
+    )?;
+    check(
+        ticket.original_price == TICKET_PRICE,
+        "ticket original price",
+    )?;
+    println!("✅ 2/6 minted ticket {}", ticket.ticket_id);
+
+    // --- 3. List it ---
+    let seller_nullifier_seed = PrivateTicket::key_nullifier_seed(&ticket_address);
+    let (seller_counter, _) = Pubkey::find_program_address(
+        &[SELLER_COUNTER_SEED, seller.pubkey().as_ref()],
+        &encore::ID,
+    );
+    let (listing, _) = ListingBuilder::address(&seller.pubkey(), &seller_commitment, 0);
+    let instruction = Instruction {
+        program_id: encore::ID,
+        accounts: encore::accounts::CreateListing {
+            seller: seller.pubkey(),
+            seller_counter,
+            listing,
+            protocol_config: None,
+            listed_event: event_config,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: create_listing_data(&ticket, seller_nullifier_seed, RESALE_PRICE, ticket_seed, 0),
+    };
+    rpc.create_and_send_transaction(&[instruction], &seller.pubkey(), &[&seller])
+        .await?;
+
+    let listed: Listing = fetch(&mut rpc, listing).await?;
+    check(listed.status == ListingStatus::Active, "listing is Active")?;
+    check(listed.price_lamports == RESALE_PRICE, "listing price")?;
+    println!("✅ 3/6 listed at {listing}");
+
+    // --- 4. Buyer claims ---
+    let buyer_secret = random_bytes();
+    let buyer_commitment = owner_commitment(&buyer.pubkey(), &buyer_secret);
+    let (escrow, _) = Pubkey::find_program_address(&[ESCROW_SEED, listing.as_ref()], &encore::ID);
+    let seller_nullifier = address(&[NULLIFIER_PREFIX, &seller_nullifier_seed], &address_tree);
+
+    let mut remaining_accounts = light_accounts()?;
+    let proof = rpc
+        .get_validity_proof(
+            vec![],
+            vec![AddressWithTree {
+                address: seller_nullifier,
+                tree: address_tree.tree,
+            }],
+            None,
+        )
+        .await?
+        .value;
+    let packed = proof.pack_tree_infos(&mut remaining_accounts);
+
+    let accounts = encore::accounts::ClaimListing {
+        buyer: buyer.pubkey(),
+        listing,
+        escrow,
+        blocked_buyer: blocked_buyer(&event_config, &buyer.pubkey()),
+        ledger,
+        client_nonce_record: None,
+        payment_mint: None,
+        buyer_token_account: None,
+        escrow_token_account: None,
+        reference: None,
+        token_program: None,
+        associated_token_program: None,
+        system_program: system_program::ID,
+    };
+    let instruction = light_instruction(
+        accounts.to_account_metas(None),
+        remaining_accounts,
+        claim_listing_data(buyer_commitment, 0, proof.proof, packed.address_trees[0]),
+    );
+    rpc.create_and_send_transaction(&[instruction], &buyer.pubkey(), &[&buyer])
+        .await?;
+
+    let listed: Listing = fetch(&mut rpc, listing).await?;
+    check(
+        matches!(listed.status, ListingStatus::Claimed(_)),
+        "listing is Claimed",
+    )?;
+    let escrowed = rpc.get_balance(&escrow).await?;
+    check(escrowed >= RESALE_PRICE, "escrow holds the price")?;
+    println!("✅ 4/6 claimed, {escrowed} lamports in escrow");
+
+    // --- 5. Seller completes ---
+    let (listed_account, ticket) = fetch_ticket(&mut rpc, ticket_address).await?;
+    let new_ticket_seed = random_bytes();
+    let new_ticket_address = address(&[TICKET_SEED, &new_ticket_seed], &address_tree);
+    let (seller_reputation, _) =
+        Pubkey::find_program_address(&[REPUTATION_SEED, seller.pubkey().as_ref()], &encore::ID);
+
+    let mut remaining_accounts = light_accounts()?;
+    let proof = rpc
+        .get_validity_proof(
+            vec![listed_account.hash],
+            vec![
+                AddressWithTree {
+                    address: seller_nullifier,
+                    tree: address_tree.tree,
+                },
+                AddressWithTree {
+                    address: new_ticket_address,
+                    tree: address_tree.tree,
+                },
+            ],
+            None,
+        )
+        .await?
+        .value;
+    // The seller's nullifier must not exist yet: proven separately, as a
+    // read-only address, before the sale spends the ticket
+    let unspent_proof = rpc
+        .get_validity_proof(
+            vec![],
+            vec![AddressWithTree {
+                address: seller_nullifier,
+                tree: address_tree.tree,
+            }],
+            None,
+        )
+        .await?
+        .value
+        .proof;
+    let packed = proof.pack_tree_infos(&mut remaining_accounts);
+    let state_trees = packed
+        .state_trees
+        .ok_or("validity proof is missing the ticket's state tree")?;
+    let ticket_account_meta = CompressedAccountMeta {
+        tree_info: state_trees.packed_tree_infos[0],
+        address: ticket_address,
+        output_state_tree_index: state_trees.output_tree_index,
+    };
+
+    let accounts = encore::accounts::CompleteSale {
+        seller: seller.pubkey(),
+        payer: seller.pubkey(),
+        listing,
+        escrow,
+        ledger,
+        protocol_config,
+        treasury,
+        metrics,
+        seller_reputation,
+        event_config,
+        fee_exemption: None,
+        instructions_sysvar: ix_sysvar::ID,
+        proceeds_hold: None,
+        royalty_vault: None,
+        escrow_token_account: None,
+        seller_token_account: None,
+        treasury_token_account: None,
+        token_program: None,
+        system_program: system_program::ID,
+    };
+    let instruction = light_instruction(
+        accounts.to_account_metas(None),
+        remaining_accounts,
+        encore_ix::CompleteSale {
+            proof: proof.proof,
+            unspent_proof,
+            address_tree_info: packed.address_trees[0],
+            output_state_tree_index: state_trees.output_tree_index,
+            new_ticket_address_seed: new_ticket_seed,
+            ticket_bump: 0,
+            ticket_account_meta,
+            current_original_price: ticket.original_price,
+            current_usd_micros_per_sol: ticket.usd_micros_per_sol,
+            current_name_commitment: ticket.name_commitment,
+            current_provenance: ticket.provenance,
+            current_custom_fields: ticket.custom_fields.clone(),
+            current_addons: ticket.addons,
+            proof_window: None,
+            queued_refunds: 0,
+        }
+        .data(),
+    );
+    rpc.create_and_send_transaction(&[instruction], &seller.pubkey(), &[&seller])
+        .await?;
+
+    let listed: Listing = fetch(&mut rpc, listing).await?;
+    check(
+        listed.status == ListingStatus::Completed,
+        "listing is Completed",
+    )?;
+    let (_, sold) = fetch_ticket(&mut rpc, new_ticket_address).await?;
+    check(
+        sold.owner_commitment == buyer_commitment,
+        "ticket owned by buyer",
+    )?;
+    check(sold.ticket_id == ticket.ticket_id, "ticket id carried over")?;
+    check(
+        sold.original_price == TICKET_PRICE,
+        "face value carried over",
+    )?;
+    let spent = rpc
+        .get_compressed_account(ticket_address, None)
+        .await
+        .map(|response| response.value.is_none())
+        .unwrap_or(true);
+    check(spent, "seller's ticket is closed")?;
+    let event: EventConfig = fetch(&mut rpc, event_config).await?;
+    check(event.marketplace_sales == 1, "marketplace_sales == 1")?;
+    println!("✅ 5/6 sale completed, ticket now at {new_ticket_address:?}");
+
+    // --- 6. Buyer redeems ---
+    let (sold_account, sold) = fetch_ticket(&mut rpc, new_ticket_address).await?;
+    let buyer_nullifier = address(
+        &[NULLIFIER_PREFIX, &secret_hash(&buyer_secret)],
+        &address_tree,
+    );
+
+    let mut remaining_accounts = light_accounts()?;
+    let proof = rpc
+        .get_validity_proof(
+            vec![sold_account.hash],
+            vec![AddressWithTree {
+                address: buyer_nullifier,
+                tree: address_tree.tree,
+            }],
+            None,
+        )
+        .await?
+        .value;
+    let packed = proof.pack_tree_infos(&mut remaining_accounts);
+    let state_trees = packed
+        .state_trees
+        .ok_or("validity proof is missing the ticket's state tree")?;
+    let ticket_account_meta = CompressedAccountMeta {
+        tree_info: state_trees.packed_tree_infos[0],
+        address: new_ticket_address,
+        output_state_tree_index: state_trees.output_tree_index,
+    };
+
+    let accounts = encore::accounts::RedeemTicket {
+        holder: buyer.pubkey(),
+        event_owner: organizer.pubkey(),
+        event_config,
+        credential_attestation: None,
+        attestation: None,
+        companion_attestation: None,
+        policy_hook_program: None,
+        policy_attestation: None,
+        proceeds_hold: None,
+        scanner: None,
+        scanner_device: None,
+        entrance_stats: None,
+    };
+    let instruction = light_instruction(
+        accounts.to_account_metas(None),
+        remaining_accounts,
+        encore_ix::RedeemTicket {
+            proof: proof.proof,
+            address_tree_info: packed.address_trees[0],
+            output_state_tree_index: state_trees.output_tree_index,
+            ticket_account_meta,
+            ticket_id: sold.ticket_id,
+            original_price: sold.original_price,
+            usd_micros_per_sol: sold.usd_micros_per_sol,
+            name_commitment: sold.name_commitment,
+            link_id: sold.link_id,
+            provenance: sold.provenance,
+            custom_fields: sold.custom_fields,
+            addons: sold.addons,
+            holder_secret: buyer_secret,
+            companion: None,
+            store_receipt: false,
+            zone: 0,
+            proof_window: None,
+            ownership: OwnershipMode::Bound,
+        }
+        .data(),
+    );
+    rpc.create_and_send_transaction(&[instruction], &buyer.pubkey(), &[&buyer])
+        .await?;
+
+    let event: EventConfig = fetch(&mut rpc, event_config).await?;
+    check(event.tickets_redeemed == 1, "tickets_redeemed == 1")?;
+    let closed = rpc
+        .get_compressed_account(new_ticket_address, None)
+        .await
+        .map(|response| response.value.is_none())
+        .unwrap_or(true);
+    check(closed, "redeemed ticket is closed")?;
+    println!("✅ 6/6 ticket {} redeemed", sold.ticket_id);
+
+    Ok(())
+}
+
+async fn connect() -> StepResult<LightClient> {
+    let rpc_url =
+        std::env::var("ENCORE_RPC_URL").unwrap_or_else(|_| "https://api.devnet.solana.com".into());
+    let photon_url = std::env::var("ENCORE_PHOTON_URL")
+        .unwrap_or_else(|_| "https://devnet.helius-rpc.com".into());
+    let api_key = std::env::var("ENCORE_PHOTON_API_KEY").ok();
+    println!("RPC {rpc_url}, indexer {photon_url}");
+    Ok(LightClient::new(LightClientConfig::new(rpc_url, Some(photon_url), api_key)).await?)
+}
+
+fn payer_path() -> String {
+    std::env::var("ENCORE_PAYER").unwrap_or_else(|_| {
+        let home = std::env::var("HOME").unwrap_or_default();
+        format!("{home}/.config/solana/id.json")
+    })
+}
+
+async fn fund(rpc: &mut LightClient, payer: &Keypair, recipients: &[&Keypair]) -> StepResult<()> {
+    let instructions: Vec<Instruction> = recipients
+        .iter()
+        .map(|recipient| {
+            system_instruction::transfer(&payer.pubkey(), &recipient.pubkey(), FUNDING_LAMPORTS)
+        })
+        .collect();
+    rpc.create_and_send_transaction(&instructions, &payer.pubkey(), &[payer])
+        .await?;
+    Ok(())
+}
+
+/// Fetch and decode an Anchor account, failing if it doesn't exist.
+async fn fetch<T: AccountDeserialize>(rpc: &mut LightClient, address: Pubkey) -> StepResult<T> {
+    let account = rpc
+        .get_account(address)
+        .await?
+        .ok_or_else(|| format!("account {address} not found"))?;
+    Ok(T::try_deserialize(&mut account.data.as_slice())?)
+}
+
+/// Fetch a compressed ticket from the indexer and decode it.
+async fn fetch_ticket(
+    rpc: &mut LightClient,
+    address: [u8; 32],
+) -> StepResult<(CompressedAccount, PrivateTicket)> {
+    let account = rpc
+        .get_compressed_account(address, None)
+        .await?
+        .value
+        .ok_or_else(|| format!("ticket {address:?} not indexed"))?;
+    let data = account
+        .data
+        .as_ref()
+        .ok_or("compressed ticket has no data")?;
+    let ticket = PrivateTicket::deserialize(&mut data.data.as_slice())?;
+    Ok((account, ticket))
+}
+
+fn light_accounts() -> StepResult<PackedAccounts> {
+    let mut remaining_accounts = PackedAccounts::default();
+    remaining_accounts.add_system_accounts_v2(SystemAccountMetaConfig::new(encore::ID))?;
+    Ok(remaining_accounts)
+}
+
+fn light_instruction(
+    accounts: Vec<AccountMeta>,
+    remaining_accounts: PackedAccounts,
+    data: Vec<u8>,
+) -> Instruction {
+    let (remaining_metas, _, _) = remaining_accounts.to_account_metas();
+    Instruction {
+        program_id: encore::ID,
+        accounts: [accounts, remaining_metas].concat(),
+        data,
+    }
+}
+
+fn address(seeds: &[&[u8]], address_tree: &TreeInfo) -> [u8; 32] {
+    derive_address(seeds, &address_tree.tree, &encore::ID).0
+}
+
+fn blocked_buyer(event_config: &Pubkey, buyer: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[BLOCKED_BUYER_SEED, event_config.as_ref(), buyer.as_ref()],
+        &encore::ID,
+    )
+    .0
+}
+
+fn random_bytes() -> [u8; 32] {
+    Keypair::new().pubkey().to_bytes()
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+fn check(holds: bool, what: &str) -> StepResult<()> {
+    if !holds {
+        return Err(format!("state check failed: {what}").into());
+    }
+    Ok(())
+}